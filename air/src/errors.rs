@@ -0,0 +1,102 @@
+use core::fmt;
+use vm_core::utils::string::String;
+
+// PROOF OPTIONS ERROR
+// ================================================================================================
+
+/// An error which can be generated while building a set of [ProofOptions](super::ProofOptions).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProofOptionsError {
+    BlowupFactorNotPowerOfTwo(usize),
+    BlowupFactorTooLarge(usize, usize),
+    BlowupFactorTooSmall(usize, usize),
+    FriFoldingFactorInvalid(usize),
+    FriMaxRemainderSizeInvalid(usize),
+    GrindingFactorTooLarge(u32, u32),
+    NumQueriesTooLarge(usize, usize),
+    NumQueriesZero,
+    SecurityLevelNotReached(u32, u32),
+    UnsupportedSecurityLevel(u32),
+}
+
+impl fmt::Display for ProofOptionsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ProofOptionsError::*;
+        match self {
+            BlowupFactorNotPowerOfTwo(value) => {
+                write!(f, "blowup factor must be a power of two, but was {value}")
+            }
+            BlowupFactorTooLarge(value, max) => {
+                write!(f, "blowup factor cannot be greater than {max}, but was {value}")
+            }
+            BlowupFactorTooSmall(value, min) => {
+                write!(f, "blowup factor cannot be smaller than {min}, but was {value}")
+            }
+            FriFoldingFactorInvalid(value) => {
+                write!(f, "FRI folding factor must be 2, 4, 8, or 16, but was {value}")
+            }
+            FriMaxRemainderSizeInvalid(value) => {
+                write!(
+                    f,
+                    "FRI max remainder size must be one less than a power of two and cannot be \
+                    greater than 255, but was {value}"
+                )
+            }
+            GrindingFactorTooLarge(value, max) => {
+                write!(f, "grinding factor cannot be greater than {max}, but was {value}")
+            }
+            NumQueriesTooLarge(value, max) => {
+                write!(f, "number of queries cannot be greater than {max}, but was {value}")
+            }
+            NumQueriesZero => write!(f, "number of queries must be greater than 0"),
+            SecurityLevelNotReached(target, actual) => {
+                write!(
+                    f,
+                    "{target}-bit security cannot be reached with the specified field extension \
+                    and hash function; at most {actual} bits can be reached"
+                )
+            }
+            UnsupportedSecurityLevel(value) => {
+                write!(f, "security level must be either 96 or 128 bits, but was {value}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ProofOptionsError {}
+
+// PARSE OPTION ERROR
+// ================================================================================================
+
+/// An error which can be generated while parsing a [HashFunction](super::HashFunction) or a
+/// [FieldExtension](super::FieldExtension) from its name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseOptionError {
+    UnknownFieldExtension(String),
+    UnknownHashFunction(String),
+}
+
+impl fmt::Display for ParseOptionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ParseOptionError::*;
+        match self {
+            UnknownFieldExtension(name) => {
+                write!(
+                    f,
+                    "unknown field extension '{name}'; expected one of: none, quadratic, cubic"
+                )
+            }
+            UnknownHashFunction(name) => {
+                write!(
+                    f,
+                    "unknown hash function '{name}'; expected one of: blake3_192, blake3_256, \
+                    rpo256"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseOptionError {}
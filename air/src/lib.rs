@@ -20,6 +20,9 @@ use constraints::{chiplets, range};
 pub mod trace;
 use trace::*;
 
+mod errors;
+pub use errors::{ParseOptionError, ProofOptionsError};
+
 mod proof;
 
 mod utils;
@@ -28,7 +31,10 @@ use utils::TransitionConstraintRange;
 // EXPORTS
 // ================================================================================================
 
-pub use proof::{ExecutionProof, HashFunction, ProofOptions};
+pub use proof::{
+    field_extension_name, parse_field_extension, ExecutionProof, HashFunction, ProofOptions,
+    ProofOptionsBuilder, PROOF_FORMAT_VERSION,
+};
 pub use vm_core::{
     utils::{DeserializationError, ToElements},
     Felt, FieldElement, StarkField,
@@ -246,6 +252,17 @@ impl Air for ProcessorAir {
 // PUBLIC INPUTS
 // ================================================================================================
 
+/// Public inputs of a Miden VM execution proof.
+///
+/// Public inputs consist of the program info (i.e., the program hash and the kernel), and the
+/// initial and final states of the operand stack.
+///
+/// Public inputs do not include roots of the Merkle trees stored in the advice provider. To prove
+/// that a program operated over a Merkle tree with a known root, the root should be provided to
+/// the program via the stack inputs (or computed by the program from values which are bound by the
+/// program hash): Merkle path requests (e.g., `mtree_get`, `mtree_verify`) are constrained to
+/// use the root located on the operand stack, and thus, the verifier can check the root by
+/// checking the stack inputs.
 #[derive(Debug)]
 pub struct PublicInputs {
     program_info: ProgramInfo,
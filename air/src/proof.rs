@@ -1,10 +1,69 @@
-use super::DeserializationError;
+use super::{
+    trace::{AUX_TRACE_WIDTH, MIN_TRACE_LEN, TRACE_WIDTH},
+    DeserializationError, ParseOptionError, ProofOptionsError,
+};
+use core::{cmp, fmt, str::FromStr};
 use vm_core::{
     crypto::hash::{Blake3_192, Blake3_256, Hasher, Rpo256},
-    utils::collections::Vec,
+    utils::{
+        collections::Vec,
+        string::{String, ToString},
+        ByteReader, ByteWriter, Deserializable, Serializable,
+    },
+    Felt, FieldElement, StarkField,
 };
 use winter_air::{proof::StarkProof, FieldExtension, ProofOptions as WinterProofOptions};
 
+#[cfg(feature = "serde")]
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "std")]
+use winter_air::proof::{Commitments, Context, OodFrame, Queries};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Maximum number of queries supported by the STARK protocol.
+const MAX_NUM_QUERIES: usize = 255;
+
+/// Minimum blowup factor required to evaluate the highest-degree constraints of Miden VM AIR.
+const MIN_BLOWUP_FACTOR: usize = 8;
+
+/// Minimum blowup factor supported by the STARK protocol.
+const MIN_STARK_BLOWUP_FACTOR: usize = 2;
+
+/// Maximum blowup factor supported by the STARK protocol.
+const MAX_BLOWUP_FACTOR: usize = 128;
+
+/// Maximum grinding factor (in bits) supported by the STARK protocol.
+const MAX_GRINDING_FACTOR: u32 = 32;
+
+/// Maximum size of the last FRI layer supported by the STARK protocol.
+const MAX_FRI_REMAINDER_SIZE: usize = 255;
+
+/// Query security level (in bits) below which grinding does not contribute to proof soundness.
+const GRINDING_CONTRIBUTION_FLOOR: u32 = 80;
+
+/// Maximum grinding factor (in bits) selected by [ProofOptions::for_target_security()].
+const MAX_TUNED_GRINDING_FACTOR: u32 = 16;
+
+/// Number of columns in the constraint composition polynomial of Miden VM AIR; this is defined by
+/// the highest degree of the transition constraints (which is 9).
+const NUM_COMPOSITION_COLUMNS: usize = 8;
+
+/// Upper bound on the number of bytes used to encode proof context, and the headers of all other
+/// proof components.
+const PROOF_HEADER_SIZE: usize = 64;
+
+/// Version of the binary format of [ExecutionProof] written by this crate.
+pub const PROOF_FORMAT_VERSION: u8 = 1;
+
+/// Byte preceding the format version in serialized proofs. Proofs of version 0 were written
+/// without a version, and start with the hash function instead; the marker is not a valid hash
+/// function, and thus, the two formats can be told apart.
+const PROOF_VERSION_MARKER: u8 = 0xff;
+
 // EXECUTION PROOF
 // ================================================================================================
 
@@ -12,10 +71,15 @@ use winter_air::{proof::StarkProof, FieldExtension, ProofOptions as WinterProofO
 ///
 /// The proof encodes the proof itself as well as STARK protocol parameters used to generate the
 /// proof. However, the proof does not contain public inputs needed to verify the proof.
+///
+/// The proof also records the version of the binary format it was read from (see
+/// [ExecutionProof::version()]), so that verifiers can reject proofs written in a format they do
+/// not support.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ExecutionProof {
     pub proof: StarkProof,
     pub hash_fn: HashFunction,
+    version: u8,
 }
 
 impl ExecutionProof {
@@ -24,8 +88,14 @@ impl ExecutionProof {
 
     /// Creates a new instance of [ExecutionProof] from the specified STARK proof and hash
     /// function.
+    ///
+    /// The proof is assigned the current format version (see [PROOF_FORMAT_VERSION]).
     pub const fn new(proof: StarkProof, hash_fn: HashFunction) -> Self {
-        Self { proof, hash_fn }
+        Self {
+            proof,
+            hash_fn,
+            version: PROOF_FORMAT_VERSION,
+        }
     }
 
     // PUBLIC ACCESSORS
@@ -41,7 +111,21 @@ impl ExecutionProof {
         self.hash_fn
     }
 
+    /// Returns the version of the binary format of this proof.
+    ///
+    /// For proofs created via [ExecutionProof::new()], this is [PROOF_FORMAT_VERSION]; for
+    /// deserialized proofs, this is the version they were serialized with (0 for proofs written
+    /// by releases which did not version the format).
+    pub const fn version(&self) -> u8 {
+        self.version
+    }
+
     /// Returns conjectured security level of this proof in bits.
+    ///
+    /// The security level is computed from the parameters embedded into the proof (the blowup
+    /// factor, the number of queries, the grinding factor, and the field extension), the length of
+    /// the execution trace, and the collision resistance of the hash function used to generate the
+    /// proof.
     pub fn security_level(&self) -> u32 {
         match self.hash_fn {
             HashFunction::Blake3_192 => self.proof.security_level::<Blake3_192>(true),
@@ -50,26 +134,135 @@ impl ExecutionProof {
         }
     }
 
+    /// Returns proven security level of this proof in bits.
+    ///
+    /// Unlike [ExecutionProof::security_level()], which relies on conjectured soundness of the
+    /// FRI protocol, this is based on provable soundness bounds, and thus, is lower than the
+    /// conjectured security level for the same proof.
+    ///
+    /// This method is available only when the `std` feature is enabled, since Winterfell computes
+    /// provable soundness bounds only in `std` builds.
+    #[cfg(feature = "std")]
+    pub fn proven_security_level(&self) -> u32 {
+        match self.hash_fn {
+            HashFunction::Blake3_192 => self.proof.security_level::<Blake3_192>(false),
+            HashFunction::Blake3_256 => self.proof.security_level::<Blake3_256>(false),
+            HashFunction::Rpo256 => self.proof.security_level::<Rpo256>(false),
+        }
+    }
+
     // SERIALIZATION / DESERIALIZATION
     // --------------------------------------------------------------------------------------------
 
     /// Serializes this proof into a vector of bytes.
+    ///
+    /// The bytes consist of a version marker byte, the format version of this proof, the hash
+    /// function, and the serialized STARK proof.
     pub fn to_bytes(&self) -> Vec<u8> {
         let mut bytes = self.proof.to_bytes();
         assert!(!bytes.is_empty(), "invalid STARK proof");
-        // TODO: ideally we should write hash function into the proof first to avoid reallocations
-        bytes.insert(0, self.hash_fn as u8);
+        // TODO: ideally we should write the header into the proof first to avoid reallocations
+        bytes.splice(0..0, [PROOF_VERSION_MARKER, self.version, self.hash_fn as u8]);
         bytes
     }
 
     /// Reads the source bytes, parsing a new proof instance.
+    ///
+    /// Both versioned proofs and proofs written by releases which did not version the format
+    /// (i.e., proofs consisting of the hash function followed by the STARK proof) are accepted;
+    /// the latter are assigned version 0.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The format version of the proof is newer than [PROOF_FORMAT_VERSION].
+    /// - The source does not contain a valid proof, including when the source is truncated or
+    ///   contains trailing bytes.
     pub fn from_bytes(source: &[u8]) -> Result<Self, DeserializationError> {
+        let (version, source) = match source {
+            [PROOF_VERSION_MARKER, version, rest @ ..] => (check_format_version(*version)?, rest),
+            [PROOF_VERSION_MARKER] => return Err(DeserializationError::UnexpectedEOF),
+            _ => (0, source),
+        };
         if source.len() < 2 {
             return Err(DeserializationError::UnexpectedEOF);
         }
         let hash_fn = HashFunction::try_from(source[0])?;
         let proof = StarkProof::from_bytes(&source[1..])?;
-        Ok(Self::new(proof, hash_fn))
+        Ok(Self {
+            proof,
+            hash_fn,
+            version,
+        })
+    }
+
+    /// Writes this proof into the specified writer.
+    ///
+    /// The written bytes are identical to the output of [ExecutionProof::to_bytes()], but the
+    /// proof is written component by component without first serializing it into a single vector
+    /// of bytes.
+    ///
+    /// # Errors
+    /// Returns an error if writing into the writer fails.
+    #[cfg(feature = "std")]
+    pub fn write_into<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let mut target = IoWriter::new(writer);
+        target.write_u8(PROOF_VERSION_MARKER);
+        target.write_u8(self.version);
+        target.write_u8(self.hash_fn as u8);
+        self.proof.context.write_into(&mut target);
+        self.proof.commitments.write_into(&mut target);
+        self.proof.trace_queries.write_into(&mut target);
+        self.proof.constraint_queries.write_into(&mut target);
+        self.proof.ood_frame.write_into(&mut target);
+        self.proof.fri_proof.write_into(&mut target);
+        target.write_u64(self.proof.pow_nonce);
+        target.finish()
+    }
+
+    /// Reads a proof from the specified reader.
+    ///
+    /// The reader is expected to contain a proof in the format produced by
+    /// [ExecutionProof::to_bytes()], or in the unversioned format accepted by
+    /// [ExecutionProof::from_bytes()]. Unlike [ExecutionProof::from_bytes()], only the bytes
+    /// encoding the proof are consumed, and any bytes following the proof are left in the reader.
+    ///
+    /// The proof is read in small chunks; thus, readers which do not buffer their input (e.g.,
+    /// network sockets) should be wrapped into a [BufReader](std::io::BufReader).
+    ///
+    /// # Errors
+    /// Returns an error if the format version of the proof is newer than [PROOF_FORMAT_VERSION],
+    /// if the reader does not contain a valid proof, or if reading from the reader fails.
+    #[cfg(feature = "std")]
+    pub fn read_from<R: std::io::Read>(reader: &mut R) -> Result<Self, DeserializationError> {
+        let mut source = IoReader::new(reader);
+        let (version, hash_fn) = match source.read_u8()? {
+            PROOF_VERSION_MARKER => (check_format_version(source.read_u8()?)?, source.read_u8()?),
+            hash_fn => (0, hash_fn),
+        };
+        let hash_fn = HashFunction::try_from(hash_fn)?;
+
+        let context = Context::read_from(&mut source)?;
+        let commitments = Commitments::read_from(&mut source)?;
+        let num_trace_segments = context.trace_layout().num_segments();
+        let mut trace_queries = Vec::with_capacity(num_trace_segments);
+        for _ in 0..num_trace_segments {
+            trace_queries.push(Queries::read_from(&mut source)?);
+        }
+
+        let proof = StarkProof {
+            context,
+            commitments,
+            trace_queries,
+            constraint_queries: Queries::read_from(&mut source)?,
+            ood_frame: OodFrame::read_from(&mut source)?,
+            fri_proof: Deserializable::read_from(&mut source)?,
+            pow_nonce: source.read_u64()?,
+        };
+        Ok(Self {
+            proof,
+            hash_fn,
+            version,
+        })
     }
 
     // DESTRUCTOR
@@ -81,11 +274,69 @@ impl ExecutionProof {
     }
 }
 
+/// Serializes the proof into its binary encoding (see [ExecutionProof::to_bytes()]). The bytes are
+/// encoded as a base64 string for human-readable formats (e.g., JSON) and written as raw bytes
+/// for compact formats.
+#[cfg(feature = "serde")]
+impl Serialize for ExecutionProof {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let bytes = self.to_bytes();
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&BASE64.encode(bytes))
+        } else {
+            serializer.serialize_bytes(&bytes)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ExecutionProof {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = if deserializer.is_human_readable() {
+            let encoded = String::deserialize(deserializer)?;
+            BASE64.decode(encoded).map_err(de::Error::custom)?
+        } else {
+            deserializer.deserialize_byte_buf(BytesVisitor)?
+        };
+        Self::from_bytes(&bytes).map_err(de::Error::custom)
+    }
+}
+
+/// A visitor which reads a byte vector from formats which encode bytes either natively or as a
+/// sequence of integers.
+#[cfg(feature = "serde")]
+struct BytesVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> de::Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a byte array")
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(v)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
+
 // PROOF OPTIONS
 // ================================================================================================
 
 /// A set of parameters specifying how Miden VM execution proofs are to be generated.
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Clone, Eq, PartialEq)]
 pub struct ProofOptions {
     pub options: WinterProofOptions,
     pub hash_fn: HashFunction,
@@ -96,6 +347,10 @@ impl ProofOptions {
     // --------------------------------------------------------------------------------------------
 
     /// Creates a new instance of [ProofOptions] from the specified parameters.
+    ///
+    /// A `grinding_factor` of 0 disables proof-of-work grinding. This speeds up proof generation
+    /// but reduces the security of the proof, and thus, should be used for testing only (see
+    /// [ProofOptions::insecure_for_testing()]).
     pub fn new(
         num_queries: usize,
         blowup_factor: usize,
@@ -116,11 +371,23 @@ impl ProofOptions {
         Self { options, hash_fn }
     }
 
+    /// Returns a new [ProofOptionsBuilder] initialized with the parameters of the default 96-bit
+    /// security preset.
+    ///
+    /// Unlike [ProofOptions::new()], the builder validates the specified parameters and returns
+    /// an error if they cannot be used to generate Miden VM execution proofs.
+    pub fn builder() -> ProofOptionsBuilder {
+        ProofOptionsBuilder::default()
+    }
+
     /// Creates a new preset instance of [ProofOptions] targeting 96-bit security level.
     ///
     /// If `recursive` flag is set to true, proofs will be generated using an arithmetization-
     /// friendly hash function (RPO). Such proofs are well-suited for recursive proof verification,
     /// but may take significantly longer to generate.
+    ///
+    /// This is equivalent to [ProofOptions::with_security_level()] with 96 bits of security, a
+    /// quadratic extension field, and either RPO or BLAKE3 (192-bit) hash function.
     pub fn with_96_bit_security(recursive: bool) -> Self {
         if recursive {
             let options = WinterProofOptions::new(27, 8, 16, FieldExtension::Quadratic, 4, 7);
@@ -142,6 +409,9 @@ impl ProofOptions {
     /// If `recursive` flag is set to true, proofs will be generated using an arithmetization-
     /// friendly hash function (RPO). Such proofs are well-suited for recursive proof verification,
     /// but may take significantly longer to generate.
+    ///
+    /// This is equivalent to [ProofOptions::with_security_level()] with 128 bits of security, a
+    /// cubic extension field, and either RPO or BLAKE3 (256-bit) hash function.
     pub fn with_128_bit_security(recursive: bool) -> Self {
         if recursive {
             let options = WinterProofOptions::new(27, 16, 21, FieldExtension::Cubic, 4, 7);
@@ -158,13 +428,301 @@ impl ProofOptions {
         }
     }
 
+    /// Creates a new preset instance of [ProofOptions] targeting the specified security level
+    /// (96 or 128 bits) for the specified field extension and hash function.
+    ///
+    /// The presets use the following parameters:
+    ///
+    /// | Security level | Queries | Blowup factor | Grinding factor |
+    /// | -------------- | ------- | ------------- | --------------- |
+    /// | 96 bits        | 27      | 8             | 16              |
+    /// | 128 bits       | 27      | 16            | 21              |
+    ///
+    /// FRI folding factor and max remainder size are set to 4 and 7 for [HashFunction::Rpo256]
+    /// (as expected by the recursive STARK verifier), and to 8 and 255 for the other hash
+    /// functions.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `security_level` is neither 96 nor 128.
+    /// - The resulting options do not reach the requested security level (as estimated by
+    ///   [ProofOptions::security_level()]). This is the case for [FieldExtension::None] at both
+    ///   levels, and for [FieldExtension::Quadratic] and [HashFunction::Blake3_192] at 128 bits.
+    pub fn with_security_level(
+        security_level: u32,
+        field_extension: FieldExtension,
+        hash_fn: HashFunction,
+    ) -> Result<Self, ProofOptionsError> {
+        let (num_queries, blowup_factor, grinding_factor) = match security_level {
+            96 => (27, 8, 16),
+            128 => (27, 16, 21),
+            _ => return Err(ProofOptionsError::UnsupportedSecurityLevel(security_level)),
+        };
+        let (fri_folding_factor, fri_max_remainder_size) = fri_parameters(hash_fn);
+
+        let options = Self::builder()
+            .num_queries(num_queries)
+            .blowup_factor(blowup_factor)
+            .grinding_factor(grinding_factor)
+            .field_extension(field_extension)
+            .fri_folding_factor(fri_folding_factor)
+            .fri_max_remainder_size(fri_max_remainder_size)
+            .hash_fn(hash_fn)
+            .build()?;
+
+        let actual = options.security_level();
+        if actual < security_level {
+            return Err(ProofOptionsError::SecurityLevelNotReached(security_level, actual));
+        }
+        Ok(options)
+    }
+
+    /// Creates a new instance of [ProofOptions] with the minimal number of queries and grinding
+    /// factor which reach the specified security level (as estimated by
+    /// [ProofOptions::security_level()]) for the specified blowup factor, field extension, and
+    /// hash function.
+    ///
+    /// Each query contributes `log2(blowup_factor)` bits of security, and grinding contributes
+    /// one bit per bit of proof-of-work once the queries alone provide at least 80 bits of
+    /// security. The number of queries is minimized first, using up to 16 bits of grinding (as
+    /// in [ProofOptions::with_96_bit_security()]); the grinding factor is then reduced to the
+    /// minimum required for the selected number of queries. FRI parameters are selected in the
+    /// same way as for [ProofOptions::with_security_level()].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The blowup factor is not a power of two, smaller than 8, or greater than 128.
+    /// - The target security level cannot be reached with the specified parameters: either the
+    ///   extension field or the hash function does not provide enough security, or more than 255
+    ///   queries would be required. The error contains the highest security level which can be
+    ///   reached.
+    pub fn for_target_security(
+        security_level: u32,
+        blowup_factor: usize,
+        field_extension: FieldExtension,
+        hash_fn: HashFunction,
+    ) -> Result<Self, ProofOptionsError> {
+        // an invalid blowup factor is rejected by the builder below
+        let security_per_query = blowup_factor.checked_ilog2().unwrap_or(0).max(1);
+        // the estimated security level is one bit less than the query security
+        let required = security_level.saturating_add(1);
+
+        let num_queries = if required > GRINDING_CONTRIBUTION_FLOOR {
+            // grinding counts only if the queries alone provide enough security
+            let min_queries = div_ceil(GRINDING_CONTRIBUTION_FLOOR, security_per_query);
+            let queries = div_ceil(required - MAX_TUNED_GRINDING_FACTOR, security_per_query);
+            queries.max(min_queries)
+        } else {
+            div_ceil(required, security_per_query)
+        };
+        let (num_queries, grinding_factor) = if num_queries as usize > MAX_NUM_QUERIES {
+            // the target cannot be reached; build the most secure options to report their level
+            (MAX_NUM_QUERIES as u32, MAX_TUNED_GRINDING_FACTOR)
+        } else if num_queries * security_per_query >= GRINDING_CONTRIBUTION_FLOOR {
+            (num_queries, required.saturating_sub(num_queries * security_per_query))
+        } else {
+            (num_queries, 0)
+        };
+
+        let (fri_folding_factor, fri_max_remainder_size) = fri_parameters(hash_fn);
+        let options = Self::builder()
+            .num_queries(num_queries as usize)
+            .blowup_factor(blowup_factor)
+            .grinding_factor(grinding_factor)
+            .field_extension(field_extension)
+            .fri_folding_factor(fri_folding_factor)
+            .fri_max_remainder_size(fri_max_remainder_size)
+            .hash_fn(hash_fn)
+            .build()?;
+
+        let actual = options.security_level();
+        if actual < security_level {
+            return Err(ProofOptionsError::SecurityLevelNotReached(security_level, actual));
+        }
+        Ok(options)
+    }
+
+    /// Creates a new preset instance of [ProofOptions] with the minimal blowup factor and with
+    /// proof-of-work grinding disabled.
+    ///
+    /// Proofs generated with these options are significantly cheaper to generate, but are
+    /// **INSECURE** and must be used for testing and development purposes only.
+    pub fn insecure_for_testing() -> Self {
+        let options = WinterProofOptions::new(
+            27,
+            MIN_BLOWUP_FACTOR,
+            0,
+            FieldExtension::Quadratic,
+            8,
+            MAX_FRI_REMAINDER_SIZE,
+        );
+        Self {
+            hash_fn: HashFunction::Blake3_192,
+            options,
+        }
+    }
+
+    /// Returns a copy of these options with the FRI folding factor set to `fri_folding_factor`.
+    ///
+    /// A higher folding factor (e.g., 8 instead of 4) reduces the number of FRI layers, and thus,
+    /// the number of Merkle authentication paths included in the proof, at the cost of opening
+    /// more values per query in each layer. For most trace lengths this results in smaller
+    /// proofs (see [ProofOptions::estimate_proof_size()]), but makes proof generation slower and
+    /// requires more work from the verifier per query. The folding factor is recorded in the
+    /// proof, and thus, no changes are required on the verifier side.
+    ///
+    /// Note that the recursive STARK verifier expects a folding factor of 4; proofs generated
+    /// with a different folding factor cannot be verified recursively.
+    ///
+    /// # Errors
+    /// Returns an error if the folding factor is not 2, 4, 8, or 16.
+    pub fn with_fri_folding_factor(
+        self,
+        fri_folding_factor: usize,
+    ) -> Result<Self, ProofOptionsError> {
+        Self::builder()
+            .num_queries(self.num_queries())
+            .blowup_factor(self.blowup_factor())
+            .grinding_factor(self.grinding_factor())
+            .field_extension(self.field_extension())
+            .fri_folding_factor(fri_folding_factor)
+            .fri_max_remainder_size(self.fri_max_remainder_size())
+            .hash_fn(self.hash_fn)
+            .build()
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns the number of queries for the STARK proof.
+    pub fn num_queries(&self) -> usize {
+        self.options.num_queries()
+    }
+
+    /// Returns the blowup factor for the low-degree extension of the execution trace.
+    pub fn blowup_factor(&self) -> usize {
+        self.options.blowup_factor()
+    }
+
+    /// Returns the number of bits of proof-of-work required for the query seed.
+    pub fn grinding_factor(&self) -> u32 {
+        self.options.grinding_factor()
+    }
+
+    /// Returns the extension field in which the composition polynomial is to be constructed.
+    pub fn field_extension(&self) -> FieldExtension {
+        self.options.field_extension()
+    }
+
+    /// Returns the factor by which the degree of a polynomial is reduced with each FRI layer.
+    pub fn fri_folding_factor(&self) -> usize {
+        self.options.to_fri_options().folding_factor()
+    }
+
+    /// Returns the maximum size of the last FRI layer.
+    pub fn fri_max_remainder_size(&self) -> usize {
+        self.options.to_fri_options().remainder_max_degree()
+    }
+
     /// Returns the hash function to be used in STARK proof generation.
     pub const fn hash_fn(&self) -> HashFunction {
         self.hash_fn
     }
+
+    /// Returns conjectured security level (in bits) of proofs generated with these options.
+    ///
+    /// The estimate is bounded by the number of queries, the blowup and grinding factors, the
+    /// size of the extension field, and the collision resistance of the hash function. The
+    /// security level of an actual proof may be slightly lower because the field bound is further
+    /// reduced by `log2(trace_length)` bits; use [ExecutionProof::security_level()] to get the
+    /// exact value for a given proof.
+    pub fn security_level(&self) -> u32 {
+        // compute max security we can get for a given field size
+        let field_security = Felt::MODULUS_BITS * self.options.field_extension().degree();
+
+        // compute security we get by executing multiple query rounds
+        let security_per_query = self.options.blowup_factor().ilog2();
+        let mut query_security = security_per_query * self.options.num_queries() as u32;
+
+        // include grinding factor contributions only for proofs with adequate security
+        if query_security >= GRINDING_CONTRIBUTION_FLOOR {
+            query_security += self.options.grinding_factor();
+        }
+
+        cmp::min(
+            cmp::min(field_security, query_security).saturating_sub(1),
+            self.hash_fn.collision_resistance(),
+        )
+    }
+
+    /// Returns an estimate of the size (in bytes) of a serialized proof generated with these
+    /// options for an execution trace of the specified length.
+    ///
+    /// The trace length is rounded up to the next power of two (but not below the minimum trace
+    /// length). The estimate accounts for the width of the execution trace, the number of queries,
+    /// the number of FRI layers, and the digest size of the hash function. Query positions are
+    /// distinct, and the size of Merkle authentication paths is bounded by assuming that no two
+    /// queried positions share a sibling. Thus, the estimate is an upper bound on the size of an
+    /// actual proof, and is usually within 10% of it.
+    pub fn estimate_proof_size(&self, trace_len: usize) -> usize {
+        let trace_len = trace_len.next_power_of_two().max(MIN_TRACE_LEN);
+        let lde_domain_size = trace_len * self.blowup_factor();
+        let num_queries = self.num_queries();
+        let element_size = Felt::ELEMENT_BYTES * self.field_extension().degree() as usize;
+        let digest_size = self.hash_fn.digest_size();
+
+        // query positions drawn from the LDE domain are distinct
+        let num_positions = cmp::min(num_queries, lde_domain_size);
+        let queries_size = |num_values: usize, value_size: usize| {
+            num_values * value_size
+                + estimate_batch_path_size(lde_domain_size, num_positions, digest_size)
+        };
+
+        // trace and constraint queries; main trace values are in the base field, while auxiliary
+        // trace and constraint composition values are in the extension field
+        let mut result = queries_size(num_positions * TRACE_WIDTH, Felt::ELEMENT_BYTES);
+        result += queries_size(num_positions * AUX_TRACE_WIDTH, element_size);
+        result += queries_size(num_positions * NUM_COMPOSITION_COLUMNS, element_size);
+
+        // out-of-domain frame consisting of two trace rows and a row of constraint evaluations
+        result += (2 * (TRACE_WIDTH + AUX_TRACE_WIDTH) + NUM_COMPOSITION_COLUMNS) * element_size;
+
+        // FRI layers; each layer commits to the evaluations folded by the folding factor
+        let fri_options = self.options.to_fri_options();
+        let folding_factor = fri_options.folding_factor();
+        let num_fri_layers = fri_options.num_fri_layers(lde_domain_size);
+        let mut domain_size = lde_domain_size;
+        for _ in 0..num_fri_layers {
+            let num_leaves = domain_size / folding_factor;
+            let num_positions = cmp::min(num_queries, num_leaves);
+            result += num_positions * folding_factor * element_size;
+            result += estimate_batch_path_size(num_leaves, num_positions, digest_size);
+            domain_size = num_leaves;
+        }
+
+        // FRI remainder polynomial
+        result += domain_size / self.blowup_factor() * element_size;
+
+        // commitments to the main and auxiliary trace segments, constraint evaluations, FRI
+        // layers, and FRI remainder
+        result += (num_fri_layers + 4) * digest_size;
+
+        result + PROOF_HEADER_SIZE
+    }
+}
+
+impl fmt::Debug for ProofOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProofOptions")
+            .field("num_queries", &self.num_queries())
+            .field("blowup_factor", &self.blowup_factor())
+            .field("grinding_factor", &self.grinding_factor())
+            .field("field_extension", &self.field_extension())
+            .field("fri_folding_factor", &self.fri_folding_factor())
+            .field("fri_max_remainder_size", &self.fri_max_remainder_size())
+            .field("hash_fn", &self.hash_fn)
+            .finish()
+    }
 }
 
 impl Default for ProofOptions {
@@ -179,11 +737,272 @@ impl From<ProofOptions> for WinterProofOptions {
     }
 }
 
+impl Serializable for ProofOptions {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.options.write_into(target);
+        target.write_u8(self.hash_fn as u8);
+    }
+}
+
+impl Deserializable for ProofOptions {
+    /// Reads proof options from the specified `source`.
+    ///
+    /// Exactly the parameters accepted by [ProofOptions::new()] are accepted; an error is
+    /// returned for parameters which would cause [ProofOptions::new()] to panic.
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        // the parameters are read individually because deserializing Winterfell proof options
+        // directly would panic on invalid values
+        let num_queries = source.read_u8()? as usize;
+        let blowup_factor = source.read_u8()? as usize;
+        let grinding_factor = source.read_u8()? as u32;
+        let field_extension = FieldExtension::read_from(source)?;
+        let fri_folding_factor = source.read_u8()? as usize;
+        let fri_max_remainder_size = source.read_u8()? as usize;
+        let hash_fn = HashFunction::try_from(source.read_u8()?)?;
+
+        ProofOptions::builder()
+            .num_queries(num_queries)
+            .blowup_factor(blowup_factor)
+            .grinding_factor(grinding_factor)
+            .field_extension(field_extension)
+            .fri_folding_factor(fri_folding_factor)
+            .fri_max_remainder_size(fri_max_remainder_size)
+            .hash_fn(hash_fn)
+            .build_with_min_blowup_factor(MIN_STARK_BLOWUP_FACTOR)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+/// Serialized representation of [ProofOptions].
+///
+/// The field extension is encoded as its degree (1, 2, or 3).
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "ProofOptions")]
+struct ProofOptionsRepr {
+    num_queries: usize,
+    blowup_factor: usize,
+    grinding_factor: u32,
+    field_extension: u32,
+    fri_folding_factor: usize,
+    fri_max_remainder_size: usize,
+    hash_fn: HashFunction,
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for ProofOptions {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let fri_options = self.options.to_fri_options();
+        ProofOptionsRepr {
+            num_queries: self.options.num_queries(),
+            blowup_factor: self.options.blowup_factor(),
+            grinding_factor: self.options.grinding_factor(),
+            field_extension: self.options.field_extension().degree(),
+            fri_folding_factor: fri_options.folding_factor(),
+            fri_max_remainder_size: fri_options.remainder_max_degree(),
+            hash_fn: self.hash_fn,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes [ProofOptions] accepting exactly the parameters accepted by [ProofOptions::new()].
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ProofOptions {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = ProofOptionsRepr::deserialize(deserializer)?;
+        let field_extension = match repr.field_extension {
+            1 => FieldExtension::None,
+            2 => FieldExtension::Quadratic,
+            3 => FieldExtension::Cubic,
+            degree => {
+                return Err(de::Error::custom(format_args!(
+                    "field extension degree must be 1, 2, or 3, but was {degree}"
+                )))
+            }
+        };
+
+        ProofOptions::builder()
+            .num_queries(repr.num_queries)
+            .blowup_factor(repr.blowup_factor)
+            .grinding_factor(repr.grinding_factor)
+            .field_extension(field_extension)
+            .fri_folding_factor(repr.fri_folding_factor)
+            .fri_max_remainder_size(repr.fri_max_remainder_size)
+            .hash_fn(repr.hash_fn)
+            .build_with_min_blowup_factor(MIN_STARK_BLOWUP_FACTOR)
+            .map_err(de::Error::custom)
+    }
+}
+
+// PROOF OPTIONS BUILDER
+// ================================================================================================
+
+/// A builder for [ProofOptions] which validates the parameters before instantiating the options.
+///
+/// The builder starts with the parameters of [ProofOptions::with_96_bit_security()] (with the
+/// `recursive` flag set to false); any of the parameters can be overridden via the chainable
+/// setters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofOptionsBuilder {
+    num_queries: usize,
+    blowup_factor: usize,
+    grinding_factor: u32,
+    field_extension: FieldExtension,
+    fri_folding_factor: usize,
+    fri_max_remainder_size: usize,
+    hash_fn: HashFunction,
+}
+
+impl ProofOptionsBuilder {
+    // SETTERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the number of queries for the STARK proof.
+    pub fn num_queries(mut self, num_queries: usize) -> Self {
+        self.num_queries = num_queries;
+        self
+    }
+
+    /// Sets the blowup factor for the low-degree extension of the execution trace.
+    pub fn blowup_factor(mut self, blowup_factor: usize) -> Self {
+        self.blowup_factor = blowup_factor;
+        self
+    }
+
+    /// Sets the number of bits of proof-of-work required for the query seed.
+    ///
+    /// Setting the grinding factor to 0 disables proof-of-work grinding, which should be done
+    /// only for testing purposes.
+    pub fn grinding_factor(mut self, grinding_factor: u32) -> Self {
+        self.grinding_factor = grinding_factor;
+        self
+    }
+
+    /// Sets the extension field in which the composition polynomial is to be constructed.
+    ///
+    /// Miden VM base field is 64 bits; thus, [FieldExtension::Quadratic] is the minimum for
+    /// proofs targeting ~100 bits of security, and [FieldExtension::Cubic] is required to reach
+    /// 128 bits. A cubic extension makes proof generation and verification noticeably slower and
+    /// increases the proof size, since all auxiliary trace and constraint evaluations are performed
+    /// over the extension field.
+    pub fn field_extension(mut self, field_extension: FieldExtension) -> Self {
+        self.field_extension = field_extension;
+        self
+    }
+
+    /// Sets the factor by which the degree of a polynomial is reduced with each FRI layer.
+    pub fn fri_folding_factor(mut self, fri_folding_factor: usize) -> Self {
+        self.fri_folding_factor = fri_folding_factor;
+        self
+    }
+
+    /// Sets the maximum size of the last FRI layer.
+    pub fn fri_max_remainder_size(mut self, fri_max_remainder_size: usize) -> Self {
+        self.fri_max_remainder_size = fri_max_remainder_size;
+        self
+    }
+
+    /// Sets the hash function to be used in STARK proof generation.
+    pub fn hash_fn(mut self, hash_fn: HashFunction) -> Self {
+        self.hash_fn = hash_fn;
+        self
+    }
+
+    // BUILDER
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new instance of [ProofOptions] instantiated from the parameters of this builder.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The number of queries is zero or greater than 255.
+    /// - The blowup factor is not a power of two, smaller than 8, or greater than 128.
+    /// - The grinding factor is greater than 32.
+    /// - The FRI folding factor is not 2, 4, 8, or 16.
+    /// - The FRI max remainder size is greater than 255 or is not one less than a power of two.
+    pub fn build(self) -> Result<ProofOptions, ProofOptionsError> {
+        self.build_with_min_blowup_factor(MIN_BLOWUP_FACTOR)
+    }
+
+    /// Returns a new instance of [ProofOptions] validating the parameters of this builder against
+    /// the specified minimum blowup factor instead of the one required by Miden VM AIR.
+    fn build_with_min_blowup_factor(
+        self,
+        min_blowup_factor: usize,
+    ) -> Result<ProofOptions, ProofOptionsError> {
+        if self.num_queries == 0 {
+            return Err(ProofOptionsError::NumQueriesZero);
+        }
+        if self.num_queries > MAX_NUM_QUERIES {
+            return Err(ProofOptionsError::NumQueriesTooLarge(self.num_queries, MAX_NUM_QUERIES));
+        }
+
+        if !self.blowup_factor.is_power_of_two() {
+            return Err(ProofOptionsError::BlowupFactorNotPowerOfTwo(self.blowup_factor));
+        }
+        if self.blowup_factor < min_blowup_factor {
+            return Err(ProofOptionsError::BlowupFactorTooSmall(
+                self.blowup_factor,
+                min_blowup_factor,
+            ));
+        }
+        if self.blowup_factor > MAX_BLOWUP_FACTOR {
+            return Err(ProofOptionsError::BlowupFactorTooLarge(
+                self.blowup_factor,
+                MAX_BLOWUP_FACTOR,
+            ));
+        }
+
+        if self.grinding_factor > MAX_GRINDING_FACTOR {
+            return Err(ProofOptionsError::GrindingFactorTooLarge(
+                self.grinding_factor,
+                MAX_GRINDING_FACTOR,
+            ));
+        }
+
+        if !matches!(self.fri_folding_factor, 2 | 4 | 8 | 16) {
+            return Err(ProofOptionsError::FriFoldingFactorInvalid(self.fri_folding_factor));
+        }
+
+        if !(self.fri_max_remainder_size + 1).is_power_of_two()
+            || self.fri_max_remainder_size > MAX_FRI_REMAINDER_SIZE
+        {
+            return Err(ProofOptionsError::FriMaxRemainderSizeInvalid(self.fri_max_remainder_size));
+        }
+
+        Ok(ProofOptions::new(
+            self.num_queries,
+            self.blowup_factor,
+            self.grinding_factor,
+            self.field_extension,
+            self.fri_folding_factor,
+            self.fri_max_remainder_size,
+            self.hash_fn,
+        ))
+    }
+}
+
+impl Default for ProofOptionsBuilder {
+    fn default() -> Self {
+        Self {
+            num_queries: 27,
+            blowup_factor: 8,
+            grinding_factor: 16,
+            field_extension: FieldExtension::Quadratic,
+            fri_folding_factor: 8,
+            fri_max_remainder_size: 255,
+            hash_fn: HashFunction::Blake3_192,
+        }
+    }
+}
+
 // HASH FUNCTION
 // ================================================================================================
 
 /// A hash function used during STARK proof generation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum HashFunction {
     /// BLAKE3 hash function with 192-bit output.
@@ -201,6 +1020,15 @@ impl Default for HashFunction {
 }
 
 impl HashFunction {
+    /// Returns the size (in bytes) of digests produced by this hash function.
+    pub const fn digest_size(&self) -> usize {
+        match self {
+            HashFunction::Blake3_192 => 24,
+            HashFunction::Blake3_256 => 32,
+            HashFunction::Rpo256 => 32,
+        }
+    }
+
     /// Returns the collision resistance level (in bits) of this hash function.
     pub const fn collision_resistance(&self) -> u32 {
         match self {
@@ -209,6 +1037,33 @@ impl HashFunction {
             HashFunction::Rpo256 => Rpo256::COLLISION_RESISTANCE,
         }
     }
+
+    /// Returns the canonical name of this hash function (e.g., "rpo256").
+    pub const fn name(&self) -> &'static str {
+        match self {
+            HashFunction::Blake3_192 => "blake3_192",
+            HashFunction::Blake3_256 => "blake3_256",
+            HashFunction::Rpo256 => "rpo256",
+        }
+    }
+}
+
+impl fmt::Display for HashFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Parses a hash function from its canonical name; names are matched case-insensitively.
+impl FromStr for HashFunction {
+    type Err = ParseOptionError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        [Self::Blake3_192, Self::Blake3_256, Self::Rpo256]
+            .into_iter()
+            .find(|hash_fn| hash_fn.name().eq_ignore_ascii_case(name))
+            .ok_or_else(|| ParseOptionError::UnknownHashFunction(String::from(name)))
+    }
 }
 
 impl TryFrom<u8> for HashFunction {
@@ -225,3 +1080,514 @@ impl TryFrom<u8> for HashFunction {
         }
     }
 }
+
+// FIELD EXTENSION
+// ================================================================================================
+// [FieldExtension] is defined in Winterfell; thus, `Display` and `FromStr` cannot be implemented
+// for it here, and the functions below are provided instead.
+
+/// Returns the canonical name of the specified field extension (i.e., "none", "quadratic", or
+/// "cubic").
+pub const fn field_extension_name(field_extension: FieldExtension) -> &'static str {
+    match field_extension {
+        FieldExtension::None => "none",
+        FieldExtension::Quadratic => "quadratic",
+        FieldExtension::Cubic => "cubic",
+    }
+}
+
+/// Parses a field extension from its canonical name; names are matched case-insensitively.
+///
+/// # Errors
+/// Returns an error if the name is not one of "none", "quadratic", or "cubic".
+pub fn parse_field_extension(name: &str) -> Result<FieldExtension, ParseOptionError> {
+    [FieldExtension::None, FieldExtension::Quadratic, FieldExtension::Cubic]
+        .into_iter()
+        .find(|&field_extension| field_extension_name(field_extension).eq_ignore_ascii_case(name))
+        .ok_or_else(|| ParseOptionError::UnknownFieldExtension(String::from(name)))
+}
+
+// STREAMING ADAPTERS
+// ================================================================================================
+
+/// Adapts a [std::io::Write] into a [ByteWriter], remembering the first error encountered.
+#[cfg(feature = "std")]
+struct IoWriter<'a, W: std::io::Write> {
+    writer: std::io::BufWriter<&'a mut W>,
+    error: Option<std::io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<'a, W: std::io::Write> IoWriter<'a, W> {
+    fn new(writer: &'a mut W) -> Self {
+        Self {
+            writer: std::io::BufWriter::new(writer),
+            error: None,
+        }
+    }
+
+    /// Flushes all buffered bytes and returns the first error encountered while writing.
+    fn finish(mut self) -> std::io::Result<()> {
+        match self.error.take() {
+            Some(err) => Err(err),
+            None => std::io::Write::flush(&mut self.writer),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, W: std::io::Write> ByteWriter for IoWriter<'a, W> {
+    fn write_u8(&mut self, value: u8) {
+        self.write_bytes(&[value]);
+    }
+
+    fn write_bytes(&mut self, values: &[u8]) {
+        if self.error.is_none() {
+            if let Err(err) = std::io::Write::write_all(&mut self.writer, values) {
+                self.error = Some(err);
+            }
+        }
+    }
+}
+
+/// Adapts a [std::io::Read] into a [ByteReader].
+///
+/// Since the reader cannot tell how many bytes remain in the underlying source, checks for
+/// remaining bytes always succeed, and truncated sources are detected when the missing bytes are
+/// read.
+#[cfg(feature = "std")]
+struct IoReader<'a, R: std::io::Read> {
+    reader: core::cell::RefCell<&'a mut R>,
+    peeked: core::cell::Cell<Option<u8>>,
+}
+
+#[cfg(feature = "std")]
+impl<'a, R: std::io::Read> IoReader<'a, R> {
+    fn new(reader: &'a mut R) -> Self {
+        Self {
+            reader: core::cell::RefCell::new(reader),
+            peeked: core::cell::Cell::new(None),
+        }
+    }
+
+    /// Reads exactly `target.len()` bytes which have not been peeked at from the reader.
+    fn read_exact(&self, target: &mut [u8]) -> Result<(), DeserializationError> {
+        self.reader.borrow_mut().read_exact(target).map_err(|err| match err.kind() {
+            std::io::ErrorKind::UnexpectedEof => DeserializationError::UnexpectedEOF,
+            _ => DeserializationError::UnknownError(err.to_string()),
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, R: std::io::Read> ByteReader for IoReader<'a, R> {
+    fn read_u8(&mut self) -> Result<u8, DeserializationError> {
+        match self.peeked.take() {
+            Some(value) => Ok(value),
+            None => {
+                let mut value = [0u8];
+                self.read_exact(&mut value)?;
+                Ok(value[0])
+            }
+        }
+    }
+
+    fn peek_u8(&self) -> Result<u8, DeserializationError> {
+        if let Some(value) = self.peeked.get() {
+            return Ok(value);
+        }
+        let mut value = [0u8];
+        self.read_exact(&mut value)?;
+        self.peeked.set(Some(value[0]));
+        Ok(value[0])
+    }
+
+    fn read_vec(&mut self, len: usize) -> Result<Vec<u8>, DeserializationError> {
+        use std::io::Read;
+
+        let mut result = Vec::new();
+        if len == 0 {
+            return Ok(result);
+        }
+        result.push(self.read_u8()?);
+
+        // read the remaining bytes without pre-allocating memory for them, so that a corrupted
+        // length does not cause a large allocation
+        let remaining = (len - 1) as u64;
+        self.reader
+            .borrow_mut()
+            .by_ref()
+            .take(remaining)
+            .read_to_end(&mut result)
+            .map_err(|err| DeserializationError::UnknownError(err.to_string()))?;
+        if result.len() != len {
+            return Err(DeserializationError::UnexpectedEOF);
+        }
+        Ok(result)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], DeserializationError> {
+        let mut result = [0u8; N];
+        if N > 0 {
+            result[0] = self.read_u8()?;
+            self.read_exact(&mut result[1..])?;
+        }
+        Ok(result)
+    }
+
+    fn check_eor(&self, _num_bytes: usize) -> Result<(), DeserializationError> {
+        Ok(())
+    }
+
+    fn has_more_bytes(&self) -> bool {
+        self.peek_u8().is_ok()
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the FRI folding factor and max remainder size used by the presets for the specified
+/// hash function.
+///
+/// Proofs generated using [HashFunction::Rpo256] use the parameters expected by the recursive
+/// STARK verifier.
+fn fri_parameters(hash_fn: HashFunction) -> (usize, usize) {
+    match hash_fn {
+        HashFunction::Rpo256 => (4, 7),
+        HashFunction::Blake3_192 | HashFunction::Blake3_256 => (8, 255),
+    }
+}
+
+/// Returns the specified proof format version if proofs of this version can be read by this
+/// crate, i.e., if it is not newer than [PROOF_FORMAT_VERSION].
+///
+/// Proofs of newer versions may lay out the STARK proof differently, and thus, are rejected before
+/// the STARK proof is read.
+fn check_format_version(version: u8) -> Result<u8, DeserializationError> {
+    if version <= PROOF_FORMAT_VERSION {
+        Ok(version)
+    } else {
+        Err(DeserializationError::InvalidValue(format!(
+            "proof format version {version} is not supported; supported versions: \
+            0..={PROOF_FORMAT_VERSION}"
+        )))
+    }
+}
+
+/// Returns `value / divisor` rounded up to the nearest integer.
+fn div_ceil(value: u32, divisor: u32) -> u32 {
+    value / divisor + u32::from(value % divisor != 0)
+}
+
+/// Returns an upper bound on the size (in bytes) of a serialized batch Merkle proof for the
+/// specified number of distinct leaves of a Merkle tree with the specified number of leaves.
+///
+/// A batch proof contains a node at every level of the tree for each node on the authentication
+/// paths which does not have its sibling on any of the paths. The bound assumes that the nodes on
+/// the paths have as few siblings among each other as possible.
+fn estimate_batch_path_size(num_leaves: usize, num_positions: usize, digest_size: usize) -> usize {
+    let mut num_nodes = 0;
+    let mut level_size = num_leaves;
+    let mut num_level_nodes = num_positions;
+    while level_size > 1 {
+        // the number of distinct nodes at the next level; each of these nodes has either one or
+        // two children on the authentication paths, and children without siblings require a
+        // node in the proof
+        let num_parent_nodes = cmp::min(num_level_nodes, level_size / 2);
+        num_nodes += (2 * num_parent_nodes).saturating_sub(num_level_nodes);
+        num_level_nodes = num_parent_nodes;
+        level_size /= 2;
+    }
+
+    // each batch proof also encodes the number of nodes for each of the queried positions
+    num_nodes * digest_size + num_positions + 1
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        field_extension_name, parse_field_extension, Deserializable, FieldExtension, HashFunction,
+        ParseOptionError, ProofOptions, ProofOptionsError, Serializable, MIN_TRACE_LEN,
+    };
+    use vm_core::utils::string::ToString;
+
+    #[test]
+    fn proof_options_builder() {
+        let options = ProofOptions::builder().build().unwrap();
+        assert_eq!(ProofOptions::with_96_bit_security(false), options);
+
+        let options = ProofOptions::builder()
+            .num_queries(27)
+            .blowup_factor(16)
+            .grinding_factor(21)
+            .field_extension(FieldExtension::Cubic)
+            .hash_fn(HashFunction::Blake3_256)
+            .build()
+            .unwrap();
+        assert_eq!(ProofOptions::with_128_bit_security(false), options);
+    }
+
+    #[test]
+    fn proof_options_builder_invalid() {
+        let result = ProofOptions::builder().blowup_factor(12).build();
+        assert_eq!(Err(ProofOptionsError::BlowupFactorNotPowerOfTwo(12)), result);
+
+        let result = ProofOptions::builder().blowup_factor(4).build();
+        assert_eq!(Err(ProofOptionsError::BlowupFactorTooSmall(4, 8)), result);
+
+        let result = ProofOptions::builder().num_queries(0).build();
+        assert_eq!(Err(ProofOptionsError::NumQueriesZero), result);
+
+        let result = ProofOptions::builder().grinding_factor(33).build();
+        assert_eq!(Err(ProofOptionsError::GrindingFactorTooLarge(33, 32)), result);
+
+        let result = ProofOptions::builder().fri_folding_factor(3).build();
+        assert_eq!(Err(ProofOptionsError::FriFoldingFactorInvalid(3)), result);
+
+        let result = ProofOptions::builder().fri_max_remainder_size(100).build();
+        assert_eq!(Err(ProofOptionsError::FriMaxRemainderSizeInvalid(100)), result);
+    }
+
+    #[test]
+    fn proof_options_accessors() {
+        let options = ProofOptions::with_128_bit_security(true);
+        assert_eq!(27, options.num_queries());
+        assert_eq!(16, options.blowup_factor());
+        assert_eq!(21, options.grinding_factor());
+        assert_eq!(FieldExtension::Cubic, options.field_extension());
+        assert_eq!(4, options.fri_folding_factor());
+        assert_eq!(7, options.fri_max_remainder_size());
+        assert_eq!(HashFunction::Rpo256, options.hash_fn());
+
+        assert_eq!(
+            "ProofOptions { num_queries: 27, blowup_factor: 16, grinding_factor: 21, \
+            field_extension: Cubic, fri_folding_factor: 4, fri_max_remainder_size: 7, \
+            hash_fn: Rpo256 }",
+            format!("{options:?}")
+        );
+    }
+
+    #[test]
+    fn proof_options_security_level_presets() {
+        use FieldExtension::*;
+        use HashFunction::*;
+
+        for (recursive, hash_fn) in [(false, Blake3_192), (true, Rpo256)] {
+            let options = ProofOptions::with_security_level(96, Quadratic, hash_fn).unwrap();
+            assert_eq!(ProofOptions::with_96_bit_security(recursive), options);
+        }
+        for (recursive, hash_fn) in [(false, Blake3_256), (true, Rpo256)] {
+            let options = ProofOptions::with_security_level(128, Cubic, hash_fn).unwrap();
+            assert_eq!(ProofOptions::with_128_bit_security(recursive), options);
+        }
+
+        // every preset which can be constructed reaches the requested security level
+        for security_level in [96, 128] {
+            for field_extension in [None, Quadratic, Cubic] {
+                for hash_fn in [Blake3_192, Blake3_256, Rpo256] {
+                    match ProofOptions::with_security_level(
+                        security_level,
+                        field_extension,
+                        hash_fn,
+                    ) {
+                        Ok(options) => assert!(options.security_level() >= security_level),
+                        Err(ProofOptionsError::SecurityLevelNotReached(target, actual)) => {
+                            assert_eq!(security_level, target);
+                            assert!(actual < target);
+                        }
+                        Err(err) => panic!("unexpected error: {err}"),
+                    }
+                }
+            }
+        }
+
+        let result = ProofOptions::with_security_level(128, Quadratic, Rpo256);
+        assert_eq!(Err(ProofOptionsError::SecurityLevelNotReached(128, 127)), result);
+        let result = ProofOptions::with_security_level(128, Cubic, Blake3_192);
+        assert_eq!(Err(ProofOptionsError::SecurityLevelNotReached(128, 96)), result);
+        let result = ProofOptions::with_security_level(96, None, Rpo256);
+        assert_eq!(Err(ProofOptionsError::SecurityLevelNotReached(96, 63)), result);
+        let result = ProofOptions::with_security_level(100, Cubic, Rpo256);
+        assert_eq!(Err(ProofOptionsError::UnsupportedSecurityLevel(100)), result);
+    }
+
+    #[test]
+    fn proof_options_for_target_security() {
+        use FieldExtension::*;
+        use HashFunction::*;
+
+        let options = ProofOptions::for_target_security(96, 8, Quadratic, Blake3_192).unwrap();
+        assert_eq!(ProofOptions::with_96_bit_security(false), options);
+        let options = ProofOptions::for_target_security(96, 8, Quadratic, Rpo256).unwrap();
+        assert_eq!(ProofOptions::with_96_bit_security(true), options);
+
+        // the selected number of queries and grinding factor are minimal
+        for blowup_factor in [8, 16, 32, 64, 128] {
+            for target in 1..=128 {
+                let options =
+                    ProofOptions::for_target_security(target, blowup_factor, Cubic, Blake3_256)
+                        .unwrap();
+                assert!(options.security_level() >= target);
+
+                let num_queries = options.num_queries();
+                let grinding_factor = options.grinding_factor();
+                let with_params = |num_queries, grinding_factor| {
+                    ProofOptions::builder()
+                        .num_queries(num_queries)
+                        .blowup_factor(blowup_factor)
+                        .grinding_factor(grinding_factor)
+                        .field_extension(Cubic)
+                        .hash_fn(Blake3_256)
+                        .build()
+                        .unwrap()
+                };
+                if num_queries > 1 {
+                    let fewer_queries =
+                        with_params(num_queries - 1, super::MAX_TUNED_GRINDING_FACTOR);
+                    assert!(fewer_queries.security_level() < target);
+                }
+                if grinding_factor > 0 {
+                    let less_grinding = with_params(num_queries, grinding_factor - 1);
+                    assert!(less_grinding.security_level() < target);
+                }
+            }
+        }
+
+        let result = ProofOptions::for_target_security(129, 16, Cubic, Blake3_256);
+        assert_eq!(Err(ProofOptionsError::SecurityLevelNotReached(129, 128)), result);
+        let result = ProofOptions::for_target_security(96, 8, None, Rpo256);
+        assert_eq!(Err(ProofOptionsError::SecurityLevelNotReached(96, 63)), result);
+        let result = ProofOptions::for_target_security(96, 4, Quadratic, Rpo256);
+        assert_eq!(Err(ProofOptionsError::BlowupFactorTooSmall(4, 8)), result);
+        let result = ProofOptions::for_target_security(96, 12, Quadratic, Rpo256);
+        assert_eq!(Err(ProofOptionsError::BlowupFactorNotPowerOfTwo(12)), result);
+    }
+
+    #[test]
+    fn proof_options_insecure_for_testing() {
+        let options = ProofOptions::insecure_for_testing();
+        assert_eq!(8, options.blowup_factor());
+        assert_eq!(0, options.grinding_factor());
+
+        // grinding factor of 0 is not adjusted by the constructor or the builder
+        let options = ProofOptions::builder().grinding_factor(0).build().unwrap();
+        assert_eq!(0, options.grinding_factor());
+        assert_eq!(80, options.security_level());
+    }
+
+    #[test]
+    fn proof_options_security_level() {
+        assert_eq!(96, ProofOptions::with_96_bit_security(false).security_level());
+        assert_eq!(96, ProofOptions::with_96_bit_security(true).security_level());
+        assert_eq!(128, ProofOptions::with_128_bit_security(false).security_level());
+        assert_eq!(128, ProofOptions::with_128_bit_security(true).security_level());
+
+        // without a field extension, security is bounded by the size of the base field
+        let options =
+            ProofOptions::builder().field_extension(FieldExtension::None).build().unwrap();
+        assert_eq!(63, options.security_level());
+
+        // grinding does not contribute to security for low query counts
+        let options = ProofOptions::builder().num_queries(10).build().unwrap();
+        assert_eq!(29, options.security_level());
+    }
+
+    #[test]
+    fn proof_options_estimate_proof_size() {
+        let options = ProofOptions::default();
+
+        // trace lengths are rounded up to the next power of two
+        assert_eq!(options.estimate_proof_size(1), options.estimate_proof_size(MIN_TRACE_LEN));
+        assert_eq!(options.estimate_proof_size(1000), options.estimate_proof_size(1024));
+
+        // longer traces, larger digests, and more queries result in larger proofs
+        assert!(options.estimate_proof_size(1024) < options.estimate_proof_size(1 << 16));
+        assert!(
+            options.estimate_proof_size(1024)
+                < ProofOptions::with_96_bit_security(true).estimate_proof_size(1024)
+        );
+        assert!(
+            options.estimate_proof_size(1024)
+                < ProofOptions::with_128_bit_security(false).estimate_proof_size(1024)
+        );
+    }
+
+    #[test]
+    fn proof_options_with_fri_folding_factor() {
+        let options = ProofOptions::with_96_bit_security(true);
+        assert_eq!(4, options.fri_folding_factor());
+
+        let folded = options.clone().with_fri_folding_factor(8).unwrap();
+        assert_eq!(8, folded.fri_folding_factor());
+        assert_eq!(options.num_queries(), folded.num_queries());
+        assert_eq!(options.fri_max_remainder_size(), folded.fri_max_remainder_size());
+        assert_eq!(options.hash_fn(), folded.hash_fn());
+        assert_eq!(options.security_level(), folded.security_level());
+
+        // folding by 8 instead of 4 results in fewer FRI layers and smaller proofs
+        assert!(folded.estimate_proof_size(1 << 16) < options.estimate_proof_size(1 << 16));
+
+        assert_eq!(
+            Err(ProofOptionsError::FriFoldingFactorInvalid(3)),
+            options.with_fri_folding_factor(3).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn hash_function_names() {
+        for hash_fn in [HashFunction::Blake3_192, HashFunction::Blake3_256, HashFunction::Rpo256] {
+            assert_eq!(Ok(hash_fn), hash_fn.to_string().parse());
+        }
+        assert_eq!("rpo256", HashFunction::Rpo256.to_string());
+        assert_eq!(Ok(HashFunction::Blake3_256), "BLAKE3_256".parse());
+        assert_eq!(
+            Err(ParseOptionError::UnknownHashFunction("sha256".to_string())),
+            "sha256".parse::<HashFunction>()
+        );
+    }
+
+    #[test]
+    fn field_extension_names() {
+        for field_extension in
+            [FieldExtension::None, FieldExtension::Quadratic, FieldExtension::Cubic]
+        {
+            let name = field_extension_name(field_extension);
+            assert_eq!(Ok(field_extension), parse_field_extension(name));
+        }
+        assert_eq!("quadratic", field_extension_name(FieldExtension::Quadratic));
+        assert_eq!(
+            Err(ParseOptionError::UnknownFieldExtension("quartic".to_string())),
+            parse_field_extension("quartic")
+        );
+    }
+
+    #[test]
+    fn proof_options_serialization() {
+        for options in [
+            ProofOptions::with_96_bit_security(false),
+            ProofOptions::with_96_bit_security(true),
+            ProofOptions::with_128_bit_security(false),
+            ProofOptions::with_128_bit_security(true),
+        ] {
+            let bytes = options.to_bytes();
+            assert_eq!(options, ProofOptions::read_from_bytes(&bytes).unwrap());
+        }
+
+        // all parameters accepted by ProofOptions::new() should be accepted
+        let options =
+            ProofOptions::new(27, 4, 16, FieldExtension::Quadratic, 8, 255, HashFunction::Rpo256);
+        assert_eq!(options, ProofOptions::read_from_bytes(&options.to_bytes()).unwrap());
+
+        // invalid parameters should be rejected instead of causing a panic
+        let mut bytes = ProofOptions::default().to_bytes();
+        bytes[1] = 3;
+        assert!(ProofOptions::read_from_bytes(&bytes).is_err());
+        let mut bytes = ProofOptions::default().to_bytes();
+        bytes[6] = 3;
+        assert!(ProofOptions::read_from_bytes(&bytes).is_err());
+    }
+}
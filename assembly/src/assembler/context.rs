@@ -1,6 +1,6 @@
 use super::{
-    AssemblyError, CallSet, CodeBlock, CodeBlockTable, Kernel, LibraryPath, Procedure,
-    ProcedureCache, ProcedureId, ToString, Vec,
+    AssemblyError, CallSet, CodeBlock, CodeBlockTable, InvokedProcs, Kernel, LibraryPath,
+    Procedure, ProcedureCache, ProcedureId, ProcedureInfo, RpoDigest, ToString, Vec,
 };
 use crate::ProcedureName;
 
@@ -220,6 +220,29 @@ impl AssemblyContext {
         self.kernel.expect("no kernel")
     }
 
+    /// Returns metadata of the main procedure of the program (with the specified MAST root) as
+    /// well as of all procedures which may be invoked from it.
+    ///
+    /// This method is invoked at the end of the compilation of an executable program.
+    ///
+    /// # Panics
+    /// Panics if the procedure currently being compiled is not the main procedure of a program.
+    pub fn program_procedures(&self, program_root: RpoDigest) -> Vec<ProcedureInfo> {
+        let main_proc_context = self.current_proc_context().expect("no procedures");
+        assert!(main_proc_context.is_main(), "not main procedure");
+
+        let invoked = &main_proc_context.invoked;
+        let main_proc = ProcedureInfo::new(
+            main_proc_context.name.to_string(),
+            program_root,
+            invoked.direct().to_vec(),
+        );
+
+        let mut procedures = vec![main_proc];
+        procedures.extend(invoked.all().cloned());
+        procedures
+    }
+
     /// Transforms this context into a [CodeBlockTable] for the compiled program.
     ///
     /// This method is invoked at the end of the compilation of an executable program.
@@ -420,6 +443,7 @@ impl ModuleContext {
         // append the callset of the called procedure to the current callset as all calls made as
         // the result of the called procedure may be made as a result of current procedure as well
         context.callset.append(called_proc.callset());
+        context.invoked.insert(called_proc);
 
         // if the called procedure was not inlined, we include it in the current callset as well
         if !inlined {
@@ -441,6 +465,7 @@ impl ModuleContext {
         // append the callset of the called procedure to the current callset as all calls made as
         // the result of the called procedure may be made as a result of current procedure as well
         context.callset.append(called_proc.callset());
+        context.invoked.insert(called_proc);
 
         // if the called procedure was not inlined, we include it in the current callset as well
         if !inlined {
@@ -480,6 +505,7 @@ struct ProcedureContext {
     is_export: bool,
     num_locals: u16,
     callset: CallSet,
+    invoked: InvokedProcs,
 }
 
 impl ProcedureContext {
@@ -489,6 +515,7 @@ impl ProcedureContext {
             is_export,
             num_locals,
             callset: CallSet::default(),
+            invoked: InvokedProcs::default(),
         }
     }
 
@@ -509,8 +536,9 @@ impl ProcedureContext {
             is_export,
             num_locals,
             callset,
+            invoked,
         } = self;
 
-        Procedure::new(id, name, is_export, num_locals as u32, code_root, callset)
+        Procedure::new(id, name, is_export, num_locals as u32, code_root, callset, invoked)
     }
 }
@@ -1,6 +1,6 @@
 use super::{validate_param, AssemblyError, SpanBuilder};
 use crate::{ast::AdviceInjectorNode, ADVICE_READ_LIMIT};
-use vm_core::{code_blocks::CodeBlock, Operation};
+use vm_core::{advice_tape_key, code_blocks::CodeBlock, AdviceInjector, Operation};
 
 // NON-DETERMINISTIC (ADVICE) INPUTS
 // ================================================================================================
@@ -18,6 +18,23 @@ pub fn adv_push(span: &mut SpanBuilder, n: u8) -> Result<Option<CodeBlock>, Asse
     Ok(None)
 }
 
+/// Appends an advice injector decorator which moves the next value of the named advice tape onto
+/// the advice stack, followed by an ADVPOP operation, to the span. This pushes the next value of
+/// the tape onto the operand stack.
+///
+/// The tape is identified by the key under which its values are stored in the advice map, and
+/// thus, the name of the tape does not affect the hash of the program.
+pub fn adv_push_tape(
+    span: &mut SpanBuilder,
+    name: &str,
+) -> Result<Option<CodeBlock>, AssemblyError> {
+    span.push_advice_injector(AdviceInjector::TapeToStack {
+        key: advice_tape_key(name),
+    });
+    span.push_op(Operation::AdvPop);
+    Ok(None)
+}
+
 // ADVICE INJECTORS
 // ================================================================================================
 
@@ -1,4 +1,4 @@
-use super::{AssemblyError, CodeBlock, Operation::*, SpanBuilder};
+use super::{AssemblyError, CodeBlock, Operation::*, SpanBuilder, ZERO};
 use vm_core::AdviceInjector::Ext2Inv;
 
 /// Given a stack in the following initial configuration [b1, b0, a1, a0, ...] where a = (a0, a1)
@@ -55,17 +55,17 @@ pub fn ext2_div(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyErr
     span.push_advice_injector(Ext2Inv);
     #[rustfmt::skip]
     let ops = [
-        AdvPop,      // [b0', b1, b0, a1, a0, ...]
-        AdvPop,      // [b1', b0', b1, b0, a1, a0, ...]
-        Ext2Mul,     // [b1', b0', 0, 1, a1, a0, ...]
-        MovUp2,      // [0, b1', b0', 1, a1, a0, ...]
-        Eqz,         // [1, b1', b0', 1, a1, a0, ...]
-        Assert,      // [b1', b0', 1, a1, a0, ...]
-        MovUp2,      // [1, b1', b0', a1, a0, ...]
-        Assert,      // [b1', b0', a1, a0, ...]
-        Ext2Mul,     // [b1', b0', a1*b1', a0*b0', ...]
-        Drop,        // [b0', a1*b1', a0*b0'...]
-        Drop         // [a1*b1', a0*b0'...]
+        AdvPop,       // [b0', b1, b0, a1, a0, ...]
+        AdvPop,       // [b1', b0', b1, b0, a1, a0, ...]
+        Ext2Mul,      // [b1', b0', 0, 1, a1, a0, ...]
+        MovUp2,       // [0, b1', b0', 1, a1, a0, ...]
+        Eqz,          // [1, b1', b0', 1, a1, a0, ...]
+        Assert(ZERO), // [b1', b0', 1, a1, a0, ...]
+        MovUp2,       // [1, b1', b0', a1, a0, ...]
+        Assert(ZERO), // [b1', b0', a1, a0, ...]
+        Ext2Mul,      // [b1', b0', a1*b1', a0*b0', ...]
+        Drop,         // [b0', a1*b1', a0*b0'...]
+        Drop          // [a1*b1', a0*b0'...]
     ];
     span.add_ops(ops)
 }
@@ -115,14 +115,14 @@ pub fn ext2_inv(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyErr
     span.push_advice_injector(Ext2Inv);
     #[rustfmt::skip]
     let ops = [
-        AdvPop,   // [a0', a1, a0, ...]
-        AdvPop,   // [a1', a0', a1, a0, ...]
-        Ext2Mul,  // [a1', a0', 0, 1, ...]
-        MovUp2,   // [0, a1', a0', 1, ...]
-        Eqz,      // [1, a1', a0', 1, ...]
-        Assert,   // [a1', a0', 1, ...]
-        MovUp2,   // [1, a1', a0', ...]
-        Assert    // [a1', a0', ...]
+        AdvPop,       // [a0', a1, a0, ...]
+        AdvPop,       // [a1', a0', a1, a0, ...]
+        Ext2Mul,      // [a1', a0', 0, 1, ...]
+        MovUp2,       // [0, a1', a0', 1, ...]
+        Eqz,          // [1, a1', a0', 1, ...]
+        Assert(ZERO), // [a1', a0', 1, ...]
+        MovUp2,       // [1, a1', a0', ...]
+        Assert(ZERO)  // [a1', a0', ...]
     ];
     span.add_ops(ops)
 }
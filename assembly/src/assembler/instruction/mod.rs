@@ -4,7 +4,7 @@ use super::{
 };
 use crate::utils::bound_into_included_u64;
 use core::ops::RangeBounds;
-use vm_core::{FieldElement, StarkField};
+use vm_core::{Decorator, FieldElement, SourceLocation, StarkField};
 
 mod adv_ops;
 mod crypto_ops;
@@ -24,6 +24,7 @@ impl Assembler {
     pub(super) fn compile_instruction(
         &self,
         instruction: &Instruction,
+        location: Option<SourceLocation>,
         span: &mut SpanBuilder,
         ctx: &mut AssemblyContext,
     ) -> Result<Option<CodeBlock>, AssemblyError> {
@@ -33,14 +34,15 @@ impl Assembler {
         // this will allow us to map the instruction to the sequence of operations which were
         // executed as a part of this instruction.
         if self.in_debug_mode() {
-            span.track_instruction(instruction, ctx);
+            span.track_instruction(instruction, location, ctx);
         }
 
         let result = match instruction {
-            Instruction::Assert => span.add_op(Assert),
-            Instruction::AssertEq => span.add_ops([Eq, Assert]),
+            Instruction::Assert => span.add_op(Assert(ZERO)),
+            Instruction::AssertWithCode(err_code) => span.add_op(Assert(*err_code)),
+            Instruction::AssertEq => span.add_ops([Eq, Assert(ZERO)]),
             Instruction::AssertEqw => field_ops::assertw(span),
-            Instruction::Assertz => span.add_ops([Eqz, Assert]),
+            Instruction::Assertz => span.add_ops([Eqz, Assert(ZERO)]),
 
             Instruction::Add => span.add_op(Add),
             Instruction::AddImm(imm) => field_ops::add_imm(span, *imm),
@@ -96,6 +98,12 @@ impl Assembler {
             Instruction::U32CheckedAddImm(v) => u32_ops::u32add(span, Checked, Some(*v)),
             Instruction::U32OverflowingAdd => u32_ops::u32add(span, Overflowing, None),
             Instruction::U32OverflowingAddImm(v) => u32_ops::u32add(span, Overflowing, Some(*v)),
+            Instruction::U32CheckedOverflowingAdd => {
+                u32_ops::u32add(span, CheckedOverflowing, None)
+            }
+            Instruction::U32CheckedOverflowingAddImm(v) => {
+                u32_ops::u32add(span, CheckedOverflowing, Some(*v))
+            }
             Instruction::U32WrappingAdd => u32_ops::u32add(span, Wrapping, None),
             Instruction::U32WrappingAddImm(v) => u32_ops::u32add(span, Wrapping, Some(*v)),
             Instruction::U32OverflowingAdd3 => span.add_op(U32add3),
@@ -105,6 +113,12 @@ impl Assembler {
             Instruction::U32CheckedSubImm(v) => u32_ops::u32sub(span, Checked, Some(*v)),
             Instruction::U32OverflowingSub => u32_ops::u32sub(span, Overflowing, None),
             Instruction::U32OverflowingSubImm(v) => u32_ops::u32sub(span, Overflowing, Some(*v)),
+            Instruction::U32CheckedOverflowingSub => {
+                u32_ops::u32sub(span, CheckedOverflowing, None)
+            }
+            Instruction::U32CheckedOverflowingSubImm(v) => {
+                u32_ops::u32sub(span, CheckedOverflowing, Some(*v))
+            }
             Instruction::U32WrappingSub => u32_ops::u32sub(span, Wrapping, None),
             Instruction::U32WrappingSubImm(v) => u32_ops::u32sub(span, Wrapping, Some(*v)),
 
@@ -112,6 +126,12 @@ impl Assembler {
             Instruction::U32CheckedMulImm(v) => u32_ops::u32mul(span, Checked, Some(*v)),
             Instruction::U32OverflowingMul => u32_ops::u32mul(span, Overflowing, None),
             Instruction::U32OverflowingMulImm(v) => u32_ops::u32mul(span, Overflowing, Some(*v)),
+            Instruction::U32CheckedOverflowingMul => {
+                u32_ops::u32mul(span, CheckedOverflowing, None)
+            }
+            Instruction::U32CheckedOverflowingMulImm(v) => {
+                u32_ops::u32mul(span, CheckedOverflowing, Some(*v))
+            }
             Instruction::U32WrappingMul => u32_ops::u32mul(span, Wrapping, None),
             Instruction::U32WrappingMulImm(v) => u32_ops::u32mul(span, Wrapping, Some(*v)),
             Instruction::U32OverflowingMadd => span.add_op(U32madd),
@@ -266,6 +286,7 @@ impl Assembler {
             Instruction::Clk => span.add_op(Clk),
             Instruction::AdvPipe => span.add_op(Pipe),
             Instruction::AdvPush(n) => adv_ops::adv_push(span, *n),
+            Instruction::AdvPushTape(name) => adv_ops::adv_push_tape(span, name),
             Instruction::AdvLoadW => span.add_op(AdvPopW),
 
             Instruction::MemStream => span.add_op(MStream),
@@ -310,7 +331,13 @@ impl Assembler {
             Instruction::Breakpoint => {
                 if self.in_debug_mode() {
                     span.add_op(Noop)?;
-                    span.track_instruction(instruction, ctx);
+                    span.track_instruction(instruction, location, ctx);
+                }
+                Ok(None)
+            }
+            Instruction::Debug(options) => {
+                if self.in_debug_mode() {
+                    span.push_decorator(Decorator::Debug(*options));
                 }
                 Ok(None)
             }
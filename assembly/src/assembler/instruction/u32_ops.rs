@@ -2,7 +2,7 @@ use super::{
     field_ops::append_pow2_op,
     push_u32_value, validate_param, AssemblyError, CodeBlock, Felt,
     Operation::{self, *},
-    SpanBuilder,
+    SpanBuilder, ZERO,
 };
 use crate::{MAX_U32_ROTATE_VALUE, MAX_U32_SHIFT_VALUE};
 
@@ -16,6 +16,7 @@ pub enum U32OpMode {
     Unchecked,
     Wrapping,
     Overflowing,
+    CheckedOverflowing,
 }
 
 // CONVERSIONS AND TESTS
@@ -82,6 +83,10 @@ pub fn u32assertw(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyE
 /// - u32wrapping_add.b: 3 cycles
 /// - u32overflowing_add: 1 cycles
 /// - u32overflowing_add.b: 2 cycles
+/// - u32checked_overflowing_add: 2 cycles
+/// - u32checked_overflowing_add.b:
+///    - 4 cycles if b = 1
+///    - 3 cycles if b != 1
 pub fn u32add(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
@@ -104,6 +109,10 @@ pub fn u32add(
 /// - u32wrapping_sub.b: 3 cycles
 /// - u32overflowing_sub: 1 cycles
 /// - u32overflowing_sub.b: 2 cycles
+/// - u32checked_overflowing_sub: 2 cycles
+/// - u32checked_overflowing_sub.b:
+///    - 4 cycles if b = 1
+///    - 3 cycles if b != 1
 pub fn u32sub(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
@@ -126,6 +135,10 @@ pub fn u32sub(
 /// - u32wrapping_mul.b: 3 cycles
 /// - u32overflowing_mul: 1 cycles
 /// - u32overflowing_mul.b: 2 cycles
+/// - u32checked_overflowing_mul: 2 cycles
+/// - u32checked_overflowing_mul.b:
+///    - 4 cycles if b = 1
+///    - 3 cycles if b != 1
 pub fn u32mul(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
@@ -318,7 +331,7 @@ pub fn u32rotr(
                 U32assert2,
 
                 // Calculate 32 - b and assert that the shift value b <= 31.
-                Push(Felt::from(MAX_U32_ROTATE_VALUE)), Dup1, U32sub, Not, Assert, Incr, Dup1,
+                Push(Felt::from(MAX_U32_ROTATE_VALUE)), Dup1, U32sub, Not, Assert(ZERO), Incr, Dup1,
 
                 // If 32-b = 32, replace it with 0.
                 Eqz, Not, CSwap, Drop,
@@ -389,6 +402,8 @@ pub fn u32popcnt(
 ///   discarded.
 /// - Overflowing: does not check if the inputs are u32 values; overflow or underflow bits are
 ///   pushed onto the stack.
+/// - CheckedOverflowing: fails if either of the inputs is not a u32 value; overflow or underflow
+///   bits are pushed onto the stack.
 fn handle_arithmetic_operation(
     span: &mut SpanBuilder,
     op: Operation,
@@ -411,13 +426,16 @@ fn handle_arithmetic_operation(
             drop_high_bits = true;
         }
         U32OpMode::Overflowing => {}
+        U32OpMode::CheckedOverflowing => {
+            span.push_op(U32assert2);
+        }
         _ => unreachable!("unsupported operation mode"),
     }
 
     span.push_op(op);
 
     if assert_u32_res {
-        span.add_ops([Eqz, Assert])
+        span.add_ops([Eqz, Assert(ZERO)])
     } else if drop_high_bits {
         span.add_op(Drop)
     } else {
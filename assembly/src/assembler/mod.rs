@@ -1,10 +1,10 @@
 use super::{
-    ast::{Instruction, ModuleAst, Node, ProcedureAst, ProgramAst},
+    ast::{CodeBody, Instruction, InvokedProcsMap, ModuleAst, Node, ProcedureAst, ProgramAst},
     btree_map,
     crypto::hash::RpoDigest,
-    AssemblyError, BTreeMap, CallSet, CodeBlock, CodeBlockTable, Felt, Kernel, Library,
-    LibraryError, LibraryPath, Module, Operation, Procedure, ProcedureId, ProcedureName, Program,
-    ToString, Vec, ONE, ZERO,
+    AssemblyError, BTreeMap, CallSet, CodeBlock, CodeBlockTable, Felt, InvokedProcs, Kernel,
+    Library, LibraryError, LibraryPath, Module, Operation, Procedure, ProcedureId, ProcedureInfo,
+    ProcedureName, Program, ToString, Vec, ONE, ZERO,
 };
 use core::{borrow::Borrow, cell::RefCell};
 use vm_core::{utils::group_vector_elements, Decorator, DecoratorList};
@@ -17,6 +17,8 @@ use module_provider::ModuleProvider;
 mod span_builder;
 use span_builder::SpanBuilder;
 
+mod optimizer;
+
 mod context;
 pub use context::{AssemblyContext, AssemblyContextType};
 
@@ -40,6 +42,7 @@ pub struct Assembler {
     module_provider: ModuleProvider,
     proc_cache: RefCell<ProcedureCache>,
     in_debug_mode: bool,
+    optimize: bool,
 }
 
 impl Assembler {
@@ -52,6 +55,30 @@ impl Assembler {
         self
     }
 
+    /// Enables or disables optimization of the compiled programs.
+    ///
+    /// When optimizations are enabled, the operations of each SPAN block are replaced with an
+    /// equivalent but possibly shorter sequence of operations: arithmetic on constants is folded
+    /// (e.g., `push.2 push.3 add` is compiled as `push.5`), and stack manipulations which have no
+    /// effect (e.g., `push.1 drop` or `swap swap`) are removed. This reduces the length of the
+    /// execution trace, and thus, the cost of proving the program.
+    ///
+    /// An optimized program computes the same results as the unoptimized program, except for
+    /// values which depend on the number of executed cycles: since an optimized program may
+    /// execute fewer cycles, the values pushed onto the stack by `clk`, and the clock cycles
+    /// reported in execution errors, may be smaller than for the unoptimized program.
+    ///
+    /// Since the MAST of an optimized program differs from the MAST of the unoptimized program,
+    /// optimized and unoptimized programs (as well as procedures) have different hashes. Thus,
+    /// a program must be compiled with the same setting by the prover and the verifier.
+    ///
+    /// Optimizations are disabled by default, and are not applied in debug mode, so that the
+    /// operations in debug mode correspond exactly to the compiled instructions.
+    pub fn with_optimizations(mut self, optimize: bool) -> Self {
+        self.optimize = optimize;
+        self
+    }
+
     /// Adds the library to provide modules for the compilation.
     pub fn with_library<L>(mut self, library: &L) -> Result<Self, AssemblyError>
     where
@@ -107,6 +134,11 @@ impl Assembler {
         self.in_debug_mode
     }
 
+    /// Returns true if this assembler optimizes the compiled programs.
+    pub fn optimizations_enabled(&self) -> bool {
+        self.optimize
+    }
+
     /// Returns a reference to the kernel for this assembler.
     ///
     /// If the assembler was instantiated without a kernel, the internal kernel will be empty.
@@ -120,7 +152,9 @@ impl Assembler {
     /// on Miden VM.
     ///
     /// # Errors
-    /// Returns an error if parsing or compilation of the specified program fails.
+    /// Returns an error if parsing or compilation of the specified program fails. To get the
+    /// source location of a parsing error, parse the source via [ProgramAst::parse()] and compile
+    /// the resulting AST via [Assembler::compile_ast()] instead.
     pub fn compile<S>(&self, source: S) -> Result<Program, AssemblyError>
     where
         S: AsRef<str>,
@@ -128,16 +162,28 @@ impl Assembler {
         // parse the program into an AST
         let source = source.as_ref();
         let program = ProgramAst::parse(source)?;
+        self.compile_ast(&program)
+    }
 
+    /// Compiles the provided [ProgramAst] into a [Program]. The resulting program can be executed
+    /// on Miden VM.
+    ///
+    /// # Errors
+    /// Returns an error if compilation of the specified program fails.
+    pub fn compile_ast(&self, program: &ProgramAst) -> Result<Program, AssemblyError> {
         // compile the program
         let mut context = AssemblyContext::new(AssemblyContextType::Program);
-        let program_root = self.compile_in_context(&program, &mut context)?;
+        let program_root = self.compile_in_context(program, &mut context)?;
+
+        // collect metadata of all procedures which may be invoked from the program
+        let procedures = context.program_procedures(program_root.hash());
 
         // convert the context into a call block table for the program
         let cb_table = context.into_cb_table(&self.proc_cache.borrow())?;
 
         // build and return the program
-        Ok(Program::with_kernel(program_root, self.kernel.clone(), cb_table))
+        Ok(Program::with_kernel(program_root, self.kernel.clone(), cb_table)
+            .with_procedures(procedures))
     }
 
     /// Compiles the provided [ProgramAst] into a program and returns the program root
@@ -159,6 +205,13 @@ impl Assembler {
             return Err(AssemblyError::InvalidProgramAssemblyContext);
         }
 
+        // make sure all procedures imported from other modules can be resolved before compiling
+        // the program, so that a misspelled procedure name is reported at the call site
+        for proc_ast in program.procedures() {
+            self.resolve_invoked_procs(&proc_ast.body, program.invoked_procs(), context)?;
+        }
+        self.resolve_invoked_procs(program.body(), program.invoked_procs(), context)?;
+
         // compile all local procedures; this will add the procedures to the specified context
         for proc_ast in program.procedures() {
             if proc_ast.is_export {
@@ -168,7 +221,7 @@ impl Assembler {
         }
 
         // compile the program body
-        let program_root = self.compile_body(program.body().nodes().iter(), context, None)?;
+        let program_root = self.compile_body(program.body(), context, None)?;
 
         Ok(program_root)
     }
@@ -212,6 +265,11 @@ impl Assembler {
             proc_roots.push(proc_mast_root);
         }
 
+        // make sure all procedures imported from other modules can be resolved
+        for proc_ast in module.ast.procs().iter() {
+            self.resolve_invoked_procs(&proc_ast.body, module.ast.invoked_procs(), context)?;
+        }
+
         // compile all local procedures in the module; once the compilation is complete, we get
         // all compiled procedures (and their combined callset) from the context
         for proc_ast in module.ast.procs().iter() {
@@ -262,9 +320,9 @@ impl Assembler {
                 prologue: vec![Operation::Push(num_locals), Operation::FmpUpdate],
                 epilogue: vec![Operation::Push(-num_locals), Operation::FmpUpdate],
             };
-            self.compile_body(proc.body.nodes().iter(), context, Some(wrapper))?
+            self.compile_body(&proc.body, context, Some(wrapper))?
         } else {
-            self.compile_body(proc.body.nodes().iter(), context, None)?
+            self.compile_body(&proc.body, context, None)?
         };
 
         context.complete_proc(code_root);
@@ -275,24 +333,26 @@ impl Assembler {
     // CODE BODY COMPILER
     // --------------------------------------------------------------------------------------------
 
-    /// TODO: add comments
-    fn compile_body<A, N>(
+    /// Compiles the specified code body into a single code block.
+    ///
+    /// If the code body contains source locations of its nodes, the locations of instructions are
+    /// recorded in the AsmOp decorators emitted in debug mode.
+    fn compile_body(
         &self,
-        body: A,
+        body: &CodeBody,
         context: &mut AssemblyContext,
         wrapper: Option<BodyWrapper>,
-    ) -> Result<CodeBlock, AssemblyError>
-    where
-        A: Iterator<Item = N>,
-        N: Borrow<Node>,
-    {
+    ) -> Result<CodeBlock, AssemblyError> {
         let mut blocks: Vec<CodeBlock> = Vec::new();
-        let mut span = SpanBuilder::new(wrapper);
+        let mut span = SpanBuilder::new(wrapper, self.optimize && !self.in_debug_mode);
 
-        for node in body {
-            match node.borrow() {
+        for (i, node) in body.nodes().iter().enumerate() {
+            match node {
                 Node::Instruction(inner) => {
-                    if let Some(block) = self.compile_instruction(inner, &mut span, context)? {
+                    let location = body.source_locations().get(i).copied();
+                    if let Some(block) =
+                        self.compile_instruction(inner, location, &mut span, context)?
+                    {
                         span.extract_span_into(&mut blocks);
                         blocks.push(block);
                     }
@@ -304,12 +364,12 @@ impl Assembler {
                 } => {
                     span.extract_span_into(&mut blocks);
 
-                    let true_case = self.compile_body(true_case.nodes().iter(), context, None)?;
+                    let true_case = self.compile_body(true_case, context, None)?;
 
                     // else is an exception because it is optional; hence, will have to be replaced
                     // by noop span
                     let false_case = if !false_case.nodes().is_empty() {
-                        self.compile_body(false_case.nodes().iter(), context, None)?
+                        self.compile_body(false_case, context, None)?
                     } else {
                         CodeBlock::new_span(vec![Operation::Noop])
                     };
@@ -322,18 +382,24 @@ impl Assembler {
                 Node::Repeat { times, body } => {
                     span.extract_span_into(&mut blocks);
 
-                    let block = self.compile_body(body.nodes().iter(), context, None)?;
+                    let block = self.compile_body(body, context, None)?;
 
                     for _ in 0..*times {
                         blocks.push(block.clone());
                     }
                 }
 
-                Node::While { body } => {
+                Node::While {
+                    body,
+                    max_iterations,
+                } => {
                     span.extract_span_into(&mut blocks);
 
-                    let block = self.compile_body(body.nodes().iter(), context, None)?;
-                    let block = CodeBlock::new_loop(block);
+                    let block = self.compile_body(body, context, None)?;
+                    let block = match max_iterations {
+                        Some(max_iterations) => CodeBlock::new_bounded_loop(block, *max_iterations),
+                        None => CodeBlock::new_loop(block),
+                    };
 
                     blocks.push(block);
                 }
@@ -383,6 +449,61 @@ impl Assembler {
         Ok(())
     }
 
+    /// Ensures that all procedures imported from other modules and invoked from the specified
+    /// code body (including nested blocks) via `exec` or `call` instructions are present in the
+    /// cache, compiling their modules if needed.
+    ///
+    /// # Errors
+    /// Returns [AssemblyError::UndefinedProcedure] with the name of the procedure (as recorded in
+    /// `invoked_procs` by the parser) and the source location of the invocation if an invoked
+    /// procedure cannot be found in the module it is imported from, or if the module itself is not
+    /// available to the assembler. Other errors (e.g., errors encountered while compiling the
+    /// imported module) are returned as is.
+    fn resolve_invoked_procs(
+        &self,
+        body: &CodeBody,
+        invoked_procs: &InvokedProcsMap,
+        context: &mut AssemblyContext,
+    ) -> Result<(), AssemblyError> {
+        for (i, node) in body.nodes().iter().enumerate() {
+            match node {
+                Node::Instruction(
+                    Instruction::ExecImported(proc_id) | Instruction::CallImported(proc_id),
+                ) => {
+                    self.ensure_procedure_is_in_cache(proc_id, context).map_err(
+                        |err| match err {
+                            AssemblyError::ImportedProcModuleNotFound(id)
+                            | AssemblyError::ImportedProcNotFoundInModule(id, _)
+                                if id == *proc_id =>
+                            {
+                                let name = match invoked_procs.get(proc_id) {
+                                    Some((name, module_path)) => module_path.append_unchecked(name),
+                                    None => proc_id.to_string(),
+                                };
+                                let location = body.source_locations().get(i).copied();
+                                AssemblyError::undefined_procedure(&name, location)
+                            }
+                            err => err,
+                        },
+                    )?;
+                }
+                Node::Instruction(_) => (),
+                Node::IfElse {
+                    true_case,
+                    false_case,
+                } => {
+                    self.resolve_invoked_procs(true_case, invoked_procs, context)?;
+                    self.resolve_invoked_procs(false_case, invoked_procs, context)?;
+                }
+                Node::Repeat { body, .. } | Node::While { body, .. } => {
+                    self.resolve_invoked_procs(body, invoked_procs, context)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     // CODE BLOCK BUILDER
     // --------------------------------------------------------------------------------------------
     /// Returns the [CodeBlockTable] associated with the [AssemblyContext].
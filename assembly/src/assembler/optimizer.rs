@@ -0,0 +1,234 @@
+use super::{Decorator, DecoratorList, Felt, Operation, Vec, ONE, ZERO};
+use vm_core::FieldElement;
+
+// SPAN OPTIMIZER
+// ================================================================================================
+
+/// Rewrites the provided operations of a SPAN block into an equivalent sequence of operations
+/// which is not longer than the original sequence; the positions of the provided decorators are
+/// updated accordingly.
+///
+/// The rewritten sequence leaves the stack in the same state as the original sequence, but it may
+/// take fewer cycles to execute. Thus, the clock cycle at which subsequent operations are executed
+/// may change (and with it, the value pushed onto the stack by `CLK`).
+///
+/// Operations between two consecutive decorators are optimized independently, so that each
+/// decorator is executed in the same state of the stack as before the optimization. If the
+/// decorators contain AsmOp decorators, the operations are left unchanged, as AsmOp decorators
+/// track the exact number of cycles of each instruction.
+///
+/// The following optimizations are applied:
+/// - Arithmetic on constants pushed onto the stack is folded, e.g., `PUSH(2) PUSH(3) ADD` is
+///   replaced with `PUSH(5)`. Operations which would fail for the given constants (e.g., `INV`
+///   of zero, or `AND` of non-binary values) are not folded.
+/// - Operations which push a value onto the stack immediately followed by a `DROP` are removed.
+/// - Pairs of stack manipulations which cancel each other out (e.g., `SWAP SWAP` or
+///   `MOVUP3 MOVDN3`) are removed.
+pub fn optimize_span(ops: &mut Vec<Operation>, decorators: &mut DecoratorList) {
+    if decorators.iter().any(|(_, decorator)| matches!(decorator, Decorator::AsmOp(_))) {
+        return;
+    }
+
+    let mut result = Vec::with_capacity(ops.len());
+    let mut segment_start = 0;
+    for (position, _) in decorators.iter_mut() {
+        optimize_ops(&ops[segment_start..*position], &mut result);
+        segment_start = *position;
+        *position = result.len();
+    }
+    optimize_ops(&ops[segment_start..], &mut result);
+
+    *ops = result;
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// An operation, or a constant value pushed onto the stack by a sequence of operations.
+enum Item {
+    Op(Operation),
+    Const { value: Felt, ops: Vec<Operation> },
+}
+
+/// Appends an optimized equivalent of the provided operations to the result.
+fn optimize_ops(ops: &[Operation], result: &mut Vec<Operation>) {
+    let mut items: Vec<Item> = Vec::with_capacity(ops.len());
+    for &op in ops {
+        push_op(&mut items, op);
+    }
+
+    for item in items {
+        match item {
+            Item::Op(op) => result.push(op),
+            Item::Const { ops, .. } => result.extend(ops),
+        }
+    }
+}
+
+/// Appends the specified operation to the list of items, folding it into the preceding items if
+/// possible.
+fn push_op(items: &mut Vec<Item>, op: Operation) {
+    use Operation::*;
+
+    match op {
+        Pad => push_const(items, ZERO, Vec::from([op])),
+        Push(value) => push_const(items, value, Vec::from([op])),
+
+        Incr => fold_unary(items, op, |a| Some(a + ONE)),
+        Neg => fold_unary(items, op, |a| Some(-a)),
+        Inv => fold_unary(items, op, |a| (a != ZERO).then(|| a.inv())),
+        Eqz => fold_unary(items, op, |a| Some(to_felt(a == ZERO))),
+        Not => fold_unary(items, op, |a| is_binary(a).then(|| ONE - a)),
+
+        Add => fold_binary(items, op, |a, b| Some(a + b)),
+        Mul => fold_binary(items, op, |a, b| Some(a * b)),
+        Eq => fold_binary(items, op, |a, b| Some(to_felt(a == b))),
+        And => fold_binary(items, op, |a, b| (is_binary(a) && is_binary(b)).then(|| a * b)),
+        Or => fold_binary(items, op, |a, b| (is_binary(a) && is_binary(b)).then(|| a + b - a * b)),
+
+        Drop => match items.last() {
+            Some(Item::Const { .. }) => {
+                items.pop();
+            }
+            Some(Item::Op(prev)) if is_dup(*prev) => {
+                items.pop();
+            }
+            _ => items.push(Item::Op(op)),
+        },
+
+        Swap => match items.as_slice() {
+            [.., Item::Const { .. }, Item::Const { .. }] => {
+                let len = items.len();
+                items.swap(len - 2, len - 1);
+            }
+            _ => push_stack_op(items, op),
+        },
+
+        _ => push_stack_op(items, op),
+    }
+}
+
+/// Appends the specified operation to the list of items, removing the last item instead if it is
+/// an operation which is undone by the specified operation.
+fn push_stack_op(items: &mut Vec<Item>, op: Operation) {
+    match items.last() {
+        Some(Item::Op(prev)) if cancels_out(*prev, op) => {
+            items.pop();
+        }
+        _ => items.push(Item::Op(op)),
+    }
+}
+
+/// Appends a constant with the specified value pushed by the specified operations.
+fn push_const(items: &mut Vec<Item>, value: Felt, ops: Vec<Operation>) {
+    items.push(Item::Const { value, ops });
+}
+
+/// Folds the specified unary operation into the constant at the top of the list of items, if the
+/// operation can be evaluated on the constant.
+fn fold_unary(items: &mut Vec<Item>, op: Operation, eval: impl Fn(Felt) -> Option<Felt>) {
+    if let Some(Item::Const { value, .. }) = items.last() {
+        if let Some(result) = eval(*value) {
+            let Some(Item::Const { ops, .. }) = items.pop() else {
+                unreachable!()
+            };
+            push_folded(items, result, ops, op);
+            return;
+        }
+    }
+    items.push(Item::Op(op));
+}
+
+/// Folds the specified binary operation into the two constants at the top of the list of items,
+/// if the operation can be evaluated on the constants.
+fn fold_binary(items: &mut Vec<Item>, op: Operation, eval: impl Fn(Felt, Felt) -> Option<Felt>) {
+    if let [.., Item::Const { value: b, .. }, Item::Const { value: a, .. }] = items.as_slice() {
+        if let Some(result) = eval(*b, *a) {
+            let Some(Item::Const { ops: ops_a, .. }) = items.pop() else {
+                unreachable!()
+            };
+            let Some(Item::Const { ops: mut ops_b, .. }) = items.pop() else {
+                unreachable!()
+            };
+            ops_b.extend(ops_a);
+            push_folded(items, result, ops_b, op);
+            return;
+        }
+    }
+    items.push(Item::Op(op));
+}
+
+/// Appends a constant with the specified value resulting from applying the specified operation
+/// to the constants pushed by `ops`; the shorter of the original and the canonical sequence of
+/// operations pushing the constant is retained.
+fn push_folded(items: &mut Vec<Item>, value: Felt, mut ops: Vec<Operation>, op: Operation) {
+    ops.push(op);
+    let canonical = const_ops(value);
+    if canonical.len() <= ops.len() {
+        push_const(items, value, canonical);
+    } else {
+        push_const(items, value, ops);
+    }
+}
+
+/// Returns the operations pushing the specified value onto the stack, in the same way as they
+/// are emitted for a `push` instruction.
+fn const_ops(value: Felt) -> Vec<Operation> {
+    if value == ZERO {
+        Vec::from([Operation::Pad])
+    } else if value == ONE {
+        Vec::from([Operation::Pad, Operation::Incr])
+    } else {
+        Vec::from([Operation::Push(value)])
+    }
+}
+
+/// Returns true if executing `second` right after `first` leaves the stack unchanged.
+fn cancels_out(first: Operation, second: Operation) -> bool {
+    use Operation::*;
+
+    matches!(
+        (first, second),
+        (Swap, Swap)
+            | (SwapW, SwapW)
+            | (SwapW2, SwapW2)
+            | (SwapW3, SwapW3)
+            | (SwapDW, SwapDW)
+            | (MovUp2, MovDn2)
+            | (MovDn2, MovUp2)
+            | (MovUp3, MovDn3)
+            | (MovDn3, MovUp3)
+            | (MovUp4, MovDn4)
+            | (MovDn4, MovUp4)
+            | (MovUp5, MovDn5)
+            | (MovDn5, MovUp5)
+            | (MovUp6, MovDn6)
+            | (MovDn6, MovUp6)
+            | (MovUp7, MovDn7)
+            | (MovDn7, MovUp7)
+            | (MovUp8, MovDn8)
+            | (MovDn8, MovUp8)
+    )
+}
+
+/// Returns true if the specified operation pushes a copy of a stack element onto the stack.
+fn is_dup(op: Operation) -> bool {
+    use Operation::*;
+
+    matches!(
+        op,
+        Dup0 | Dup1 | Dup2 | Dup3 | Dup4 | Dup5 | Dup6 | Dup7 | Dup9 | Dup11 | Dup13 | Dup15
+    )
+}
+
+fn is_binary(value: Felt) -> bool {
+    value == ZERO || value == ONE
+}
+
+fn to_felt(value: bool) -> Felt {
+    if value {
+        ONE
+    } else {
+        ZERO
+    }
+}
@@ -1,8 +1,8 @@
 use super::{
-    AssemblyContext, AssemblyError, BodyWrapper, Borrow, CodeBlock, Decorator, DecoratorList,
-    Instruction, Operation, ToString, Vec,
+    optimizer::optimize_span, AssemblyContext, AssemblyError, BodyWrapper, Borrow, CodeBlock,
+    Decorator, DecoratorList, Instruction, Operation, ToString, Vec,
 };
-use vm_core::{AdviceInjector, AssemblyOp};
+use vm_core::{AdviceInjector, AssemblyOp, SourceLocation};
 
 // SPAN BUILDER
 // ================================================================================================
@@ -20,6 +20,7 @@ pub struct SpanBuilder {
     decorators: DecoratorList,
     epilogue: Vec<Operation>,
     last_asmop_pos: usize,
+    optimize: bool,
 }
 
 impl SpanBuilder {
@@ -30,15 +31,22 @@ impl SpanBuilder {
     /// If the wrapper is provided, the prologue of the wrapper is immediately appended to the
     /// vector of span operations. The epilogue of the wrapper is appended to the list of
     /// operations upon consumption of the builder via `extract_final_span_into()` method.
-    pub(super) fn new(wrapper: Option<BodyWrapper>) -> Self {
+    ///
+    /// If `optimize` is true, the operations of each SPAN block are optimized before the block is
+    /// extracted from the builder.
+    pub(super) fn new(wrapper: Option<BodyWrapper>, optimize: bool) -> Self {
         match wrapper {
             Some(wrapper) => Self {
                 ops: wrapper.prologue,
                 decorators: Vec::new(),
                 epilogue: wrapper.epilogue,
                 last_asmop_pos: 0,
+                optimize,
+            },
+            None => Self {
+                optimize,
+                ..Self::default()
             },
-            None => Self::default(),
         }
     }
 
@@ -98,12 +106,22 @@ impl SpanBuilder {
     ///
     /// This indicates that the provided instruction should be tracked and the cycle count for
     /// this instruction will be computed when the call to set_instruction_cycle_count() is made.
-    pub fn track_instruction(&mut self, instruction: &Instruction, ctx: &mut AssemblyContext) {
+    /// If the `location` of the instruction in the source code is known, it is recorded in the
+    /// decorator as well.
+    pub fn track_instruction(
+        &mut self,
+        instruction: &Instruction,
+        location: Option<SourceLocation>,
+        ctx: &mut AssemblyContext,
+    ) {
         let context_name = ctx.current_context_name().to_string();
         let num_cycles = 0;
         let op = instruction.to_string();
         let should_break = instruction.should_break();
-        let op = AssemblyOp::new(context_name, num_cycles, op, should_break);
+        let mut op = AssemblyOp::new(context_name, num_cycles, op, should_break);
+        if let Some(location) = location {
+            op = op.with_location(location);
+        }
         self.push_decorator(Decorator::AsmOp(op));
         self.last_asmop_pos = self.decorators.len() - 1;
     }
@@ -139,15 +157,27 @@ impl SpanBuilder {
     ///
     /// This consumes all operations and decorators in the builder, but does not touch the
     /// operations in the epilogue of the builder.
+    ///
+    /// If the builder contains decorators but no operations, a NOOP is added to the new SPAN block
+    /// so that the decorators are executed at this point of the program (i.e., before the control
+    /// flow block which follows them, or at the end of the code body). For advice injectors, this
+    /// does not depend on whether the program is compiled in debug mode, and thus, yields the same
+    /// MAST in both modes. Debug decorators, however, are emitted only in debug mode; thus, a
+    /// `debug` instruction which is not followed by an operation in the same SPAN block changes
+    /// the MAST of the program compiled in debug mode.
     pub fn extract_span_into(&mut self, target: &mut Vec<CodeBlock>) {
+        if self.optimize {
+            optimize_span(&mut self.ops, &mut self.decorators);
+        }
+
+        if self.ops.is_empty() && !self.decorators.is_empty() {
+            self.ops.push(Operation::Noop);
+        }
+
         if !self.ops.is_empty() {
             let ops = self.ops.drain(..).collect();
             let decorators = self.decorators.drain(..).collect();
             target.push(CodeBlock::new_span_with_decorators(ops, decorators));
-        } else if !self.decorators.is_empty() {
-            // this is a bug in the assembler. we shouldn't have decorators added without their
-            // associated operations
-            unreachable!()
         }
     }
 
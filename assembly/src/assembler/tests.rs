@@ -1,4 +1,4 @@
-use super::{combine_blocks, Assembler, CodeBlock, Library, Module, Operation};
+use super::{combine_blocks, Assembler, CodeBlock, Decorator, Library, Module, Operation};
 use crate::{ast::ModuleAst, LibraryNamespace, LibraryPath, Version};
 use core::slice::Iter;
 
@@ -139,3 +139,130 @@ fn nested_blocks() {
 
     assert_eq!(combined.hash(), program.hash());
 }
+
+#[test]
+fn optimized_spans() {
+    use vm_core::{AdviceInjector, Decorator, Felt};
+
+    let source = "begin push.2 push.3 add push.7 drop swap swap push.1 movup.2 movdn.2 end";
+    let assembler = Assembler::default().with_optimizations(true);
+    assert!(assembler.optimizations_enabled());
+    let program = assembler.compile(source).unwrap();
+    let expected =
+        CodeBlock::new_span(vec![Operation::Push(Felt::new(5)), Operation::Pad, Operation::Incr]);
+    assert_eq!(expected.hash(), program.hash());
+
+    // optimized and unoptimized programs have different hashes
+    let unoptimized = Assembler::default().compile(source).unwrap();
+    assert_ne!(program.hash(), unoptimized.hash());
+
+    // operations which would fail are not folded
+    let program = assembler.compile("begin push.0 inv push.2 push.3 and end").unwrap();
+    let expected = CodeBlock::new_span(vec![
+        Operation::Pad,
+        Operation::Inv,
+        Operation::Push(Felt::new(2)),
+        Operation::Push(Felt::new(3)),
+        Operation::And,
+    ]);
+    assert_eq!(expected.hash(), program.hash());
+
+    // operations are not folded across decorators
+    let program = assembler
+        .compile("begin push.2 push.3 adv.push_u64div mul push.4 drop end")
+        .unwrap();
+    let expected = CodeBlock::new_span_with_decorators(
+        vec![Operation::Push(Felt::new(2)), Operation::Push(Felt::new(3)), Operation::Mul],
+        vec![(2, Decorator::Advice(AdviceInjector::DivU64))],
+    );
+    assert_eq!(expected.hash(), program.hash());
+    assert_eq!(format!("{expected:?}"), format!("{:?}", program.root()));
+
+    // optimizations are not applied in debug mode
+    let program = Assembler::default()
+        .with_optimizations(true)
+        .with_debug_mode(true)
+        .compile(source)
+        .unwrap();
+    assert_eq!(unoptimized.hash(), program.hash());
+}
+
+#[test]
+fn debug_decorators_do_not_affect_hash() {
+    // debug decorators followed by an operation in the same code body do not affect the MAST
+    let sources = [
+        "begin push.1 while.true push.2 drop push.0 end debug.stack push.3 end",
+        "begin if.true debug.stack.2 end push.1 debug.mem end",
+        "begin push.1 while.true if.true push.0 end debug.stack push.0 end end",
+        "proc.foo.1 if.true loc_load.0 end debug.stack end begin exec.foo debug.stack push.1 end",
+    ];
+    for source in sources {
+        let release = Assembler::default().compile(source).unwrap();
+        let debug = Assembler::default().with_debug_mode(true).compile(source).unwrap();
+        assert_eq!(release.hash(), debug.hash(), "hash mismatch for {source}");
+    }
+
+    // debug decorators following a control flow block are attached to the next operation
+    let source = "begin push.1 if.true push.2 end debug.stack push.3 end";
+    let program = Assembler::default().with_debug_mode(true).compile(source).unwrap();
+    let last_span = match program.root() {
+        CodeBlock::Join(join) => match join.second() {
+            CodeBlock::Span(span) => span.clone(),
+            _ => panic!("expected a span block"),
+        },
+        _ => panic!("expected a join block"),
+    };
+    assert!(last_span
+        .decorators()
+        .iter()
+        .any(|(pos, decorator)| *pos == 0 && matches!(decorator, Decorator::Debug(_))));
+
+    // advice injectors following a control flow block are executed at the same point of the
+    // program in both modes
+    let source = "begin push.1 if.true push.2 end adv.push_u64div if.true push.3 end end";
+    let release = Assembler::default().compile(source).unwrap();
+    let debug = Assembler::default().with_debug_mode(true).compile(source).unwrap();
+    assert_eq!(release.hash(), debug.hash());
+}
+
+#[test]
+fn trailing_debug_decorators() {
+    // debug decorators following the last control flow block of a body are put into a NOOP span
+    // block executed after the control flow block
+    let sources = [
+        "begin push.1 while.true push.0 end debug.stack end",
+        "begin push.1 if.true push.2 end debug.stack end",
+    ];
+    for source in sources {
+        let program = Assembler::default().with_debug_mode(true).compile(source).unwrap();
+        let last_span = match program.root() {
+            CodeBlock::Join(join) => match join.second() {
+                CodeBlock::Span(span) => span.clone(),
+                _ => panic!("expected a span block for {source}"),
+            },
+            _ => panic!("expected a join block for {source}"),
+        };
+        let ops: Vec<Operation> =
+            last_span.op_batches().iter().flat_map(|batch| batch.ops().to_vec()).collect();
+        assert_eq!(vec![Operation::Noop], ops);
+        assert!(matches!(last_span.decorators().as_slice(), [(0, Decorator::Debug(_))]));
+    }
+
+    // debug decorators between two control flow blocks are executed between the blocks
+    let source = "begin push.1 if.true push.2 end debug.stack if.true push.3 end end";
+    let program = Assembler::default().with_debug_mode(true).compile(source).unwrap();
+    let expected = "\
+        begin \
+            join \
+                join \
+                    span pad incr end \
+                    if.true span push(2) end else span noop end end \
+                end \
+                join \
+                    span noop end \
+                    if.true span push(3) end else span noop end end \
+                end \
+            end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+}
@@ -16,6 +16,7 @@ pub use super::tokens::SourceLocation;
 
 mod nodes;
 pub use nodes::{AdviceInjectorNode, Instruction, Node};
+pub use vm_core::DebugOptions;
 
 mod code_body;
 pub use code_body::CodeBody;
@@ -26,7 +27,9 @@ pub use invocation_target::InvocationTarget;
 mod parsers;
 use parsers::{parse_constants, parse_imports, ParserContext};
 
-pub(crate) use parsers::{NAMESPACE_LABEL_PARSER, PROCEDURE_LABEL_PARSER};
+pub(crate) use parsers::{
+    ADVICE_TAPE_LABEL_PARSER, NAMESPACE_LABEL_PARSER, PROCEDURE_LABEL_PARSER,
+};
 
 mod serde;
 pub use serde::AstSerdeOptions;
@@ -60,6 +63,7 @@ const MAX_STACK_WORD_OFFSET: u8 = 12;
 type LocalProcMap = BTreeMap<String, (u16, ProcedureAst)>;
 type LocalConstMap = BTreeMap<String, u64>;
 type ReExportedProcMap = BTreeMap<String, ProcReExport>;
+pub(crate) type InvokedProcsMap = BTreeMap<ProcedureId, (ProcedureName, LibraryPath)>;
 
 // EXECUTABLE PROGRAM AST
 // ================================================================================================
@@ -72,6 +76,7 @@ pub struct ProgramAst {
     body: CodeBody,
     local_procs: Vec<ProcedureAst>,
     imports: BTreeMap<String, LibraryPath>,
+    invoked_procs: InvokedProcsMap,
     start: SourceLocation,
 }
 
@@ -99,10 +104,22 @@ impl ProgramAst {
             body,
             local_procs,
             imports,
+            invoked_procs: InvokedProcsMap::default(),
             start,
         })
     }
 
+    /// Binds the provided names to the procedures imported from other modules and invoked from
+    /// this program.
+    ///
+    /// The names are used only for error reporting; the procedures themselves are identified by
+    /// their [ProcedureId]s. The names are not serialized, and thus, errors about procedures
+    /// invoked from deserialized ASTs refer to the procedures by their IDs.
+    pub(crate) fn with_invoked_procs(mut self, invoked_procs: InvokedProcsMap) -> Self {
+        self.invoked_procs = invoked_procs;
+        self
+    }
+
     /// Binds the provided `locations` to the nodes of this program's body.
     ///
     /// The `start` location points to the `begin` token which does not have its own node.
@@ -137,6 +154,12 @@ impl ProgramAst {
         &self.body
     }
 
+    /// Returns the names of procedures imported from other modules and invoked from this program,
+    /// together with the paths of the modules they are imported from.
+    pub(crate) fn invoked_procs(&self) -> &InvokedProcsMap {
+        &self.invoked_procs
+    }
+
     // PARSER
     // --------------------------------------------------------------------------------------------
     /// Parses the provided source into a [ProgramAst].
@@ -152,6 +175,7 @@ impl ProgramAst {
             local_procs: LocalProcMap::default(),
             reexported_procs: ReExportedProcMap::default(),
             local_constants,
+            invoked_procs: InvokedProcsMap::default(),
         };
 
         context.parse_procedures(&mut tokens, false)?;
@@ -200,8 +224,11 @@ impl ProgramAst {
         }
 
         let local_procs = sort_procs_into_vec(context.local_procs);
+        let invoked_procs = context.invoked_procs;
         let (nodes, locations) = body.into_parts();
-        Ok(Self::new(nodes, local_procs, imports)?.with_source_locations(locations, start))
+        Ok(Self::new(nodes, local_procs, imports)?
+            .with_invoked_procs(invoked_procs)
+            .with_source_locations(locations, start))
     }
 
     // SERIALIZATION / DESERIALIZATION
@@ -315,6 +342,7 @@ pub struct ModuleAst {
     local_procs: Vec<ProcedureAst>,
     reexported_procs: Vec<ProcReExport>,
     imports: BTreeMap<String, LibraryPath>,
+    invoked_procs: InvokedProcsMap,
     docs: Option<String>,
 }
 
@@ -351,10 +379,22 @@ impl ModuleAst {
             local_procs,
             reexported_procs,
             imports,
+            invoked_procs: InvokedProcsMap::default(),
             docs,
         })
     }
 
+    /// Binds the provided names to the procedures imported from other modules and invoked from
+    /// this module.
+    ///
+    /// The names are used only for error reporting; the procedures themselves are identified by
+    /// their [ProcedureId]s. The names are not serialized, and thus, errors about procedures
+    /// invoked from deserialized ASTs refer to the procedures by their IDs.
+    pub(crate) fn with_invoked_procs(mut self, invoked_procs: InvokedProcsMap) -> Self {
+        self.invoked_procs = invoked_procs;
+        self
+    }
+
     // PARSER
     // --------------------------------------------------------------------------------------------
     /// Parses the provided source into a [ModuleAst].
@@ -370,6 +410,7 @@ impl ModuleAst {
             local_procs: LocalProcMap::default(),
             reexported_procs: ReExportedProcMap::default(),
             local_constants,
+            invoked_procs: InvokedProcsMap::default(),
         };
         context.parse_procedures(&mut tokens, true)?;
 
@@ -387,11 +428,13 @@ impl ModuleAst {
 
         // build a list of re-exported procedures sorted by procedure name
         let reexported_procs = context.reexported_procs.into_values().collect();
+        let invoked_procs = context.invoked_procs;
 
         // get module docs and make sure the size is within the limit
         let docs = tokens.take_module_comments();
 
         Self::new(local_procs, reexported_procs, imports, docs)
+            .map(|module| module.with_invoked_procs(invoked_procs))
     }
 
     // PUBLIC ACCESSORS
@@ -417,6 +460,12 @@ impl ModuleAst {
         &self.imports
     }
 
+    /// Returns the names of procedures imported from other modules and invoked from this module,
+    /// together with the paths of the modules they are imported from.
+    pub(crate) fn invoked_procs(&self) -> &InvokedProcsMap {
+        &self.invoked_procs
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
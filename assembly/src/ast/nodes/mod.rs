@@ -1,5 +1,6 @@
-use super::{CodeBody, Felt, ProcedureId, RpoDigest, ToString, Vec};
+use super::{CodeBody, Felt, ProcedureId, RpoDigest, String, ToString, Vec};
 use core::fmt;
+use vm_core::DebugOptions;
 
 mod advice;
 pub use advice::AdviceInjectorNode;
@@ -24,6 +25,7 @@ pub enum Node {
     },
     While {
         body: CodeBody,
+        max_iterations: Option<u32>,
     },
 }
 
@@ -31,6 +33,7 @@ pub enum Node {
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum Instruction {
     Assert,
+    AssertWithCode(Felt),
     AssertEq,
     AssertEqw,
     Assertz,
@@ -86,6 +89,8 @@ pub enum Instruction {
     U32WrappingAddImm(u32),
     U32OverflowingAdd,
     U32OverflowingAddImm(u32),
+    U32CheckedOverflowingAdd,
+    U32CheckedOverflowingAddImm(u32),
     U32OverflowingAdd3,
     U32WrappingAdd3,
     U32CheckedSub,
@@ -94,12 +99,16 @@ pub enum Instruction {
     U32WrappingSubImm(u32),
     U32OverflowingSub,
     U32OverflowingSubImm(u32),
+    U32CheckedOverflowingSub,
+    U32CheckedOverflowingSubImm(u32),
     U32CheckedMul,
     U32CheckedMulImm(u32),
     U32WrappingMul,
     U32WrappingMulImm(u32),
     U32OverflowingMul,
     U32OverflowingMulImm(u32),
+    U32CheckedOverflowingMul,
+    U32CheckedOverflowingMulImm(u32),
     U32OverflowingMadd,
     U32WrappingMadd,
     U32CheckedDiv,
@@ -266,6 +275,7 @@ pub enum Instruction {
     AdvPipe,
 
     AdvPush(u8),
+    AdvPushTape(String),
     AdvLoadW,
 
     AdvInject(AdviceInjectorNode),
@@ -292,6 +302,7 @@ pub enum Instruction {
 
     // ----- debug decorators ---------------------------------------------------------------------
     Breakpoint,
+    Debug(DebugOptions),
 }
 
 impl Instruction {
@@ -305,6 +316,7 @@ impl fmt::Display for Instruction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Assert => write!(f, "assert"),
+            Self::AssertWithCode(err_code) => write!(f, "assert.{err_code}"),
             Self::AssertEq => write!(f, "assert_eq"),
             Self::AssertEqw => write!(f, "assert_eqw"),
             Self::Assertz => write!(f, "assertz"),
@@ -360,6 +372,10 @@ impl fmt::Display for Instruction {
             Self::U32WrappingAddImm(value) => write!(f, "u32wrapping_add.{value}"),
             Self::U32OverflowingAdd => write!(f, "u32overflowing_add"),
             Self::U32OverflowingAddImm(value) => write!(f, "u32overflowing_add.{value}"),
+            Self::U32CheckedOverflowingAdd => write!(f, "u32checked_overflowing_add"),
+            Self::U32CheckedOverflowingAddImm(value) => {
+                write!(f, "u32checked_overflowing_add.{value}")
+            }
             Self::U32OverflowingAdd3 => write!(f, "u32overflowing_add3"),
             Self::U32WrappingAdd3 => write!(f, "u32wrapping_add3"),
             Self::U32CheckedSub => write!(f, "u32checked_sub"),
@@ -368,12 +384,20 @@ impl fmt::Display for Instruction {
             Self::U32WrappingSubImm(value) => write!(f, "u32wrapping_sub.{value}"),
             Self::U32OverflowingSub => write!(f, "u32overflowing_sub"),
             Self::U32OverflowingSubImm(value) => write!(f, "u32overflowing_sub.{value}"),
+            Self::U32CheckedOverflowingSub => write!(f, "u32checked_overflowing_sub"),
+            Self::U32CheckedOverflowingSubImm(value) => {
+                write!(f, "u32checked_overflowing_sub.{value}")
+            }
             Self::U32CheckedMul => write!(f, "u32checked_mul"),
             Self::U32CheckedMulImm(value) => write!(f, "u32checked_mul.{value}"),
             Self::U32WrappingMul => write!(f, "u32wrapping_mul"),
             Self::U32WrappingMulImm(value) => write!(f, "u32wrapping_mul.{value}"),
             Self::U32OverflowingMul => write!(f, "u32overflowing_mul"),
             Self::U32OverflowingMulImm(value) => write!(f, "u32overflowing_mul.{value}"),
+            Self::U32CheckedOverflowingMul => write!(f, "u32checked_overflowing_mul"),
+            Self::U32CheckedOverflowingMulImm(value) => {
+                write!(f, "u32checked_overflowing_mul.{value}")
+            }
             Self::U32OverflowingMadd => write!(f, "u32overflowing_madd"),
             Self::U32WrappingMadd => write!(f, "u32wrapping_madd"),
             Self::U32CheckedDiv => write!(f, "u32checked_div"),
@@ -541,6 +565,7 @@ impl fmt::Display for Instruction {
             Self::AdvPipe => write!(f, "adv_pipe"),
 
             Self::AdvPush(value) => write!(f, "adv_push.{value}"),
+            Self::AdvPushTape(name) => write!(f, "adv_push.{name}"),
             Self::AdvLoadW => write!(f, "adv_loadw"),
 
             Self::AdvInject(injector) => write!(f, "adv.{injector}"),
@@ -569,6 +594,7 @@ impl fmt::Display for Instruction {
 
             // ----- debug decorators -------------------------------------------------------------
             Self::Breakpoint => write!(f, "breakpoint"),
+            Self::Debug(options) => write!(f, "debug.{options}"),
         }
     }
 }
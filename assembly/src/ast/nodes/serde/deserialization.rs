@@ -1,7 +1,10 @@
 use super::{
-    super::AdviceInjectorNode, ByteReader, CodeBody, Deserializable, DeserializationError, Felt,
-    Instruction, Node, OpCode, ProcedureId, RpoDigest, ToString, MAX_PUSH_INPUTS,
+    super::{AdviceInjectorNode, DebugOptions},
+    ByteReader, CodeBody, Deserializable, DeserializationError, Felt, Instruction, Node, OpCode,
+    ProcedureId, RpoDigest, ToString, MAX_PUSH_INPUTS,
 };
+use crate::ast::ADVICE_TAPE_LABEL_PARSER;
+use core::str::from_utf8;
 
 // NODE DESERIALIZATION
 // ================================================================================================
@@ -35,14 +38,30 @@ impl Deserializable for Node {
             let body = CodeBody::new(nodes);
 
             Ok(Node::Repeat { times, body })
-        } else if first_byte == OpCode::While as u8 {
+        } else if first_byte == OpCode::While as u8 || first_byte == OpCode::BoundedWhile as u8 {
             source.read_u8()?;
 
+            let max_iterations = if first_byte == OpCode::BoundedWhile as u8 {
+                match source.read_u32()? {
+                    0 => {
+                        return Err(DeserializationError::InvalidValue(
+                            "max iterations of a while loop must be greater than zero".to_string(),
+                        ))
+                    }
+                    max_iterations => Some(max_iterations),
+                }
+            } else {
+                None
+            };
+
             let nodes_len = source.read_u16()? as usize;
             let nodes = Deserializable::read_batch_from(source, nodes_len)?;
             let body = CodeBody::new(nodes);
 
-            Ok(Node::While { body })
+            Ok(Node::While {
+                body,
+                max_iterations,
+            })
         } else {
             let inner = Deserializable::read_from(source)?;
             Ok(Node::Instruction(inner))
@@ -59,6 +78,7 @@ impl Deserializable for Instruction {
 
         match opcode {
             OpCode::Assert => Ok(Instruction::Assert),
+            OpCode::AssertWithCode => Ok(Instruction::AssertWithCode(Felt::read_from(source)?)),
             OpCode::AssertEq => Ok(Instruction::AssertEq),
             OpCode::AssertEqw => Ok(Instruction::AssertEqw),
             OpCode::Assertz => Ok(Instruction::Assertz),
@@ -116,6 +136,10 @@ impl Deserializable for Instruction {
             OpCode::U32OverflowingAddImm => {
                 Ok(Instruction::U32OverflowingAddImm(source.read_u32()?))
             }
+            OpCode::U32CheckedOverflowingAdd => Ok(Instruction::U32CheckedOverflowingAdd),
+            OpCode::U32CheckedOverflowingAddImm => {
+                Ok(Instruction::U32CheckedOverflowingAddImm(source.read_u32()?))
+            }
             OpCode::U32OverflowingAdd3 => Ok(Instruction::U32OverflowingAdd3),
             OpCode::U32WrappingAdd3 => Ok(Instruction::U32WrappingAdd3),
             OpCode::U32CheckedSub => Ok(Instruction::U32CheckedSub),
@@ -126,6 +150,10 @@ impl Deserializable for Instruction {
             OpCode::U32OverflowingSubImm => {
                 Ok(Instruction::U32OverflowingSubImm(source.read_u32()?))
             }
+            OpCode::U32CheckedOverflowingSub => Ok(Instruction::U32CheckedOverflowingSub),
+            OpCode::U32CheckedOverflowingSubImm => {
+                Ok(Instruction::U32CheckedOverflowingSubImm(source.read_u32()?))
+            }
             OpCode::U32CheckedMul => Ok(Instruction::U32CheckedMul),
             OpCode::U32CheckedMulImm => Ok(Instruction::U32CheckedMulImm(source.read_u32()?)),
             OpCode::U32WrappingMul => Ok(Instruction::U32WrappingMul),
@@ -134,6 +162,10 @@ impl Deserializable for Instruction {
             OpCode::U32OverflowingMulImm => {
                 Ok(Instruction::U32OverflowingMulImm(source.read_u32()?))
             }
+            OpCode::U32CheckedOverflowingMul => Ok(Instruction::U32CheckedOverflowingMul),
+            OpCode::U32CheckedOverflowingMulImm => {
+                Ok(Instruction::U32CheckedOverflowingMulImm(source.read_u32()?))
+            }
             OpCode::U32OverflowingMadd => Ok(Instruction::U32OverflowingMadd),
             OpCode::U32WrappingMadd => Ok(Instruction::U32WrappingMadd),
             OpCode::U32CheckedDiv => Ok(Instruction::U32CheckedDiv),
@@ -331,6 +363,16 @@ impl Deserializable for Instruction {
             OpCode::AdvPipe => Ok(Instruction::AdvPipe),
 
             OpCode::AdvPush => Ok(Instruction::AdvPush(source.read_u8()?)),
+            OpCode::AdvPushTape => {
+                let len = source.read_u8()? as usize;
+                let name = source.read_vec(len)?;
+                let name = from_utf8(&name)
+                    .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+                ADVICE_TAPE_LABEL_PARSER
+                    .parse_label(name)
+                    .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+                Ok(Instruction::AdvPushTape(name.to_string()))
+            }
             OpCode::AdvLoadW => Ok(Instruction::AdvLoadW),
 
             OpCode::AdvInject => Ok(Instruction::AdvInject(AdviceInjectorNode::read_from(source)?)),
@@ -355,12 +397,16 @@ impl Deserializable for Instruction {
             OpCode::CallImported => Ok(Instruction::CallImported(ProcedureId::read_from(source)?)),
             OpCode::SysCall => Ok(Instruction::SysCall(ProcedureId::read_from(source)?)),
 
+            // ----- debug decorators -------------------------------------------------------------
+            OpCode::Debug => Ok(Instruction::Debug(DebugOptions::read_from(source)?)),
+
             // ----- control flow -----------------------------------------------------------------
             // control flow instructions should be parsed as a part of Node::read_from() and we
             // should never get here
             OpCode::IfElse => unreachable!(),
             OpCode::Repeat => unreachable!(),
             OpCode::While => unreachable!(),
+            OpCode::BoundedWhile => unreachable!(),
         }
     }
 }
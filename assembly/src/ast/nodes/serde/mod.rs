@@ -273,7 +273,25 @@ pub enum OpCode {
     CallImported = 238,
     SysCall = 239,
 
+    // ----- debug decorators ---------------------------------------------------------------------
+    Debug = 240,
+
+    // ----- u32 operations (continued) -----------------------------------------------------------
+    U32CheckedOverflowingAdd = 241,
+    U32CheckedOverflowingAddImm = 242,
+    U32CheckedOverflowingSub = 243,
+    U32CheckedOverflowingSubImm = 244,
+    U32CheckedOverflowingMul = 245,
+    U32CheckedOverflowingMulImm = 246,
+
+    // ----- field operations (continued) ---------------------------------------------------------
+    AssertWithCode = 247,
+
+    // ----- input / output operations (continued) ------------------------------------------------
+    AdvPushTape = 248,
+
     // ----- control flow -------------------------------------------------------------------------
+    BoundedWhile = 252,
     IfElse = 253,
     Repeat = 254,
     While = 255,
@@ -1,5 +1,5 @@
 use super::{ByteWriter, Instruction, Node, OpCode, Serializable};
-use crate::ast::MAX_BODY_LEN;
+use crate::ast::{ADVICE_TAPE_LABEL_PARSER, MAX_BODY_LEN};
 
 // NODE SERIALIZATION
 // ================================================================================================
@@ -35,8 +35,19 @@ impl Serializable for Node {
                 target.write_u16(body.nodes().len() as u16);
                 body.nodes().write_into(target);
             }
-            Self::While { body } => {
-                OpCode::While.write_into(target);
+            Self::While {
+                body,
+                max_iterations,
+            } => {
+                // bounded loops are encoded with a separate opcode so that the encoding of
+                // unbounded loops stays the same
+                match max_iterations {
+                    Some(max_iterations) => {
+                        OpCode::BoundedWhile.write_into(target);
+                        target.write_u32(*max_iterations);
+                    }
+                    None => OpCode::While.write_into(target),
+                }
 
                 assert!(body.nodes().len() <= MAX_BODY_LEN, "too many body nodes");
                 target.write_u16(body.nodes().len() as u16);
@@ -53,6 +64,10 @@ impl Serializable for Instruction {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         match self {
             Self::Assert => OpCode::Assert.write_into(target),
+            Self::AssertWithCode(err_code) => {
+                OpCode::AssertWithCode.write_into(target);
+                err_code.write_into(target);
+            }
             Self::AssertEq => OpCode::AssertEq.write_into(target),
             Self::AssertEqw => OpCode::AssertEqw.write_into(target),
             Self::Assertz => OpCode::Assertz.write_into(target),
@@ -141,6 +156,11 @@ impl Serializable for Instruction {
                 OpCode::U32OverflowingAddImm.write_into(target);
                 target.write_u32(*v);
             }
+            Self::U32CheckedOverflowingAdd => OpCode::U32CheckedOverflowingAdd.write_into(target),
+            Self::U32CheckedOverflowingAddImm(v) => {
+                OpCode::U32CheckedOverflowingAddImm.write_into(target);
+                target.write_u32(*v);
+            }
             Self::U32OverflowingAdd3 => OpCode::U32OverflowingAdd3.write_into(target),
             Self::U32WrappingAdd3 => OpCode::U32WrappingAdd3.write_into(target),
             Self::U32CheckedSub => OpCode::U32CheckedSub.write_into(target),
@@ -158,6 +178,11 @@ impl Serializable for Instruction {
                 OpCode::U32OverflowingSubImm.write_into(target);
                 target.write_u32(*v);
             }
+            Self::U32CheckedOverflowingSub => OpCode::U32CheckedOverflowingSub.write_into(target),
+            Self::U32CheckedOverflowingSubImm(v) => {
+                OpCode::U32CheckedOverflowingSubImm.write_into(target);
+                target.write_u32(*v);
+            }
             Self::U32CheckedMul => OpCode::U32CheckedMul.write_into(target),
             Self::U32CheckedMulImm(v) => {
                 OpCode::U32CheckedMulImm.write_into(target);
@@ -173,6 +198,11 @@ impl Serializable for Instruction {
                 OpCode::U32OverflowingMulImm.write_into(target);
                 target.write_u32(*v);
             }
+            Self::U32CheckedOverflowingMul => OpCode::U32CheckedOverflowingMul.write_into(target),
+            Self::U32CheckedOverflowingMulImm(v) => {
+                OpCode::U32CheckedOverflowingMulImm.write_into(target);
+                target.write_u32(*v);
+            }
             Self::U32OverflowingMadd => OpCode::U32OverflowingMadd.write_into(target),
             Self::U32WrappingMadd => OpCode::U32WrappingMadd.write_into(target),
             Self::U32CheckedDiv => OpCode::U32CheckedDiv.write_into(target),
@@ -443,6 +473,15 @@ impl Serializable for Instruction {
             Self::MemStream => OpCode::MemStream.write_into(target),
             Self::AdvPipe => OpCode::AdvPipe.write_into(target),
 
+            Self::AdvPushTape(name) => {
+                OpCode::AdvPushTape.write_into(target);
+                debug_assert!(
+                    ADVICE_TAPE_LABEL_PARSER.parse_label(name).is_ok(),
+                    "the parser should ensure the name of the tape is valid"
+                );
+                target.write_u8(name.len() as u8);
+                target.write_bytes(name.as_bytes());
+            }
             Self::AdvPush(v) => {
                 OpCode::AdvPush.write_into(target);
                 target.write_u8(*v);
@@ -496,6 +535,10 @@ impl Serializable for Instruction {
             Self::Breakpoint => {
                 // this is a transparent instruction and will not be encoded into the library
             }
+            Self::Debug(options) => {
+                OpCode::Debug.write_into(target);
+                options.write_into(target)
+            }
         }
     }
 }
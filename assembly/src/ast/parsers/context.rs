@@ -1,7 +1,8 @@
 use super::{
-    super::ProcReExport, adv_ops, field_ops, io_ops, stack_ops, u32_ops, CodeBody, Instruction,
-    InvocationTarget, LibraryPath, LocalConstMap, LocalProcMap, Node, ParsingError, ProcedureAst,
-    ProcedureId, ReExportedProcMap, Token, TokenStream, MAX_BODY_LEN, MAX_DOCS_LEN,
+    super::ProcReExport, adv_ops, debug, field_ops, io_ops, stack_ops, u32_ops, CodeBody,
+    Instruction, InvocationTarget, InvokedProcsMap, LibraryPath, LocalConstMap, LocalProcMap, Node,
+    ParsingError, ProcedureAst, ProcedureId, ProcedureName, ReExportedProcMap, Token, TokenStream,
+    MAX_BODY_LEN, MAX_DOCS_LEN,
 };
 use vm_core::utils::{
     collections::{BTreeMap, Vec},
@@ -17,6 +18,7 @@ pub struct ParserContext<'a> {
     pub local_procs: LocalProcMap,
     pub reexported_procs: ReExportedProcMap,
     pub local_constants: LocalConstMap,
+    pub invoked_procs: InvokedProcsMap,
 }
 
 impl ParserContext<'_> {
@@ -24,7 +26,7 @@ impl ParserContext<'_> {
     // --------------------------------------------------------------------------------------------
 
     /// Parses an if-else statement from the provided token stream into an AST node.
-    fn parse_if(&self, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
+    fn parse_if(&mut self, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
         // record start of the if-else block and consume the 'if' token
         let if_start = tokens.pos();
         let if_token = tokens.read().expect("no if token");
@@ -99,11 +101,11 @@ impl ParserContext<'_> {
     }
 
     /// Parses a while statement from the provided token stream into an AST node.
-    fn parse_while(&self, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
+    fn parse_while(&mut self, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
         // record start of the while block and consume the 'while' token
         let while_start = tokens.pos();
         let while_token = tokens.read().expect("no while token");
-        while_token.validate_while()?;
+        let max_iterations = while_token.parse_while()?;
         tokens.advance();
 
         // read the loop body
@@ -126,11 +128,14 @@ impl ParserContext<'_> {
         }?;
         tokens.advance();
 
-        Ok(Node::While { body })
+        Ok(Node::While {
+            body,
+            max_iterations,
+        })
     }
 
     /// Parses a repeat statement from the provided token stream into an AST node.
-    fn parse_repeat(&self, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
+    fn parse_repeat(&mut self, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
         // record start of the repeat block and consume the 'repeat' token
         let repeat_start = tokens.pos();
         let repeat_token = tokens.read().expect("no repeat token");
@@ -164,7 +169,7 @@ impl ParserContext<'_> {
     // --------------------------------------------------------------------------------------------
 
     /// Parse an `exec` token into an instruction node.
-    fn parse_exec(&self, token: &Token) -> Result<Node, ParsingError> {
+    fn parse_exec(&mut self, token: &Token) -> Result<Node, ParsingError> {
         match token.parse_invocation(token.parts()[0])? {
             InvocationTarget::MastRoot(_) => Err(ParsingError::exec_with_mast_root(token)),
             InvocationTarget::ProcedureName(proc_name) => {
@@ -181,7 +186,7 @@ impl ParserContext<'_> {
     }
 
     /// Parse a `call` token into an instruction node.
-    fn parse_call(&self, token: &Token) -> Result<Node, ParsingError> {
+    fn parse_call(&mut self, token: &Token) -> Result<Node, ParsingError> {
         match token.parse_invocation(token.parts()[0])? {
             InvocationTarget::MastRoot(root_hash) => {
                 let inner = Instruction::CallMastRoot(root_hash);
@@ -264,7 +269,7 @@ impl ParserContext<'_> {
     /// - Procedure declaration or procedure body is malformed.
     /// - A procedure with the same name has already been either declared or re-exported from this
     ///   context.
-    fn parse_procedure(&self, tokens: &mut TokenStream) -> Result<ProcedureAst, ParsingError> {
+    fn parse_procedure(&mut self, tokens: &mut TokenStream) -> Result<ProcedureAst, ParsingError> {
         let proc_start = tokens.pos();
 
         // parse procedure declaration, make sure the procedure with the same name hasn't been
@@ -357,7 +362,7 @@ impl ParserContext<'_> {
     /// Nodes are added to the list until `if`, `else`, `while`, `repeat`, `end`, `export`, `proc`,
     /// or `begin` tokens are encountered, or an error occurs.
     pub fn parse_body(
-        &self,
+        &mut self,
         tokens: &mut TokenStream,
         break_on_else: bool,
     ) -> Result<CodeBody, ParsingError> {
@@ -422,13 +427,13 @@ impl ParserContext<'_> {
     // --------------------------------------------------------------------------------------------
 
     /// Parses a token into an instruction node.
-    fn parse_op_token(&self, op: &Token) -> Result<Node, ParsingError> {
+    fn parse_op_token(&mut self, op: &Token) -> Result<Node, ParsingError> {
         use Instruction::*;
 
         // based on the instruction, invoke the correct parser for the operation
         match op.parts()[0] {
             // ----- field operations -------------------------------------------------------------
-            "assert" => simple_instruction(op, Assert),
+            "assert" => field_ops::parse_assert(op),
             "assertz" => simple_instruction(op, Assertz),
             "assert_eq" => simple_instruction(op, AssertEq),
             "assert_eqw" => simple_instruction(op, AssertEqw),
@@ -476,6 +481,7 @@ impl ParserContext<'_> {
             "u32checked_add" => u32_ops::parse_u32checked_add(op),
             "u32wrapping_add" => u32_ops::parse_u32wrapping_add(op),
             "u32overflowing_add" => u32_ops::parse_u32overflowing_add(op),
+            "u32checked_overflowing_add" => u32_ops::parse_u32checked_overflowing_add(op),
 
             "u32overflowing_add3" => simple_instruction(op, U32OverflowingAdd3),
             "u32wrapping_add3" => simple_instruction(op, U32WrappingAdd3),
@@ -483,10 +489,12 @@ impl ParserContext<'_> {
             "u32checked_sub" => u32_ops::parse_u32checked_sub(op),
             "u32wrapping_sub" => u32_ops::parse_u32wrapping_sub(op),
             "u32overflowing_sub" => u32_ops::parse_u32overflowing_sub(op),
+            "u32checked_overflowing_sub" => u32_ops::parse_u32checked_overflowing_sub(op),
 
             "u32checked_mul" => u32_ops::parse_u32checked_mul(op),
             "u32wrapping_mul" => u32_ops::parse_u32wrapping_mul(op),
             "u32overflowing_mul" => u32_ops::parse_u32overflowing_mul(op),
+            "u32checked_overflowing_mul" => u32_ops::parse_u32checked_overflowing_mul(op),
 
             "u32overflowing_madd" => simple_instruction(op, U32OverflowingMadd),
             "u32wrapping_madd" => simple_instruction(op, U32WrappingMadd),
@@ -610,6 +618,7 @@ impl ParserContext<'_> {
 
             // ----- debug decorators -------------------------------------------------------------
             "breakpoint" => simple_instruction(op, Breakpoint),
+            "debug" => debug::parse_debug(op),
 
             // ----- catch all --------------------------------------------------------------------
             _ => Err(ParsingError::invalid_op(op)),
@@ -627,13 +636,14 @@ impl ParserContext<'_> {
             .map(|(index, _)| *index)
     }
 
-    /// Returns procedure ID of a procedure imported from the specified module.
+    /// Returns procedure ID of a procedure imported from the specified module, and records the
+    /// name of the procedure in the map of invoked procedures.
     ///
     /// # Errors
     /// Return an error if the module with the specified name has not been imported via the `use`
     /// statement.
     fn get_imported_proc_id(
-        &self,
+        &mut self,
         proc_name: &str,
         module_name: &str,
         token: &Token,
@@ -643,6 +653,13 @@ impl ParserContext<'_> {
             .get(module_name)
             .ok_or_else(|| ParsingError::procedure_module_not_imported(token, module_name))?;
         let proc_id = ProcedureId::from_name(proc_name, module_path);
+
+        // remember the name of the procedure so that the assembler can refer to the procedure by
+        // name (e.g., when the procedure cannot be found in the imported module)
+        let name = ProcedureName::try_from(proc_name.to_string())
+            .map_err(|_| ParsingError::invalid_proc_invocation(token, proc_name))?;
+        self.invoked_procs.insert(proc_id, (name, module_path.clone()));
+
         Ok(proc_id)
     }
 
@@ -0,0 +1,45 @@
+use super::{
+    parse_checked_param, parse_param, DebugOptions,
+    Instruction::Debug,
+    Node::{self, Instruction},
+    ParsingError, Token,
+};
+
+// INSTRUCTION PARSERS
+// ================================================================================================
+
+/// Returns `Debug` instruction node with the parsed debug options.
+///
+/// # Errors
+/// Returns an error if the instruction has invalid number of parameters or if the parameter
+/// values are invalid.
+pub fn parse_debug(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "debug");
+    if op.num_parts() < 2 {
+        return Err(ParsingError::missing_param(op));
+    }
+
+    let options = match op.parts()[1] {
+        "stack" => match op.num_parts() {
+            2 => DebugOptions::StackAll,
+            3 => DebugOptions::StackTop(parse_checked_param(op, 2, 1..=u16::MAX)?),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "mem" => match op.num_parts() {
+            2 => DebugOptions::MemAll,
+            3 => {
+                let addr = parse_param(op, 2)?;
+                DebugOptions::MemInterval(addr, addr)
+            }
+            4 => {
+                let start = parse_param(op, 2)?;
+                let end = parse_checked_param(op, 3, start..=u32::MAX)?;
+                DebugOptions::MemInterval(start, end)
+            }
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        _ => return Err(ParsingError::invalid_op(op)),
+    };
+
+    Ok(Instruction(Debug(options)))
+}
@@ -4,11 +4,35 @@ use super::{
     Node::{self, Instruction},
     ParsingError, Token,
 };
-use vm_core::{Felt, StarkField, ONE};
+use vm_core::{Felt, StarkField, ONE, ZERO};
 
 // INSTRUCTION PARSERS
 // ================================================================================================
 
+/// Returns `Assert` instruction node if no error code is provided or `AssertWithCode` instruction
+/// node otherwise.
+///
+/// An error code of zero is equivalent to no error code, and thus results in `Assert` node.
+///
+/// # Errors
+/// Returns an error if the instruction token has invalid param or more than one param.
+pub fn parse_assert(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "assert");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(Assert)),
+        2 => {
+            let err_code = parse_imm_value(op)?;
+            if err_code == ZERO {
+                Ok(Instruction(Assert))
+            } else {
+                Ok(Instruction(AssertWithCode(err_code)))
+            }
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
 /// Returns `Add` instruction node if no immediate value is provided or `AddImm` instruction
 /// node otherwise.
 ///
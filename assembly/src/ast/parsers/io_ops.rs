@@ -3,7 +3,7 @@ use super::{
     Instruction::*,
     LocalConstMap,
     Node::{self, Instruction},
-    ParsingError, Token, Vec, CONSTANT_LABEL_PARSER,
+    ParsingError, ToString, Token, Vec, ADVICE_TAPE_LABEL_PARSER, CONSTANT_LABEL_PARSER,
 };
 use crate::{StarkField, ADVICE_READ_LIMIT, HEX_CHUNK_SIZE, MAX_PUSH_INPUTS};
 use core::{convert::TryFrom, ops::RangeBounds};
@@ -74,20 +74,28 @@ pub fn parse_locaddr(op: &Token, constants: &LocalConstMap) -> Result<Node, Pars
     }
 }
 
-/// Returns `AdvPush` instruction node.
+/// Returns `AdvPush` instruction node if the parameter is a number, or `AdvPushTape` instruction
+/// node if the parameter is the name of an advice tape.
 ///
 /// # Errors
-/// Returns an error if the instruction token does not have exactly one parameter, or if the
-/// parameter is smaller than 1 or greater than 16.
+/// Returns an error if the instruction token does not have exactly one parameter, if the numeric
+/// parameter is smaller than 1 or greater than 16, or if the name of the tape is not a valid
+/// label.
 pub fn parse_adv_push(op: &Token) -> Result<Node, ParsingError> {
     debug_assert_eq!(op.parts()[0], "adv_push");
     match op.num_parts() {
         0 => unreachable!(),
         1 => Err(ParsingError::missing_param(op)),
-        2 => {
+        2 if op.parts()[1].starts_with(|c: char| c.is_ascii_digit()) => {
             let num_vals = parse_checked_param(op, 1, 1..=ADVICE_READ_LIMIT)?;
             Ok(Instruction(AdvPush(num_vals)))
         }
+        2 => {
+            let name = ADVICE_TAPE_LABEL_PARSER
+                .parse_label(op.parts()[1])
+                .map_err(|err| ParsingError::invalid_param_with_reason(op, 1, &err.to_string()))?;
+            Ok(Instruction(AdvPushTape(name.to_string())))
+        }
         _ => Err(ParsingError::extra_param(op)),
     }
 }
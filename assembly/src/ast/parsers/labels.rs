@@ -19,6 +19,14 @@ pub const NAMESPACE_LABEL_PARSER: LabelParser = LabelParser {
     start_with_letter: true,
 };
 
+/// Named advice tape label parser.
+pub const ADVICE_TAPE_LABEL_PARSER: LabelParser = LabelParser {
+    caps: false,
+    max_len: MAX_LABEL_LEN,
+    numbers_letters_underscore: true,
+    start_with_letter: true,
+};
+
 /// Procedure label parser.
 pub const PROCEDURE_LABEL_PARSER: LabelParser = LabelParser {
     caps: false,
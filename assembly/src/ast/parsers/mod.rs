@@ -1,13 +1,14 @@
 use super::{
-    bound_into_included_u64, AdviceInjectorNode, BTreeMap, CodeBody, Deserializable, Felt,
-    Instruction, InvocationTarget, LabelError, LibraryPath, LocalConstMap, LocalProcMap, Node,
-    ParsingError, ProcedureAst, ProcedureId, ReExportedProcMap, RpoDigest, SliceReader, StarkField,
-    String, ToString, Token, TokenStream, Vec, MAX_BODY_LEN, MAX_DOCS_LEN, MAX_IMPORTS,
-    MAX_LABEL_LEN, MAX_STACK_WORD_OFFSET,
+    bound_into_included_u64, AdviceInjectorNode, BTreeMap, CodeBody, DebugOptions, Deserializable,
+    Felt, Instruction, InvocationTarget, InvokedProcsMap, LabelError, LibraryPath, LocalConstMap,
+    LocalProcMap, Node, ParsingError, ProcedureAst, ProcedureId, ProcedureName, ReExportedProcMap,
+    RpoDigest, SliceReader, StarkField, String, ToString, Token, TokenStream, Vec, MAX_BODY_LEN,
+    MAX_DOCS_LEN, MAX_IMPORTS, MAX_LABEL_LEN, MAX_STACK_WORD_OFFSET,
 };
 use core::{fmt::Display, ops::RangeBounds};
 
 pub mod adv_ops;
+pub mod debug;
 pub mod field_ops;
 pub mod io_ops;
 pub mod stack_ops;
@@ -18,8 +19,8 @@ pub use context::ParserContext;
 
 mod labels;
 pub use labels::{
-    decode_hex_rpo_digest_label, CONSTANT_LABEL_PARSER, NAMESPACE_LABEL_PARSER,
-    PROCEDURE_LABEL_PARSER,
+    decode_hex_rpo_digest_label, ADVICE_TAPE_LABEL_PARSER, CONSTANT_LABEL_PARSER,
+    NAMESPACE_LABEL_PARSER, PROCEDURE_LABEL_PARSER,
 };
 
 // PARSERS FUNCTIONS
@@ -86,6 +86,25 @@ pub fn parse_u32overflowing_add(op: &Token) -> Result<Node, ParsingError> {
     }
 }
 
+/// Returns `U32CheckedOverflowingAdd` instruction node if no immediate value is provided or
+/// `U32CheckedOverflowingAddImm` instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token contains wrong number of parameters, or if the
+/// provided parameter is not a u32 value.
+pub fn parse_u32checked_overflowing_add(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "u32checked_overflowing_add");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(U32CheckedOverflowingAdd)),
+        2 => {
+            let value = parse_param::<u32>(op, 1)?;
+            Ok(Instruction(U32CheckedOverflowingAddImm(value)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
 /// Returns `U32CheckedSub` instruction node if no immediate value is provided or
 /// `U32CheckedSubImm` instruction node otherwise.
 ///
@@ -142,6 +161,25 @@ pub fn parse_u32overflowing_sub(op: &Token) -> Result<Node, ParsingError> {
     }
 }
 
+/// Returns `U32CheckedOverflowingSub` instruction node if no immediate value is provided or
+/// `U32CheckedOverflowingSubImm` instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token contains wrong number of parameters, or if the
+/// provided parameter is not a u32 value.
+pub fn parse_u32checked_overflowing_sub(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "u32checked_overflowing_sub");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(U32CheckedOverflowingSub)),
+        2 => {
+            let value = parse_param::<u32>(op, 1)?;
+            Ok(Instruction(U32CheckedOverflowingSubImm(value)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
 /// Returns `U32CheckedMul` instruction node if no immediate value is provided or
 /// `U32CheckedMulImm` instruction node otherwise.
 ///
@@ -199,6 +237,25 @@ pub fn parse_u32overflowing_mul(op: &Token) -> Result<Node, ParsingError> {
     }
 }
 
+/// Returns `U32CheckedOverflowingMul` instruction node if no immediate value is provided or
+/// `U32CheckedOverflowingMulImm` instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token contains wrong number of parameters, or if the
+/// provided parameter is not a u32 value.
+pub fn parse_u32checked_overflowing_mul(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "u32checked_overflowing_mul");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(U32CheckedOverflowingMul)),
+        2 => {
+            let value = parse_param::<u32>(op, 1)?;
+            Ok(Instruction(U32CheckedOverflowingMulImm(value)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
 /// Returns one of four possible instructions:
 /// - checked without parameter: `U32CheckedDiv`
 /// - unchecked without parameter: `U32UncheckedDiv`
@@ -2,7 +2,7 @@ use super::{
     AstSerdeOptions, BTreeMap, CodeBody, Felt, Instruction, LocalProcMap, ModuleAst, Node,
     ParsingError, ProcedureAst, ProcedureId, ProgramAst, SourceLocation, Token,
 };
-use vm_core::utils::SliceReader;
+use vm_core::utils::{Deserializable, Serializable, SliceReader};
 
 // UNIT TESTS
 // ================================================================================================
@@ -20,6 +20,21 @@ fn test_ast_parsing_program_simple() {
     assert_program_output(source, BTreeMap::new(), nodes);
 }
 
+#[test]
+fn test_ast_parsing_program_assert() {
+    let source = "begin push.1 assert push.1 assert.0 push.1 assert.42 end";
+    let nodes: Vec<Node> = vec![
+        Node::Instruction(Instruction::PushU8(1)),
+        Node::Instruction(Instruction::Assert),
+        Node::Instruction(Instruction::PushU8(1)),
+        Node::Instruction(Instruction::Assert),
+        Node::Instruction(Instruction::PushU8(1)),
+        Node::Instruction(Instruction::AssertWithCode(Felt::new(42))),
+    ];
+
+    assert_program_output(source, BTreeMap::new(), nodes);
+}
+
 #[test]
 fn test_ast_parsing_program_push() {
     let source = "begin push.10 push.500 push.70000 push.5000000000 push.5000000000.7000000000.9000000000.11000000000 push.5.7 push.500.700 push.70000.90000 push.5000000000.7000000000 end";
@@ -191,6 +206,22 @@ fn test_ast_parsing_adv_ops() {
     assert_program_output(source, BTreeMap::new(), nodes);
 }
 
+#[test]
+fn test_ast_parsing_adv_push_tape() {
+    let source = "begin adv_push.inputs adv_push.16 adv_push.tape_2 end";
+    let nodes: Vec<Node> = vec![
+        Node::Instruction(Instruction::AdvPushTape(String::from("inputs"))),
+        Node::Instruction(Instruction::AdvPush(16)),
+        Node::Instruction(Instruction::AdvPushTape(String::from("tape_2"))),
+    ];
+
+    assert_program_output(source, BTreeMap::new(), nodes);
+
+    // tape names must be valid labels
+    assert!(ProgramAst::parse("begin adv_push._tape end").is_err());
+    assert!(ProgramAst::parse("begin adv_push.a-b end").is_err());
+}
+
 #[test]
 fn test_ast_parsing_adv_injection() {
     use super::AdviceInjectorNode::*;
@@ -207,6 +238,34 @@ fn test_ast_parsing_adv_injection() {
     assert_program_output(source, BTreeMap::new(), nodes);
 }
 
+#[test]
+fn test_ast_parsing_debug() {
+    use super::DebugOptions::*;
+    use Instruction::Debug;
+
+    let source = "begin debug.stack debug.stack.4 debug.mem debug.mem.2 debug.mem.1.3 end";
+    let nodes: Vec<Node> = vec![
+        Node::Instruction(Debug(StackAll)),
+        Node::Instruction(Debug(StackTop(4))),
+        Node::Instruction(Debug(MemAll)),
+        Node::Instruction(Debug(MemInterval(2, 2))),
+        Node::Instruction(Debug(MemInterval(1, 3))),
+    ];
+    assert_program_output(source, BTreeMap::new(), nodes);
+
+    // invalid parameters should be rejected
+    for source in [
+        "begin debug end",
+        "begin debug.stack.0 end",
+        "begin debug.stack.1.2 end",
+        "begin debug.mem.3.1 end",
+        "begin debug.mem.1.2.3 end",
+        "begin debug.local end",
+    ] {
+        assert!(ProgramAst::parse(source).is_err(), "{source}");
+    }
+}
+
 #[test]
 fn test_ast_parsing_use() {
     let source = "\
@@ -418,6 +477,7 @@ fn parsed_while_if_body() {
         Node::While {
             body: CodeBody::new([Node::Instruction(Instruction::Mul)])
                 .with_source_locations([SourceLocation::new(4, 13), SourceLocation::new(5, 9)]),
+            max_iterations: None,
         },
         Node::Instruction(Instruction::Add),
         Node::IfElse {
@@ -730,6 +790,24 @@ fn test_ast_program_serde_simple() {
     assert_correct_program_serialization(source, false);
 }
 
+#[test]
+fn test_ast_program_serde_assert() {
+    let source = "begin push.1 assert push.1 assert.42 end";
+    assert_correct_program_serialization(source, false);
+}
+
+#[test]
+fn test_ast_program_serde_adv_push_tape() {
+    let source = "begin adv_push.1 adv_push.inputs adv_push.tape_2 end";
+    assert_correct_program_serialization(source, false);
+}
+
+#[test]
+fn test_ast_program_serde_debug() {
+    let source = "begin push.1 debug.stack debug.stack.2 debug.mem debug.mem.1.8 end";
+    assert_correct_program_serialization(source, false);
+}
+
 #[test]
 fn test_ast_program_serde_local_procs() {
     let source = "\
@@ -781,6 +859,10 @@ fn test_ast_program_serde_control_flow() {
             push.0
         end
 
+        while.true.max=16
+            push.0
+        end
+
         repeat.3
             push.2
             u32overflowing_mul
@@ -790,6 +872,28 @@ fn test_ast_program_serde_control_flow() {
     assert_correct_program_serialization(source, false);
 }
 
+#[test]
+fn test_ast_while_serde_format() {
+    // unbounded loops are encoded the same way as before bounded loops were introduced, and
+    // bounded loops are encoded with a separate opcode followed by the bound
+    let body = CodeBody::new([Node::Instruction(Instruction::Add)]);
+    let node = Node::While {
+        body: body.clone(),
+        max_iterations: None,
+    };
+    assert_eq!(vec![255, 1, 0, 4], node.to_bytes());
+
+    let node = Node::While {
+        body,
+        max_iterations: Some(16),
+    };
+    assert_eq!(vec![252, 16, 0, 0, 0, 1, 0, 4], node.to_bytes());
+
+    // a bound of zero is rejected
+    let mut source = SliceReader::new(&[252, 0, 0, 0, 0, 1, 0, 4]);
+    assert!(Node::read_from(&mut source).is_err());
+}
+
 #[test]
 fn assert_parsing_line_unmatched_begin() {
     let source = format!("\n\nbegin\npush.1.2\n\nadd mul");
@@ -935,11 +1039,12 @@ fn assert_program_output(source: &str, procedures: LocalProcMap, body: Vec<Node>
 // HELPER FUNCTIONS
 // ================================================================================================
 
-/// Clears the proc locations.
+/// Clears the proc locations and the names of invoked procedures.
 ///
-/// Currently, the locations are not part of the serialized libraries; thus, they have to be
-/// cleared before testing for equality
+/// Currently, the locations and the names are not part of the serialized libraries; thus, they have
+/// to be cleared before testing for equality
 fn clear_procs_loc_module(mut module: ModuleAst) -> ModuleAst {
+    module.invoked_procs.clear();
     module.local_procs.iter_mut().for_each(|m| {
         m.body.clear_locations();
         m.start = SourceLocation::default();
@@ -947,11 +1052,12 @@ fn clear_procs_loc_module(mut module: ModuleAst) -> ModuleAst {
     module
 }
 
-/// Clears the proc locations.
+/// Clears the proc locations and the names of invoked procedures.
 ///
-/// Currently, the locations are not part of the serialized libraries; thus, they have to be
-/// cleared before testing for equality
+/// Currently, the locations and the names are not part of the serialized libraries; thus, they have
+/// to be cleared before testing for equality
 fn clear_procs_loc_program(mut program: ProgramAst) -> ProgramAst {
+    program.invoked_procs.clear();
     program.start = SourceLocation::default();
     program.local_procs.iter_mut().for_each(|m| {
         m.body.clear_locations();
@@ -1007,6 +1113,7 @@ fn assert_correct_program_serialization(source: &str, serialize_imports: bool) {
     if !serialize_imports {
         program_deserialized.imports = program.imports.clone();
     }
+    program_deserialized.invoked_procs = program.invoked_procs.clone();
     assert_eq!(program, program_deserialized);
 }
 
@@ -1038,5 +1145,6 @@ fn assert_correct_module_serialization(source: &str, serialize_imports: bool) {
     if !serialize_imports {
         module_deserialized.imports = module.imports.clone();
     }
+    module_deserialized.invoked_procs = module.invoked_procs.clone();
     assert_eq!(module, module_deserialized);
 }
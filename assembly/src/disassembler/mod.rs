@@ -0,0 +1,1063 @@
+use super::{
+    ast::AdviceInjectorNode, crypto::hash::RpoDigest, Assembler, BTreeMap, CodeBlock,
+    CodeBlockTable, DisassemblyError, Felt, Operation, Program, StarkField, String, ToString, Vec,
+    ONE, ZERO,
+};
+use core::fmt::Write;
+use vm_core::{code_blocks::Span, utils::Box, AdviceInjector, Decorator};
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of spaces used to indent nested code.
+const INDENT: usize = 4;
+
+/// Instructions which are not parameterized (or are parameterized by a small number of values)
+/// and which are used as building blocks for rendering the operations of SPAN blocks.
+///
+/// If several instructions compile into the same sequence of operations, the one listed first is
+/// used.
+const SIMPLE_INSTRUCTIONS: &[&str] = &[
+    // field operations
+    "assert",
+    "assertz",
+    "assert_eq",
+    "assert_eqw",
+    "add",
+    "add.0",
+    "add.1",
+    "sub",
+    "mul",
+    "div",
+    "neg",
+    "inv",
+    "pow2",
+    "exp",
+    "not",
+    "and",
+    "or",
+    "xor",
+    "eq",
+    "eq.0",
+    "eq.1",
+    "neq",
+    "neq.0",
+    "neq.1",
+    "lt",
+    "lte",
+    "gt",
+    "gte",
+    "is_odd",
+    "eqw",
+    // ext2 operations
+    "ext2add",
+    "ext2sub",
+    "ext2mul",
+    "ext2div",
+    "ext2neg",
+    "ext2inv",
+    // u32 operations
+    "u32test",
+    "u32testw",
+    "u32assert",
+    "u32assert.2",
+    "u32assertw",
+    "u32cast",
+    "u32split",
+    "u32checked_add",
+    "u32wrapping_add",
+    "u32overflowing_add",
+    "u32overflowing_add3",
+    "u32wrapping_add3",
+    "u32checked_sub",
+    "u32wrapping_sub",
+    "u32overflowing_sub",
+    "u32checked_mul",
+    "u32wrapping_mul",
+    "u32overflowing_mul",
+    "u32overflowing_madd",
+    "u32wrapping_madd",
+    "u32checked_div",
+    "u32unchecked_div",
+    "u32checked_mod",
+    "u32unchecked_mod",
+    "u32checked_divmod",
+    "u32unchecked_divmod",
+    "u32checked_and",
+    "u32checked_or",
+    "u32checked_xor",
+    "u32checked_not",
+    "u32checked_shr",
+    "u32unchecked_shr",
+    "u32checked_shl",
+    "u32unchecked_shl",
+    "u32checked_rotr",
+    "u32unchecked_rotr",
+    "u32checked_rotl",
+    "u32unchecked_rotl",
+    "u32checked_popcnt",
+    "u32unchecked_popcnt",
+    "u32checked_eq",
+    "u32checked_neq",
+    "u32checked_lt",
+    "u32unchecked_lt",
+    "u32checked_lte",
+    "u32unchecked_lte",
+    "u32checked_gt",
+    "u32unchecked_gt",
+    "u32checked_gte",
+    "u32unchecked_gte",
+    "u32checked_min",
+    "u32unchecked_min",
+    "u32checked_max",
+    "u32unchecked_max",
+    // stack manipulation
+    "drop",
+    "dropw",
+    "padw",
+    "swap",
+    "swapdw",
+    "cswap",
+    "cswapw",
+    "cdrop",
+    "cdropw",
+    // input / output operations
+    "push.0",
+    "push.1",
+    "sdepth",
+    "clk",
+    "mem_load",
+    "mem_loadw",
+    "mem_store",
+    "mem_storew",
+    "mem_stream",
+    "adv_pipe",
+    "adv_push.1",
+    "adv_loadw",
+    // cryptographic operations
+    "hash",
+    "hmerge",
+    "hperm",
+    "mtree_get",
+    "mtree_set",
+    "mtree_merge",
+    "mtree_verify",
+    "fri_ext2fold4",
+];
+
+/// Instructions parameterized by a bit shift or rotation amount.
+const SHIFT_INSTRUCTIONS: &[&str] = &[
+    "u32checked_shr",
+    "u32unchecked_shr",
+    "u32checked_shl",
+    "u32unchecked_shl",
+    "u32checked_rotr",
+    "u32unchecked_rotr",
+    "u32checked_rotl",
+    "u32unchecked_rotl",
+];
+
+// DISASSEMBLER
+// ================================================================================================
+
+/// Renders the specified program as Miden assembly source code.
+///
+/// This builds a new [Disassembler] on every invocation. To disassemble several programs, build a
+/// [Disassembler] once and use [Disassembler::disassemble()] instead.
+///
+/// # Errors
+/// Returns an error under the conditions described in [Disassembler::disassemble()].
+pub fn disassemble(program: &Program) -> Result<String, DisassemblyError> {
+    Disassembler::new().disassemble(program)
+}
+
+/// Renders compiled programs as Miden assembly source code.
+///
+/// Building a disassembler involves compiling every instruction which is used as a building block
+/// for rendering the operations of SPAN blocks; thus, a disassembler should be built once and
+/// reused for all programs which need to be disassembled.
+pub struct Disassembler {
+    assembler: Assembler,
+    patterns: PatternTable,
+}
+
+impl Disassembler {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new disassembler.
+    pub fn new() -> Self {
+        let assembler = Assembler::default();
+        let patterns = PatternTable::new(&assembler);
+        Self {
+            assembler,
+            patterns,
+        }
+    }
+
+    // DISASSEMBLER
+    // --------------------------------------------------------------------------------------------
+
+    /// Renders the specified program as Miden assembly source code.
+    ///
+    /// The returned source code compiles into a program with the same hash as the specified
+    /// program. This is checked by re-assembling the rendered source code before it is returned.
+    ///
+    /// Since the MAST of a program does not retain all information present in the original source
+    /// code, the rendered source code differs from it in a number of ways:
+    /// - Procedure names, comments, and constants are not recovered. Procedures are named
+    ///   `proc_0`, `proc_1` etc.
+    /// - Procedures are emitted only where the MAST requires them (i.e., for `call` targets and
+    ///   for code which uses procedure locals); all other invoked procedures are inlined.
+    /// - Instructions which compile into the same sequence of operations are indistinguishable,
+    ///   and `repeat` blocks are recovered only for repeated control flow blocks.
+    /// - Advice injectors (e.g., `adv.push_mapval`) and `debug` decorators are emitted as
+    ///   instructions, but the source location decorators of programs compiled in debug mode are
+    ///   dropped.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The program contains a `syscall` or a `call` to a procedure not present in the code
+    ///   block table of the program.
+    /// - The program contains a code block or an operation which cannot be produced by the
+    ///   assembler.
+    /// - The program contains a decorator which cannot be expressed in Miden assembly. This
+    ///   includes injectors of named advice tapes (i.e., `adv_push.<name>`), since the MAST
+    ///   retains only the key under which the values of a tape are stored.
+    pub fn disassemble(&self, program: &Program) -> Result<String, DisassemblyError> {
+        let mut renderer = ProgramRenderer::new(program.cb_table(), &self.patterns);
+
+        let mut body = String::new();
+        renderer.render_body(&mut body, program.root(), 0, INDENT)?;
+
+        let mut source = String::new();
+        for proc in renderer.procs {
+            source.push_str(&proc);
+            source.push('\n');
+        }
+        source.push_str("begin\n");
+        source.push_str(&body);
+        source.push_str("end\n");
+
+        // make sure the rendered source indeed describes the same program
+        let reassembled = self
+            .assembler
+            .compile(&source)
+            .map_err(|err| DisassemblyError::RoundTripFailed(err.to_string()))?;
+        if reassembled.hash() != program.hash() {
+            return Err(DisassemblyError::RoundTripFailed("program hash mismatch".to_string()));
+        }
+
+        Ok(source)
+    }
+}
+
+impl Default for Disassembler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// PROGRAM RENDERER
+// ================================================================================================
+
+/// Accumulates procedures required to render a program.
+struct ProgramRenderer<'a> {
+    cb_table: &'a CodeBlockTable,
+    patterns: &'a PatternTable,
+    procs: Vec<String>,
+    proc_names: BTreeMap<RpoDigest, String>,
+}
+
+impl<'a> ProgramRenderer<'a> {
+    fn new(cb_table: &'a CodeBlockTable, patterns: &'a PatternTable) -> Self {
+        Self {
+            cb_table,
+            patterns,
+            procs: Vec::new(),
+            proc_names: BTreeMap::new(),
+        }
+    }
+
+    // CODE BLOCKS
+    // --------------------------------------------------------------------------------------------
+
+    /// Renders a code body compiled into the specified block.
+    fn render_body(
+        &mut self,
+        out: &mut String,
+        block: &CodeBlock,
+        num_locals: u16,
+        indent: usize,
+    ) -> Result<(), DisassemblyError> {
+        // empty bodies are compiled into a SPAN with a single NOOP
+        if is_empty_body(block) {
+            return Ok(());
+        }
+
+        match block {
+            CodeBlock::Join(_) if get_frame(block).is_none() => {
+                let blocks = split_body(block, false)?;
+                self.render_blocks(out, &blocks, num_locals, indent)
+            }
+            _ => self.render_block(out, block, num_locals, indent),
+        }
+    }
+
+    /// Renders a sequence of blocks of a code body, collapsing consecutive identical control flow
+    /// blocks into `repeat` statements.
+    fn render_blocks(
+        &mut self,
+        out: &mut String,
+        blocks: &[&CodeBlock],
+        num_locals: u16,
+        indent: usize,
+    ) -> Result<(), DisassemblyError> {
+        let mut i = 0;
+        while i < blocks.len() {
+            let block = blocks[i];
+            let mut count = 1;
+            if !block.is_span() {
+                while blocks.get(i + count).map(|b| b.hash()) == Some(block.hash()) {
+                    count += 1;
+                }
+            }
+
+            if count > 1 {
+                write_line(out, indent, format_args!("repeat.{count}"));
+                self.render_body(out, block, num_locals, indent + INDENT)?;
+                write_line(out, indent, format_args!("end"));
+            } else {
+                self.render_block(out, block, num_locals, indent)?;
+            }
+            i += count;
+        }
+        Ok(())
+    }
+
+    /// Renders a single block of a code body.
+    fn render_block(
+        &mut self,
+        out: &mut String,
+        block: &CodeBlock,
+        num_locals: u16,
+        indent: usize,
+    ) -> Result<(), DisassemblyError> {
+        match block {
+            CodeBlock::Span(span) => {
+                let (ops, decorators) = get_span_contents(span);
+                if ops == [Operation::Noop] {
+                    // a body consisting only of decorators is compiled into a SPAN with a NOOP
+                    return render_decorators(out, &decorators.concat(), indent);
+                }
+                self.render_ops(out, &ops, &decorators, num_locals, indent)
+            }
+            CodeBlock::Join(_) => {
+                if get_frame(block).is_some() {
+                    // a procedure with locals which cannot be inlined
+                    let name = self.add_proc(block)?;
+                    write_line(out, indent, format_args!("exec.{name}"));
+                } else {
+                    // code blocks compiled from a nested body of a repeat statement
+                    write_line(out, indent, format_args!("repeat.1"));
+                    self.render_body(out, block, num_locals, indent + INDENT)?;
+                    write_line(out, indent, format_args!("end"));
+                }
+                Ok(())
+            }
+            CodeBlock::Split(split) => {
+                write_line(out, indent, format_args!("if.true"));
+                self.render_body(out, split.on_true(), num_locals, indent + INDENT)?;
+                if !is_empty_body(split.on_false()) {
+                    write_line(out, indent, format_args!("else"));
+                    self.render_body(out, split.on_false(), num_locals, indent + INDENT)?;
+                }
+                write_line(out, indent, format_args!("end"));
+                Ok(())
+            }
+            CodeBlock::Loop(loop_block) => {
+                match loop_block.max_iterations() {
+                    Some(max_iterations) => {
+                        write_line(out, indent, format_args!("while.true.max={max_iterations}"))
+                    }
+                    None => write_line(out, indent, format_args!("while.true")),
+                }
+                self.render_body(out, loop_block.body(), num_locals, indent + INDENT)?;
+                write_line(out, indent, format_args!("end"));
+                Ok(())
+            }
+            CodeBlock::Call(call) => {
+                if call.is_syscall() {
+                    return Err(DisassemblyError::UnsupportedSyscall(call.fn_hash()));
+                }
+                let callee = self
+                    .cb_table
+                    .get(call.fn_hash())
+                    .ok_or(DisassemblyError::CallTargetNotFound(call.fn_hash()))?;
+                let name = self.add_proc(callee)?;
+                write_line(out, indent, format_args!("call.{name}"));
+                Ok(())
+            }
+            CodeBlock::Proxy(_) => Err(DisassemblyError::UnsupportedBlockStructure(block.hash())),
+        }
+    }
+
+    // PROCEDURES
+    // --------------------------------------------------------------------------------------------
+
+    /// Renders a procedure with the specified code root (unless such a procedure has already been
+    /// rendered), and returns the name of the procedure.
+    fn add_proc(&mut self, block: &CodeBlock) -> Result<String, DisassemblyError> {
+        if let Some(name) = self.proc_names.get(&block.hash()) {
+            return Ok(name.clone());
+        }
+
+        let mut body = String::new();
+        let num_locals = match (block, get_frame(block)) {
+            (CodeBlock::Span(span), Some(num_locals)) => {
+                let (ops, decorators) = get_span_contents(span);
+                let end = ops.len() - 2;
+                self.render_ops(&mut body, &ops[2..end], &decorators[2..=end], num_locals, INDENT)?;
+                num_locals
+            }
+            (CodeBlock::Join(_), Some(num_locals)) => {
+                let blocks = split_body(block, true)?;
+                let (first, last) = match (blocks[0], blocks[blocks.len() - 1]) {
+                    (CodeBlock::Span(first), CodeBlock::Span(last)) => {
+                        (get_span_contents(first), get_span_contents(last))
+                    }
+                    _ => unreachable!("procedure frame not in a span block"),
+                };
+                let end = last.0.len() - 2;
+                self.render_ops(&mut body, &first.0[2..], &first.1[2..], num_locals, INDENT)?;
+                self.render_blocks(&mut body, &blocks[1..blocks.len() - 1], num_locals, INDENT)?;
+                self.render_ops(&mut body, &last.0[..end], &last.1[..=end], num_locals, INDENT)?;
+                num_locals
+            }
+            _ => {
+                self.render_body(&mut body, block, 0, INDENT)?;
+                0
+            }
+        };
+
+        let name = format!("proc_{}", self.procs.len());
+        let mut proc = String::new();
+        if num_locals > 0 {
+            write_line(&mut proc, 0, format_args!("proc.{name}.{num_locals}"));
+        } else {
+            write_line(&mut proc, 0, format_args!("proc.{name}"));
+        }
+        proc.push_str(&body);
+        write_line(&mut proc, 0, format_args!("end"));
+
+        self.procs.push(proc);
+        self.proc_names.insert(block.hash(), name.clone());
+        Ok(name)
+    }
+
+    // OPERATIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Renders a sequence of operations of a SPAN block as a sequence of instructions.
+    ///
+    /// The decorators are grouped by the position of the operation before which they are executed,
+    /// and thus, there is one more group of decorators than there are operations.
+    ///
+    /// Operations executed within a frame of procedure locals are rendered as an invocation of a
+    /// procedure with locals.
+    fn render_ops(
+        &mut self,
+        out: &mut String,
+        ops: &[Operation],
+        decorators: &[Vec<Decorator>],
+        num_locals: u16,
+        indent: usize,
+    ) -> Result<(), DisassemblyError> {
+        let mut start = 0;
+        let mut i = 0;
+        while i < ops.len() {
+            match find_frame_end(ops, i) {
+                Some(end) => {
+                    self.render_instructions(
+                        out,
+                        &ops[start..i],
+                        &decorators[start..=i],
+                        num_locals,
+                        indent,
+                    )?;
+                    // decorators of the procedure body are located between the prologue and the
+                    // epilogue of the frame
+                    let body_decorators = decorators[i + 2..=end - 2]
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(pos, group)| group.iter().map(move |d| (pos + 2, d.clone())))
+                        .collect();
+                    let frame =
+                        CodeBlock::new_span_with_decorators(ops[i..end].to_vec(), body_decorators);
+                    let name = self.add_proc(&frame)?;
+                    write_line(out, indent, format_args!("exec.{name}"));
+                    start = end;
+                    i = end;
+                }
+                None => i += 1,
+            }
+        }
+        self.render_instructions(out, &ops[start..], &decorators[start..], num_locals, indent)
+    }
+
+    /// Renders a sequence of operations as the shortest sequence of instructions which compiles
+    /// into it, interleaved with the decorators executed before each of the operations.
+    ///
+    /// Decorators which are produced by the rendered instructions themselves (e.g., the advice
+    /// injector of `mtree_get`) are not rendered separately.
+    fn render_instructions(
+        &self,
+        out: &mut String,
+        ops: &[Operation],
+        decorators: &[Vec<Decorator>],
+        num_locals: u16,
+        indent: usize,
+    ) -> Result<(), DisassemblyError> {
+        // best[i] contains the number of instructions in the shortest rendering of ops[..i]
+        // (including the instructions rendered for decorators), together with the last
+        // instruction of this rendering
+        let mut best: Vec<Option<(usize, Instruction)>> = vec![None; ops.len() + 1];
+        best[0] = Some((0, Instruction::Pattern(0)));
+
+        for i in 0..ops.len() {
+            let count = match best[i] {
+                Some((count, _)) => count + 1 + decorators[i].len(),
+                None => continue,
+            };
+            for instruction in self.patterns.matches(&ops[i..], &decorators[i..], num_locals) {
+                let end = i + instruction.num_ops(self.patterns);
+                let count = count - instruction.num_decorators(self.patterns);
+                if best[end].map_or(true, |(c, _)| count < c) {
+                    best[end] = Some((count, instruction));
+                }
+            }
+        }
+
+        if best[ops.len()].is_none() {
+            let stuck_at = best.iter().rposition(|b| b.is_some()).expect("no rendering");
+            return Err(DisassemblyError::UnsupportedOperation(ops[stuck_at]));
+        }
+
+        let mut instructions = Vec::new();
+        let mut end = ops.len();
+        while end > 0 {
+            let (_, instruction) = best[end].expect("no rendering");
+            end -= instruction.num_ops(self.patterns);
+            instructions.push(instruction);
+        }
+        let mut pos = 0;
+        for instruction in instructions.into_iter().rev() {
+            let group = &decorators[pos];
+            let num_own = instruction.num_decorators(self.patterns);
+            render_decorators(out, &group[..group.len() - num_own], indent)?;
+            pos += instruction.num_ops(self.patterns);
+
+            match instruction {
+                Instruction::Pattern(idx) => {
+                    write_line(out, indent, format_args!("{}", self.patterns.names[idx]))
+                }
+                Instruction::Push(value) => {
+                    write_line(out, indent, format_args!("push.{}", value.as_int()))
+                }
+                Instruction::LocAddr(index) => {
+                    write_line(out, indent, format_args!("locaddr.{index}"))
+                }
+                Instruction::Assert(err_code) => {
+                    write_line(out, indent, format_args!("assert.{}", err_code.as_int()))
+                }
+            }
+        }
+        render_decorators(out, &decorators[ops.len()], indent)
+    }
+}
+
+// PATTERN TABLE
+// ================================================================================================
+
+/// An instruction rendered from a sequence of operations.
+#[derive(Clone, Copy)]
+enum Instruction {
+    /// An instruction from the pattern table at the specified index.
+    Pattern(usize),
+    /// A `push` instruction with the specified value (which must not be 0 or 1).
+    Push(Felt),
+    /// A `locaddr` instruction with the specified local index.
+    LocAddr(u16),
+    /// An `assert` instruction with the specified error code (which must not be 0).
+    Assert(Felt),
+}
+
+impl Instruction {
+    /// Returns the number of operations this instruction compiles into.
+    fn num_ops(&self, patterns: &PatternTable) -> usize {
+        match self {
+            Self::Pattern(idx) => patterns.ops[*idx].len(),
+            Self::Push(_) => 1,
+            Self::LocAddr(_) => 2,
+            Self::Assert(_) => 1,
+        }
+    }
+
+    /// Returns the number of decorators this instruction compiles into. All such decorators are
+    /// executed before the first operation of the instruction.
+    fn num_decorators(&self, patterns: &PatternTable) -> usize {
+        match self {
+            Self::Pattern(idx) => patterns.decorators[*idx][0].len(),
+            _ => 0,
+        }
+    }
+}
+
+/// Sequences of operations produced by simple instructions.
+struct PatternTable {
+    names: Vec<String>,
+    ops: Vec<Vec<Operation>>,
+    /// Decorators of each pattern grouped by the position of the operation before which they are
+    /// executed.
+    decorators: Vec<Vec<Vec<Decorator>>>,
+    /// Indexes of patterns grouped by the opcode of the first operation.
+    by_opcode: BTreeMap<u8, Vec<usize>>,
+}
+
+impl PatternTable {
+    /// Builds the pattern table by compiling each of the simple instructions.
+    fn new(assembler: &Assembler) -> Self {
+        let mut instructions: Vec<String> =
+            SIMPLE_INSTRUCTIONS.iter().map(|s| s.to_string()).collect();
+        for i in 0..16 {
+            instructions.push(format!("dup.{i}"));
+        }
+        for i in 1..16 {
+            instructions.push(format!("swap.{i}"));
+        }
+        for i in 2..16 {
+            instructions.push(format!("movup.{i}"));
+            instructions.push(format!("movdn.{i}"));
+        }
+        for i in 0..4 {
+            instructions.push(format!("dupw.{i}"));
+        }
+        for i in 1..4 {
+            instructions.push(format!("swapw.{i}"));
+        }
+        for i in 2..4 {
+            instructions.push(format!("movupw.{i}"));
+            instructions.push(format!("movdnw.{i}"));
+        }
+        for bits in 1..=64 {
+            instructions.push(format!("exp.u{bits}"));
+        }
+        for instruction in SHIFT_INSTRUCTIONS {
+            for i in 0..32 {
+                instructions.push(format!("{instruction}.{i}"));
+            }
+        }
+
+        let mut table = Self {
+            names: Vec::new(),
+            ops: Vec::new(),
+            decorators: Vec::new(),
+            by_opcode: BTreeMap::new(),
+        };
+
+        for instruction in instructions {
+            let (ops, mut decorators) = match assembler.compile(format!("begin {instruction} end"))
+            {
+                Ok(program) => match program.root() {
+                    CodeBlock::Span(span) => get_span_contents(span),
+                    _ => continue,
+                },
+                Err(_) => continue,
+            };
+            // decorators executed after the last operation cannot be attributed to the instruction
+            if !decorators.pop().expect("no decorators").is_empty() {
+                continue;
+            }
+            if table
+                .ops
+                .iter()
+                .zip(&table.decorators)
+                .any(|p| (p.0, p.1) == (&ops, &decorators))
+            {
+                continue;
+            }
+            table.by_opcode.entry(ops[0].op_code()).or_default().push(table.ops.len());
+            table.names.push(instruction);
+            table.ops.push(ops);
+            table.decorators.push(decorators);
+        }
+        table
+    }
+
+    /// Returns all instructions whose operations form a prefix of the specified operations.
+    ///
+    /// Decorators executed within an instruction must be exactly the ones this instruction
+    /// compiles into, while the decorators executed before the first operation of the
+    /// instruction may also include decorators of preceding instructions.
+    fn matches(
+        &self,
+        ops: &[Operation],
+        decorators: &[Vec<Decorator>],
+        num_locals: u16,
+    ) -> Vec<Instruction> {
+        let mut result: Vec<Instruction> = self
+            .by_opcode
+            .get(&ops[0].op_code())
+            .into_iter()
+            .flatten()
+            .filter(|&&idx| {
+                let pattern = &self.decorators[idx];
+                ops.starts_with(&self.ops[idx])
+                    && decorators[0].ends_with(&pattern[0])
+                    && decorators[1..pattern.len()] == pattern[1..]
+            })
+            .map(|&idx| Instruction::Pattern(idx))
+            .collect();
+
+        match ops[0] {
+            Operation::Push(value) if value != ZERO && value != ONE => {
+                result.push(Instruction::Push(value))
+            }
+            Operation::Assert(err_code) if err_code != ZERO => {
+                result.push(Instruction::Assert(err_code))
+            }
+            _ => (),
+        }
+
+        // locaddr.i compiles into push(-(num_locals - 1 - i)) followed by FMPADD
+        if num_locals > 0 && ops.get(1) == Some(&Operation::FmpAdd) && decorators[1].is_empty() {
+            let offset = match ops[0] {
+                Operation::Pad => Some(0),
+                Operation::Push(value) => Some((-value).as_int()),
+                _ => None,
+            };
+            if let Some(offset) = offset.filter(|&offset| offset < num_locals as u64) {
+                result.push(Instruction::LocAddr(num_locals - 1 - offset as u16));
+            }
+        }
+
+        result
+    }
+}
+
+// BLOCK STRUCTURE
+// ================================================================================================
+
+/// The shape of a tree of JOIN blocks built by the assembler for a code body.
+enum Shape {
+    Leaf,
+    Join(Box<Shape>, Box<Shape>),
+}
+
+impl Shape {
+    /// Returns the shape of a tree of JOIN blocks combining the specified number of blocks.
+    ///
+    /// This mirrors the way the assembler combines the blocks of a code body: adjacent pairs of
+    /// blocks are joined level by level, and for an odd number of blocks the last block is carried
+    /// over to the next level.
+    fn new(num_blocks: usize) -> Self {
+        let mut shapes: Vec<Shape> = (0..num_blocks).map(|_| Shape::Leaf).collect();
+        while shapes.len() > 1 {
+            let last = if shapes.len() % 2 == 0 { None } else { shapes.pop() };
+
+            let mut grouped = Vec::with_capacity(shapes.len() / 2 + 1);
+            let mut iter = shapes.into_iter();
+            while let (Some(first), Some(second)) = (iter.next(), iter.next()) {
+                grouped.push(Shape::Join(Box::new(first), Box::new(second)));
+            }
+            grouped.extend(last);
+            shapes = grouped;
+        }
+        shapes.remove(0)
+    }
+
+    /// Matches the specified block against this shape and collects the blocks at the leaves of
+    /// this shape into `blocks`. Returns false if the block does not have this shape.
+    fn collect<'a>(&self, block: &'a CodeBlock, blocks: &mut Vec<&'a CodeBlock>) -> bool {
+        match (self, block) {
+            (Shape::Leaf, _) => {
+                blocks.push(block);
+                true
+            }
+            (Shape::Join(first, second), CodeBlock::Join(join)) => {
+                first.collect(join.first(), blocks) && second.collect(join.second(), blocks)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Splits a JOIN block into the longest sequence of blocks which the assembler could have
+/// combined into it.
+///
+/// If `framed` is true, the block is expected to be the code root of a procedure with locals, and
+/// thus, the sequence must start and end with SPAN blocks containing the procedure frame.
+fn split_body(block: &CodeBlock, framed: bool) -> Result<Vec<&CodeBlock>, DisassemblyError> {
+    for num_blocks in (2..=count_leaves(block)).rev() {
+        let mut blocks = Vec::with_capacity(num_blocks);
+        if Shape::new(num_blocks).collect(block, &mut blocks) && is_valid_body(&blocks, framed) {
+            return Ok(blocks);
+        }
+    }
+    Err(DisassemblyError::UnsupportedBlockStructure(block.hash()))
+}
+
+/// Returns true if the assembler could have produced the specified sequence of blocks for a code
+/// body.
+fn is_valid_body(blocks: &[&CodeBlock], framed: bool) -> bool {
+    // consecutive SPAN blocks are always merged by the assembler
+    if blocks.windows(2).any(|pair| pair[0].is_span() && pair[1].is_span()) {
+        return false;
+    }
+
+    let last = blocks.len() - 1;
+    blocks.iter().enumerate().all(|(i, block)| match block {
+        CodeBlock::Span(span) => {
+            let (has_open, has_close) = get_unmatched_frames(&get_span_ops(span));
+            (framed && i == 0 || !has_open) && (framed && i == last || !has_close)
+        }
+        CodeBlock::Join(_) => is_frame_on_edge(block, true) && is_frame_on_edge(block, false),
+        _ => !framed || (i != 0 && i != last),
+    })
+}
+
+/// Returns the number of non-JOIN blocks in a tree of JOIN blocks.
+fn count_leaves(block: &CodeBlock) -> usize {
+    match block {
+        CodeBlock::Join(join) => count_leaves(join.first()) + count_leaves(join.second()),
+        _ => 1,
+    }
+}
+
+/// Returns true if the specified block is a SPAN block compiled from an empty code body.
+fn is_empty_body(block: &CodeBlock) -> bool {
+    match block {
+        CodeBlock::Span(span) => {
+            let (ops, decorators) = get_span_contents(span);
+            ops == [Operation::Noop] && decorators.iter().all(|group| group.is_empty())
+        }
+        _ => false,
+    }
+}
+
+/// Returns all operations of the specified SPAN block.
+fn get_span_ops(span: &Span) -> Vec<Operation> {
+    span.op_batches().iter().flat_map(|batch| batch.ops().iter().copied()).collect()
+}
+
+/// Returns all operations of the specified SPAN block, together with the decorators of this block
+/// grouped by the position of the operation before which they are executed.
+///
+/// The last group contains the decorators executed after the last operation. Source location
+/// decorators (which are only present in programs compiled in debug mode) are dropped.
+fn get_span_contents(span: &Span) -> (Vec<Operation>, Vec<Vec<Decorator>>) {
+    let ops = get_span_ops(span);
+    let mut decorators = vec![Vec::new(); ops.len() + 1];
+    for (pos, decorator) in span.decorators() {
+        if !matches!(decorator, Decorator::AsmOp(_)) {
+            decorators[*pos].push(decorator.clone());
+        }
+    }
+    (ops, decorators)
+}
+
+// PROCEDURE FRAMES
+// ================================================================================================
+
+/// Procedures with locals allocate their locals by incrementing the frame pointer in a prologue
+/// `PUSH(num_locals) FMPUPDATE`, and deallocate them with an epilogue `PUSH(-num_locals)
+/// FMPUPDATE`.
+///
+/// Returns the number of locals allocated (if positive) or deallocated (if negative) by a frame
+/// pointer update at the specified position.
+fn get_frame_update(ops: &[Operation], pos: usize) -> Option<i32> {
+    match (ops.get(pos), ops.get(pos + 1)) {
+        (Some(Operation::Push(value)), Some(Operation::FmpUpdate)) => {
+            let max_locals = u16::MAX as u64;
+            if value.as_int() <= max_locals {
+                Some(value.as_int() as i32)
+            } else if (-*value).as_int() <= max_locals {
+                Some(-((-*value).as_int() as i32))
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// If a procedure frame is allocated at the specified position, returns the position right after
+/// the operations which deallocate it.
+fn find_frame_end(ops: &[Operation], start: usize) -> Option<usize> {
+    let num_locals = get_frame_update(ops, start).filter(|&update| update > 0)?;
+
+    let mut frames = vec![num_locals];
+    let mut pos = start + 2;
+    while pos < ops.len() {
+        match get_frame_update(ops, pos) {
+            Some(update) if update > 0 => frames.push(update),
+            Some(update) => {
+                if frames.pop() != Some(-update) {
+                    return None;
+                }
+                if frames.is_empty() {
+                    return Some(pos + 2);
+                }
+            }
+            None => {
+                pos += 1;
+                continue;
+            }
+        }
+        pos += 2;
+    }
+    None
+}
+
+/// Returns flags indicating whether the specified operations contain frame allocations which are
+/// not deallocated, and frame deallocations which were not allocated by these operations.
+fn get_unmatched_frames(ops: &[Operation]) -> (bool, bool) {
+    let mut depth = 0;
+    let mut has_close = false;
+    let mut pos = 0;
+    while pos < ops.len() {
+        match get_frame_update(ops, pos) {
+            Some(update) => {
+                if update > 0 {
+                    depth += 1;
+                } else if depth > 0 {
+                    depth -= 1;
+                } else {
+                    has_close = true;
+                }
+                pos += 2;
+            }
+            None => pos += 1,
+        }
+    }
+    (depth > 0, has_close)
+}
+
+/// Returns true if the leftmost (if `first` is true) or the rightmost SPAN block of the specified
+/// block does not contain an unmatched frame allocation or deallocation respectively, unless the
+/// frame belongs to a procedure with locals along the way to this SPAN block.
+fn is_frame_on_edge(block: &CodeBlock, first: bool) -> bool {
+    match block {
+        CodeBlock::Span(span) => {
+            let (has_open, has_close) = get_unmatched_frames(&get_span_ops(span));
+            if first {
+                !has_open
+            } else {
+                !has_close
+            }
+        }
+        CodeBlock::Join(_) if get_frame(block).is_some() => true,
+        CodeBlock::Join(join) if first => is_frame_on_edge(join.first(), first),
+        CodeBlock::Join(join) => is_frame_on_edge(join.second(), first),
+        _ => true,
+    }
+}
+
+/// If the specified block is the code root of a procedure with locals, returns the number of
+/// locals of this procedure.
+fn get_frame(block: &CodeBlock) -> Option<u16> {
+    let first = get_edge_span(block, true)?;
+    let last = get_edge_span(block, false)?;
+
+    let num_locals = get_frame_update(&first, 0).filter(|&update| update > 0)?;
+    if last.len() < 2 || get_frame_update(&last, last.len() - 2) != Some(-num_locals) {
+        return None;
+    }
+
+    match block {
+        // the frame must span the entire block
+        CodeBlock::Span(_) => (find_frame_end(&first, 0) == Some(first.len())).then_some(()),
+        // the frame is allocated in the first block and deallocated in the last one
+        _ => (get_unmatched_frames(&first).0 && get_unmatched_frames(&last).1).then_some(()),
+    }?;
+
+    Some(num_locals as u16)
+}
+
+/// Returns operations of the leftmost (if `first` is true) or the rightmost SPAN block in a tree
+/// of JOIN blocks.
+fn get_edge_span(block: &CodeBlock, first: bool) -> Option<Vec<Operation>> {
+    match block {
+        CodeBlock::Span(span) => Some(get_span_ops(span)),
+        CodeBlock::Join(join) if first => get_edge_span(join.first(), first),
+        CodeBlock::Join(join) => get_edge_span(join.second(), first),
+        _ => None,
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Renders the specified decorators as a sequence of instructions.
+///
+/// # Errors
+/// Returns an error if a decorator is not produced by any standalone instruction.
+fn render_decorators(
+    out: &mut String,
+    decorators: &[Decorator],
+    indent: usize,
+) -> Result<(), DisassemblyError> {
+    for decorator in decorators {
+        let injector = match decorator {
+            Decorator::Advice(injector) => injector,
+            Decorator::Debug(options) => {
+                write_line(out, indent, format_args!("debug.{options}"));
+                continue;
+            }
+            Decorator::AsmOp(_) => continue,
+        };
+
+        let unsupported = || DisassemblyError::UnsupportedDecorator(decorator.clone());
+        let node = match *injector {
+            AdviceInjector::DivU64 => AdviceInjectorNode::PushU64div,
+            AdviceInjector::Ext2Intt => AdviceInjectorNode::PushExt2intt,
+            AdviceInjector::SmtGet => AdviceInjectorNode::PushSmtGet,
+            AdviceInjector::MapValueToStack {
+                include_len,
+                key_offset,
+            } => {
+                let offset = u8::try_from(key_offset).map_err(|_| unsupported())?;
+                match (include_len, offset) {
+                    (false, 0) => AdviceInjectorNode::PushMapVal,
+                    (false, offset) => AdviceInjectorNode::PushMapValImm { offset },
+                    (true, 0) => AdviceInjectorNode::PushMapValN,
+                    (true, offset) => AdviceInjectorNode::PushMapValNImm { offset },
+                }
+            }
+            AdviceInjector::MerkleNodeToStack => AdviceInjectorNode::PushMtNode,
+            AdviceInjector::MemToMap => AdviceInjectorNode::InsertMem,
+            AdviceInjector::HdwordToMap { domain } if domain == ZERO => {
+                AdviceInjectorNode::InsertHdword
+            }
+            AdviceInjector::HdwordToMap { domain } => {
+                let domain = u8::try_from(domain.as_int()).map_err(|_| unsupported())?;
+                AdviceInjectorNode::InsertHdwordImm { domain }
+            }
+            // merging of Merkle nodes and inversion of ext2 elements are injected only by the
+            // instructions which use them, and the names of advice tapes are not retained
+            AdviceInjector::MerkleNodeMerge
+            | AdviceInjector::Ext2Inv
+            | AdviceInjector::TapeToStack { .. } => return Err(unsupported()),
+        };
+        write_line(out, indent, format_args!("adv.{node}"));
+    }
+    Ok(())
+}
+
+/// Writes a line of source code with the specified indentation.
+fn write_line(out: &mut String, indent: usize, line: core::fmt::Arguments<'_>) {
+    writeln!(out, "{:indent$}{line}", "").expect("failed to write to a string");
+}
@@ -0,0 +1,218 @@
+use super::{disassemble, Assembler, CodeBlock, Disassembler, DisassemblyError};
+use vm_core::Decorator;
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn disassemble_simple_program() {
+    let program = Assembler::default()
+        .compile("begin push.3 push.1 add if.true push.7 mul else drop end end")
+        .unwrap();
+    let source = disassemble(&program).unwrap();
+
+    let expected = "\
+begin
+    push.3
+    push.1
+    add
+    if.true
+        push.7
+        mul
+    else
+        drop
+    end
+end
+";
+    assert_eq!(expected, source);
+}
+
+#[test]
+fn disassemble_assert_with_code() {
+    let source = "begin push.1 assert push.1 assert.42 end";
+    let program = Assembler::default().compile(source).unwrap();
+    let disassembled = disassemble(&program).unwrap();
+    assert!(disassembled.contains("    assert\n"));
+    assert!(disassembled.contains("    assert.42\n"));
+    assert_round_trip(source);
+}
+
+#[test]
+fn disassemble_control_flow() {
+    let source = "\
+        begin \
+            push.1 \
+            while.true push.2 drop push.0 end \
+            while.true.max=5 push.0 end \
+            repeat.3 push.1 if.true add end end \
+            if.true end \
+            push.0 \
+            if.true else dup.1 end \
+        end";
+    assert_round_trip(source);
+
+    let source = "begin repeat.4 push.2 mul if.true push.3 else push.4 end drop end end";
+    assert_round_trip(source);
+}
+
+#[test]
+fn disassemble_procedures() {
+    // procedures which can be inlined
+    let source = "\
+        proc.foo push.3 mul end \
+        proc.bar if.true exec.foo else push.1 end end \
+        begin exec.foo exec.bar exec.bar push.2 exec.foo end";
+    assert_round_trip(source);
+
+    // empty procedures
+    let source = "proc.foo end proc.bar.2 end begin exec.foo push.1 exec.bar exec.foo end";
+    assert_round_trip(source);
+
+    // calls
+    let source = "\
+        proc.foo push.3 mul end \
+        proc.bar.1 loc_store.0 call.foo loc_load.0 end \
+        begin call.foo call.bar exec.bar end";
+    let program = Assembler::default().compile(source).unwrap();
+    let disassembled = disassemble(&program).unwrap();
+    assert_eq!(3, disassembled.matches("call.proc_").count());
+}
+
+#[test]
+fn disassemble_procedure_locals() {
+    // procedure locals within a single span
+    let source = "\
+        proc.foo.3 \
+            loc_store.0 loc_store.2 locaddr.1 mem_load loc_load.0 loc_loadw.2 \
+        end \
+        begin push.1 push.2 exec.foo push.3 exec.foo end";
+    assert_round_trip(source);
+
+    // procedure locals with control flow
+    let source = "\
+        proc.foo.2 \
+            loc_store.1 \
+            if.true loc_load.1 else locaddr.0 end \
+            while.true loc_load.1 end \
+        end \
+        proc.bar.1 \
+            loc_store.0 exec.foo loc_load.0 \
+        end \
+        begin push.1 exec.bar repeat.2 exec.foo end exec.bar end";
+    assert_round_trip(source);
+
+    // procedure locals within nested bodies
+    let source = "\
+        proc.foo.1 \
+            repeat.2 if.true loc_load.0 end end \
+        end \
+        begin exec.foo if.true exec.foo end end";
+    assert_round_trip(source);
+}
+
+#[test]
+fn disassemble_syscall() {
+    let kernel = "export.foo add end";
+    let assembler = Assembler::default().with_kernel(kernel).unwrap();
+    let program = assembler.compile("begin syscall.foo end").unwrap();
+    assert!(matches!(disassemble(&program), Err(DisassemblyError::UnsupportedSyscall(_))));
+}
+
+#[test]
+fn disassemble_decorators() {
+    let source = "\
+        begin \
+            adv.push_u64div push.1 adv.push_mapval.4 adv.insert_hdword.3 add \
+            if.true adv.push_mtnode end \
+            mtree_get adv.push_smtget mtree_merge \
+            push.1 ext2inv \
+            adv.insert_mem \
+        end";
+    let program = Assembler::default().compile(source).unwrap();
+    let disassembled = disassemble(&program).unwrap();
+    let expected = "\
+begin
+    adv.push_u64div
+    push.1
+    adv.push_mapval.4
+    adv.insert_hdword.3
+    add
+    if.true
+        adv.push_mtnode
+    end
+    mtree_get
+    adv.push_smtget
+    mtree_merge
+    push.1
+    ext2inv
+    adv.insert_mem
+end
+";
+    assert_eq!(expected, disassembled);
+
+    // the decorators are executed at the same positions in the reassembled program
+    let reassembled = Assembler::default().compile(&disassembled).unwrap();
+    assert_eq!(get_decorators(program.root()), get_decorators(reassembled.root()));
+
+    // decorators are recovered from procedures with locals and from debug builds
+    let source = "\
+        proc.foo.1 adv.push_mapvaln dup debug.stack.4 loc_store.0 end \
+        begin push.1 exec.foo debug.mem.1.2 adv.push_ext2intt drop end";
+    let program = Assembler::default().with_debug_mode(true).compile(source).unwrap();
+    let disassembled = disassemble(&program).unwrap();
+    assert!(disassembled.contains("    adv.push_mapvaln\n    dup.0\n    debug.stack.4\n"));
+    assert!(disassembled.contains("    debug.mem.1.2\n    adv.push_ext2intt\n    drop\n"));
+}
+
+#[test]
+fn disassemble_advice_tape() {
+    // the names of advice tapes are not retained in the MAST
+    let program = Assembler::default().compile("begin adv_push.foo end").unwrap();
+    assert!(matches!(disassemble(&program), Err(DisassemblyError::UnsupportedDecorator(_))));
+}
+
+#[test]
+fn disassemble_with_reused_disassembler() {
+    let disassembler = Disassembler::new();
+    for source in ["begin push.1 push.2 add end", "begin push.3 if.true mul else drop end end"] {
+        let program = Assembler::default().compile(source).unwrap();
+        assert_eq!(disassemble(&program).unwrap(), disassembler.disassemble(&program).unwrap());
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Asserts that a program compiled from the specified source code is disassembled into source
+/// code which compiles into a program with the same hash.
+fn assert_round_trip(source: &str) {
+    let program = Assembler::default().compile(source).unwrap();
+    let disassembled = disassemble(&program).unwrap();
+    let reassembled = Assembler::default().compile(&disassembled).unwrap();
+    assert_eq!(program.hash(), reassembled.hash(), "disassembled source:\n{disassembled}");
+}
+
+/// Returns all advice and debug decorators of SPAN blocks in the specified block, together with
+/// their positions within their SPAN blocks.
+fn get_decorators(block: &CodeBlock) -> Vec<(usize, Decorator)> {
+    match block {
+        CodeBlock::Span(span) => span
+            .decorators()
+            .iter()
+            .filter(|(_, decorator)| !matches!(decorator, Decorator::AsmOp(_)))
+            .cloned()
+            .collect(),
+        CodeBlock::Join(join) => {
+            let mut decorators = get_decorators(join.first());
+            decorators.extend(get_decorators(join.second()));
+            decorators
+        }
+        CodeBlock::Split(split) => {
+            let mut decorators = get_decorators(split.on_true());
+            decorators.extend(get_decorators(split.on_false()));
+            decorators
+        }
+        CodeBlock::Loop(loop_block) => get_decorators(loop_block.body()),
+        _ => Vec::new(),
+    }
+}
@@ -1,9 +1,9 @@
 use super::{
-    crypto::hash::RpoDigest, tokens::SourceLocation, LibraryNamespace, ProcedureId, String,
-    ToString, Token, Vec,
+    crypto::hash::RpoDigest, tokens::SourceLocation, LibraryNamespace, Operation, ProcedureId,
+    String, ToString, Token, Vec,
 };
 use core::fmt;
-use vm_core::utils::write_hex_bytes;
+use vm_core::{utils::write_hex_bytes, Decorator};
 
 // ASSEMBLY ERROR
 // ================================================================================================
@@ -30,6 +30,10 @@ pub enum AssemblyError {
     ParamOutOfBounds(u64, u64, u64),
     ProcedureNameError(String),
     SysCallInKernel(String),
+    UndefinedProcedure {
+        name: String,
+        location: Option<SourceLocation>,
+    },
     LibraryError(String),
     Io(String),
 }
@@ -97,6 +101,13 @@ impl AssemblyError {
     pub fn invalid_cache_lock() -> Self {
         Self::InvalidCacheLock
     }
+
+    pub fn undefined_procedure(name: &str, location: Option<SourceLocation>) -> Self {
+        Self::UndefinedProcedure {
+            name: name.to_string(),
+            location,
+        }
+    }
 }
 
 impl From<ParsingError> for AssemblyError {
@@ -143,6 +154,8 @@ impl fmt::Display for AssemblyError {
                 write_hex_bytes(f, &digest.as_bytes())
             },
             SysCallInKernel(proc_name) => write!(f, "syscall instruction used in kernel procedure '{proc_name}'"),
+            UndefinedProcedure { name, location: Some(location) } => write!(f, "undefined procedure '{name}' invoked at {location}"),
+            UndefinedProcedure { name, location: None } => write!(f, "undefined procedure '{name}'"),
         }
     }
 }
@@ -157,6 +170,53 @@ impl From<std::io::Error> for AssemblyError {
 #[cfg(feature = "std")]
 impl std::error::Error for AssemblyError {}
 
+// DISASSEMBLY ERROR
+// ================================================================================================
+
+/// An error which can be generated while rendering a compiled [Program](super::Program) back into
+/// Miden assembly source code.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum DisassemblyError {
+    CallTargetNotFound(RpoDigest),
+    RoundTripFailed(String),
+    UnsupportedBlockStructure(RpoDigest),
+    UnsupportedDecorator(Decorator),
+    UnsupportedOperation(Operation),
+    UnsupportedSyscall(RpoDigest),
+}
+
+impl fmt::Display for DisassemblyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use DisassemblyError::*;
+        match self {
+            CallTargetNotFound(digest) => {
+                write!(f, "code block not found in program for call target - ")?;
+                write_hex_bytes(f, &digest.as_bytes())
+            }
+            RoundTripFailed(reason) => {
+                write!(f, "disassembled source does not reassemble into the same program: {reason}")
+            }
+            UnsupportedBlockStructure(digest) => {
+                write!(f, "code block cannot be expressed in Miden assembly - ")?;
+                write_hex_bytes(f, &digest.as_bytes())
+            }
+            UnsupportedDecorator(decorator) => {
+                write!(f, "decorator {decorator} cannot be expressed in Miden assembly")
+            }
+            UnsupportedOperation(op) => {
+                write!(f, "operation {op} cannot be expressed in Miden assembly")
+            }
+            UnsupportedSyscall(digest) => {
+                write!(f, "syscalls are not supported by the disassembler - ")?;
+                write_hex_bytes(f, &digest.as_bytes())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DisassemblyError {}
+
 // PARSING ERROR
 // ================================================================================================
 
@@ -725,6 +785,7 @@ pub enum LibraryError {
         component: String,
     },
     ModuleNotFound(String),
+    ModuleParsingFailed(String, ParsingError),
     NoModulesInLibrary {
         name: LibraryNamespace,
     },
@@ -785,6 +846,10 @@ impl LibraryError {
         }
     }
 
+    pub fn module_parsing_failed(path: &str, err: ParsingError) -> Self {
+        Self::ModuleParsingFailed(path.into(), err)
+    }
+
     pub fn no_modules_in_library(name: LibraryNamespace) -> Self {
         Self::NoModulesInLibrary { name }
     }
@@ -848,6 +913,7 @@ impl fmt::Display for LibraryError {
                 write!(f, "version '{version}' is invalid: missing {component} version component")
             }
             ModuleNotFound(path) => write!(f, "module '{path}' not found"),
+            ModuleParsingFailed(path, err) => write!(f, "failed to parse module '{path}': {err}"),
             NoModulesInLibrary { name } => {
                 write!(f, "library '{}' does not contain any modules", name.as_str())
             }
@@ -12,14 +12,14 @@ use vm_core::{
         string::{String, ToString},
         ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
     },
-    CodeBlockTable, Felt, Kernel, Operation, Program, StarkField, ONE, ZERO,
+    CodeBlockTable, Felt, Kernel, Operation, ProcedureInfo, Program, StarkField, ONE, ZERO,
 };
 
 mod library;
 pub use library::{Library, LibraryNamespace, LibraryPath, MaslLibrary, Module, Version};
 
 mod procedures;
-use procedures::{CallSet, Procedure};
+use procedures::{CallSet, InvokedProcs, Procedure};
 pub use procedures::{ProcedureId, ProcedureName};
 
 pub mod ast;
@@ -29,11 +29,16 @@ mod tokens;
 use tokens::{Token, TokenStream};
 
 mod errors;
-pub use errors::{AssemblyError, LabelError, LibraryError, ParsingError, PathError};
+pub use errors::{
+    AssemblyError, DisassemblyError, LabelError, LibraryError, ParsingError, PathError,
+};
 
 mod assembler;
 pub use assembler::{Assembler, AssemblyContext, AssemblyContextType};
 
+mod disassembler;
+pub use disassembler::{disassemble, Disassembler};
+
 #[cfg(test)]
 mod tests;
 
@@ -104,6 +104,56 @@ impl MaslLibrary {
         })
     }
 
+    /// Returns a new [Library] instantiated from the specified in-memory module sources.
+    ///
+    /// Each module is described by a tuple of a module path relative to the library `namespace`
+    /// and the source code of the module. For example, a module with path `math::u64` in a library
+    /// with namespace `foo` can be imported into other modules and programs via
+    /// `use.foo::math::u64`. Modules can import each other within the same library.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the module paths is invalid or appears more than once.
+    /// - Any of the module sources fails to parse.
+    /// - The number of modules is zero or greater than [u16::MAX].
+    pub fn from_sources<I, P, S>(
+        namespace: LibraryNamespace,
+        version: Version,
+        modules: I,
+    ) -> Result<Self, LibraryError>
+    where
+        I: IntoIterator<Item = (P, S)>,
+        P: AsRef<str>,
+        S: AsRef<str>,
+    {
+        let root_path = LibraryPath::new(&namespace)?;
+        let mut dependencies = BTreeSet::new();
+        let mut paths = BTreeSet::new();
+
+        let mut parsed_modules = Vec::new();
+        for (path, source) in modules {
+            let path = root_path.append(path)?;
+            if !paths.insert(path.clone()) {
+                return Err(LibraryError::duplicate_module_path(&path));
+            }
+
+            let ast = ModuleAst::parse(source.as_ref())
+                .map_err(|err| LibraryError::module_parsing_failed(&path, err))?;
+
+            // add dependencies of this module to the dependencies of this library
+            for import_path in ast.imports().values() {
+                let ns = LibraryNamespace::new(import_path.first())?;
+                if ns != namespace {
+                    dependencies.insert(ns);
+                }
+            }
+
+            parsed_modules.push(Module::new(path, ast));
+        }
+
+        Self::new(namespace, version, true, parsed_modules, dependencies.into_iter().collect())
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -1,6 +1,7 @@
 use super::{
-    crypto::hash::Blake3_160, BTreeSet, ByteReader, ByteWriter, CodeBlock, Deserializable,
-    DeserializationError, LabelError, LibraryPath, Serializable, String, ToString,
+    crypto::hash::{Blake3_160, RpoDigest},
+    BTreeMap, BTreeSet, ByteReader, ByteWriter, CodeBlock, Deserializable, DeserializationError,
+    LabelError, LibraryPath, ProcedureInfo, Serializable, String, ToString, Vec,
     PROCEDURE_LABEL_PARSER,
 };
 use core::{
@@ -21,6 +22,7 @@ pub struct Procedure {
     num_locals: u32,
     code_root: CodeBlock,
     callset: CallSet,
+    invoked: InvokedProcs,
 }
 
 impl Procedure {
@@ -34,6 +36,7 @@ impl Procedure {
         num_locals: u32,
         code_root: CodeBlock,
         callset: CallSet,
+        invoked: InvokedProcs,
     ) -> Self {
         Procedure {
             id,
@@ -42,6 +45,7 @@ impl Procedure {
             num_locals,
             code_root,
             callset,
+            invoked,
         }
     }
 
@@ -79,6 +83,21 @@ impl Procedure {
     pub fn callset(&self) -> &CallSet {
         &self.callset
     }
+
+    /// Returns a reference to a set of all procedures which may be invoked (via exec, call, or
+    /// syscall instructions) during the execution of this procedure.
+    pub fn invoked(&self) -> &InvokedProcs {
+        &self.invoked
+    }
+
+    /// Returns metadata of this procedure.
+    pub fn info(&self) -> ProcedureInfo {
+        ProcedureInfo::new(
+            self.label.to_string(),
+            self.code_root.hash(),
+            self.invoked.direct().to_vec(),
+        )
+    }
 }
 
 // PROCEDURE NAME
@@ -297,6 +316,48 @@ impl ops::Deref for CallSet {
     }
 }
 
+// INVOKED PROCEDURES
+// ================================================================================================
+
+/// Contains metadata of all procedures which may be invoked from a procedure via exec, call, or
+/// syscall instructions.
+///
+/// Unlike [CallSet], this also includes procedures which are inlined into the procedure body, and
+/// keeps track of which procedures are invoked directly from the procedure body.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct InvokedProcs {
+    direct: Vec<RpoDigest>,
+    all: BTreeMap<RpoDigest, ProcedureInfo>,
+}
+
+impl InvokedProcs {
+    /// Returns MAST roots of procedures invoked directly from the procedure body, in the order in
+    /// which they are first invoked.
+    pub fn direct(&self) -> &[RpoDigest] {
+        &self.direct
+    }
+
+    /// Returns metadata of all procedures which may be invoked directly or indirectly from the
+    /// procedure body, ordered by their MAST roots.
+    pub fn all(&self) -> impl Iterator<Item = &ProcedureInfo> {
+        self.all.values()
+    }
+
+    /// Records a direct invocation of the specified procedure. This also records all procedures
+    /// which may be invoked from the specified procedure.
+    pub fn insert(&mut self, proc: &Procedure) {
+        let mast_root = proc.code_root().hash();
+        if !self.direct.contains(&mast_root) {
+            self.direct.push(mast_root);
+        }
+
+        self.all.insert(mast_root, proc.info());
+        for info in proc.invoked().all() {
+            self.all.entry(info.digest()).or_insert_with(|| info.clone());
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::{super::MAX_LABEL_LEN, LabelError, ProcedureName};
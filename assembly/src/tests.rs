@@ -1,8 +1,10 @@
 use crate::{
-    ast::{ModuleAst, ProgramAst},
-    Assembler, AssemblyContextType, Library, LibraryNamespace, LibraryPath, Module, Version,
+    ast::{ModuleAst, ProgramAst, SourceLocation},
+    Assembler, AssemblyContextType, AssemblyError, Deserializable, Library, LibraryError,
+    LibraryNamespace, LibraryPath, MaslLibrary, Module, ProcedureId, Serializable, Version,
 };
 use core::slice::Iter;
+use std::collections::HashMap;
 
 // SIMPLE PROGRAMS
 // ================================================================================================
@@ -35,6 +37,35 @@ fn simple_instructions() {
     assert_eq!(expected, format!("{program}"));
 }
 
+#[test]
+fn program_equality_ignores_formatting() {
+    let assembler = super::Assembler::default();
+    let program = assembler
+        .compile("proc.foo push.1 add end begin push.2 exec.foo mul end")
+        .unwrap();
+
+    // different whitespace, comments, and procedure names produce the same program
+    let source = "\
+        # computes (2 + 1) * (2 + 1)
+        proc.increment
+            push.1
+            add   # add one
+        end
+
+        begin
+            push.2
+            exec.increment
+            mul
+        end";
+    let reformatted = assembler.compile(source).unwrap();
+    assert_eq!(program, reformatted);
+    assert_eq!(program.fingerprint(), reformatted.fingerprint());
+
+    let different = assembler.compile("begin push.2 push.1 mul add end").unwrap();
+    assert_ne!(program, different);
+    assert_ne!(program.fingerprint(), different.fingerprint());
+}
+
 #[test]
 fn empty_program() {
     let assembler = super::Assembler::default();
@@ -62,6 +93,19 @@ fn empty_while() {
     assert_eq!(expected, format!("{}", program));
 }
 
+#[test]
+fn bounded_while() {
+    let assembler = super::Assembler::default();
+    let source = "begin while.true.max=8 push.0 end end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "begin while.true.max=8 span pad end end end";
+    assert_eq!(expected, format!("{}", program));
+
+    // the bound on the number of iterations does not affect the hash of the program
+    let unbounded = assembler.compile("begin while.true push.0 end end").unwrap();
+    assert_eq!(unbounded.hash(), program.hash());
+}
+
 #[test]
 fn empty_repeat() {
     let assembler = super::Assembler::default();
@@ -513,6 +557,33 @@ fn program_with_nested_procedure() {
     assert_eq!(expected, format!("{program}"));
 }
 
+#[test]
+fn program_with_procedure_metadata() {
+    let assembler = super::Assembler::default();
+    let source = "\
+        proc.foo push.3 push.7 mul end \
+        proc.bar push.5 exec.foo add end \
+        begin push.2 push.4 add call.bar exec.foo exec.bar end";
+    let program = assembler.compile(source).unwrap();
+
+    let foo = assembler.compile("begin push.3 push.7 mul end").unwrap().hash();
+    let bar = assembler.compile("begin push.5 push.3 push.7 mul add end").unwrap().hash();
+
+    let procedures = program.procedures();
+    assert_eq!(3, procedures.len());
+    assert_eq!("#main", procedures[0].name());
+    assert_eq!(program.hash(), procedures[0].digest());
+    assert_eq!(&[bar, foo], procedures[0].calls());
+
+    let foo_info = procedures.iter().find(|p| p.name() == "foo").unwrap();
+    assert_eq!(foo, foo_info.digest());
+    assert!(foo_info.calls().is_empty());
+
+    let bar_info = procedures.iter().find(|p| p.name() == "bar").unwrap();
+    assert_eq!(bar, bar_info.digest());
+    assert_eq!(&[foo], bar_info.calls());
+}
+
 #[test]
 fn program_with_proc_locals() {
     let assembler = super::Assembler::default();
@@ -827,6 +898,119 @@ fn program_with_import_errors() {
     assert!(assembler.compile(source).is_err());
 }
 
+#[test]
+fn program_with_undefined_procedure() {
+    let namespace = LibraryNamespace::new("dummy").unwrap();
+    let modules = [("math::u64", "export.checked_eqz u32assert.2 eq.0 swap eq.0 and end")];
+    let library = MaslLibrary::from_sources(namespace, Version::MIN, modules).unwrap();
+    let assembler = super::Assembler::default().with_library(&library).unwrap();
+
+    // --- misspelled procedure name --------------------------------------------------------------
+    let source = "use.dummy::math::u64\nbegin\n    push.1\n    exec.u64::chekced_eqz\nend";
+    let err = assembler.compile(source).unwrap_err();
+    let expected = AssemblyError::undefined_procedure(
+        "dummy::math::u64::chekced_eqz",
+        Some(SourceLocation::new(4, 5)),
+    );
+    assert_eq!(expected, err);
+    assert_eq!(
+        "undefined procedure 'dummy::math::u64::chekced_eqz' invoked at [4:5]",
+        err.to_string()
+    );
+
+    // --- undefined procedure called in a nested block of a local procedure ----------------------
+    let source = "\
+        use.dummy::math::u64
+        proc.foo
+            if.true
+                call.u64::checked_eqz
+            else
+                call.u64::checked_neqz
+            end
+        end
+        begin
+            exec.foo
+        end";
+    let err = assembler.compile(source).unwrap_err();
+    let expected = AssemblyError::undefined_procedure(
+        "dummy::math::u64::checked_neqz",
+        Some(SourceLocation::new(6, 17)),
+    );
+    assert_eq!(expected, err);
+
+    // --- undefined procedure in a library module ------------------------------------------------
+    let namespace = LibraryNamespace::new("dummy").unwrap();
+    let modules = [
+        ("math::u64", "export.checked_eqz u32assert.2 eq.0 swap eq.0 and end"),
+        ("math::u256", "use.dummy::math::u64\nexport.iszero exec.u64::is_zero end"),
+    ];
+    let library = MaslLibrary::from_sources(namespace, Version::MIN, modules).unwrap();
+    let assembler = super::Assembler::default().with_library(&library).unwrap();
+    let source = "use.dummy::math::u256\nbegin exec.u256::iszero end";
+    let err = assembler.compile(source).unwrap_err();
+    let expected = AssemblyError::undefined_procedure(
+        "dummy::math::u64::is_zero",
+        Some(SourceLocation::new(2, 15)),
+    );
+    assert_eq!(expected, err);
+
+    // --- undefined procedure in a deserialized library module -----------------------------------
+    // names of invoked procedures are not serialized, and thus, the procedure is referred to by ID
+    let library = MaslLibrary::read_from_bytes(&library.to_bytes()).unwrap();
+    let assembler = super::Assembler::default().with_library(&library).unwrap();
+    let err = assembler.compile(source).unwrap_err();
+    let path = LibraryPath::new("dummy::math::u64").unwrap();
+    let proc_id = ProcedureId::from_name("is_zero", &path);
+    let expected =
+        AssemblyError::undefined_procedure(&proc_id.to_string(), Some(SourceLocation::new(2, 15)));
+    assert_eq!(expected, err);
+}
+
+#[test]
+fn program_with_imports_from_sources() {
+    let namespace = LibraryNamespace::new("dummy").unwrap();
+    let modules = HashMap::from([
+        ("math::u64", "export.checked_eqz u32assert.2 eq.0 swap eq.0 and end"),
+        (
+            "math::u256",
+            "use.dummy::math::u64
+            export.iszero_unsafe exec.u64::checked_eqz exec.u64::checked_eqz and end",
+        ),
+    ]);
+    let library = MaslLibrary::from_sources(namespace, Version::MIN, modules).unwrap();
+    assert_eq!(2, library.modules().len());
+    assert!(library.dependencies().is_empty());
+
+    // the same procedure is imported both directly and via another module
+    let assembler = super::Assembler::default().with_library(&library).unwrap();
+    let source = "\
+        use.dummy::math::u64
+        use.dummy::math::u256
+        begin \
+            exec.u256::iszero_unsafe \
+            exec.u64::checked_eqz \
+        end";
+    assert!(assembler.compile(source).is_ok());
+
+    // --- circular imports -----------------------------------------------------------------------
+    let namespace = LibraryNamespace::new("dummy").unwrap();
+    let modules = HashMap::from([
+        ("a", "use.dummy::b\nexport.foo exec.b::bar end"),
+        ("b", "use.dummy::a\nexport.bar exec.a::foo end"),
+    ]);
+    let library = MaslLibrary::from_sources(namespace, Version::MIN, modules).unwrap();
+    let assembler = super::Assembler::default().with_library(&library).unwrap();
+    let source = "use.dummy::a\nbegin exec.a::foo end";
+    let err = assembler.compile(source).unwrap_err();
+    assert!(matches!(err, AssemblyError::CircularModuleDependency(_)));
+
+    // --- invalid module source ------------------------------------------------------------------
+    let namespace = LibraryNamespace::new("dummy").unwrap();
+    let modules = [("a", "export.foo add")];
+    let err = MaslLibrary::from_sources(namespace, Version::MIN, modules).unwrap_err();
+    assert!(matches!(err, LibraryError::ModuleParsingFailed(path, _) if path == "dummy::a"));
+}
+
 // COMMENTS
 // ================================================================================================
 
@@ -1066,6 +1250,26 @@ fn invalid_while() {
         );
     }
 
+    let source = "begin push.1 add while.true.max=0 mul end end";
+    let program = assembler.compile(source);
+    assert!(program.is_err());
+    if let Err(error) = program {
+        assert_eq!(
+            error.to_string(),
+            "malformed instruction `while.true.max=0`: parameter 'max=0' is invalid"
+        );
+    }
+
+    let source = "begin push.1 add while.true.max=8.1 mul end end";
+    let program = assembler.compile(source);
+    assert!(program.is_err());
+    if let Err(error) = program {
+        assert_eq!(
+            error.to_string(),
+            "malformed instruction 'while.true.max=8.1': too many parameters provided"
+        );
+    }
+
     let source = "begin push.1 add while.true mul";
     let program = assembler.compile(source);
     assert!(program.is_err());
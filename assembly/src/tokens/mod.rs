@@ -1,14 +1,13 @@
 use super::{
-    ast::InvocationTarget, BTreeMap, ByteReader, ByteWriter, Deserializable, DeserializationError,
-    LibraryPath, ParsingError, ProcedureName, Serializable, String, ToString, Vec,
+    ast::InvocationTarget, BTreeMap, LibraryPath, ParsingError, ProcedureName, String, ToString,
+    Vec,
 };
 use core::fmt;
 
 mod lines;
 pub use lines::{LineInfo, LinesStream};
 
-mod location;
-pub use location::SourceLocation;
+pub use vm_core::SourceLocation;
 
 mod stream;
 pub use stream::TokenStream;
@@ -203,18 +202,19 @@ impl<'a> Token<'a> {
         }
     }
 
-    pub fn validate_while(&self) -> Result<(), ParsingError> {
+    /// Returns the maximum number of loop iterations if the while token is of the form
+    /// `while.true.max=N`, or None if the token is `while.true`.
+    pub fn parse_while(&self) -> Result<Option<u32>, ParsingError> {
         assert_eq!(Self::WHILE, self.parts[0], "not a while");
         match self.num_parts() {
             0 => unreachable!(),
             1 => Err(ParsingError::missing_param(self)),
-            2 => {
-                if self.parts[1] != "true" {
-                    Err(ParsingError::invalid_param(self, 1))
-                } else {
-                    Ok(())
-                }
-            }
+            2 | 3 if self.parts[1] != "true" => Err(ParsingError::invalid_param(self, 1)),
+            2 => Ok(None),
+            3 => match self.parts[2].strip_prefix("max=").map(|value| value.parse::<u32>()) {
+                Some(Ok(max_iterations)) if max_iterations > 0 => Ok(Some(max_iterations)),
+                _ => Err(ParsingError::invalid_param(self, 2)),
+            },
             _ => Err(ParsingError::extra_param(self)),
         }
     }
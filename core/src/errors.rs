@@ -1,3 +1,4 @@
+use crate::crypto::merkle::MerkleError;
 use core::fmt;
 
 // INPUT ERROR
@@ -7,6 +8,14 @@ use core::fmt;
 pub enum InputError {
     NotFieldElement(u64, &'static str),
     DuplicateAdviceRoot([u8; 32]),
+    InvalidMerklePathSet(MerkleError),
+    MerkleStoreLookupFailed(MerkleError),
+    MerkleStoreUpdateFailed(MerkleError),
+    InvalidStackOutputsLength(usize),
+    MerkleRootNotFound([u8; 32]),
+    NotEnoughAdviceStackValues(usize, usize),
+    ConflictingAdviceMapValues([u8; 32]),
+    ConflictingStackInputs,
 }
 
 impl fmt::Display for InputError {
@@ -19,9 +28,98 @@ impl fmt::Display for InputError {
             DuplicateAdviceRoot(key) => {
                 write!(f, "{key:02x?} is a duplicate of the current merkle set")
             }
+            InvalidMerklePathSet(reason) => {
+                write!(f, "failed to build Merkle path set: {reason}")
+            }
+            MerkleStoreLookupFailed(reason) => {
+                write!(f, "failed to lookup value in Merkle store: {reason}")
+            }
+            MerkleStoreUpdateFailed(reason) => {
+                write!(f, "failed to update Merkle store: {reason}")
+            }
+            InvalidStackOutputsLength(len) => {
+                write!(f, "{len} elements do not form a valid encoding of stack outputs")
+            }
+            MerkleRootNotFound(root) => {
+                write!(f, "Merkle tree with root {root:02x?} is not in the Merkle store")
+            }
+            NotEnoughAdviceStackValues(required, provided) => write!(
+                f,
+                "expected at least {required} advice stack values, but {provided} were provided"
+            ),
+            ConflictingAdviceMapValues(key) => {
+                write!(f, "advice map contains conflicting values for key {key:02x?}")
+            }
+            ConflictingStackInputs => {
+                write!(f, "stack inputs conflict with previously provided stack inputs")
+            }
         }
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for InputError {}
+
+// OUTPUT MISMATCH
+// ================================================================================================
+
+/// The first position at which the stack outputs differ from the expected values (see
+/// [StackOutputs::assert_top()](crate::StackOutputs::assert_top)).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OutputMismatch {
+    /// Position of the mismatching element, with 0 being the top of the stack.
+    pub index: usize,
+    /// The expected value at this position.
+    pub expected: u64,
+    /// The actual value at this position, or None if the stack outputs contain fewer elements.
+    pub actual: Option<u64>,
+}
+
+impl fmt::Display for OutputMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            index,
+            expected,
+            actual,
+        } = self;
+        match actual {
+            Some(actual) => {
+                write!(f, "expected {expected} at stack position {index}, but found {actual}")
+            }
+            None => {
+                write!(f, "expected {expected} at stack position {index}, but the stack ended")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutputMismatch {}
+
+// DIGEST PARSE ERROR
+// ================================================================================================
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DigestParseError {
+    InvalidLength(usize),
+    InvalidCharacter(char),
+    NotFieldElement(u64),
+}
+
+impl fmt::Display for DigestParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use DigestParseError::*;
+        match self {
+            InvalidLength(len) => {
+                write!(f, "expected 64 hex digits for a digest, but found {len}")
+            }
+            InvalidCharacter(c) => write!(f, "'{c}' is not a valid hex digit"),
+            NotFieldElement(value) => {
+                write!(f, "digest element {value} is not a valid field element")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DigestParseError {}
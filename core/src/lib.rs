@@ -36,11 +36,15 @@ pub use math::{
 };
 
 mod program;
-pub use program::{blocks as code_blocks, CodeBlockTable, Kernel, Program, ProgramInfo};
+pub use program::{
+    blocks as code_blocks, CodeBlockTable, Kernel, ProcedureInfo, Program, ProgramComplexity,
+    ProgramInfo,
+};
 
 mod operations;
 pub use operations::{
-    AdviceInjector, AssemblyOp, Decorator, DecoratorIterator, DecoratorList, Operation,
+    advice_tape_key, AdviceInjector, AssemblyOp, DebugOptions, Decorator, DecoratorIterator,
+    DecoratorList, OpClass, Operation, SourceLocation,
 };
 
 pub mod stack;
@@ -1,4 +1,7 @@
-use crate::Felt;
+use crate::{
+    crypto::hash::{Rpo256, RpoDigest},
+    Felt, Word,
+};
 use core::fmt;
 
 // ADVICE INJECTORS
@@ -151,6 +154,24 @@ pub enum AdviceInjector {
     /// - f2 is a boolean flag set to `1` if a remaining key is not zero.
     SmtGet,
 
+    /// Pushes the next unread element of the named advice tape with the specified key onto the
+    /// advice stack, and advances the read cursor of the tape.
+    ///
+    /// Named advice tapes are stored in the advice map under the key computed from the name of
+    /// the tape via [advice_tape_key()]. Each tape has its own read cursor, and thus, reading
+    /// from one tape does not affect the values read from other tapes or from the advice stack.
+    ///
+    /// Inputs:
+    ///   Advice stack: [...]
+    ///   Advice map: {KEY: [v0, ..., vn]}
+    ///
+    /// Outputs:
+    ///   Advice stack: [vi, ...]
+    ///   Advice map: {KEY: [v0, ..., vn]}
+    ///
+    /// Where `vi` is the first element of the tape which has not been read yet.
+    TapeToStack { key: Word },
+
     // ADVICE MAP INJECTORS
     // --------------------------------------------------------------------------------------------
     /// Reads words from memory at the specified range and inserts them into the advice map under
@@ -202,8 +223,20 @@ impl fmt::Display for AdviceInjector {
             Self::Ext2Inv => write!(f, "ext2_inv"),
             Self::Ext2Intt => write!(f, "ext2_intt"),
             Self::SmtGet => write!(f, "smt_get"),
+            Self::TapeToStack { key } => write!(f, "tape_to_stack.{}", RpoDigest::from(*key)),
             Self::MemToMap => write!(f, "mem_to_map"),
             Self::HdwordToMap { domain } => write!(f, "hdword_to_map.{domain}"),
         }
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the key under which the values of the named advice tape with the specified name are
+/// stored in the advice map.
+///
+/// The key is computed as the hash of the UTF-8 encoding of the name.
+pub fn advice_tape_key(name: &str) -> Word {
+    Rpo256::hash(name.as_bytes()).into()
+}
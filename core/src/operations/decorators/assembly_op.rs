@@ -1,3 +1,4 @@
+use super::SourceLocation;
 use crate::utils::string::String;
 use core::fmt;
 
@@ -11,6 +12,7 @@ pub struct AssemblyOp {
     num_cycles: u8,
     op: String,
     should_break: bool,
+    location: Option<SourceLocation>,
 }
 
 impl AssemblyOp {
@@ -22,9 +24,17 @@ impl AssemblyOp {
             num_cycles,
             op,
             should_break,
+            location: None,
         }
     }
 
+    /// Returns this [AssemblyOp] with the specified location of the assembly instruction in the
+    /// source code.
+    pub fn with_location(mut self, location: SourceLocation) -> Self {
+        self.location = Some(location);
+        self
+    }
+
     /// Returns the context name for this operation.
     pub fn context_name(&self) -> &str {
         &self.context_name
@@ -40,6 +50,11 @@ impl AssemblyOp {
         &self.op
     }
 
+    /// Returns the location of the assembly instruction in the source code, if known.
+    pub const fn location(&self) -> Option<SourceLocation> {
+        self.location
+    }
+
     /// Returns `true` if there is a breakpoint for the current operation.
     pub const fn should_break(&self) -> bool {
         self.should_break
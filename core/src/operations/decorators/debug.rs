@@ -0,0 +1,88 @@
+use crate::utils::{
+    string::ToString, ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+};
+use core::fmt;
+
+// CONSTANTS
+// ================================================================================================
+
+const STACK_ALL: u8 = 0;
+const STACK_TOP: u8 = 1;
+const MEM_ALL: u8 = 2;
+const MEM_INTERVAL: u8 = 3;
+
+// DEBUG OPTIONS
+// ================================================================================================
+
+/// Options of the `Debug` decorator.
+///
+/// These options define the debug info which gets captured when the `Debug` decorator is
+/// executed. Debug decorators are emitted by the assembler only in debug mode.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DebugOptions {
+    /// Captures the entire contents of the operand stack.
+    StackAll,
+    /// Captures the specified number of items from the top of the operand stack.
+    StackTop(u16),
+    /// Captures all words stored in memory of the current execution context.
+    MemAll,
+    /// Captures words stored in memory of the current execution context at addresses in the
+    /// specified inclusive range.
+    MemInterval(u32, u32),
+}
+
+impl fmt::Display for DebugOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StackAll => write!(f, "stack"),
+            Self::StackTop(n) => write!(f, "stack.{n}"),
+            Self::MemAll => write!(f, "mem"),
+            Self::MemInterval(start, end) => write!(f, "mem.{start}.{end}"),
+        }
+    }
+}
+
+impl Serializable for DebugOptions {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::StackAll => target.write_u8(STACK_ALL),
+            Self::StackTop(n) => {
+                target.write_u8(STACK_TOP);
+                target.write_u16(*n);
+            }
+            Self::MemAll => target.write_u8(MEM_ALL),
+            Self::MemInterval(start, end) => {
+                target.write_u8(MEM_INTERVAL);
+                target.write_u32(*start);
+                target.write_u32(*end);
+            }
+        }
+    }
+}
+
+impl Deserializable for DebugOptions {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            STACK_ALL => Ok(Self::StackAll),
+            STACK_TOP => {
+                let n = source.read_u16()?;
+                if n == 0 {
+                    return Err(DeserializationError::InvalidValue(
+                        "invalid stack top".to_string(),
+                    ));
+                }
+                Ok(Self::StackTop(n))
+            }
+            MEM_ALL => Ok(Self::MemAll),
+            MEM_INTERVAL => {
+                let start = source.read_u32()?;
+                let end = source.read_u32()?;
+                if start > end {
+                    return Err(DeserializationError::InvalidValue("invalid interval".to_string()));
+                }
+                Ok(Self::MemInterval(start, end))
+            }
+            val => Err(DeserializationError::InvalidValue(val.to_string())),
+        }
+    }
+}
@@ -1,5 +1,5 @@
-use super::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use core::fmt;
+use winter_utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
 // SOURCE LOCATION
 // ================================================================================================
@@ -35,6 +35,11 @@ impl SourceLocation {
         self.line
     }
 
+    /// Returns the column of the location.
+    pub const fn column(&self) -> u32 {
+        self.column
+    }
+
     // STATE MUTATORS
     // -------------------------------------------------------------------------------------------------
 
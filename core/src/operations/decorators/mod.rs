@@ -1,9 +1,13 @@
 mod advice;
 mod assembly_op;
+mod debug;
+mod location;
 use crate::utils::collections::Vec;
-pub use advice::AdviceInjector;
+pub use advice::{advice_tape_key, AdviceInjector};
 pub use assembly_op::AssemblyOp;
 use core::fmt;
+pub use debug::DebugOptions;
+pub use location::SourceLocation;
 
 // DECORATORS
 // ================================================================================================
@@ -17,6 +21,9 @@ pub enum Decorator {
     /// Adds information about the assembly instruction at a particular index
     /// (only applicable in debug mode)
     AsmOp(AssemblyOp),
+    /// Captures the debug info specified by the options (e.g., the state of the operand stack);
+    /// this has no effect on the state of the VM, and does not advance the VM clock.
+    Debug(DebugOptions),
 }
 
 impl fmt::Display for Decorator {
@@ -26,6 +33,7 @@ impl fmt::Display for Decorator {
             Self::AsmOp(assembly_op) => {
                 write!(f, "asmOp({}, {})", assembly_op.op(), assembly_op.num_cycles())
             }
+            Self::Debug(options) => write!(f, "debug({options})"),
         }
     }
 }
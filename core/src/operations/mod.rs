@@ -1,7 +1,11 @@
-use super::Felt;
+use super::{Felt, StarkField, ZERO};
 use core::fmt;
+use winter_utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 mod decorators;
-pub use decorators::{AdviceInjector, AssemblyOp, Decorator, DecoratorIterator, DecoratorList};
+pub use decorators::{
+    advice_tape_key, AdviceInjector, AssemblyOp, DebugOptions, Decorator, DecoratorIterator,
+    DecoratorList, SourceLocation,
+};
 
 // OPERATIONS
 // ================================================================================================
@@ -16,7 +20,10 @@ pub enum Operation {
     Noop,
 
     /// Pops the stack; if the popped value is not 1, execution fails.
-    Assert,
+    ///
+    /// The internal value specifies an error code reported when the assertion fails. The error
+    /// code does not affect the hash of the program.
+    Assert(Felt),
 
     /// Pops an element off the stack, adds the current value of the `fmp` register to it, and
     /// pushes the result back onto the stack.
@@ -464,7 +471,7 @@ impl Operation {
             Self::SwapDW    => 0b0001_1110,
             // <empty>      => 0b0001_1111,
 
-            Self::Assert    => 0b0010_0000,
+            Self::Assert(_) => 0b0010_0000,
             Self::Eq        => 0b0010_0001,
             Self::Add       => 0b0010_0010,
             Self::Mul       => 0b0010_0011,
@@ -559,6 +566,159 @@ impl Operation {
                 | Self::SysCall
         )
     }
+
+    /// Returns the class of this operation.
+    pub fn op_class(&self) -> OpClass {
+        match self {
+            Self::Noop
+            | Self::Assert(_)
+            | Self::FmpAdd
+            | Self::FmpUpdate
+            | Self::SDepth
+            | Self::Caller
+            | Self::Clk => OpClass::System,
+
+            Self::Join
+            | Self::Split
+            | Self::Loop
+            | Self::Call
+            | Self::SysCall
+            | Self::Span
+            | Self::End
+            | Self::Repeat
+            | Self::Respan
+            | Self::Halt => OpClass::ControlFlow,
+
+            Self::Add
+            | Self::Neg
+            | Self::Mul
+            | Self::Inv
+            | Self::Incr
+            | Self::And
+            | Self::Or
+            | Self::Not
+            | Self::Eq
+            | Self::Eqz
+            | Self::Expacc
+            | Self::Ext2Mul => OpClass::FieldArithmetic,
+
+            Self::U32assert2
+            | Self::U32split
+            | Self::U32add
+            | Self::U32add3
+            | Self::U32sub
+            | Self::U32mul
+            | Self::U32madd
+            | Self::U32div
+            | Self::U32and
+            | Self::U32xor => OpClass::U32Arithmetic,
+
+            Self::Drop
+            | Self::Pad
+            | Self::Dup0
+            | Self::Dup1
+            | Self::Dup2
+            | Self::Dup3
+            | Self::Dup4
+            | Self::Dup5
+            | Self::Dup6
+            | Self::Dup7
+            | Self::Dup9
+            | Self::Dup11
+            | Self::Dup13
+            | Self::Dup15
+            | Self::Swap
+            | Self::SwapW
+            | Self::SwapW2
+            | Self::SwapW3
+            | Self::SwapDW
+            | Self::MovUp2
+            | Self::MovUp3
+            | Self::MovUp4
+            | Self::MovUp5
+            | Self::MovUp6
+            | Self::MovUp7
+            | Self::MovUp8
+            | Self::MovDn2
+            | Self::MovDn3
+            | Self::MovDn4
+            | Self::MovDn5
+            | Self::MovDn6
+            | Self::MovDn7
+            | Self::MovDn8
+            | Self::CSwap
+            | Self::CSwapW
+            | Self::Push(_) => OpClass::StackManipulation,
+
+            Self::AdvPop | Self::AdvPopW => OpClass::Advice,
+
+            Self::MLoadW
+            | Self::MStoreW
+            | Self::MLoad
+            | Self::MStore
+            | Self::MStream
+            | Self::Pipe => OpClass::Memory,
+
+            Self::HPerm | Self::MpVerify | Self::MrUpdate | Self::FriE2F4 => OpClass::Crypto,
+        }
+    }
+}
+
+// OPERATION CLASSES
+// ================================================================================================
+
+/// A coarse classification of VM operations, used for accounting of executed cycles.
+///
+/// Every operation belongs to exactly one class; the classification of existing operations does
+/// not change across patch releases.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum OpClass {
+    /// Operations which start and end code blocks (e.g., `JOIN`, `SPAN`, `END`, `CALL`).
+    ControlFlow,
+    /// System operations (e.g., `NOOP`, `ASSERT`, `FMPUPDATE`, `CLK`).
+    System,
+    /// Arithmetic and logic operations over field elements, including extension field
+    /// multiplication.
+    FieldArithmetic,
+    /// Arithmetic and bitwise operations over u32 values.
+    U32Arithmetic,
+    /// Operations which push values onto the stack or rearrange its top elements.
+    StackManipulation,
+    /// Operations which move values from the advice stack onto the operand stack.
+    Advice,
+    /// Operations which read from or write to memory (including `PIPE`).
+    Memory,
+    /// Hashing, Merkle tree, and FRI operations.
+    Crypto,
+}
+
+impl OpClass {
+    /// All operation classes, in the order in which they are listed in this enum.
+    pub const ALL: [OpClass; 8] = [
+        Self::ControlFlow,
+        Self::System,
+        Self::FieldArithmetic,
+        Self::U32Arithmetic,
+        Self::StackManipulation,
+        Self::Advice,
+        Self::Memory,
+        Self::Crypto,
+    ];
+}
+
+impl fmt::Display for OpClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ControlFlow => write!(f, "control flow"),
+            Self::System => write!(f, "system"),
+            Self::FieldArithmetic => write!(f, "field arithmetic"),
+            Self::U32Arithmetic => write!(f, "u32 arithmetic"),
+            Self::StackManipulation => write!(f, "stack manipulation"),
+            Self::Advice => write!(f, "advice"),
+            Self::Memory => write!(f, "memory"),
+            Self::Crypto => write!(f, "crypto"),
+        }
+    }
 }
 
 impl fmt::Display for Operation {
@@ -566,7 +726,13 @@ impl fmt::Display for Operation {
         match self {
             // ----- system operations ------------------------------------------------------------
             Self::Noop => write!(f, "noop"),
-            Self::Assert => write!(f, "assert"),
+            Self::Assert(err_code) => {
+                if *err_code == ZERO {
+                    write!(f, "assert")
+                } else {
+                    write!(f, "assert({err_code})")
+                }
+            }
 
             Self::FmpAdd => write!(f, "fmpadd"),
             Self::FmpUpdate => write!(f, "fmpupdate"),
@@ -685,3 +851,131 @@ impl fmt::Display for Operation {
         }
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for Operation {
+    /// Serializes this operation as its opcode followed by the immediate value (as a
+    /// little-endian `u64`) for operations which carry one (i.e., `PUSH` and `ASSERT`).
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.op_code());
+        match self {
+            Self::Assert(value) | Self::Push(value) => target.write_u64(value.as_int()),
+            _ => (),
+        }
+    }
+}
+
+impl Deserializable for Operation {
+    #[rustfmt::skip]
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let op_code = source.read_u8()?;
+        let op = match op_code {
+            0b0000_0000 => Self::Noop,
+            0b0000_0001 => Self::Eqz,
+            0b0000_0010 => Self::Neg,
+            0b0000_0011 => Self::Inv,
+            0b0000_0100 => Self::Incr,
+            0b0000_0101 => Self::Not,
+            0b0000_0110 => Self::FmpAdd,
+            0b0000_0111 => Self::MLoad,
+            0b0000_1000 => Self::Swap,
+            0b0000_1001 => Self::Caller,
+            0b0000_1010 => Self::MovUp2,
+            0b0000_1011 => Self::MovDn2,
+            0b0000_1100 => Self::MovUp3,
+            0b0000_1101 => Self::MovDn3,
+            0b0000_1110 => Self::AdvPopW,
+            0b0000_1111 => Self::Expacc,
+            0b0001_0000 => Self::MovUp4,
+            0b0001_0001 => Self::MovDn4,
+            0b0001_0010 => Self::MovUp5,
+            0b0001_0011 => Self::MovDn5,
+            0b0001_0100 => Self::MovUp6,
+            0b0001_0101 => Self::MovDn6,
+            0b0001_0110 => Self::MovUp7,
+            0b0001_0111 => Self::MovDn7,
+            0b0001_1000 => Self::SwapW,
+            0b0001_1001 => Self::Ext2Mul,
+            0b0001_1010 => Self::MovUp8,
+            0b0001_1011 => Self::MovDn8,
+            0b0001_1100 => Self::SwapW2,
+            0b0001_1101 => Self::SwapW3,
+            0b0001_1110 => Self::SwapDW,
+            0b0010_0000 => Self::Assert(read_felt(source)?),
+            0b0010_0001 => Self::Eq,
+            0b0010_0010 => Self::Add,
+            0b0010_0011 => Self::Mul,
+            0b0010_0100 => Self::And,
+            0b0010_0101 => Self::Or,
+            0b0010_0110 => Self::U32and,
+            0b0010_0111 => Self::U32xor,
+            0b0010_1000 => Self::FriE2F4,
+            0b0010_1001 => Self::Drop,
+            0b0010_1010 => Self::CSwap,
+            0b0010_1011 => Self::CSwapW,
+            0b0010_1100 => Self::MLoadW,
+            0b0010_1101 => Self::MStore,
+            0b0010_1110 => Self::MStoreW,
+            0b0010_1111 => Self::FmpUpdate,
+            0b0011_0000 => Self::Pad,
+            0b0011_0001 => Self::Dup0,
+            0b0011_0010 => Self::Dup1,
+            0b0011_0011 => Self::Dup2,
+            0b0011_0100 => Self::Dup3,
+            0b0011_0101 => Self::Dup4,
+            0b0011_0110 => Self::Dup5,
+            0b0011_0111 => Self::Dup6,
+            0b0011_1000 => Self::Dup7,
+            0b0011_1001 => Self::Dup9,
+            0b0011_1010 => Self::Dup11,
+            0b0011_1011 => Self::Dup13,
+            0b0011_1100 => Self::Dup15,
+            0b0011_1101 => Self::AdvPop,
+            0b0011_1110 => Self::SDepth,
+            0b0011_1111 => Self::Clk,
+            0b0100_0000 => Self::U32add,
+            0b0100_0010 => Self::U32sub,
+            0b0100_0100 => Self::U32mul,
+            0b0100_0110 => Self::U32div,
+            0b0100_1000 => Self::U32split,
+            0b0100_1010 => Self::U32assert2,
+            0b0100_1100 => Self::U32add3,
+            0b0100_1110 => Self::U32madd,
+            0b0101_0000 => Self::HPerm,
+            0b0101_0001 => Self::MpVerify,
+            0b0101_0010 => Self::Pipe,
+            0b0101_0011 => Self::MStream,
+            0b0101_0100 => Self::Split,
+            0b0101_0101 => Self::Loop,
+            0b0101_0110 => Self::Span,
+            0b0101_0111 => Self::Join,
+            0b0110_0000 => Self::MrUpdate,
+            0b0110_0100 => Self::Push(read_felt(source)?),
+            0b0110_1000 => Self::SysCall,
+            0b0110_1100 => Self::Call,
+            0b0111_0000 => Self::End,
+            0b0111_0100 => Self::Repeat,
+            0b0111_1000 => Self::Respan,
+            0b0111_1100 => Self::Halt,
+            _ => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "{op_code} is not a valid opcode"
+                )))
+            }
+        };
+        Ok(op)
+    }
+}
+
+/// Reads a field element encoded as a little-endian `u64` from the specified source.
+fn read_felt<R: ByteReader>(source: &mut R) -> Result<Felt, DeserializationError> {
+    let value = source.read_u64()?;
+    if value >= Felt::MODULUS {
+        return Err(DeserializationError::InvalidValue(format!(
+            "{value} is not a valid field element"
+        )));
+    }
+    Ok(Felt::new(value))
+}
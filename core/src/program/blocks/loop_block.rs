@@ -12,10 +12,15 @@ use super::{fmt, hasher, Box, CodeBlock, Digest, Felt, Operation};
 /// > hash(body_hash || padding, domain=LOOP_DOMAIN)
 ///
 /// Where `body_hash` is 4 field elements (256 bits), and `padding` is 4 ZERO elements (256 bits).
+///
+/// A loop block may optionally specify the maximum number of times its body can be executed. This
+/// bound is enforced only during program execution and is not a part of the hash of the block;
+/// thus, a proof of execution of a loop block does not attest to the bound being respected.
 #[derive(Clone, Debug)]
 pub struct Loop {
     body: Box<CodeBlock>,
     hash: Digest,
+    max_iterations: Option<u32>,
 }
 
 impl Loop {
@@ -32,9 +37,18 @@ impl Loop {
         Self {
             body: Box::new(body),
             hash,
+            max_iterations: None,
         }
     }
 
+    /// Returns a new [Loop] block instantiated with the specified body, and which fails during
+    /// execution if the body is executed more than `max_iterations` times.
+    pub fn with_max_iterations(body: CodeBlock, max_iterations: u32) -> Self {
+        let mut block = Self::new(body);
+        block.max_iterations = Some(max_iterations);
+        block
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -47,10 +61,21 @@ impl Loop {
     pub fn body(&self) -> &CodeBlock {
         &self.body
     }
+
+    /// Returns the maximum number of times the body of this loop can be executed, or None if the
+    /// number of iterations is not bounded.
+    pub fn max_iterations(&self) -> Option<u32> {
+        self.max_iterations
+    }
 }
 
 impl fmt::Display for Loop {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "while.true {} end", self.body)
+        match self.max_iterations {
+            Some(max_iterations) => {
+                write!(f, "while.true.max={max_iterations} {} end", self.body)
+            }
+            None => write!(f, "while.true {} end", self.body),
+        }
     }
 }
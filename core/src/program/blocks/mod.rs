@@ -61,6 +61,12 @@ impl CodeBlock {
         Self::Loop(Loop::new(body))
     }
 
+    /// Returns a new loop block which fails during execution if the specified body is executed
+    /// more than `max_iterations` times.
+    pub fn new_bounded_loop(body: CodeBlock, max_iterations: u32) -> Self {
+        Self::Loop(Loop::with_max_iterations(body, max_iterations))
+    }
+
     /// TODO: add comments
     pub fn new_call(fn_hash: Digest) -> Self {
         Self::Call(Call::new(fn_hash))
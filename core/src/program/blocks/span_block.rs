@@ -390,7 +390,9 @@ pub fn get_span_op_group_count(op_batches: &[OpBatch]) -> usize {
 
 /// Checks if a given decorators list is valid (only checked in debug mode)
 /// - Assert the decorator list is in ascending order.
-/// - Assert the last op index in decorator list is less than the number of operations.
+/// - Assert the last op index in decorator list is less than or equal to the number of
+///   operations (decorators at the index equal to the number of operations are executed after
+///   the last operation of the span).
 #[cfg(debug_assertions)]
 fn validate_decorators(operations: &[Operation], decorators: &DecoratorList) {
     if !decorators.is_empty() {
@@ -398,10 +400,10 @@ fn validate_decorators(operations: &[Operation], decorators: &DecoratorList) {
         for i in 0..(decorators.len() - 1) {
             debug_assert!(decorators[i + 1].0 >= decorators[i].0, "unsorted decorators list");
         }
-        // assert the last index in decorator list is less than operations vector length
+        // assert the last index in decorator list is not greater than operations vector length
         debug_assert!(
-            operations.len() > decorators.last().expect("empty decorators list").0,
-            "last op index in decorator list should be less than number of ops"
+            operations.len() >= decorators.last().expect("empty decorators list").0,
+            "last op index in decorator list should not be greater than number of ops"
         );
     }
 }
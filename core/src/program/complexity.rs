@@ -0,0 +1,199 @@
+use super::{blocks::CodeBlock, BTreeMap, CodeBlockTable, Operation};
+
+// PROGRAM COMPLEXITY
+// ================================================================================================
+
+/// A report on the size and complexity of a [Program](super::Program), computed by static analysis
+/// of the program's MAST; the program is not executed.
+///
+/// The report is intended for comparing programs and for getting a rough estimate of the cost of
+/// proving a program before executing it. Since the number of iterations of a loop is not known
+/// statically, the body of each loop is assumed to be executed exactly once, and for each
+/// conditional block, the branch requiring fewer cycles is assumed to be executed. Thus, for
+/// programs in which every loop is entered, the estimated number of cycles is a lower bound on
+/// the number of cycles required to execute the program.
+///
+/// For programs in which every loop specifies the maximum number of its iterations (i.e., which
+/// use `while.true.max=N` loops only), an upper bound on the number of cycles is reported as well;
+/// see [ProgramComplexity::max_cycles()].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProgramComplexity {
+    num_operations: usize,
+    max_loop_depth: usize,
+    num_hash_operations: usize,
+    num_cycles: usize,
+    max_cycles: Option<usize>,
+}
+
+impl ProgramComplexity {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the complexity of the specified code block; procedures invoked via `call` and
+    /// `syscall` instructions are looked up in the provided code block table.
+    pub(super) fn new(block: &CodeBlock, cb_table: &CodeBlockTable) -> Self {
+        analyze_block(block, cb_table, &mut BTreeMap::new())
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of operations in all SPAN blocks of the program.
+    ///
+    /// Operations of blocks which appear in the program multiple times (e.g., bodies of `repeat`
+    /// statements, or procedures invoked from multiple places) are counted once per appearance,
+    /// and operations of both branches of each conditional block are counted. The count includes
+    /// NOOPs inserted by the assembler to align operation batches.
+    pub fn num_operations(&self) -> usize {
+        self.num_operations
+    }
+
+    /// Returns the maximum nesting depth of loops in the program (0 if the program contains no
+    /// loops).
+    pub fn max_loop_depth(&self) -> usize {
+        self.max_loop_depth
+    }
+
+    /// Returns the number of `HPERM`, `MPVERIFY`, and `MRUPDATE` operations in the program; these
+    /// are the operations which are offloaded to the hash chiplet.
+    ///
+    /// The operations are counted in the same way as in [ProgramComplexity::num_operations()].
+    pub fn num_hash_operations(&self) -> usize {
+        self.num_hash_operations
+    }
+
+    /// Returns the estimated number of cycles required to execute the program, assuming that each
+    /// loop body is executed once.
+    pub fn estimated_cycles(&self) -> usize {
+        self.num_cycles
+    }
+
+    /// Returns an upper bound on the number of cycles required to execute the program, or None if
+    /// the number of cycles cannot be bounded statically.
+    ///
+    /// The bound assumes that each bounded loop is executed the maximum number of times and that
+    /// the branch requiring more cycles is executed for each conditional block. None is returned if
+    /// the program contains a loop without an iteration bound, or invokes a procedure whose code
+    /// is not known.
+    ///
+    /// The bound can be used to pad the execution traces of all executions of a program to the
+    /// same length (see `ExecutionOptions::with_min_trace_len()` in the processor); however, the
+    /// trace may need to be longer than the bound to accommodate the chiplets.
+    pub fn max_cycles(&self) -> Option<usize> {
+        self.max_cycles
+    }
+
+    /// Returns the estimated length of the execution trace of the program.
+    ///
+    /// The estimate is based on [ProgramComplexity::estimated_cycles()] only; the trace may need
+    /// to be longer to accommodate the chiplets (e.g., for programs performing many hash
+    /// operations), and the VM pads the trace to a minimum length.
+    pub fn estimated_trace_len(&self) -> usize {
+        // the last row of the trace is reserved for the HALT operation
+        (self.num_cycles + 1).next_power_of_two()
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the complexity of executing this block followed by the `other` block.
+    fn then(self, other: Self) -> Self {
+        Self {
+            num_operations: self.num_operations + other.num_operations,
+            max_loop_depth: self.max_loop_depth.max(other.max_loop_depth),
+            num_hash_operations: self.num_hash_operations + other.num_hash_operations,
+            num_cycles: self.num_cycles + other.num_cycles,
+            max_cycles: self
+                .max_cycles
+                .zip(other.max_cycles)
+                .map(|(first, second)| first.saturating_add(second)),
+        }
+    }
+
+    /// Returns the complexity of this block with the specified number of cycles added to it.
+    fn with_cycles(mut self, num_cycles: usize) -> Self {
+        self.num_cycles += num_cycles;
+        self.max_cycles = self.max_cycles.map(|max_cycles| max_cycles.saturating_add(num_cycles));
+        self
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the complexity of the specified code block; the complexity of procedures invoked via
+/// `call` and `syscall` instructions is cached by the hash of the procedure.
+fn analyze_block(
+    block: &CodeBlock,
+    cb_table: &CodeBlockTable,
+    cache: &mut BTreeMap<[u8; 32], ProgramComplexity>,
+) -> ProgramComplexity {
+    match block {
+        CodeBlock::Span(span) => {
+            let mut result = ProgramComplexity {
+                max_cycles: Some(0),
+                ..Default::default()
+            };
+            for batch in span.op_batches() {
+                result.num_operations += batch.ops().len();
+                result.num_hash_operations += batch
+                    .ops()
+                    .iter()
+                    .filter(|op| {
+                        matches!(op, Operation::HPerm | Operation::MpVerify | Operation::MrUpdate)
+                    })
+                    .count();
+            }
+            // SPAN and END operations, and a RESPAN operation for each batch after the first
+            result.with_cycles(result.num_operations + span.op_batches().len() + 1)
+        }
+        CodeBlock::Join(join) => {
+            let first = analyze_block(join.first(), cb_table, cache);
+            let second = analyze_block(join.second(), cb_table, cache);
+            first.then(second).with_cycles(2)
+        }
+        CodeBlock::Split(split) => {
+            let on_true = analyze_block(split.on_true(), cb_table, cache);
+            let on_false = analyze_block(split.on_false(), cb_table, cache);
+            let num_cycles = on_true.num_cycles.min(on_false.num_cycles);
+            let max_cycles = on_true.max_cycles.zip(on_false.max_cycles).map(|(a, b)| a.max(b));
+            ProgramComplexity {
+                num_cycles,
+                max_cycles,
+                ..on_true.then(on_false)
+            }
+            .with_cycles(2)
+        }
+        CodeBlock::Loop(loop_block) => {
+            let mut body = analyze_block(loop_block.body(), cb_table, cache);
+            body.max_loop_depth += 1;
+            // executing the body n times requires LOOP and END operations, and a REPEAT operation
+            // before each iteration after the first one
+            body.max_cycles =
+                body.max_cycles.zip(loop_block.max_iterations()).map(|(cycles, n)| {
+                    (n as usize).saturating_mul(cycles.saturating_add(1)).saturating_add(1)
+                });
+            ProgramComplexity {
+                num_cycles: body.num_cycles + 2,
+                ..body
+            }
+        }
+        CodeBlock::Call(call) => {
+            let key: [u8; 32] = call.fn_hash().into();
+            let callee = match cache.get(&key) {
+                Some(callee) => *callee,
+                None => {
+                    let callee = cb_table
+                        .get(call.fn_hash())
+                        .map(|body| analyze_block(body, cb_table, cache))
+                        .unwrap_or_default();
+                    cache.insert(key, callee);
+                    callee
+                }
+            };
+            callee.with_cycles(2)
+        }
+        // the code of proxy blocks is not known
+        CodeBlock::Proxy(_) => ProgramComplexity::default(),
+    }
+}
@@ -2,9 +2,11 @@ use super::{
     chiplets::hasher::{self, Digest},
     utils::{
         collections::{BTreeMap, Vec},
+        digest_to_hex,
+        string::String,
         Box,
     },
-    Felt, FieldElement, Operation,
+    Felt, FieldElement, Operation, ToElements,
 };
 use core::fmt;
 use winter_utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
@@ -12,6 +14,9 @@ use winter_utils::{ByteReader, ByteWriter, Deserializable, DeserializationError,
 pub mod blocks;
 use blocks::CodeBlock;
 
+mod complexity;
+pub use complexity::ProgramComplexity;
+
 mod info;
 pub use info::ProgramInfo;
 
@@ -30,6 +35,7 @@ pub struct Program {
     root: CodeBlock,
     kernel: Kernel,
     cb_table: CodeBlockTable,
+    procedures: Vec<ProcedureInfo>,
 }
 
 impl Program {
@@ -46,9 +52,30 @@ impl Program {
             root,
             kernel,
             cb_table,
+            procedures: Vec::new(),
         }
     }
 
+    /// Instantiates the smallest valid [Program]: a program consisting of a single `NOOP`
+    /// operation.
+    ///
+    /// The program does not modify the stack, and its execution trace is padded to the minimum
+    /// trace length supported by the VM. Both the hash of this program and the length of its
+    /// execution trace are fixed, which makes this program useful for checking that proof
+    /// generation and verification work end-to-end.
+    pub fn new_empty() -> Self {
+        Self::new(CodeBlock::new_span(vec![Operation::Noop]))
+    }
+
+    /// Returns this program with the specified procedure metadata attached to it.
+    ///
+    /// Procedure metadata is not a part of the program MAST, and thus, does not affect the hash
+    /// of the program.
+    pub fn with_procedures(mut self, procedures: Vec<ProcedureInfo>) -> Self {
+        self.procedures = procedures;
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -62,6 +89,13 @@ impl Program {
         self.root.hash()
     }
 
+    /// Returns a hash of this program encoded as a lowercase hex string.
+    ///
+    /// The string encodes the 32 bytes of the hash in the same order as they are serialized.
+    pub fn hash_hex(&self) -> String {
+        digest_to_hex(&self.hash())
+    }
+
     /// Returns a kernel for this program.
     pub fn kernel(&self) -> &Kernel {
         &self.kernel
@@ -71,14 +105,99 @@ impl Program {
     pub fn cb_table(&self) -> &CodeBlockTable {
         &self.cb_table
     }
+
+    /// Returns metadata of the procedures which were compiled into this program.
+    ///
+    /// For programs produced by the assembler, the first entry describes the main procedure of
+    /// the program (its digest is the hash of the program). The remaining entries describe all
+    /// procedures which may be invoked, directly or indirectly, from the main procedure. The list
+    /// is empty for programs which were not produced by the assembler.
+    pub fn procedures(&self) -> &[ProcedureInfo] {
+        &self.procedures
+    }
+
+    /// Returns a report on the size and complexity of this program.
+    ///
+    /// The report is computed by static analysis of the program's MAST, and thus, does not
+    /// require executing the program; see [ProgramComplexity] for details on how the reported
+    /// values are estimated.
+    pub fn complexity(&self) -> ProgramComplexity {
+        ProgramComplexity::new(&self.root, &self.cb_table)
+    }
+
+    /// Returns a 32-byte fingerprint of this program.
+    ///
+    /// The fingerprint is the RPO hash of the program hash and the roots of the kernel procedures
+    /// (encoded in the same way as they are encoded in the public inputs of a proof). Thus, the
+    /// fingerprint depends only on the MAST of the program and its kernel, and is not affected by
+    /// formatting or comments in the source code of the program, by decorators (e.g., debug
+    /// information), or by procedure names and other procedure metadata. Equal programs always
+    /// have the same fingerprint.
+    ///
+    /// The fingerprint is stable across patch releases of this crate.
+    pub fn fingerprint(&self) -> [u8; 32] {
+        let program_info = ProgramInfo::new(self.hash(), self.kernel.clone());
+        hasher::hash_elements(&program_info.to_elements()).into()
+    }
 }
 
+/// Two programs are equal if they have the same MAST root (i.e., the same program hash) and the
+/// same kernel; decorators and procedure metadata are not taken into account.
+impl PartialEq for Program {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash() == other.hash() && self.kernel == other.kernel
+    }
+}
+
+impl Eq for Program {}
+
 impl fmt::Display for Program {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "begin {} end", self.root)
     }
 }
 
+// PROCEDURE INFO
+// ================================================================================================
+
+/// Metadata of a procedure compiled into a [Program].
+///
+/// Contains the name of the procedure, its digest (i.e., the hash of the procedure's MAST), and
+/// the digests of all procedures invoked directly from the procedure body (via `exec`, `call`, or
+/// `syscall` instructions).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProcedureInfo {
+    name: String,
+    digest: Digest,
+    calls: Vec<Digest>,
+}
+
+impl ProcedureInfo {
+    /// Returns a new [ProcedureInfo] instantiated from the specified parameters.
+    pub fn new(name: String, digest: Digest, calls: Vec<Digest>) -> Self {
+        Self {
+            name,
+            digest,
+            calls,
+        }
+    }
+
+    /// Returns the name of the procedure.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the digest of the procedure.
+    pub fn digest(&self) -> Digest {
+        self.digest
+    }
+
+    /// Returns digests of procedures invoked directly from this procedure.
+    pub fn calls(&self) -> &[Digest] {
+        &self.calls
+    }
+}
+
 // CODE BLOCK TABLE
 // ================================================================================================
 
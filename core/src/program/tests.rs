@@ -1,4 +1,7 @@
-use super::{Deserializable, Digest, Felt, Kernel, ProgramInfo, Serializable};
+use super::{
+    blocks::CodeBlock, Deserializable, Digest, Felt, Kernel, Program, ProgramInfo, Serializable,
+};
+use crate::Operation;
 use crate::Word;
 use proptest::prelude::*;
 use rand_utils::prng_array;
@@ -24,6 +27,19 @@ proptest! {
     }
 }
 
+#[test]
+fn program_hash_hex() {
+    let program = Program::new(CodeBlock::new_span(vec![Operation::Add, Operation::Mul]));
+    let hash_hex = program.hash_hex();
+
+    assert_eq!(64, hash_hex.len());
+    assert!(hash_hex.chars().all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c)));
+
+    let bytes = program.hash().as_bytes();
+    assert_eq!(format!("{:02x}", bytes[0]), hash_hex[..2]);
+    assert_eq!(format!("{:02x}", bytes[31]), hash_hex[62..]);
+}
+
 // HELPER FUNCTIONS
 // --------------------------------------------------------------------------------------------
 
@@ -37,3 +53,54 @@ fn digest_from_seed(seed: [u8; 32]) -> Digest {
     });
     digest.into()
 }
+
+#[test]
+fn program_equality_and_fingerprint() {
+    let program = Program::new(CodeBlock::new_span(vec![Operation::Add, Operation::Mul]));
+    let same = Program::new(CodeBlock::new_span(vec![Operation::Add, Operation::Mul]));
+    let different = Program::new(CodeBlock::new_span(vec![Operation::Mul, Operation::Add]));
+
+    assert_eq!(program, same);
+    assert_eq!(program.fingerprint(), same.fingerprint());
+    assert_ne!(program, different);
+    assert_ne!(program.fingerprint(), different.fingerprint());
+
+    // the kernel is a part of the program's identity
+    let kernel = Kernel::new(&[digest_from_seed([1; 32])]);
+    let with_kernel = Program::with_kernel(program.root().clone(), kernel, Default::default());
+    assert_eq!(program.hash(), with_kernel.hash());
+    assert_ne!(program, with_kernel);
+    assert_ne!(program.fingerprint(), with_kernel.fingerprint());
+}
+
+#[test]
+fn program_complexity() {
+    let span = CodeBlock::new_span(vec![Operation::HPerm, Operation::Add]);
+    let inner_loop = CodeBlock::new_loop(CodeBlock::new_span(vec![Operation::MpVerify]));
+    let outer_loop = CodeBlock::new_loop(CodeBlock::new_join([span.clone(), inner_loop]));
+    let split = CodeBlock::new_split(span, CodeBlock::new_span(vec![Operation::Noop]));
+    let program = Program::new(CodeBlock::new_join([outer_loop, split]));
+
+    let complexity = program.complexity();
+    assert_eq!(6, complexity.num_operations());
+    assert_eq!(2, complexity.max_loop_depth());
+    assert_eq!(3, complexity.num_hash_operations());
+
+    // join (2) + outer loop (2) + inner join (2) + span (4) + inner loop (2) + span (3) + split (2)
+    // + the shorter branch (3)
+    assert_eq!(20, complexity.estimated_cycles());
+    assert_eq!(32, complexity.estimated_trace_len());
+
+    // the number of cycles cannot be bounded for programs with unbounded loops
+    assert_eq!(None, complexity.max_cycles());
+
+    // bounded loop (3 iterations of a 3-cycle body, 2 REPEATs, LOOP, and END) + split (2) + the
+    // longer branch (4) + join (2)
+    let bounded_loop = CodeBlock::new_bounded_loop(CodeBlock::new_span(vec![Operation::Add]), 3);
+    let split = CodeBlock::new_split(
+        CodeBlock::new_span(vec![Operation::HPerm, Operation::Add]),
+        CodeBlock::new_span(vec![Operation::Noop]),
+    );
+    let program = Program::new(CodeBlock::new_join([bounded_loop, split]));
+    assert_eq!(Some(21), program.complexity().max_cycles());
+}
@@ -1,6 +1,14 @@
-use super::{vec, ByteWriter, Felt, InputError, Serializable, ToElements, Vec};
+use super::{
+    vec, ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, InputError,
+    Serializable, StarkField, ToElements, Vec, STACK_TOP_SIZE,
+};
 use core::slice;
 
+#[cfg(feature = "serde")]
+use crate::utils::string::{String, ToString};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
 // STACK INPUTS
 // ================================================================================================
 
@@ -8,6 +16,11 @@ use core::slice;
 ///
 /// The program execution expects the inputs to be a stack on the VM, and it will be stored in
 /// reversed order on this struct.
+///
+/// Stack inputs are public: they are committed to in the proof and must be provided to the
+/// verifier together with the proof. Secret inputs should be supplied to the VM via the advice
+/// provider instead. The number of stack inputs is not limited; values beyond the top 16 elements
+/// of the stack are placed into the stack overflow table.
 #[derive(Clone, Debug, Default)]
 pub struct StackInputs {
     values: Vec<Felt>,
@@ -17,6 +30,14 @@ impl StackInputs {
     // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns `[StackInputs]` with no values.
+    ///
+    /// This is the same as [StackInputs::default()]; the stack of a program executed against
+    /// empty inputs is initialized with zeros.
+    pub const fn empty() -> Self {
+        Self { values: Vec::new() }
+    }
+
     /// Returns `[StackInputs]` from a list of values, reversing them into a stack.
     pub fn new(mut values: Vec<Felt>) -> Self {
         values.reverse();
@@ -31,14 +52,38 @@ impl StackInputs {
     {
         iter.into_iter()
             .map(|v| {
-                Felt::try_from(v).map_err(|_| {
-                    InputError::NotFieldElement(v, "the provided value isn't a valid field element")
-                })
+                if v < Felt::MODULUS {
+                    Ok(Felt::new(v))
+                } else {
+                    Err(InputError::NotFieldElement(
+                        v,
+                        "the provided value isn't a valid field element",
+                    ))
+                }
             })
             .collect::<Result<Vec<_>, _>>()
             .map(Self::new)
     }
 
+    /// Combines these stack inputs with the `other` stack inputs.
+    ///
+    /// Stack inputs describe the entire initial state of the stack, and thus cannot be combined
+    /// from several fragments. Instead, the inputs are merged as follows:
+    /// - If either of the inputs is empty, the other inputs are returned.
+    /// - If both inputs contain the same values in the same order, these inputs are returned.
+    ///
+    /// # Errors
+    /// Returns an error if both inputs are non-empty and contain different values.
+    pub fn merge(self, other: Self) -> Result<Self, InputError> {
+        if other.values.is_empty() || self.values == other.values {
+            Ok(self)
+        } else if self.values.is_empty() {
+            Ok(other)
+        } else {
+            Err(InputError::ConflictingStackInputs)
+        }
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -46,6 +91,13 @@ impl StackInputs {
     pub fn values(&self) -> &[Felt] {
         &self.values
     }
+
+    /// Returns the initial stack values converted to integers in the order in which they were
+    /// provided to [StackInputs::try_from_values()], i.e., with the value at the top of the stack
+    /// in the last position.
+    pub fn to_ints(&self) -> Vec<u64> {
+        self.values.iter().rev().map(|v| v.as_int()).collect()
+    }
 }
 
 impl<'a> IntoIterator for &'a StackInputs {
@@ -78,8 +130,106 @@ impl Serializable for StackInputs {
     }
 }
 
+impl Deserializable for StackInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        // the number of values is read from untrusted input, and thus only a bounded number of
+        // values is preallocated; the rest of the vector grows as the values are actually read.
+        let num_values = source.read_u32()? as usize;
+        let mut values = Vec::with_capacity(num_values.min(STACK_TOP_SIZE));
+        for _ in 0..num_values {
+            values.push(Felt::read_from(source)?);
+        }
+        Ok(Self { values })
+    }
+}
+
+/// Stack inputs are serialized as a sequence of decimal strings in the order in which they are
+/// provided to [StackInputs::try_from_values()], i.e., with the value at the top of the stack in
+/// the last position (e.g., `["1", "2", "3"]` in JSON).
+///
+/// Values are encoded as strings because formats such as JSON are frequently parsed into
+/// double-precision floats, which cannot represent all field elements exactly.
+#[cfg(feature = "serde")]
+impl Serialize for StackInputs {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.values.iter().rev().map(|value| value.as_int().to_string()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for StackInputs {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = Vec::<String>::deserialize(deserializer)?
+            .into_iter()
+            .map(|value| {
+                value.parse::<u64>().map_err(|err| {
+                    de::Error::custom(format_args!("invalid stack input '{value}': {err}"))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::try_from_values(values).map_err(de::Error::custom)
+    }
+}
+
 impl ToElements<Felt> for StackInputs {
     fn to_elements(&self) -> Vec<Felt> {
         self.values.to_vec()
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Deserializable, Felt, InputError, Serializable, StackInputs, StarkField};
+
+    #[test]
+    fn stack_inputs_ints_roundtrip() {
+        let values = [1, 2, 3, Felt::MODULUS - 1];
+        let inputs = StackInputs::try_from_values(values).unwrap();
+        assert_eq!(Felt::new(Felt::MODULUS - 1), inputs.values()[0]);
+        assert_eq!(values.to_vec(), inputs.to_ints());
+
+        let result = StackInputs::try_from_values([1, Felt::MODULUS]);
+        assert!(
+            matches!(result, Err(InputError::NotFieldElement(value, _)) if value == Felt::MODULUS)
+        );
+    }
+
+    #[test]
+    fn stack_inputs_empty() {
+        let inputs = StackInputs::empty();
+        assert!(inputs.values().is_empty());
+        assert!(inputs.to_ints().is_empty());
+        assert_eq!(StackInputs::default().values(), inputs.values());
+    }
+
+    #[test]
+    fn stack_inputs_merge() {
+        let inputs = StackInputs::try_from_values([1, 2, 3]).unwrap();
+
+        let merged = inputs.clone().merge(StackInputs::empty()).unwrap();
+        assert_eq!(inputs.values(), merged.values());
+        let merged = StackInputs::empty().merge(inputs.clone()).unwrap();
+        assert_eq!(inputs.values(), merged.values());
+        let merged = inputs.clone().merge(inputs.clone()).unwrap();
+        assert_eq!(inputs.values(), merged.values());
+
+        let other = StackInputs::try_from_values([1, 2]).unwrap();
+        let result = inputs.merge(other);
+        assert!(matches!(result, Err(InputError::ConflictingStackInputs)));
+    }
+
+    #[test]
+    fn stack_inputs_serialization() {
+        let inputs = StackInputs::try_from_values([1, 2, 3, Felt::MODULUS - 1]).unwrap();
+        let bytes = inputs.to_bytes();
+        let deserialized = StackInputs::read_from_bytes(&bytes).unwrap();
+        assert_eq!(inputs.values(), deserialized.values());
+
+        // a length which is not backed by values is rejected without preallocating the values
+        assert!(StackInputs::read_from_bytes(&[0xff; 4]).is_err());
+        assert!(StackInputs::read_from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+}
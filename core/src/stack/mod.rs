@@ -1,7 +1,10 @@
-use super::{errors::InputError, Felt, StackTopState, StarkField, ToElements};
+use super::{
+    errors::{InputError, OutputMismatch},
+    Felt, StackTopState, StarkField, ToElements,
+};
 use winter_utils::{
     collections::{vec, Vec},
-    ByteWriter, Serializable,
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
 };
 
 mod inputs;
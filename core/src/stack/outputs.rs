@@ -1,5 +1,6 @@
 use super::{
-    ByteWriter, Felt, Serializable, StackTopState, StarkField, ToElements, Vec, STACK_TOP_SIZE,
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, InputError, OutputMismatch,
+    Serializable, StackTopState, StarkField, ToElements, Vec, STACK_TOP_SIZE,
 };
 
 // STACK OUTPUTS
@@ -19,7 +20,13 @@ use super::{
 /// overflow table (the row representing the deepest element in the stack) and then be followed by
 /// the address (`clk` value) of each row in the table starting from the deepest element in the
 /// stack and finishing with the row which was added to the table last.
+///
+/// The logical state of the stack at the end of execution consists of the top [STACK_TOP_SIZE]
+/// elements of `stack` (see [StackOutputs::stack_top()]) followed by the values which were stored
+/// in the overflow table (see [StackOutputs::overflow_values()]). The i-th overflow value from the
+/// bottom of the stack was stored in the overflow table row with address `overflow_addrs[i + 1]`.
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StackOutputs {
     /// The elements on the stack at the end of execution.
     stack: Vec<u64>,
@@ -56,6 +63,33 @@ impl StackOutputs {
         }
     }
 
+    /// Returns stack outputs decoded from their flat representation produced by
+    /// [ToElements::to_elements()] (i.e., the stack elements followed by the overflow addresses).
+    ///
+    /// The number of stack elements is inferred from the total number of elements: there are no
+    /// overflow addresses if there are at most [STACK_TOP_SIZE] elements; otherwise, `n` stack
+    /// elements are followed by `n - STACK_TOP_SIZE + 1` overflow addresses. Thus, the round-trip
+    /// is lossless for all stack outputs produced by the VM.
+    ///
+    /// # Errors
+    /// Returns an error if the number of elements does not correspond to this layout.
+    pub fn try_from_elements(elements: &[Felt]) -> Result<Self, InputError> {
+        let num_elements = elements.len();
+        let stack_len = if num_elements <= STACK_TOP_SIZE {
+            num_elements
+        } else {
+            // num_elements = stack_len + (stack_len - STACK_TOP_SIZE + 1)
+            let double_stack_len = num_elements + STACK_TOP_SIZE - 1;
+            if double_stack_len % 2 != 0 || double_stack_len / 2 <= STACK_TOP_SIZE {
+                return Err(InputError::InvalidStackOutputsLength(num_elements));
+            }
+            double_stack_len / 2
+        };
+
+        let (stack, overflow_addrs) = elements.split_at(stack_len);
+        Ok(Self::from_elements(stack.to_vec(), overflow_addrs.to_vec()))
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -65,6 +99,16 @@ impl StackOutputs {
         &self.stack
     }
 
+    /// Returns the full state of the stack at the end of execution, including the values stored
+    /// in the overflow table.
+    ///
+    /// The elements are ordered from the top of the stack to the bottom: the first
+    /// [STACK_TOP_SIZE] elements are the top of the stack, and the remaining elements are the
+    /// values in the overflow table starting with the row which was added to the table last.
+    pub fn full_stack(&self) -> Vec<Felt> {
+        self.stack.iter().map(|&v| Felt::new(v)).collect()
+    }
+
     /// Returns the number of requested stack outputs or returns the full stack if fewer than the
     /// requested number of stack values exist.
     pub fn stack_truncated(&self, num_outputs: usize) -> &[u64] {
@@ -89,6 +133,49 @@ impl StackOutputs {
         &self.overflow_addrs
     }
 
+    /// Returns the values which were stored in the overflow table at the end of execution, i.e., all
+    /// elements of the stack outputs below the top [STACK_TOP_SIZE] elements.
+    ///
+    /// The values are ordered as they would be popped off the stack: the first value is the
+    /// element directly below the top of the stack, and the last value is the deepest element in
+    /// the stack.
+    pub fn overflow_values(&self) -> &[u64] {
+        let start = self.stack.len().min(STACK_TOP_SIZE);
+        &self.stack[start..]
+    }
+
+    /// Returns true if the top of the stack matches the specified values.
+    ///
+    /// `expected` is ordered from the top of the stack; the elements of the stack below the first
+    /// `expected.len()` elements are ignored. See [StackOutputs::assert_top()] for details about a
+    /// mismatch.
+    pub fn matches(&self, expected: &[u64]) -> bool {
+        self.assert_top(expected).is_ok()
+    }
+
+    /// Checks that the top of the stack matches the specified values.
+    ///
+    /// `expected` is ordered from the top of the stack; the elements of the stack below the first
+    /// `expected.len()` elements are ignored.
+    ///
+    /// # Errors
+    /// Returns an error describing the first position (starting from the top of the stack) at
+    /// which the stack differs from `expected`, including the case when the stack contains fewer
+    /// elements than `expected`.
+    pub fn assert_top(&self, expected: &[u64]) -> Result<(), OutputMismatch> {
+        for (index, &expected) in expected.iter().enumerate() {
+            let actual = self.stack.get(index).copied();
+            if actual != Some(expected) {
+                return Err(OutputMismatch {
+                    index,
+                    expected,
+                    actual,
+                });
+            }
+        }
+        Ok(())
+    }
+
     /// Returns true if the overflow table outputs are non-empty.
     pub fn has_overflow(&self) -> bool {
         !self.overflow_addrs.is_empty()
@@ -139,6 +226,23 @@ fn are_valid_elements(outputs: &[u64]) -> bool {
     true
 }
 
+/// Reads a length-prefixed vector of integers from the source, returning an error if any of the
+/// integers is not a valid field element.
+fn read_elements<R: ByteReader>(source: &mut R) -> Result<Vec<u64>, DeserializationError> {
+    let num_elements = source.read_u32()? as usize;
+    let mut elements = Vec::with_capacity(num_elements.min(STACK_TOP_SIZE));
+    for _ in 0..num_elements {
+        let element = source.read_u64()?;
+        if element >= Felt::MODULUS {
+            return Err(DeserializationError::InvalidValue(format!(
+                "{element} is not a valid field element"
+            )));
+        }
+        elements.push(element);
+    }
+    Ok(elements)
+}
+
 impl Serializable for StackOutputs {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         // TODO the length of the stack, by design, will not be greater than `u32::MAX`. however,
@@ -157,6 +261,21 @@ impl Serializable for StackOutputs {
     }
 }
 
+impl Deserializable for StackOutputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let stack = read_elements(source)?;
+        let overflow_addrs = read_elements(source)?;
+
+        Ok(Self {
+            stack,
+            overflow_addrs,
+        })
+    }
+}
+
+/// Stack outputs are encoded as all elements of the stack (ordered from the top of the stack to
+/// the bottom) followed by the overflow addresses. See [StackOutputs::try_from_elements()] for the
+/// inverse conversion.
 impl ToElements<Felt> for StackOutputs {
     fn to_elements(&self) -> Vec<Felt> {
         // infallible conversion from u64 to Felt is OK here because we check validity of u64
@@ -170,3 +289,38 @@ impl ToElements<Felt> for StackOutputs {
             .collect()
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{OutputMismatch, StackOutputs};
+
+    #[test]
+    fn stack_outputs_assert_top() {
+        let outputs = StackOutputs::new(vec![3, 2, 1], vec![]);
+        assert!(outputs.matches(&[]));
+        assert!(outputs.matches(&[3, 2]));
+        assert!(outputs.matches(&[3, 2, 1]));
+        assert_eq!(Ok(()), outputs.assert_top(&[3, 2, 1]));
+
+        // the first mismatching position is reported
+        assert!(!outputs.matches(&[3, 5, 7]));
+        let expected = OutputMismatch {
+            index: 1,
+            expected: 5,
+            actual: Some(2),
+        };
+        assert_eq!(Err(expected), outputs.assert_top(&[3, 5, 7]));
+
+        // expecting more values than the stack contains is a mismatch
+        assert!(!outputs.matches(&[3, 2, 1, 0]));
+        let expected = OutputMismatch {
+            index: 3,
+            expected: 0,
+            actual: None,
+        };
+        assert_eq!(Err(expected), outputs.assert_top(&[3, 2, 1, 0]));
+    }
+}
@@ -1,4 +1,8 @@
-use super::{Felt, StarkField};
+use super::{
+    chiplets::hasher::Digest,
+    errors::{DigestParseError, InputError},
+    Felt, StarkField, Word, ZERO,
+};
 use core::fmt::{self, Write};
 use core::{
     fmt::Debug,
@@ -59,6 +63,97 @@ impl IntoBytes<32> for [Felt; 4] {
     }
 }
 
+// WORD HELPERS
+// ================================================================================================
+
+/// Helpers for constructing, inspecting, and displaying [Word]s.
+///
+/// [Word] is an alias for `[Felt; 4]`, and thus these helpers are provided via a trait; with the
+/// trait in scope they can be invoked as, for example, `Word::from_ints([1, 2, 3, 4])`.
+pub trait WordExt: Sized {
+    /// Returns a word built from the specified integers.
+    ///
+    /// # Errors
+    /// Returns an error if any of the integers is not a valid field element.
+    fn from_ints(values: [u64; 4]) -> Result<Self, InputError>;
+
+    /// Returns the integer values of the elements of this word.
+    ///
+    /// [Word] does not implement `Ord` or `Hash` (and, being an alias for an array of foreign
+    /// types, cannot implement them in this crate); the returned array can be used instead as a
+    /// key in a `BTreeMap` or a `HashMap`. Keys are ordered lexicographically over the canonical
+    /// values of the four elements. Note that the `Ord` implementation of [Digest] compares the
+    /// internal representation of the elements, and thus orders digests differently.
+    fn to_ints(&self) -> [u64; 4];
+
+    /// Returns a word consisting of four ZERO elements.
+    fn zero() -> Self;
+
+    /// Returns true if all elements of this word are ZERO.
+    fn is_zero(&self) -> bool;
+
+    /// Returns an object which displays this word as `[a, b, c, d]`, where each element is
+    /// rendered as an integer.
+    fn display(&self) -> DisplayWord<'_>;
+}
+
+impl WordExt for Word {
+    fn from_ints(values: [u64; 4]) -> Result<Self, InputError> {
+        let mut word = [ZERO; 4];
+        for (element, value) in word.iter_mut().zip(values) {
+            if value >= Felt::MODULUS {
+                return Err(InputError::NotFieldElement(
+                    value,
+                    "the provided value isn't a valid field element",
+                ));
+            }
+            *element = Felt::new(value);
+        }
+        Ok(word)
+    }
+
+    fn to_ints(&self) -> [u64; 4] {
+        self.map(|element| element.as_int())
+    }
+
+    fn zero() -> Self {
+        [ZERO; 4]
+    }
+
+    fn is_zero(&self) -> bool {
+        self.iter().all(|&element| element == ZERO)
+    }
+
+    fn display(&self) -> DisplayWord<'_> {
+        DisplayWord(self)
+    }
+}
+
+/// Displays a [Word] as `[a, b, c, d]`; returned by [WordExt::display()].
+pub struct DisplayWord<'a>(&'a Word);
+
+impl fmt::Display for DisplayWord<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b, c, d] = self.0.to_ints();
+        write!(f, "[{a}, {b}, {c}, {d}]")
+    }
+}
+
+#[test]
+fn word_helpers() {
+    let word = Word::from_ints([1, 2, 3, Felt::MODULUS - 1]).unwrap();
+    assert_eq!([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(Felt::MODULUS - 1)], word);
+    assert_eq!([1, 2, 3, Felt::MODULUS - 1], word.to_ints());
+    assert_eq!("[1, 2, 3, 18446744069414584320]", word.display().to_string());
+    assert!(!word.is_zero());
+
+    assert!(Word::zero().is_zero());
+    assert_eq!("[0, 0, 0, 0]", Word::zero().display().to_string());
+
+    let result = Word::from_ints([1, Felt::MODULUS, 3, 4]);
+    assert!(matches!(result, Err(InputError::NotFieldElement(value, _)) if value == Felt::MODULUS));
+}
+
 // PUSH MANY
 // ================================================================================================
 
@@ -149,3 +244,71 @@ pub fn write_hex_bytes(f: &mut fmt::Formatter<'_>, bytes: &[u8]) -> fmt::Result
     }
     Ok(())
 }
+
+/// Returns a lowercase hex string representation of the provided digest.
+///
+/// The digest is encoded as 32 bytes in which each of the 4 digest elements is serialized as a
+/// little-endian `u64`, starting with the first element. This is the same encoding as used by
+/// [Program::hash_hex()](crate::Program::hash_hex()), and is guaranteed to remain stable.
+pub fn digest_to_hex(digest: &Digest) -> String {
+    let bytes: [u8; 32] = digest.into();
+    to_hex(&bytes).expect("failed to write digest into a string")
+}
+
+/// Parses a digest from the hex string representation produced by [digest_to_hex()].
+///
+/// The string may optionally be prefixed with `0x`; both lowercase and uppercase hex digits are
+/// accepted.
+///
+/// # Errors
+/// Returns an error if the string does not consist of exactly 64 hex digits, or if any of the
+/// decoded elements is not a valid field element.
+pub fn digest_from_hex(hex: &str) -> Result<Digest, DigestParseError> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    if let Some(c) = hex.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(DigestParseError::InvalidCharacter(c));
+    }
+    if hex.len() != 64 {
+        return Err(DigestParseError::InvalidLength(hex.len()));
+    }
+
+    let mut bytes = [0u8; 32];
+    for (byte, digits) in bytes.iter_mut().zip(hex.as_bytes().chunks_exact(2)) {
+        let digits = core::str::from_utf8(digits).expect("hex digits are not valid UTF-8");
+        *byte = u8::from_str_radix(digits, 16).expect("failed to parse hex digits");
+    }
+
+    let mut elements = [ZERO; 4];
+    for (element, chunk) in elements.iter_mut().zip(bytes.chunks_exact(8)) {
+        let value = u64::from_le_bytes(chunk.try_into().expect("invalid chunk length"));
+        if value >= Felt::MODULUS {
+            return Err(DigestParseError::NotFieldElement(value));
+        }
+        *element = Felt::new(value);
+    }
+
+    Ok(elements.into())
+}
+
+#[test]
+fn digest_hex_roundtrip() {
+    use crate::Word;
+
+    let word: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(Felt::MODULUS - 1)];
+    let digest = Digest::from(word);
+    assert_eq!(word, Word::from(digest));
+
+    let hex = digest_to_hex(&digest);
+    let expected = "01000000000000000200000000000000030000000000000000000000ffffffff";
+    assert_eq!(expected, hex);
+    assert_eq!(Ok(digest), digest_from_hex(&hex));
+    assert_eq!(Ok(digest), digest_from_hex(&format!("0x{}", hex.to_uppercase())));
+
+    assert_eq!(Err(DigestParseError::InvalidLength(62)), digest_from_hex(&hex[2..]));
+    assert_eq!(
+        Err(DigestParseError::InvalidCharacter('g')),
+        digest_from_hex(&hex.replace('f', "g"))
+    );
+    let invalid = "ffffffffffffffff".repeat(4);
+    assert_eq!(Err(DigestParseError::NotFieldElement(u64::MAX)), digest_from_hex(&invalid));
+}
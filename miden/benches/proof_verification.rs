@@ -0,0 +1,59 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use miden::{
+    prove, verify, verify_batch, Assembler, ExecutionProof, MemAdviceProvider, ProgramInfo,
+    ProofOptions, StackInputs, StackOutputs,
+};
+use std::time::Duration;
+
+const BATCH_SIZE: u64 = 16;
+
+type BatchItem = (ProgramInfo, StackInputs, StackOutputs, ExecutionProof);
+
+fn build_batch() -> Vec<BatchItem> {
+    let source = "
+        begin
+            repeat.64
+                dup.1 dup.1 add swap drop
+            end
+        end";
+    let program = Assembler::default().compile(source).expect("Failed to compile test source.");
+    let program_info = ProgramInfo::from(program.clone());
+
+    (0..BATCH_SIZE)
+        .map(|i| {
+            let stack_inputs = StackInputs::try_from_values([i, i + 1]).unwrap();
+            let (stack_outputs, proof) = prove(
+                &program,
+                stack_inputs.clone(),
+                MemAdviceProvider::default(),
+                ProofOptions::default(),
+            )
+            .expect("failed to generate proof");
+            (program_info.clone(), stack_inputs, stack_outputs, proof)
+        })
+        .collect()
+}
+
+fn proof_verification(c: &mut Criterion) {
+    let mut group = c.benchmark_group("proof_verification");
+    group.measurement_time(Duration::from_secs(10));
+
+    let batch = build_batch();
+
+    group.bench_function("sequential", |bench| {
+        bench.iter(|| {
+            for (program_info, stack_inputs, stack_outputs, proof) in batch.clone() {
+                verify(program_info, stack_inputs, stack_outputs, proof).unwrap();
+            }
+        });
+    });
+
+    group.bench_function("batch", |bench| {
+        bench.iter(|| verify_batch(batch.clone()).unwrap());
+    });
+
+    group.finish();
+}
+
+criterion_group!(verification_group, proof_verification);
+criterion_main!(verification_group);
@@ -26,8 +26,7 @@ impl CompileCmd {
         let program = ProgramFile::read(&self.assembly_file, &Debug::Off, libraries.libraries)?;
 
         // report program hash to user
-        let program_hash: [u8; 32] = program.hash().into();
-        println!("program hash is {}", hex::encode(program_hash));
+        println!("program hash is {}", program.hash_hex());
 
         Ok(())
     }
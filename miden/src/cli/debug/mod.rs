@@ -38,8 +38,7 @@ impl DebugCmd {
         // load program from file and compile
         let program = ProgramFile::read(&self.assembly_file, &Debug::On, libraries.libraries)?;
 
-        let program_hash: [u8; 32] = program.hash().into();
-        println!("Debugging program with hash {}... ", hex::encode(program_hash));
+        println!("Debugging program with hash {}... ", program.hash_hex());
 
         // load input data from file
         let input_data = InputFile::read(&self.input_file, &self.assembly_file)?;
@@ -69,8 +69,8 @@ impl ProveCmd {
         // load input data from file
         let input_data = InputFile::read(&self.input_file, &self.assembly_file)?;
 
-        let program_hash: [u8; 32] = program.hash().into();
-        println!("Proving program with hash {}...", hex::encode(program_hash));
+        let program_hash = program.hash_hex();
+        println!("Proving program with hash {program_hash}...");
         let now = Instant::now();
 
         // fetch the stack and program inputs from the arguments
@@ -82,11 +82,7 @@ impl ProveCmd {
             prover::prove(&program, stack_inputs, advice_provider, self.get_proof_options())
                 .map_err(|err| format!("Failed to prove program - {:?}", err))?;
 
-        println!(
-            "Program with hash {} proved in {} ms",
-            hex::encode(program_hash),
-            now.elapsed().as_millis()
-        );
+        println!("Program with hash {} proved in {} ms", program_hash, now.elapsed().as_millis());
 
         // write proof to file
         ProofFile::write(proof, &self.proof_file, &self.assembly_file)?;
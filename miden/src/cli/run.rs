@@ -41,8 +41,7 @@ impl RunCmd {
         let stack_inputs = input_data.parse_stack_inputs()?;
         let advice_provider = input_data.parse_advice_provider()?;
 
-        let program_hash: [u8; 32] = program.hash().into();
-        print!("Executing program with hash {}... ", hex::encode(program_hash));
+        print!("Executing program with hash {}... ", program.hash_hex());
         let now = Instant::now();
 
         // execute program and generate outputs
@@ -51,6 +50,14 @@ impl RunCmd {
 
         println!("done ({} steps in {} ms)", trace.get_trace_len(), now.elapsed().as_millis());
 
+        let trace_len_summary = trace.trace_len_summary();
+        println!(
+            "Trace length: {} rows, padded to {} rows ({}% padding)",
+            trace_len_summary.trace_len(),
+            trace_len_summary.padded_trace_len(),
+            trace_len_summary.padding_percentage()
+        );
+
         if let Some(output_path) = &self.output_file {
             // write outputs to file if one was specified
             OutputFile::write(trace.stack_outputs(), output_path)?;
@@ -4,14 +4,40 @@
 // EXPORTS
 // ================================================================================================
 
-pub use assembly::{Assembler, AssemblyError, ParsingError};
+pub use assembly::{
+    disassemble, Assembler, AssemblyError, Disassembler, DisassemblyError, ParsingError,
+};
 pub use processor::{
-    crypto, execute, execute_iter, utils, AdviceInputs, AdviceProvider, AsmOpInfo, ExecutionError,
-    ExecutionTrace, Kernel, MemAdviceProvider, Operation, ProgramInfo, StackInputs, VmState,
-    VmStateIterator,
+    advice_tape_key, crypto, execute, execute_bounded, execute_continued, execute_fuzz,
+    execute_iter, execute_to_witness, execute_with_debug, execute_with_hook, execute_with_options,
+    utils, AdviceInputs, AdviceProvider, AsmOpInfo, ChainedProvider, DebugEvent, DebugOptions,
+    ExecutionError, ExecutionOptions, ExecutionTrace, ExecutionWitness, Kernel, MemAdviceProvider,
+    OpClass, Operation, ProcedureInfo, ProgramComplexity, ProgramInfo, PublicInputsSegment,
+    SourceLocation, StackInputs, StateDelta, TraceLenSummary, VmInitialState, VmState,
+    VmStateIterator, DEFAULT_MAX_STACK_DEPTH, FUZZ_MAX_CYCLES,
 };
+#[cfg(feature = "std")]
+pub use processor::{ColumnStat, Debugger};
 pub use prover::{
-    math, prove, Digest, ExecutionProof, FieldExtension, HashFunction, InputError, Program,
-    ProofOptions, StackOutputs, StarkProof, Word,
+    estimate_proving_memory, field_extension_name, math, num_threads, parse_field_extension, prove,
+    prove_from_witness, prove_to_elements, prove_trace, prove_with_memory_limit,
+    prove_with_num_threads, prove_with_stats, DeserializationError, Digest, DigestParseError,
+    ExecutionProof, FieldExtension, HashFunction, InputError, OutputMismatch, ParseOptionError,
+    Program, ProofOptions, ProofOptionsBuilder, ProofOptionsError, ProvingStats, StackOutputs,
+    StarkProof, Word, WordExt, PROOF_FORMAT_VERSION,
+};
+pub use verifier::{
+    proof_to_elements, supported_hash_functions, supported_proof_versions, verify, verify_batch,
+    verify_from_parts, verify_program, verify_verbose, verify_with_policy, verify_with_transcript,
+    PolicyViolation, PublicInputsMismatch, VerificationError, VerificationPolicy,
+    VerificationReport, VerificationTranscript,
 };
-pub use verifier::{verify, VerificationError};
+
+#[cfg(feature = "std")]
+pub use verifier::verify_with_cancellation;
+
+#[cfg(feature = "testing")]
+pub use prover::{prove_and_verify, ProveAndVerifyError};
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
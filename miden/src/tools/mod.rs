@@ -264,7 +264,8 @@ mod tests {
         let stack_inputs = StackInputs::try_from_values(stack_inputs).unwrap();
         let advice_provider = MemAdviceProvider::default();
         let execution_details = super::analyze(source, stack_inputs, advice_provider);
-        let expected_error = "Execution Error: DivideByZero(1)";
+        let expected_error = "Execution Error: DivideByZero { clk: 1, source_location: \
+            Some(SourceLocation { line: 1, column: 7 }) }";
         assert_eq!(execution_details.err().unwrap().to_string(), expected_error);
     }
 
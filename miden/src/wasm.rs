@@ -0,0 +1,166 @@
+//! Byte-oriented entry points for proving and verifying programs from JavaScript via
+//! `wasm-bindgen`.
+//!
+//! All arguments and results are passed as byte buffers or integer slices, and are converted
+//! into the regular Miden VM types internally:
+//! - programs are expected to be serialized via [ProgramAst::to_bytes()](assembly::ast::ProgramAst)
+//!   and are compiled with the standard library available.
+//! - proof options are expected to be serialized via [ProofOptions::to_bytes()].
+//! - proofs are returned as the serialized [StackOutputs] followed by the serialized
+//!   [ExecutionProof], with each of the two sections prefixed by its length in bytes encoded as
+//!   a little-endian `u32`.
+
+use super::{
+    utils::{ByteReader, ByteWriter, Deserializable, Serializable, SliceReader},
+    AdviceInputs, Assembler, ExecutionProof, MemAdviceProvider, Program, ProgramInfo, ProofOptions,
+    StackInputs, StackOutputs,
+};
+use assembly::ast::ProgramAst;
+use stdlib::StdLibrary;
+use wasm_bindgen::prelude::*;
+
+// WASM ENTRY POINTS
+// ================================================================================================
+
+/// Executes and proves the specified program, and returns length-prefixed serialized stack
+/// outputs followed by the length-prefixed serialized execution proof.
+///
+/// * `program_bytes` is a serialized program AST.
+/// * `stack_inputs` specifies the initial state of the stack (in the regular input order).
+/// * `advice_stack` specifies the initial state of the advice stack.
+/// * `options_bytes` is a serialized set of proof options.
+#[wasm_bindgen]
+pub fn prove_bytes(
+    program_bytes: &[u8],
+    stack_inputs: &[u64],
+    advice_stack: &[u64],
+    options_bytes: &[u8],
+) -> Result<Vec<u8>, JsValue> {
+    prove_from_bytes(program_bytes, stack_inputs, advice_stack, options_bytes)
+        .map_err(|err| JsValue::from_str(&err))
+}
+
+/// Verifies a proof returned by [prove_bytes()] against the specified program and stack inputs,
+/// and returns the security level of the proof.
+#[wasm_bindgen]
+pub fn verify_bytes(
+    program_bytes: &[u8],
+    stack_inputs: &[u64],
+    proof_bytes: &[u8],
+) -> Result<u32, JsValue> {
+    verify_from_bytes(program_bytes, stack_inputs, proof_bytes)
+        .map_err(|err| JsValue::from_str(&err))
+}
+
+/// Returns the stack outputs contained in a proof returned by [prove_bytes()].
+#[wasm_bindgen]
+pub fn read_stack_outputs(proof_bytes: &[u8]) -> Result<Vec<u64>, JsValue> {
+    read_proof(proof_bytes)
+        .map(|(stack_outputs, _)| stack_outputs.stack().to_vec())
+        .map_err(|err| JsValue::from_str(&err))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn prove_from_bytes(
+    program_bytes: &[u8],
+    stack_inputs: &[u64],
+    advice_stack: &[u64],
+    options_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let program = compile_program(program_bytes)?;
+    let stack_inputs = StackInputs::try_from_values(stack_inputs.iter().copied())
+        .map_err(|err| err.to_string())?;
+    let advice_inputs = AdviceInputs::default()
+        .with_stack_values(advice_stack.iter().copied())
+        .map_err(|err| err.to_string())?;
+    let options = ProofOptions::read_from_bytes(options_bytes).map_err(|err| err.to_string())?;
+
+    let (stack_outputs, proof) =
+        super::prove(&program, stack_inputs, MemAdviceProvider::from(advice_inputs), options)
+            .map_err(|err| err.to_string())?;
+
+    let mut result = Vec::new();
+    write_section(&mut result, &stack_outputs.to_bytes())?;
+    write_section(&mut result, &proof.to_bytes())?;
+    Ok(result)
+}
+
+fn verify_from_bytes(
+    program_bytes: &[u8],
+    stack_inputs: &[u64],
+    proof_bytes: &[u8],
+) -> Result<u32, String> {
+    let program_info = ProgramInfo::from(compile_program(program_bytes)?);
+    let stack_inputs = StackInputs::try_from_values(stack_inputs.iter().copied())
+        .map_err(|err| err.to_string())?;
+    let (stack_outputs, proof) = read_proof(proof_bytes)?;
+
+    super::verify(program_info, stack_inputs, stack_outputs, proof).map_err(|err| err.to_string())
+}
+
+fn compile_program(program_bytes: &[u8]) -> Result<Program, String> {
+    let program = ProgramAst::from_bytes(program_bytes).map_err(|err| err.to_string())?;
+    Assembler::default()
+        .with_library(&StdLibrary::default())
+        .and_then(|assembler| assembler.compile_ast(&program))
+        .map_err(|err| err.to_string())
+}
+
+fn read_proof(proof_bytes: &[u8]) -> Result<(StackOutputs, ExecutionProof), String> {
+    let mut source = SliceReader::new(proof_bytes);
+    let outputs_bytes = read_section(&mut source)?;
+    let proof_section = read_section(&mut source)?;
+    if source.has_more_bytes() {
+        return Err("unexpected bytes after the execution proof".into());
+    }
+
+    let stack_outputs =
+        StackOutputs::read_from_bytes(&outputs_bytes).map_err(|err| err.to_string())?;
+    let proof = ExecutionProof::from_bytes(&proof_section).map_err(|err| err.to_string())?;
+    Ok((stack_outputs, proof))
+}
+
+/// Writes the specified bytes into the target prefixed by their length.
+fn write_section(target: &mut Vec<u8>, bytes: &[u8]) -> Result<(), String> {
+    let len = u32::try_from(bytes.len()).map_err(|_| "section is too large".to_string())?;
+    target.write_u32(len);
+    target.write_bytes(bytes);
+    Ok(())
+}
+
+/// Reads a section of bytes prefixed by its length from the source.
+fn read_section(source: &mut SliceReader) -> Result<Vec<u8>, String> {
+    let len = source.read_u32().map_err(|err| err.to_string())?;
+    source.read_vec(len as usize).map_err(|err| err.to_string())
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{prove_from_bytes, read_proof, verify_from_bytes, ProgramAst, ProofOptions};
+    use crate::utils::Serializable;
+    use assembly::ast::AstSerdeOptions;
+
+    #[test]
+    fn prove_and_verify_bytes() {
+        let program = ProgramAst::parse("begin adv_push.1 add end").unwrap();
+        let program_bytes = program.to_bytes(AstSerdeOptions::new(false));
+        let options_bytes = ProofOptions::default().to_bytes();
+
+        let proof_bytes = prove_from_bytes(&program_bytes, &[1], &[2], &options_bytes).unwrap();
+        let (stack_outputs, _) = read_proof(&proof_bytes).unwrap();
+        assert_eq!(&[3], stack_outputs.stack_truncated(1));
+
+        assert!(verify_from_bytes(&program_bytes, &[1], &proof_bytes).is_ok());
+        assert!(verify_from_bytes(&program_bytes, &[2], &proof_bytes).is_err());
+        assert!(verify_from_bytes(&program_bytes, &[1], &proof_bytes[..100]).is_err());
+
+        let mut trailing_bytes = proof_bytes.clone();
+        trailing_bytes.push(0);
+        assert!(read_proof(&trailing_bytes).is_err());
+    }
+}
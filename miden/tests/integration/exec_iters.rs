@@ -1,6 +1,9 @@
-use processor::{AsmOpInfo, VmState};
+use core::ops::ControlFlow;
+use processor::{
+    AsmOpInfo, Debugger, ExecutionError, MemAdviceProvider, StackInputs, StateDelta, VmState,
+};
 use test_utils::{build_debug_test, Felt, FieldElement, ToElements};
-use vm_core::{AssemblyOp, Operation};
+use vm_core::{AssemblyOp, DebugOptions, Operation, SourceLocation};
 
 // EXEC ITER TESTS
 // =================================================================
@@ -31,7 +34,7 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Span),
             asmop: None,
-            stack: [16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 1].to_elements(),
+            stack: [16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1].to_elements(),
             fmp,
             memory: Vec::new(),
         },
@@ -40,7 +43,8 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Pad),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 3, "mem_storew.1".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 3, "mem_storew.1".to_string(), false)
+                    .with_location(SourceLocation::new(1, 34)),
                 1,
             )),
             stack: [0, 16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1].to_elements(),
@@ -52,10 +56,11 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Incr),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 3, "mem_storew.1".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 3, "mem_storew.1".to_string(), false)
+                    .with_location(SourceLocation::new(1, 34)),
                 2,
             )),
-            stack: [1, 16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2].to_elements(),
+            stack: [1, 16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1].to_elements(),
             fmp,
             memory: Vec::new(),
         },
@@ -64,7 +69,8 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::MStoreW),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 3, "mem_storew.1".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 3, "mem_storew.1".to_string(), false)
+                    .with_location(SourceLocation::new(1, 34)),
                 3,
             )),
             stack: [16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1].to_elements(),
@@ -76,7 +82,8 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Drop),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false)
+                    .with_location(SourceLocation::new(1, 47)),
                 1,
             )),
             stack: [15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0].to_elements(),
@@ -88,7 +95,8 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Drop),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false)
+                    .with_location(SourceLocation::new(1, 47)),
                 2,
             )),
             stack: [14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0].to_elements(),
@@ -100,7 +108,8 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Drop),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false)
+                    .with_location(SourceLocation::new(1, 47)),
                 3,
             )),
             stack: [13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0].to_elements(),
@@ -112,10 +121,11 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Drop),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false)
+                    .with_location(SourceLocation::new(1, 47)),
                 4,
             )),
-            stack: [12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0, 0].to_elements(),
+            stack: [12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
             fmp,
             memory: mem.clone(),
         },
@@ -124,7 +134,8 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Push(Felt::new(17))),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 1, "push.17".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 1, "push.17".to_string(), false)
+                    .with_location(SourceLocation::new(1, 53)),
                 1,
             )),
             stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
@@ -136,7 +147,7 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Noop),
             asmop: None,
-            stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0, 0].to_elements(),
+            stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
             fmp,
             memory: mem.clone(),
         },
@@ -145,7 +156,7 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Push(Felt::new(1))),
             asmop: None,
-            stack: [1, 17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0].to_elements(),
+            stack: [1, 17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
             fmp,
             memory: mem.clone(),
         },
@@ -154,7 +165,7 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::FmpUpdate),
             asmop: None,
-            stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0, 0].to_elements(),
+            stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
             fmp: next_fmp,
             memory: mem.clone(),
         },
@@ -163,7 +174,8 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Pad),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false),
+                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false)
+                    .with_location(SourceLocation::new(1, 12)),
                 1,
             )),
             stack: [0, 17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
@@ -175,10 +187,11 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::FmpAdd),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false),
+                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false)
+                    .with_location(SourceLocation::new(1, 12)),
                 2,
             )),
-            stack: [2u64.pow(30) + 1, 17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0]
+            stack: [2u64.pow(30) + 1, 17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0]
                 .to_elements(),
             fmp: next_fmp,
             memory: mem,
@@ -188,10 +201,11 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::MStore),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false),
+                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false)
+                    .with_location(SourceLocation::new(1, 12)),
                 3,
             )),
-            stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0].to_elements(),
+            stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
             fmp: next_fmp,
             memory: vec![
                 (1_u64, slice_to_word(&[13, 14, 15, 16])),
@@ -203,10 +217,11 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Drop),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false),
+                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false)
+                    .with_location(SourceLocation::new(1, 12)),
                 4,
             )),
-            stack: [12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0, 0].to_elements(),
+            stack: [12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
             fmp: next_fmp,
             memory: vec![
                 (1_u64, slice_to_word(&[13, 14, 15, 16])),
@@ -230,3 +245,262 @@ fn slice_to_word(values: &[i32]) -> [Felt; 4] {
         Felt::new(values[3] as u64),
     ]
 }
+
+#[test]
+fn test_exec_with_hook() {
+    let source = "proc.foo.1 loc_store.0 end begin mem_storew.1 dropw push.17 exec.foo end";
+
+    // the hook should observe the same states as the ones yielded by the state iterator, including
+    // the items in the overflow table when the stack is initialized with more than 16 items
+    for num_inputs in [16, 20] {
+        let inputs = (1..=num_inputs).collect::<Vec<u64>>();
+        let test = build_debug_test!(source, &inputs);
+        let program = test.compile();
+        let expected_states = test.execute_iter().map(|state| state.unwrap()).collect::<Vec<_>>();
+        assert_eq!(num_inputs as usize, expected_states[0].stack.len());
+
+        let mut states = Vec::new();
+        processor::execute_with_hook(
+            &program,
+            StackInputs::try_from_values(inputs).unwrap(),
+            MemAdviceProvider::default(),
+            &mut |state| {
+                states.push(state.clone());
+                ControlFlow::Continue(())
+            },
+        )
+        .unwrap();
+        assert_eq!(expected_states, states);
+    }
+
+    // the hook should be able to abort the execution
+    let program = build_debug_test!(source).compile();
+    let mut num_states = 0;
+    let result = processor::execute_with_hook(
+        &program,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        &mut |state| {
+            num_states += 1;
+            if state.clk == 3 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        },
+    );
+    assert!(matches!(result, Err(ExecutionError::ExecutionAborted(3))));
+    assert_eq!(4, num_states);
+
+    // the hook should observe all cycles up to the failing one
+    let program = build_debug_test!("begin push.1 push.0 assert end").compile();
+    let mut num_states = 0;
+    let result = processor::execute_with_hook(
+        &program,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        &mut |_| {
+            num_states += 1;
+            ControlFlow::Continue(())
+        },
+    );
+    assert!(result.is_err());
+    assert!(num_states > 0);
+}
+
+#[test]
+fn test_debugger() {
+    let test = build_debug_test!("begin push.1 push.2 add push.3 mul end");
+    let program = test.compile();
+    let expected_states = test.execute_iter().map(|state| state.unwrap()).collect::<Vec<_>>();
+
+    let mut debugger =
+        Debugger::new(&program, StackInputs::default(), MemAdviceProvider::default());
+    debugger.add_breakpoint(3);
+    debugger.add_breakpoint(5);
+    assert_eq!(None, debugger.state());
+
+    // run to the first breakpoint and step past it
+    assert_eq!(Some(expected_states[3].clone()), debugger.run_to_breakpoint().unwrap());
+    assert_eq!(Some(&expected_states[3]), debugger.state());
+    assert_eq!(Some(expected_states[4].clone()), debugger.step().unwrap());
+
+    // removed breakpoints are skipped
+    assert!(debugger.remove_breakpoint(5));
+    assert!(!debugger.remove_breakpoint(5));
+    assert_eq!(vec![3], debugger.breakpoints().collect::<Vec<_>>());
+    assert_eq!(None, debugger.run_to_breakpoint().unwrap());
+    assert_eq!(expected_states.last(), debugger.state());
+    assert_eq!(None, debugger.step().unwrap());
+
+    // resuming runs to completion and returns the final state
+    let mut debugger =
+        Debugger::new(&program, StackInputs::default(), MemAdviceProvider::default());
+    debugger.add_breakpoint(1);
+    assert_eq!(expected_states.last().cloned(), debugger.resume().unwrap());
+    assert_eq!(None, debugger.resume().unwrap());
+
+    // execution errors are returned when the failing cycle is reached
+    let program = build_debug_test!("begin push.1 push.0 assert end").compile();
+    let mut debugger =
+        Debugger::new(&program, StackInputs::default(), MemAdviceProvider::default());
+    debugger.add_breakpoint(1);
+    assert_eq!(1, debugger.run_to_breakpoint().unwrap().unwrap().clk);
+    assert!(debugger.run_to_breakpoint().is_err());
+
+    // programs which do not terminate are executed only as far as the debugger is advanced, and
+    // are aborted when the debugger is dropped
+    let program = build_debug_test!("begin push.1 while.true push.1 end end").compile();
+    let mut debugger =
+        Debugger::new(&program, StackInputs::default(), MemAdviceProvider::default());
+    debugger.add_breakpoint(100);
+    assert_eq!(100, debugger.run_to_breakpoint().unwrap().unwrap().clk);
+    assert_eq!(101, debugger.step().unwrap().unwrap().clk);
+    drop(debugger);
+}
+
+#[test]
+fn test_exec_with_debug() {
+    let source = "\
+        begin \
+            push.2 push.1 debug.stack.2 \
+            push.3 mem_store.4 debug.mem debug.mem.0.3 \
+            if.true debug.stack.1 end \
+            push.5 add debug.stack \
+        end";
+    let program = build_debug_test!(source).compile();
+    let (trace, events) = processor::execute_with_debug(
+        &program,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+    )
+    .unwrap();
+    assert_eq!(Felt::new(7), trace.stack_outputs().stack()[0].into());
+
+    assert_eq!(5, events.len());
+    assert!(events.windows(2).all(|pair| pair[0].clk <= pair[1].clk));
+    assert!(events.iter().all(|event| event.ctx == 0));
+
+    assert_eq!(DebugOptions::StackTop(2), events[0].options);
+    assert_eq!(vec![Felt::new(1), Felt::new(2)], events[0].stack);
+    assert!(events[0].memory.is_empty());
+
+    assert_eq!(DebugOptions::MemAll, events[1].options);
+    assert_eq!(vec![(4, [Felt::new(3), Felt::ZERO, Felt::ZERO, Felt::ZERO])], events[1].memory);
+    assert!(events[1].stack.is_empty());
+
+    assert_eq!(DebugOptions::MemInterval(0, 3), events[2].options);
+    assert!(events[2].memory.is_empty());
+
+    // decorators in blocks without any operations
+    assert_eq!(vec![Felt::new(2)], events[3].stack);
+
+    // decorators after the last operation of a block; the stack contains one overflow item
+    let mut expected_stack = vec![Felt::ZERO; 17];
+    expected_stack[0] = Felt::new(7);
+    assert_eq!(DebugOptions::StackAll, events[4].options);
+    assert_eq!(expected_stack, events[4].stack);
+
+    // debug decorators are not emitted when a program is not compiled in debug mode
+    let program = test_utils::build_test!(source).compile();
+    let (_, events) = processor::execute_with_debug(
+        &program,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+    )
+    .unwrap();
+    assert!(events.is_empty());
+}
+
+#[test]
+fn test_exec_with_debug_after_control_flow() {
+    // debug decorators following a loop are executed after the loop
+    let source = "begin push.5 debug.stack push.1 while.true push.9 push.0 end debug.stack end";
+    let (_, events) = processor::execute_with_debug(
+        &build_debug_test!(source).compile(),
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+    )
+    .unwrap();
+    assert_eq!(2, events.len());
+    assert_eq!(Felt::new(5), events[0].stack[0]);
+    assert_eq!(Felt::new(9), events[1].stack[0]);
+    assert_eq!(Felt::new(5), events[1].stack[1]);
+    assert!(events[1].clk > events[0].clk + 4);
+
+    // debug decorators following a conditional block are executed after the block
+    let source = "begin push.1 if.true push.7 else push.8 end debug.stack.1 end";
+    let (_, events) = processor::execute_with_debug(
+        &build_debug_test!(source).compile(),
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+    )
+    .unwrap();
+    assert_eq!(1, events.len());
+    assert_eq!(vec![Felt::new(7)], events[0].stack);
+}
+
+#[test]
+fn test_vm_state_diff() {
+    let states_a = build_debug_test!("begin push.20 push.5 add end").execute_iter();
+    let states_b = build_debug_test!("begin push.30 push.5 add end").execute_iter();
+
+    let deltas = states_a
+        .zip(states_b)
+        .map(|(a, b)| a.unwrap().diff(&b.unwrap()))
+        .find(|deltas| !deltas.is_empty())
+        .unwrap();
+    assert_eq!(
+        vec![
+            StateDelta::Op {
+                left: Some(Operation::Push(Felt::new(20))),
+                right: Some(Operation::Push(Felt::new(30))),
+            },
+            StateDelta::Stack {
+                index: 0,
+                left: Some(Felt::new(20)),
+                right: Some(Felt::new(30)),
+            },
+        ],
+        deltas
+    );
+
+    // stacks of different depths and memory at different addresses
+    let state = VmState {
+        clk: 3,
+        ctx: 0,
+        op: None,
+        asmop: None,
+        fmp: Felt::new(2u64.pow(30)),
+        stack: vec![Felt::new(1), Felt::new(2)],
+        memory: vec![(1, slice_to_word(&[1, 2, 3, 4]))],
+    };
+    let other = VmState {
+        clk: 4,
+        stack: vec![Felt::new(1)],
+        memory: vec![(2, slice_to_word(&[1, 2, 3, 4]))],
+        ..state.clone()
+    };
+    assert!(state.diff(&state).is_empty());
+    assert_eq!(
+        vec![
+            StateDelta::Clk { left: 3, right: 4 },
+            StateDelta::Stack {
+                index: 1,
+                left: Some(Felt::new(2)),
+                right: None,
+            },
+            StateDelta::Memory {
+                addr: 1,
+                left: Some(slice_to_word(&[1, 2, 3, 4])),
+                right: None,
+            },
+            StateDelta::Memory {
+                addr: 2,
+                left: None,
+                right: Some(slice_to_word(&[1, 2, 3, 4])),
+            },
+        ],
+        state.diff(&other)
+    );
+}
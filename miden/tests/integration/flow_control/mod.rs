@@ -1,3 +1,5 @@
+use miden::ProofOptions;
+use processor::{execute_with_options, ExecutionOptions, ExecutionTrace, MemAdviceProvider};
 use test_utils::{build_test, AdviceInputs, StackInputs, Test, TestError};
 
 // SIMPLE FLOW CONTROL TESTS
@@ -47,6 +49,54 @@ fn conditional_loop() {
     test.expect_stack(&[10]);
 }
 
+#[test]
+fn bounded_conditional_loop() {
+    // computes sum of values from 0 to the value at the top of the stack
+    let source = "
+        begin
+            dup push.0 movdn.2 neq.0
+            while.true.max=10
+                dup movup.2 add swap push.1 sub dup neq.0
+            end
+            drop
+        end";
+
+    // --- loop body executed the maximum number of times -----------------------------------------
+    let test = build_test!(source, &[10]);
+    test.expect_stack(&[55]);
+
+    // --- loop bound exceeded --------------------------------------------------------------------
+    let test = build_test!(source, &[11]);
+    test.expect_error(TestError::ExecutionError("LoopBoundExceeded"));
+
+    // --- traces padded to the bound on the number of cycles -------------------------------------
+    let program = build_test!(&source.replace("max=10", "max=100")).compile();
+    let max_cycles = program.complexity().max_cycles().unwrap();
+    let options =
+        ExecutionOptions::default().with_min_trace_len(max_cycles + ExecutionTrace::NUM_RAND_ROWS);
+    let execute = |value| {
+        let stack_inputs = StackInputs::try_from_values([value]).unwrap();
+        execute_with_options(&program, stack_inputs, MemAdviceProvider::default(), options).unwrap()
+    };
+
+    let short_trace = execute(1);
+    let long_trace = execute(100);
+    assert!(long_trace.cycle_count() as usize <= max_cycles);
+    assert_eq!(short_trace.get_trace_len(), long_trace.get_trace_len());
+
+    // without padding, the trace of the short execution would be shorter
+    let stack_inputs = StackInputs::try_from_values([1]).unwrap();
+    let trace = processor::execute(&program, stack_inputs, MemAdviceProvider::default()).unwrap();
+    assert!(trace.get_trace_len() < short_trace.get_trace_len());
+
+    // padded traces can be proven
+    let stack_inputs = StackInputs::try_from_values([1]).unwrap();
+    let options = ProofOptions::builder().grinding_factor(0).build().unwrap();
+    let (stack_outputs, proof) =
+        miden::prove_trace(short_trace, stack_inputs.clone(), options).unwrap();
+    miden::verify(program.into(), stack_inputs, stack_outputs, proof).unwrap();
+}
+
 #[test]
 fn counter_controlled_loop() {
     // --- entering the loop ----------------------------------------------------------------------
@@ -133,7 +183,7 @@ fn local_fn_call() {
             call.foo
         end";
 
-    let expected_err = TestError::ExecutionError("InvalidStackDepthOnReturn(17)");
+    let expected_err = TestError::ExecutionError("InvalidStackDepthOnReturn { clk: 5, depth: 17 }");
     build_test!(source, &[1, 2]).expect_error(expected_err);
 
     // dropping values from the stack in the current execution context should not affect values
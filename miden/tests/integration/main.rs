@@ -1,4 +1,10 @@
-use test_utils::build_test;
+use miden::{
+    prove, prove_trace, prove_with_stats, AdviceInputs, AdviceProvider, ExecutionError,
+    ExecutionOptions, ExecutionProof, FieldExtension, HashFunction, MemAdviceProvider, OpClass,
+    PolicyViolation, ProgramInfo, ProofOptions, PublicInputsMismatch, PublicInputsSegment,
+    StackInputs, StackOutputs, StarkProof, VerificationError, VerificationPolicy, VmInitialState,
+};
+use test_utils::{build_test, math::ToElements, Felt, StarkField, ONE, STACK_TOP_SIZE, ZERO};
 
 mod air;
 mod cli;
@@ -19,3 +25,1666 @@ fn multi_output_program() {
     let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
     test.prove_and_verify(vec![1, 2, 3], false);
 }
+
+#[test]
+fn overflow_stack_inputs() {
+    // overflow table rows initialized from stack inputs have "negative" (mod p) addresses; when
+    // some of these rows remain in the overflow table at the end of execution, their addresses
+    // are a part of the public stack outputs
+    let inputs = (1..=20).collect::<Vec<u64>>();
+    let test = build_test!("begin push.1 add swap drop end", &inputs);
+    let stack_outputs = test.execute().unwrap().stack_outputs().clone();
+    assert_eq!(3, stack_outputs.overflow_values().len());
+    test.prove_and_verify(inputs.clone(), false);
+    test.prove_and_verify(inputs, true);
+}
+
+#[test]
+fn full_stack_outputs() {
+    let pushes = (1..=20).map(|i| format!("push.{i}")).collect::<Vec<_>>().join(" ");
+    let source = format!("begin {pushes} end");
+    let program = build_test!(&source).compile();
+
+    let (stack_outputs, _) = prove(
+        &program,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+
+    // the 20 pushed values are at the top of the stack followed by the 16 initial zeros
+    let mut expected = (1..=20).rev().map(Felt::new).collect::<Vec<_>>();
+    expected.resize(20 + STACK_TOP_SIZE, ZERO);
+    assert_eq!(expected, stack_outputs.full_stack());
+}
+
+#[test]
+fn execute_continued_from_prior_run() {
+    // the first program leaves 20 values on the stack and writes two words to memory
+    let pushes = (1..=20).map(|i| format!("push.{i}")).collect::<Vec<_>>().join(" ");
+    let source =
+        format!("begin {pushes} push.5.6.7.8 push.1 mem_storew push.2 mem_storew dropw end");
+    let program = build_test!(&source).compile();
+    let trace =
+        miden::execute(&program, StackInputs::default(), MemAdviceProvider::default()).unwrap();
+
+    let initial_state = VmInitialState::from_trace(&trace).unwrap();
+    assert_eq!(
+        &[[ZERO; 4], [5, 6, 7, 8].map(Felt::new), [5, 6, 7, 8].map(Felt::new)],
+        initial_state.memory()
+    );
+
+    // the second program restores the memory and drops the top 16 values of the stack, exposing
+    // the bottom of the stack of the first program
+    let commitment = initial_state.memory_commitment().map(|value| value.as_int().to_string());
+    let source = format!(
+        "use.std::mem
+        begin
+            push.0 push.3 push.{} exec.mem::load_memory_segment drop
+            repeat.16 drop end
+        end",
+        commitment.join(".")
+    );
+    let mut test = build_test!(&source);
+    test.libraries = vec![stdlib::StdLibrary::default().into()];
+    let program = test.compile();
+    let continued =
+        miden::execute_continued(&program, initial_state, AdviceInputs::default()).unwrap();
+
+    assert_eq!(&[4, 3, 2, 1], &continued.stack_outputs().stack()[..4]);
+    assert_eq!(trace.final_memory(), continued.final_memory());
+}
+
+#[test]
+fn execute_continued_memory_segment() {
+    // procedure locals are not carried over into the memory segment
+    let source = "proc.foo.1 push.7 loc_store.0 end begin push.9 mem_store.2 exec.foo end";
+    let trace = build_test!(source).execute().unwrap();
+    let initial_state = VmInitialState::from_trace(&trace).unwrap();
+    assert_eq!(&[[ZERO; 4], [ZERO; 4], [9, 0, 0, 0].map(Felt::new)], initial_state.memory());
+
+    // a single word written at a high address results in a sparse segment, which is rejected
+    let trace = build_test!("begin push.1 mem_store.1000000 end").execute().unwrap();
+    let result = VmInitialState::from_trace(&trace);
+    assert!(matches!(
+        result,
+        Err(ExecutionError::SparseMemorySegment {
+            len: 1000001,
+            num_words: 1
+        })
+    ));
+}
+
+#[test]
+fn stack_overflow_outputs() {
+    let pushes = (1..=20).map(|i| format!("push.{i}")).collect::<Vec<_>>().join(" ");
+    let source = format!("begin {pushes} end");
+    let stack_outputs = build_test!(&source).execute().unwrap().stack_outputs().clone();
+
+    // the top of the stack holds the last 16 pushed values, and the overflow table holds the
+    // remaining 4 pushed values followed by the 16 initial zeros
+    let mut expected_overflow = (1..=4).rev().collect::<Vec<u64>>();
+    expected_overflow.resize(4 + STACK_TOP_SIZE, 0);
+    assert_eq!(expected_overflow, stack_outputs.overflow_values());
+
+    // the overflow table has one address per overflow value plus the initial `prev` address
+    assert_eq!(expected_overflow.len() + 1, stack_outputs.overflow_addrs().len());
+
+    // each overflow table row pairs an address with a value, starting with the deepest element
+    let overflow = stack_outputs.stack_overflow();
+    for (i, &(addr, value)) in overflow.iter().enumerate() {
+        assert_eq!(stack_outputs.overflow_addrs()[i + 1], addr.as_int());
+        assert_eq!(expected_overflow[expected_overflow.len() - 1 - i], value.as_int());
+    }
+
+    // the top of the stack combined with the overflow values forms the full stack
+    let mut full_stack = stack_outputs.stack_top().iter().map(|v| v.as_int()).collect::<Vec<_>>();
+    full_stack.extend_from_slice(stack_outputs.overflow_values());
+    assert_eq!(stack_outputs.stack(), full_stack);
+}
+
+#[test]
+fn stack_outputs_flat_encoding() {
+    // stack outputs with and without overflow can be decoded from their flat encoding
+    for num_pushes in [3, 20] {
+        let pushes = (1..=num_pushes).map(|i| format!("push.{i}")).collect::<Vec<_>>().join(" ");
+        let source = format!("begin {pushes} end");
+        let stack_outputs = build_test!(&source).execute().unwrap().stack_outputs().clone();
+
+        let elements = stack_outputs.to_elements();
+        assert_eq!(stack_outputs.full_stack(), elements[..stack_outputs.stack().len()]);
+        assert_eq!(stack_outputs, StackOutputs::try_from_elements(&elements).unwrap());
+    }
+
+    // element counts which cannot be split into stack values and overflow addresses are rejected
+    let elements = vec![ONE; STACK_TOP_SIZE + 2];
+    assert!(StackOutputs::try_from_elements(&elements).is_err());
+    let elements = vec![ONE; STACK_TOP_SIZE + 4];
+    assert!(StackOutputs::try_from_elements(&elements).is_err());
+}
+
+#[test]
+fn prove_with_stats_program() {
+    let program = build_test!("begin push.1 push.2 add end").compile();
+
+    let (stack_outputs, _, stats) = prove_with_stats(
+        &program,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+
+    let trace =
+        miden::execute(&program, StackInputs::default(), MemAdviceProvider::default()).unwrap();
+    assert_eq!(trace.stack_outputs(), &stack_outputs);
+    assert_eq!(trace.get_trace_len(), stats.trace_len);
+    assert_eq!(trace.cycle_count(), stats.cycle_count);
+    assert_eq!(trace.trace_len_summary(), &stats.trace_len_summary);
+    assert!(stats.cycle_count as usize <= stats.trace_len);
+    assert!(stats.main_trace_width > 0);
+    assert!(stats.aux_trace_width > 0);
+}
+
+#[test]
+fn trace_len_summary() {
+    let program = build_test!("begin repeat.1100 push.1 drop end end").compile();
+    let trace =
+        miden::execute(&program, StackInputs::default(), MemAdviceProvider::default()).unwrap();
+
+    let summary = trace.trace_len_summary();
+    assert_eq!(trace.cycle_count() as usize, summary.main_trace_len());
+    assert!(summary.trace_len() >= summary.main_trace_len());
+    assert!(summary.trace_len() >= summary.range_trace_len());
+    assert!(summary.trace_len() >= summary.chiplets_trace_len());
+
+    // the trace is padded to the smallest power of two which fits the random rows
+    let padded_len = summary.padded_trace_len();
+    assert_eq!(trace.get_trace_len(), padded_len);
+    assert!(padded_len.is_power_of_two());
+    assert!(padded_len >= summary.trace_len() + miden::ExecutionTrace::NUM_RAND_ROWS);
+    assert!(padded_len / 2 < summary.trace_len() + miden::ExecutionTrace::NUM_RAND_ROWS);
+    assert_eq!(
+        (padded_len - summary.trace_len()) * 100 / padded_len,
+        summary.padding_percentage()
+    );
+}
+
+#[test]
+fn operation_histogram() {
+    let program = build_test!("begin push.5 push.7 add hperm end").compile();
+    let trace =
+        miden::execute(&program, StackInputs::default(), MemAdviceProvider::default()).unwrap();
+
+    let histogram = trace.operation_histogram();
+    assert_eq!(histogram.len(), OpClass::ALL.len());
+    assert_eq!(trace.cycle_count() as u64, histogram.values().sum::<u64>());
+
+    // SPAN and END
+    assert_eq!(2, histogram[&OpClass::ControlFlow]);
+    assert_eq!(1, histogram[&OpClass::FieldArithmetic]);
+    assert_eq!(1, histogram[&OpClass::Crypto]);
+    assert_eq!(0, histogram[&OpClass::Memory]);
+}
+
+#[test]
+fn proof_security_level() {
+    let program = build_test!("begin repeat.100 push.5 push.7 add drop end end").compile();
+    // proven security also depends on the length of the trace, which is 1024 for this program
+    let cases = [
+        (ProofOptions::with_96_bit_security(false), 96, 55),
+        (ProofOptions::with_96_bit_security(true), 96, 55),
+        (ProofOptions::with_128_bit_security(false), 128, 73),
+        (ProofOptions::with_128_bit_security(true), 128, 73),
+    ];
+    for (options, conjectured, proven) in cases {
+        let (_, proof) =
+            prove(&program, StackInputs::default(), MemAdviceProvider::default(), options).unwrap();
+        assert_eq!(1024, proof.stark_proof().trace_length());
+        assert_eq!(conjectured, proof.security_level());
+        assert_eq!(proven, proof.proven_security_level());
+    }
+}
+
+#[test]
+fn proof_serde_roundtrip() {
+    let program = build_test!("begin push.1 push.2 add end").compile();
+    let options = ProofOptions::with_96_bit_security(true);
+    let (stack_outputs, proof) =
+        prove(&program, StackInputs::default(), MemAdviceProvider::default(), options.clone())
+            .unwrap();
+
+    // human-readable format
+    let json = serde_json::to_string(&proof).unwrap();
+    let json_proof: ExecutionProof = serde_json::from_str(&json).unwrap();
+    assert_eq!(proof.to_bytes(), json_proof.to_bytes());
+
+    // compact format
+    let bin = bincode::serialize(&proof).unwrap();
+    let bin_proof: ExecutionProof = bincode::deserialize(&bin).unwrap();
+    assert_eq!(proof.to_bytes(), bin_proof.to_bytes());
+
+    let json = serde_json::to_string(&options).unwrap();
+    assert_eq!(options, serde_json::from_str(&json).unwrap());
+    let bin = bincode::serialize(&options).unwrap();
+    assert_eq!(options, bincode::deserialize(&bin).unwrap());
+
+    // all parameters accepted by ProofOptions::new() should be accepted
+    let options =
+        ProofOptions::new(27, 4, 16, FieldExtension::Quadratic, 8, 255, HashFunction::Rpo256);
+    let json = serde_json::to_string(&options).unwrap();
+    assert_eq!(options, serde_json::from_str(&json).unwrap());
+
+    let json = serde_json::to_string(&stack_outputs).unwrap();
+    let json_outputs: StackOutputs = serde_json::from_str(&json).unwrap();
+    assert_eq!(stack_outputs, json_outputs);
+
+    let program_info = ProgramInfo::from(program);
+    assert!(miden::verify(
+        program_info.clone(),
+        StackInputs::default(),
+        json_outputs.clone(),
+        json_proof
+    )
+    .is_ok());
+    assert!(miden::verify(program_info, StackInputs::default(), json_outputs, bin_proof).is_ok());
+}
+
+#[test]
+fn proof_from_truncated_bytes() {
+    use miden::utils::Serializable;
+    use test_utils::rand::rand_value;
+
+    let program = build_test!("begin push.1 push.2 add end").compile();
+    let (_, proof) = prove(
+        &program,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+
+    let bytes = proof.to_bytes();
+    assert_eq!(proof, ExecutionProof::from_bytes(&bytes).unwrap());
+
+    // the proof consists of a 3-byte header followed by the components of the STARK proof
+    let stark_proof = proof.stark_proof();
+    let section_lens = [
+        3,
+        stark_proof.context.to_bytes().len(),
+        stark_proof.commitments.to_bytes().len(),
+        stark_proof.trace_queries.to_bytes().len(),
+        stark_proof.constraint_queries.to_bytes().len(),
+        stark_proof.ood_frame.to_bytes().len(),
+        stark_proof.fri_proof.to_bytes().len(),
+        8,
+    ];
+    let boundaries = section_lens
+        .iter()
+        .scan(0, |offset, len| {
+            *offset += len;
+            Some(*offset)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(Some(&bytes.len()), boundaries.last());
+
+    // cut the proof at (and right around) each section boundary, and at a few random offsets
+    let mut cut_points = vec![0, 1];
+    cut_points.extend(boundaries.iter().flat_map(|&offset| [offset - 1, offset, offset + 1]));
+    cut_points.extend((0..8).map(|_| rand_value::<u64>() as usize % bytes.len()));
+    for len in cut_points.into_iter().filter(|&len| len < bytes.len()) {
+        assert!(ExecutionProof::from_bytes(&bytes[..len]).is_err(), "accepted {len} bytes");
+    }
+    assert_eq!(
+        proof.stark_proof().clone(),
+        StarkProof::from_bytes(&proof.stark_proof().to_bytes()).unwrap()
+    );
+}
+
+#[test]
+fn prove_and_verify_program() {
+    let program = build_test!("begin mul movup.2 drop end").compile();
+    let stack_inputs = StackInputs::try_from_values([1, 2, 3]).unwrap();
+
+    let stack_outputs = prover::prove_and_verify(
+        &program,
+        stack_inputs,
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(&[6, 1], stack_outputs.stack_truncated(2));
+
+    // execution errors should be propagated
+    let program = build_test!("begin push.1 push.0 div end").compile();
+    let result = prover::prove_and_verify(
+        &program,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    );
+    assert!(matches!(
+        result,
+        Err(prover::ProveAndVerifyError::ExecutionError(ExecutionError::DivideByZero { .. }))
+    ));
+}
+
+#[test]
+fn ffi_verify() {
+    use miden::utils::Serializable;
+    use verifier::ffi::{
+        miden_verify, MIDEN_VERIFY_ERR_INVALID_OUTPUTS, MIDEN_VERIFY_ERR_INVALID_PROOF,
+        MIDEN_VERIFY_ERR_NULL_POINTER, MIDEN_VERIFY_ERR_VERIFICATION_FAILED, MIDEN_VERIFY_OK,
+    };
+
+    let program = build_test!("begin mul movup.2 drop end").compile();
+    let stack_inputs = [1, 2, 3];
+    let (stack_outputs, proof) = prove(
+        &program,
+        StackInputs::try_from_values(stack_inputs).unwrap(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+
+    let program_hash = program.hash().to_bytes();
+    let outputs = stack_outputs.stack();
+    let proof = proof.to_bytes();
+    let verify = |inputs: &[u64], outputs: &[u64], proof: &[u8]| unsafe {
+        miden_verify(
+            program_hash.as_ptr(),
+            inputs.as_ptr(),
+            inputs.len(),
+            outputs.as_ptr(),
+            outputs.len(),
+            proof.as_ptr(),
+            proof.len(),
+        )
+    };
+
+    assert_eq!(MIDEN_VERIFY_OK, verify(&stack_inputs, outputs, &proof));
+    assert_eq!(MIDEN_VERIFY_ERR_VERIFICATION_FAILED, verify(&[1, 2, 4], outputs, &proof));
+    assert_eq!(MIDEN_VERIFY_ERR_INVALID_OUTPUTS, verify(&stack_inputs, &outputs[..4], &proof));
+    assert_eq!(MIDEN_VERIFY_ERR_INVALID_PROOF, verify(&stack_inputs, outputs, &proof[..100]));
+
+    let result = unsafe {
+        miden_verify(
+            core::ptr::null(),
+            core::ptr::null(),
+            0,
+            outputs.as_ptr(),
+            16,
+            proof.as_ptr(),
+            0,
+        )
+    };
+    assert_eq!(MIDEN_VERIFY_ERR_NULL_POINTER, result);
+}
+
+#[test]
+fn prove_trace_program() {
+    let program = build_test!("begin mul movup.2 drop end").compile();
+    let stack_inputs = StackInputs::try_from_values([1, 2, 3]).unwrap();
+
+    let trace =
+        miden::execute(&program, stack_inputs.clone(), MemAdviceProvider::default()).unwrap();
+    let expected_outputs = trace.stack_outputs().clone();
+    let program_info = trace.program_info().clone();
+
+    let (stack_outputs, proof) =
+        prove_trace(trace, stack_inputs.clone(), ProofOptions::default()).unwrap();
+    assert_eq!(expected_outputs, stack_outputs);
+    assert!(miden::verify(program_info, stack_inputs, stack_outputs, proof).is_ok());
+
+    // proving the trace against different stack inputs should fail
+    let stack_inputs = StackInputs::try_from_values([1, 2, 3]).unwrap();
+    let trace = miden::execute(&program, stack_inputs, MemAdviceProvider::default()).unwrap();
+    let stack_inputs = StackInputs::try_from_values([1, 2, 4]).unwrap();
+    let result = prove_trace(trace, stack_inputs, ProofOptions::default());
+    assert!(matches!(
+        result,
+        Err(ExecutionError::InconsistentPublicInputs {
+            segment: PublicInputsSegment::StackInputs,
+            index: 0,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn verify_program_with_kernel() {
+    let program = miden::Assembler::default()
+        .with_kernel("export.foo add end")
+        .unwrap()
+        .compile("begin syscall.foo end")
+        .unwrap();
+    let stack_inputs = StackInputs::try_from_values([1, 2, 3]).unwrap();
+
+    let (stack_outputs, proof) = prove(
+        &program,
+        stack_inputs.clone(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+    assert!(
+        miden::verify_program(&program, stack_inputs, stack_outputs.clone(), proof.clone()).is_ok()
+    );
+
+    // verifying against a different program should fail
+    let other = build_test!("begin add end").compile();
+    let stack_inputs = StackInputs::try_from_values([1, 2, 3]).unwrap();
+    assert!(miden::verify_program(&other, stack_inputs, stack_outputs, proof).is_err());
+}
+
+#[test]
+fn merkle_root_bound_by_stack_inputs() {
+    use test_utils::crypto::{init_merkle_store, MerkleTree};
+
+    let (leaves, store) = init_merkle_store(&[1, 2, 3, 4]);
+    let root = MerkleTree::new(leaves.clone()).unwrap().root();
+    let program = build_test!("begin mtree_get swapw dropw end").compile();
+    let advice_inputs = AdviceInputs::default().with_merkle_store(store);
+
+    // the root is provided via stack inputs; the top of the stack is [depth, index, root, ...]
+    let stack_inputs = |root: [Felt; 4]| {
+        let mut values: Vec<u64> = root.iter().map(|v| v.as_int()).collect();
+        values.extend([1, 2]);
+        StackInputs::try_from_values(values).unwrap()
+    };
+
+    let (stack_outputs, proof) = prove(
+        &program,
+        stack_inputs(root.into()),
+        MemAdviceProvider::from(advice_inputs),
+        ProofOptions::default(),
+    )
+    .unwrap();
+    let leaf: Vec<u64> = leaves[1].iter().rev().map(|v| v.as_int()).collect();
+    assert_eq!(&leaf, stack_outputs.stack_truncated(4));
+
+    let program_info = ProgramInfo::from(program);
+    let result = miden::verify(
+        program_info.clone(),
+        stack_inputs(root.into()),
+        stack_outputs.clone(),
+        proof.clone(),
+    );
+    assert!(result.is_ok());
+
+    // the proof does not verify against a different root
+    let other_root = MerkleTree::new(leaves[..2].to_vec()).unwrap().root();
+    let result = miden::verify(program_info, stack_inputs(other_root.into()), stack_outputs, proof);
+    assert!(result.is_err());
+}
+
+#[test]
+fn prove_from_execution_witness() {
+    use miden::{
+        utils::{Deserializable, Serializable},
+        ExecutionWitness,
+    };
+    use test_utils::crypto::{init_merkle_store, MerkleTree};
+
+    let (leaves, store) = init_merkle_store(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let root: [Felt; 4] = MerkleTree::new(leaves.clone()).unwrap().root().into();
+    let program = build_test!("begin adv_push.1 drop mtree_get swapw dropw end").compile();
+    let advice_inputs = AdviceInputs::default()
+        .with_stack_values([5, 6])
+        .unwrap()
+        .with_merkle_store(store);
+    let mut values: Vec<u64> = root.iter().map(|v| v.as_int()).collect();
+    values.extend([2, 3]);
+    let stack_inputs = StackInputs::try_from_values(values).unwrap();
+
+    let witness =
+        miden::execute_to_witness(&program, stack_inputs.clone(), advice_inputs.clone()).unwrap();
+    let trace =
+        miden::execute(&program, stack_inputs.clone(), MemAdviceProvider::from(advice_inputs))
+            .unwrap();
+    assert_eq!(trace.stack_outputs(), witness.stack_outputs());
+
+    // the witness contains only the Merkle paths touched by the program
+    assert!(witness.advice_inputs().contains_merkle_node(root, 3, 2));
+    assert!(!witness.advice_inputs().contains_merkle_node(root, 3, 6));
+
+    // the witness is much smaller than the execution trace
+    let bytes = witness.to_bytes();
+    assert!(bytes.len() < trace.get_trace_len() * 8);
+
+    let witness = ExecutionWitness::read_from_bytes(&bytes).unwrap();
+    let (stack_outputs, proof) =
+        miden::prove_from_witness(&program, witness, ProofOptions::default()).unwrap();
+    assert_eq!(trace.stack_outputs(), &stack_outputs);
+    assert!(miden::verify_program(&program, stack_inputs, stack_outputs, proof).is_ok());
+}
+
+#[test]
+fn verify_insufficient_outputs() {
+    let program = build_test!("begin push.1 push.2 add end").compile();
+    let (stack_outputs, proof) = prove(
+        &program,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+
+    let stack_outputs = StackOutputs::new(stack_outputs.stack_truncated(1).to_vec(), Vec::new());
+    let result = miden::verify_program(&program, StackInputs::default(), stack_outputs, proof);
+    assert_eq!(Err(VerificationError::InsufficientStackOutputs(1)), result);
+}
+
+#[test]
+fn verify_inconsistent_stack_overflow() {
+    // the program leaves one value in the stack overflow table
+    let program = build_test!("begin push.1 end").compile();
+    let (stack_outputs, proof) = prove(
+        &program,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(1, stack_outputs.overflow_values().len());
+    assert_eq!(2, stack_outputs.overflow_addrs().len());
+    let trace_len = proof.stark_proof().trace_length();
+
+    let stack = stack_outputs.stack().to_vec();
+    let addrs = stack_outputs.overflow_addrs().to_vec();
+    let outputs = StackOutputs::new(stack.clone(), addrs[..1].to_vec());
+    let result = miden::verify_program(&program, StackInputs::default(), outputs, proof.clone());
+    assert_eq!(
+        Err(VerificationError::PublicInputsMismatch(
+            PublicInputsMismatch::InconsistentStackOverflow {
+                num_values: 1,
+                num_addrs: 1
+            }
+        )),
+        result
+    );
+
+    let outputs = StackOutputs::new(stack, vec![addrs[0], trace_len as u64]);
+    let result = miden::verify_program(&program, StackInputs::default(), outputs, proof.clone());
+    assert_eq!(
+        Err(VerificationError::PublicInputsMismatch(
+            PublicInputsMismatch::OverflowAddressOutOfBounds {
+                addr: trace_len as u64,
+                trace_len
+            }
+        )),
+        result
+    );
+
+    let result = miden::verify_program(&program, StackInputs::default(), stack_outputs, proof);
+    assert!(result.is_ok());
+}
+
+#[test]
+fn prove_verify_cubic_extension() {
+    let program = build_test!("begin push.1 push.2 add end").compile();
+    let options = ProofOptions::builder().field_extension(FieldExtension::Cubic).build().unwrap();
+    let (stack_outputs, proof) =
+        prove(&program, StackInputs::default(), MemAdviceProvider::default(), options).unwrap();
+
+    let mut bytes = proof.to_bytes();
+    assert!(
+        miden::verify_program(&program, StackInputs::default(), stack_outputs.clone(), proof)
+            .is_ok()
+    );
+
+    // a tampered proof should be rejected
+    let idx = bytes.len() - 16;
+    bytes[idx] ^= 1;
+    let rejected = ExecutionProof::from_bytes(&bytes).map_or(true, |proof| {
+        miden::verify_program(&program, StackInputs::default(), stack_outputs, proof).is_err()
+    });
+    assert!(rejected);
+}
+
+#[test]
+fn estimate_proof_size() {
+    // the estimate of the proof size is an upper bound which is expected to exceed the size of an
+    // actual proof by at most 10%; the difference stems from Merkle authentication paths, which are
+    // estimated assuming that no two queried positions share a sibling
+    const TOLERANCE_PERCENT: usize = 10;
+
+    let short = build_test!("begin repeat.100 push.1 add end end").compile();
+    let long = build_test!("begin repeat.1000 push.1 add end end").compile();
+    for (program, options) in [
+        (&short, ProofOptions::with_96_bit_security(false)),
+        (&short, ProofOptions::with_96_bit_security(true)),
+        (&long, ProofOptions::with_128_bit_security(false)),
+    ] {
+        let (_, proof) =
+            prove(program, StackInputs::default(), MemAdviceProvider::default(), options.clone())
+                .unwrap();
+
+        let actual = proof.to_bytes().len();
+        let estimate = options.estimate_proof_size(proof.stark_proof().context.trace_length());
+        assert!(estimate >= actual, "estimate {estimate} is below actual size {actual}");
+        assert!(
+            (estimate - actual) * 100 <= actual * TOLERANCE_PERCENT,
+            "estimate {estimate} exceeds actual size {actual} by over {TOLERANCE_PERCENT}% \
+            for {options:?}"
+        );
+    }
+}
+
+#[test]
+fn prove_with_higher_fri_folding_factor() {
+    let program = build_test!("begin repeat.100 push.1 add end end").compile();
+    let options = ProofOptions::with_96_bit_security(false).with_fri_folding_factor(4).unwrap();
+    let folded = options.clone().with_fri_folding_factor(8).unwrap();
+
+    let mut sizes = Vec::new();
+    for options in [options, folded] {
+        let (stack_outputs, proof) =
+            prove(&program, StackInputs::default(), MemAdviceProvider::default(), options).unwrap();
+        sizes.push(proof.to_bytes().len());
+
+        // the verifier reads the folding factor from the proof
+        assert!(
+            miden::verify_program(&program, StackInputs::default(), stack_outputs, proof).is_ok()
+        );
+    }
+    assert!(sizes[1] < sizes[0], "proof sizes: {sizes:?}");
+}
+
+#[test]
+fn execute_with_cycle_limit() {
+    let program = build_test!("begin push.1 while.true push.1 end end").compile();
+    let result = miden::execute_bounded(
+        &program,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        1000,
+    );
+    assert!(matches!(result, Err(ExecutionError::CycleLimitExceeded(1000))));
+
+    // a program which terminates within the limit should execute normally
+    let program = build_test!("begin push.1 push.2 add end").compile();
+    let trace =
+        miden::execute_bounded(&program, StackInputs::default(), MemAdviceProvider::default(), 64)
+            .unwrap();
+    assert_eq!(3, trace.stack_outputs().stack()[0]);
+}
+
+#[test]
+fn execute_with_stack_depth_limit() {
+    // a runaway program is stopped once the stack reaches the default maximum depth
+    let program = build_test!("begin push.1 while.true push.1 push.1 end end").compile();
+    let result = miden::execute(&program, StackInputs::default(), MemAdviceProvider::default());
+    assert!(matches!(
+        result,
+        Err(ExecutionError::StackOverflow { depth, capacity: miden::DEFAULT_MAX_STACK_DEPTH })
+            if depth == miden::DEFAULT_MAX_STACK_DEPTH + 1
+    ));
+
+    // the maximum depth can be lowered or raised via execution options
+    let program = build_test!("begin repeat.100 push.1 end end").compile();
+    let options = ExecutionOptions::default().with_max_stack_depth(64);
+    let result = miden::execute_with_options(
+        &program,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        options,
+    );
+    assert!(matches!(
+        result,
+        Err(ExecutionError::StackOverflow {
+            depth: 65,
+            capacity: 64
+        })
+    ));
+
+    let options = ExecutionOptions::default().with_max_stack_depth(116);
+    let trace = miden::execute_with_options(
+        &program,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        options,
+    )
+    .unwrap();
+    assert_eq!(116, trace.stack_outputs().stack().len());
+}
+
+#[test]
+fn execution_error_source_location() {
+    let source = "\
+begin
+    push.1 push.2
+    assert_eq
+end";
+
+    // source locations of failed instructions are available only in debug mode
+    let program = test_utils::build_debug_test!(source).compile();
+    let result = miden::execute(&program, StackInputs::default(), MemAdviceProvider::default());
+    let location = Some(miden::SourceLocation::new(3, 5));
+    assert!(matches!(
+        result,
+        Err(ExecutionError::FailedAssertion { source_location, .. }) if source_location == location
+    ));
+
+    let program = build_test!(source).compile();
+    let result = miden::execute(&program, StackInputs::default(), MemAdviceProvider::default());
+    assert!(matches!(
+        result,
+        Err(ExecutionError::FailedAssertion {
+            source_location: None,
+            ..
+        })
+    ));
+
+    let program = test_utils::build_debug_test!("begin push.0\n push.1 swap div end").compile();
+    let result = miden::execute(&program, StackInputs::default(), MemAdviceProvider::default());
+    let err = result.err().unwrap();
+    assert_eq!("Division by zero at clock cycle 5 (line 2, column 14)", err.to_string());
+}
+
+#[test]
+fn execute_with_dyn_advice_provider() {
+    let program = build_test!("begin adv_push.2 add end").compile();
+    let advice_inputs = AdviceInputs::default().with_stack_values([3, 4]).unwrap();
+
+    let mut advice_provider = MemAdviceProvider::from(advice_inputs);
+    let dyn_provider: &mut dyn AdviceProvider = &mut advice_provider;
+    let trace = miden::execute(&program, StackInputs::default(), dyn_provider).unwrap();
+    assert_eq!(trace.stack_outputs().stack_truncated(1), &[7]);
+
+    // the advice stack of the borrowed provider has been consumed
+    let dyn_provider: &mut (dyn AdviceProvider + Send) = &mut advice_provider;
+    let result = prove(
+        &program,
+        StackInputs::default(),
+        dyn_provider,
+        ProofOptions::with_96_bit_security(false),
+    );
+    assert!(matches!(result, Err(ExecutionError::AdviceStackReadFailed { .. })));
+}
+
+#[test]
+fn prove_with_memory_limit() {
+    let program = build_test!("begin repeat.10 push.1 add end end").compile();
+    let options = ProofOptions::with_96_bit_security(false);
+    let trace =
+        miden::execute(&program, StackInputs::default(), MemAdviceProvider::default()).unwrap();
+    let estimate = miden::estimate_proving_memory(&trace, &options);
+
+    let prove = |max_memory| {
+        miden::prove_with_memory_limit(
+            &program,
+            StackInputs::default(),
+            MemAdviceProvider::default(),
+            options.clone(),
+            max_memory,
+        )
+    };
+
+    let result = prove(estimate - 1);
+    assert!(matches!(
+        result,
+        Err(ExecutionError::TraceTooLarge { estimated_bytes, max_bytes })
+            if estimated_bytes == estimate && max_bytes == estimate - 1
+    ));
+    assert!(prove(estimate).is_ok());
+}
+
+/// Proof generation is single-threaded when the `concurrent` feature is disabled; the test is
+/// meaningful only when proofs are actually generated by thread pools of different sizes.
+#[test]
+#[cfg(feature = "concurrent")]
+fn prove_with_different_num_threads() {
+    let program = build_test!("begin repeat.20 push.1 add end end").compile();
+    let options = ProofOptions::builder().grinding_factor(0).build().unwrap();
+
+    let prove = |num_threads| {
+        let (stack_outputs, proof) = miden::prove_with_num_threads(
+            &program,
+            StackInputs::default(),
+            MemAdviceProvider::default(),
+            options.clone(),
+            num_threads,
+        )
+        .unwrap();
+        (stack_outputs, proof.to_bytes())
+    };
+
+    assert_eq!(prove(1), prove(4));
+
+    // the number of threads reported from within a thread pool is the size of the pool
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+    assert_eq!(4, pool.install(miden::num_threads));
+}
+
+#[test]
+fn prove_with_num_threads_grinding() {
+    // proofs generated with a non-zero grinding factor may depend on the number of threads
+    let program = build_test!("begin push.1 push.2 add end").compile();
+    let options = ProofOptions::builder().grinding_factor(16).build().unwrap();
+    let result = miden::prove_with_num_threads(
+        &program,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        options,
+        2,
+    );
+    assert!(matches!(
+        result,
+        Err(ExecutionError::NonReproducibleProofOptions {
+            grinding_factor: 16
+        })
+    ));
+}
+
+#[test]
+fn verify_with_transcript() {
+    use miden::crypto::Rpo256;
+    use test_utils::QuadFelt;
+
+    let program = build_test!("begin repeat.20 push.1 add end end").compile();
+    let program_info = ProgramInfo::from(program.clone());
+    let options = ProofOptions::with_96_bit_security(true);
+    let (stack_outputs, proof) =
+        prove(&program, StackInputs::default(), MemAdviceProvider::default(), options).unwrap();
+
+    let transcript = miden::verify_with_transcript(
+        program_info.clone(),
+        StackInputs::default(),
+        stack_outputs.clone(),
+        proof.clone(),
+    )
+    .unwrap();
+
+    let stark_proof = proof.stark_proof();
+    let trace_info = stark_proof.get_trace_info();
+    assert_eq!(2, transcript.trace_commitments.len());
+    assert_eq!(trace_info.width(), transcript.deep_trace_coefficients.len());
+    assert_eq!(stark_proof.fri_proof.num_layers() + 1, transcript.fri_commitments.len());
+    assert_eq!(transcript.fri_commitments.len(), transcript.fri_alphas.len());
+    assert_eq!(stark_proof.pow_nonce, transcript.pow_nonce);
+    assert!(transcript.query_positions.len() <= 27);
+    assert!(transcript.query_positions.iter().all(|&p| p < stark_proof.lde_domain_size()));
+
+    // the query positions are the ones the prover opened the trace and constraint commitments at;
+    // thus, a transcript replayed in a wrong order would not match the queries in the proof
+    let positions = &transcript.query_positions;
+    let domain_size = stark_proof.lde_domain_size();
+    let layout = trace_info.layout();
+    let (main_queries, _) = stark_proof.trace_queries[0]
+        .clone()
+        .parse::<Rpo256, Felt>(domain_size, positions.len(), layout.main_trace_width())
+        .unwrap();
+    assert_eq!(transcript.trace_commitments[0], main_queries.get_root(positions).unwrap());
+    let (aux_queries, _) = stark_proof.trace_queries[1]
+        .clone()
+        .parse::<Rpo256, QuadFelt>(domain_size, positions.len(), layout.aux_trace_width())
+        .unwrap();
+    assert_eq!(transcript.trace_commitments[1], aux_queries.get_root(positions).unwrap());
+    let num_composition_columns = transcript.deep_constraint_coefficients.len();
+    let (constraint_queries, _) = stark_proof
+        .constraint_queries
+        .clone()
+        .parse::<Rpo256, QuadFelt>(domain_size, positions.len(), num_composition_columns)
+        .unwrap();
+    assert_eq!(
+        transcript.constraint_commitment,
+        constraint_queries.get_root(positions).unwrap()
+    );
+
+    // the transcript is fully determined by the proof and the public inputs
+    let transcript2 = miden::verify_with_transcript(
+        program_info.clone(),
+        StackInputs::default(),
+        stack_outputs.clone(),
+        proof,
+    )
+    .unwrap();
+    assert_eq!(transcript, transcript2);
+
+    // transcripts are available only for proofs which can be verified recursively
+    let (stack_outputs, proof) = prove(
+        &program,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+    let result =
+        miden::verify_with_transcript(program_info, StackInputs::default(), stack_outputs, proof);
+    assert_eq!(
+        Err(VerificationError::UnsupportedTranscriptOptions(
+            HashFunction::Blake3_192,
+            FieldExtension::Quadratic
+        )),
+        result
+    );
+}
+
+#[test]
+fn prove_to_elements() {
+    use miden::crypto::Rpo256;
+
+    let program = build_test!("begin repeat.20 push.1 add end end").compile();
+    let program_info = ProgramInfo::from(program.clone());
+    let options = ProofOptions::with_96_bit_security(true);
+    let (stack_outputs, elements) = miden::prove_to_elements(
+        &program,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        options.clone(),
+    )
+    .unwrap();
+
+    // the same elements are produced from a proof generated separately
+    let (_, proof) =
+        prove(&program, StackInputs::default(), MemAdviceProvider::default(), options).unwrap();
+    let expected = miden::proof_to_elements(
+        program_info.clone(),
+        StackInputs::default(),
+        stack_outputs.clone(),
+        &proof,
+    )
+    .unwrap();
+    assert_eq!(expected, elements);
+
+    // the elements start with the proof context followed by the commitments
+    let transcript = miden::verify_with_transcript(
+        program_info,
+        StackInputs::default(),
+        stack_outputs,
+        proof.clone(),
+    )
+    .unwrap();
+    let context: Vec<Felt> = proof.stark_proof().context.to_elements();
+    assert_eq!(context.len() as u64, elements[0].as_int());
+    assert_eq!(context, elements[1..=context.len()]);
+
+    let mut reader = elements[context.len() + 1..].iter().copied();
+    let mut read = |n: usize| reader.by_ref().take(n).collect::<Vec<Felt>>();
+    assert_eq!(transcript.trace_commitments[0].as_elements(), read(4));
+    assert_eq!(transcript.trace_commitments[1].as_elements(), read(4));
+    assert_eq!(transcript.constraint_commitment.as_elements(), read(4));
+    assert_eq!(vec![Felt::from(transcript.fri_commitments.len() as u32)], read(1));
+    for commitment in transcript.fri_commitments.iter() {
+        assert_eq!(commitment.as_elements(), read(4));
+    }
+
+    // skip out-of-domain evaluations at z and z * g and check the proof-of-work nonce
+    let num_trace_columns = transcript.deep_trace_coefficients.len();
+    let num_composition_columns = transcript.deep_constraint_coefficients.len();
+    read(2 * (2 * num_trace_columns + num_composition_columns));
+    let nonce = read(2);
+    assert_eq!(transcript.pow_nonce, nonce[0].as_int() | (nonce[1].as_int() << 32));
+
+    // the Merkle path of the main trace row in the first query opens to the main trace commitment
+    let main_width = proof.stark_proof().get_trace_info().layout().main_trace_width();
+    let depth = proof.stark_proof().lde_domain_size().trailing_zeros() as usize;
+    assert_eq!(vec![Felt::from(transcript.query_positions.len() as u32)], read(1));
+    let mut index = read(1)[0].as_int();
+    assert_eq!(transcript.query_positions[0] as u64, index);
+    let mut node = Rpo256::hash_elements(&read(main_width));
+    for _ in 0..depth {
+        let sibling = read(4);
+        let sibling = [sibling[0], sibling[1], sibling[2], sibling[3]].into();
+        node = if index & 1 == 0 {
+            Rpo256::merge(&[node, sibling])
+        } else {
+            Rpo256::merge(&[sibling, node])
+        };
+        index >>= 1;
+    }
+    assert_eq!(transcript.trace_commitments[0], node);
+
+    // only proofs which can be verified recursively can be encoded as elements
+    let result = miden::prove_to_elements(
+        &program,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    );
+    assert!(matches!(result, Err(ExecutionError::NonRecursiveProofOptions)));
+}
+
+#[test]
+fn proof_to_elements_tampered_proof() {
+    use miden::{
+        utils::{Deserializable, Serializable},
+        ExecutionProof,
+    };
+
+    let program = build_test!("begin repeat.20 push.1 add end end").compile();
+    let program_info = ProgramInfo::from(program.clone());
+    let options = ProofOptions::with_96_bit_security(true);
+    let (stack_outputs, proof) =
+        prove(&program, StackInputs::default(), MemAdviceProvider::default(), options.clone())
+            .unwrap();
+    let (hash_fn, stark_proof) = proof.into_parts();
+
+    // a proof without auxiliary trace queries cannot be parsed
+    let mut tampered = stark_proof.clone();
+    tampered.trace_queries.truncate(1);
+    let result = miden::proof_to_elements(
+        program_info.clone(),
+        StackInputs::default(),
+        stack_outputs.clone(),
+        &ExecutionProof::new(tampered, hash_fn),
+    );
+    assert!(matches!(result, Err(VerificationError::VerifierError(_))));
+
+    // a proof with fewer queries in a FRI layer than queried positions cannot be parsed; the FRI
+    // proof starts with the number of layers, followed by the length of the first layer's values
+    let mut tampered = stark_proof.clone();
+    let mut fri_bytes = tampered.fri_proof.to_bytes();
+    let num_value_bytes = u32::from_le_bytes(fri_bytes[1..5].try_into().unwrap()) as usize;
+    let query_bytes = options.fri_folding_factor() * 2 * 8;
+    fri_bytes.drain(5..5 + query_bytes);
+    fri_bytes[1..5].copy_from_slice(&((num_value_bytes - query_bytes) as u32).to_le_bytes());
+    tampered.fri_proof = Deserializable::read_from_bytes(&fri_bytes).unwrap();
+    let result = miden::proof_to_elements(
+        program_info.clone(),
+        StackInputs::default(),
+        stack_outputs.clone(),
+        &ExecutionProof::new(tampered, hash_fn),
+    );
+    assert!(matches!(result, Err(VerificationError::VerifierError(_))));
+
+    // a proof which does not describe a Miden VM execution trace is rejected before parsing
+    let mut tampered = stark_proof;
+    let mut context_bytes = tampered.context.to_bytes();
+    context_bytes[0] = 1;
+    tampered.context = Deserializable::read_from_bytes(&context_bytes).unwrap();
+    let result = miden::proof_to_elements(
+        program_info,
+        StackInputs::default(),
+        stack_outputs,
+        &ExecutionProof::new(tampered, hash_fn),
+    );
+    assert!(matches!(result, Err(VerificationError::UnexpectedTraceLayout { .. })));
+}
+
+#[test]
+fn supported_hash_functions() {
+    // the full build of the verifier supports all hash functions
+    let supported = miden::supported_hash_functions();
+    for hash_fn in [HashFunction::Blake3_192, HashFunction::Blake3_256, HashFunction::Rpo256] {
+        assert!(supported.contains(&hash_fn));
+    }
+
+    // verifiers built with a subset of hash functions report the ones they support
+    let err = VerificationError::UnsupportedHashFunction {
+        requested: HashFunction::Rpo256,
+        supported: vec![HashFunction::Blake3_192, HashFunction::Blake3_256],
+    };
+    assert_eq!(
+        "proofs generated with rpo256 hash function are not supported by this verifier; \
+        supported hash functions: blake3_192, blake3_256",
+        err.to_string()
+    );
+}
+
+#[test]
+fn verify_verbose() {
+    use miden::math::FieldElement;
+
+    let program = build_test!("begin repeat.20 push.1 add end end").compile();
+    let program_info = ProgramInfo::from(program.clone());
+    let options = ProofOptions::with_96_bit_security(true);
+    let (stack_outputs, proof) =
+        prove(&program, StackInputs::default(), MemAdviceProvider::default(), options).unwrap();
+
+    let report = miden::verify_verbose(
+        program_info.clone(),
+        StackInputs::default(),
+        stack_outputs.clone(),
+        proof.clone(),
+    )
+    .unwrap();
+    let transcript = miden::verify_with_transcript(
+        program_info.clone(),
+        StackInputs::default(),
+        stack_outputs.clone(),
+        proof.clone(),
+    )
+    .unwrap();
+
+    let mut query_positions = transcript.query_positions.clone();
+    query_positions.sort_unstable();
+    query_positions.dedup();
+    assert_eq!(proof.security_level(), report.security_level);
+    assert_eq!(proof.stark_proof().fri_proof.num_layers(), report.num_fri_layers);
+    assert_eq!(query_positions.len(), report.num_queries);
+    assert_eq!(FieldElement::slice_as_base_elements(&[transcript.ood_point]), report.ood_point);
+    assert!(report.verification_time.is_some());
+
+    // reports are available for proofs generated with any hash function
+    let (stack_outputs, proof) = prove(
+        &program,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+    let report = miden::verify_verbose(
+        program_info.clone(),
+        StackInputs::default(),
+        stack_outputs.clone(),
+        proof.clone(),
+    )
+    .unwrap();
+    assert_eq!(2, report.ood_point.len());
+    assert!(report.num_queries <= 27);
+
+    // invalid proofs are rejected
+    let stack_outputs = StackOutputs::new(vec![0; STACK_TOP_SIZE], vec![]);
+    assert!(
+        miden::verify_verbose(program_info, StackInputs::default(), stack_outputs, proof).is_err()
+    );
+}
+
+#[test]
+fn verify_with_policy() {
+    let program = build_test!("begin push.1 push.2 add end").compile();
+    let program_info = ProgramInfo::from(program.clone());
+    let options = ProofOptions::with_96_bit_security(false);
+    let (stack_outputs, proof) =
+        prove(&program, StackInputs::default(), MemAdviceProvider::default(), options.clone())
+            .unwrap();
+
+    let policy = VerificationPolicy::from_options(&options);
+    let result = miden::verify_with_policy(
+        program_info.clone(),
+        StackInputs::default(),
+        stack_outputs.clone(),
+        proof.clone(),
+        &policy,
+    );
+    assert_eq!(Ok(proof.security_level()), result);
+
+    // the policy is checked before the proof is verified
+    let strict_policy = VerificationPolicy {
+        min_num_queries: 40,
+        ..policy.clone()
+    };
+    let result = miden::verify_with_policy(
+        program_info.clone(),
+        StackInputs::default(),
+        stack_outputs.clone(),
+        proof.clone(),
+        &strict_policy,
+    );
+    assert_eq!(
+        Err(VerificationError::PolicyViolation(PolicyViolation::NumQueriesTooSmall {
+            actual: 27,
+            min: 40
+        })),
+        result
+    );
+
+    let rpo_policy = VerificationPolicy {
+        allowed_hash_fns: vec![HashFunction::Rpo256],
+        ..policy.clone()
+    };
+    let result = miden::verify_with_policy(
+        program_info,
+        StackInputs::default(),
+        stack_outputs,
+        proof,
+        &rpo_policy,
+    );
+    assert_eq!(
+        Err(VerificationError::PolicyViolation(PolicyViolation::HashFunctionNotAllowed(
+            HashFunction::Blake3_192
+        ))),
+        result
+    );
+
+    // policies can be stored in configuration files, with field extensions referred to by name
+    let json = serde_json::to_string(&policy).unwrap();
+    assert_eq!(policy, serde_json::from_str(&json).unwrap());
+    let name = miden::field_extension_name(options.field_extension());
+    assert!(json.contains(&format!("\"allowed_field_extensions\":[\"{name}\"]")));
+    let json = json.replace(&format!("\"{name}\""), "\"quartic\"");
+    assert!(serde_json::from_str::<VerificationPolicy>(&json).is_err());
+}
+
+#[test]
+fn verify_from_raw_parts() {
+    let program = build_test!("begin mul movup.2 drop end").compile();
+    let stack_inputs = [1, 2, 3];
+    let (stack_outputs, proof) = prove(
+        &program,
+        StackInputs::try_from_values(stack_inputs).unwrap(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+
+    let program_hash: [u8; 32] = program.hash().into();
+    let outputs = stack_outputs.stack();
+    let proof = proof.to_bytes();
+
+    assert!(miden::verify_from_parts(program_hash, &stack_inputs, outputs, &proof).is_ok());
+
+    // malformed parts are reported separately from verification failures
+    let result = miden::verify_from_parts([255; 32], &stack_inputs, outputs, &proof);
+    assert!(matches!(result, Err(VerificationError::InvalidProgramHash(_))));
+    let result = miden::verify_from_parts(program_hash, &[u64::MAX], outputs, &proof);
+    assert_eq!(Err(VerificationError::InputNotFieldElement(u64::MAX)), result);
+    let result = miden::verify_from_parts(program_hash, &stack_inputs, outputs, &proof[..100]);
+    assert!(matches!(result, Err(VerificationError::ProofDeserializationFailed(_))));
+    let result = miden::verify_from_parts(program_hash, &[1, 2, 4], outputs, &proof);
+    assert!(matches!(result, Err(VerificationError::VerifierError(_))));
+}
+
+#[test]
+fn verify_proof_batch() {
+    let program = build_test!("begin mul movup.2 drop end").compile();
+    let program_info = ProgramInfo::from(program.clone());
+    let mut batch = (0..3)
+        .map(|i| {
+            let stack_inputs = StackInputs::try_from_values([1, 2, i]).unwrap();
+            let (stack_outputs, proof) = prove(
+                &program,
+                stack_inputs.clone(),
+                MemAdviceProvider::default(),
+                ProofOptions::default(),
+            )
+            .unwrap();
+            (program_info.clone(), stack_inputs, stack_outputs, proof)
+        })
+        .collect::<Vec<_>>();
+
+    assert!(miden::verify_batch(batch.clone()).is_ok());
+    assert!(miden::verify_batch(Vec::new()).is_ok());
+
+    // the index of the first invalid item is reported
+    batch[1].1 = StackInputs::try_from_values([1, 2, 5]).unwrap();
+    batch[2].2 = StackOutputs::new(vec![0; 4], vec![]);
+    let (index, err) = miden::verify_batch(batch).unwrap_err();
+    assert_eq!(1, index);
+    assert!(matches!(err, VerificationError::VerifierError(_)));
+}
+
+#[test]
+fn execution_proof_streaming() {
+    let program = build_test!("begin push.3 push.4 mul end").compile();
+    let (_, proof) = prove(
+        &program,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+
+    // the streamed proof is identical to the in-memory serialization
+    let mut streamed = Vec::new();
+    proof.write_into(&mut streamed).unwrap();
+    assert_eq!(proof.to_bytes(), streamed);
+
+    // bytes following the proof are left in the reader
+    streamed.extend_from_slice(&[1, 2, 3]);
+    let mut reader = streamed.as_slice();
+    assert_eq!(proof, ExecutionProof::read_from(&mut reader).unwrap());
+    assert_eq!(&[1, 2, 3], reader);
+
+    // truncated proofs are rejected
+    let bytes = proof.to_bytes();
+    let mut reader = &bytes[..bytes.len() - 1];
+    assert!(ExecutionProof::read_from(&mut reader).is_err());
+}
+
+#[test]
+fn execution_proof_versions() {
+    let program = build_test!("begin push.3 push.4 mul end").compile();
+    let (stack_outputs, proof) = prove(
+        &program,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+    let program_info = ProgramInfo::from(program);
+    assert_eq!(miden::PROOF_FORMAT_VERSION, proof.version());
+    assert!(miden::supported_proof_versions().contains(&proof.version()));
+
+    // proofs written by earlier releases consist of the hash function and the STARK proof only
+    let bytes = proof.to_bytes();
+    let mut legacy = vec![proof.hash_fn() as u8];
+    legacy.extend(proof.stark_proof().to_bytes());
+    assert!(bytes.ends_with(&legacy));
+
+    let legacy_proof = ExecutionProof::from_bytes(&legacy).unwrap();
+    assert_eq!(0, legacy_proof.version());
+    assert_eq!(proof.stark_proof(), legacy_proof.stark_proof());
+    assert_eq!(legacy_proof, ExecutionProof::read_from(&mut legacy.as_slice()).unwrap());
+    assert_eq!(legacy_proof, ExecutionProof::from_bytes(&legacy_proof.to_bytes()).unwrap());
+    let result = miden::verify(program_info, StackInputs::default(), stack_outputs, legacy_proof);
+    assert!(result.is_ok());
+
+    // proofs of versions newer than the current one are rejected when they are deserialized
+    let index = bytes.len() - legacy.len() - 1;
+    let mut future = bytes.clone();
+    future[index] = miden::PROOF_FORMAT_VERSION + 1;
+    let expected = "proof format version 2 is not supported; supported versions: 0..=1";
+    for result in [
+        ExecutionProof::from_bytes(&future),
+        ExecutionProof::read_from(&mut future.as_slice()),
+    ] {
+        let expected = miden::DeserializationError::InvalidValue(expected.to_string());
+        assert_eq!(Err(expected), result);
+    }
+
+    // the verifier reports the versions it supports when rejecting a proof
+    let error = VerificationError::UnsupportedProofVersion {
+        found: miden::PROOF_FORMAT_VERSION + 1,
+        supported: miden::supported_proof_versions(),
+    };
+    assert_eq!(
+        "proof format version 2 is not supported by this verifier; supported versions: 0..=1",
+        error.to_string()
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn verify_with_cancellation() {
+    use core::{
+        cell::Cell,
+        sync::atomic::{AtomicBool, Ordering},
+    };
+
+    let program = build_test!("begin push.3 push.4 mul end").compile();
+    let (stack_outputs, proof) = prove(
+        &program,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+    let program_info = ProgramInfo::from(program);
+    let verify = |should_cancel: &dyn Fn() -> bool| {
+        miden::verify_with_cancellation(
+            program_info.clone(),
+            StackInputs::default(),
+            stack_outputs.clone(),
+            proof.clone(),
+            should_cancel,
+        )
+    };
+
+    // proofs are verified as usual unless verification is cancelled
+    let cancelled = AtomicBool::new(false);
+    let expected = miden::verify(
+        program_info.clone(),
+        StackInputs::default(),
+        stack_outputs.clone(),
+        proof.clone(),
+    );
+    assert_eq!(expected, verify(&|| cancelled.load(Ordering::Relaxed)));
+
+    cancelled.store(true, Ordering::Relaxed);
+    assert_eq!(Err(VerificationError::Cancelled), verify(&|| cancelled.load(Ordering::Relaxed)));
+
+    // the cancellation check is polled while the STARK proof is verified, at least once per FRI
+    // layer and once more when the query positions are drawn
+    let num_polls = Cell::new(0);
+    let result = verify(&|| {
+        num_polls.set(num_polls.get() + 1);
+        false
+    });
+    assert_eq!(expected, result);
+    let total_polls = num_polls.get();
+    let stark_proof = proof.stark_proof();
+    let num_fri_layers = stark_proof
+        .options()
+        .to_fri_options()
+        .num_fri_layers(stark_proof.lde_domain_size());
+    assert!(num_fri_layers > 0);
+    assert!(total_polls >= 2 + num_fri_layers);
+
+    // cancellation in the middle of verifying the STARK proof (i.e., when the last FRI layer is
+    // folded, or when the query positions are drawn) aborts verification at this point
+    for cancel_at in [total_polls - 1, total_polls] {
+        num_polls.set(0);
+        let result = verify(&|| {
+            num_polls.set(num_polls.get() + 1);
+            num_polls.get() >= cancel_at
+        });
+        assert_eq!(Err(VerificationError::Cancelled), result);
+        assert_eq!(cancel_at, num_polls.get());
+    }
+}
+
+#[test]
+fn prove_with_empty_inputs() {
+    let program = miden::Assembler::default().compile("begin push.3 push.5 add end").unwrap();
+    let options = ProofOptions::with_96_bit_security(false);
+
+    let advice_provider = MemAdviceProvider::from(AdviceInputs::empty());
+    let (outputs, proof) = prove(&program, StackInputs::empty(), advice_provider, options).unwrap();
+    assert!(outputs.matches(&[8]));
+
+    let program_info = ProgramInfo::from(program);
+    miden::verify(program_info, StackInputs::empty(), outputs, proof).unwrap();
+}
+
+#[test]
+fn program_complexity() {
+    let programs = [
+        "begin push.1 push.2 add end",
+        "begin repeat.40 push.1 add end end",
+        "begin push.1 while.true push.0 end push.1 if.true hperm else push.1 end end",
+        "proc.foo push.7 mul end begin call.foo push.1 while.true exec.foo push.0 end end",
+    ];
+
+    for source in programs {
+        let program = build_test!(source).compile();
+        let complexity = program.complexity();
+        let trace =
+            miden::execute(&program, StackInputs::default(), MemAdviceProvider::default()).unwrap();
+
+        // every loop in the programs above is executed exactly once
+        assert!(complexity.estimated_cycles() <= trace.cycle_count() as usize, "{source}");
+        assert!(complexity.estimated_trace_len() <= trace.get_trace_len(), "{source}");
+    }
+
+    let program = build_test!(programs[2]).compile();
+    let complexity = program.complexity();
+    assert_eq!(1, complexity.max_loop_depth());
+    assert_eq!(1, complexity.num_hash_operations());
+}
+
+#[test]
+fn optimized_programs_are_equivalent() {
+    use miden::Assembler;
+    use stdlib::StdLibrary;
+
+    let programs = [
+        "begin push.2 push.3 add push.4 mul push.5 sub end",
+        "begin push.7 drop push.0 eq.0 push.1 and push.0 or not swap swap end",
+        "begin push.3 neg push.5 add push.4 inv mul push.9 eq end",
+        "begin dup.3 drop movup.2 movdn.2 swapw swapw mul push.1 push.1 add add end",
+        "begin push.1 push.2 push.3 push.4 swapdw swapdw add add add end",
+        "begin push.0 inv end",
+        "begin push.5 push.3 if.true push.2 add else push.4 mul end push.6 drop end",
+        "begin push.3 while.true push.1 sub dup push.0 neq end push.10 push.20 add end",
+        "begin repeat.4 push.1 add push.2 drop end end",
+        "proc.foo.2 push.3 push.4 add loc_store.0 loc_load.0 push.1 drop end
+        begin exec.foo push.8 swap drop end",
+        "begin push.1.2 mem_store mem_load.1 push.0 mem_load add end",
+        "use.std::math::u64 begin push.0.5 push.0.7 exec.u64::checked_add push.3 push.4 add end",
+    ];
+
+    for source in programs {
+        let unoptimized = Assembler::default()
+            .with_library(&StdLibrary::default())
+            .unwrap()
+            .compile(source);
+        let optimized = Assembler::default()
+            .with_optimizations(true)
+            .with_library(&StdLibrary::default())
+            .unwrap()
+            .compile(source);
+        let (unoptimized, optimized) = (unoptimized.unwrap(), optimized.unwrap());
+
+        let stack_inputs = StackInputs::try_from_values([1, 2, 3, 4, 5, 6]).unwrap();
+        let expected =
+            miden::execute(&unoptimized, stack_inputs.clone(), MemAdviceProvider::default());
+        let actual = miden::execute(&optimized, stack_inputs, MemAdviceProvider::default());
+        match (expected, actual) {
+            (Ok(expected), Ok(actual)) => {
+                assert_eq!(
+                    expected.stack_outputs().stack(),
+                    actual.stack_outputs().stack(),
+                    "{source}"
+                );
+                assert!(actual.cycle_count() <= expected.cycle_count(), "{source}");
+            }
+            (Err(expected), Err(actual)) => {
+                assert_eq!(expected.to_string(), actual.to_string(), "{source}")
+            }
+            (expected, actual) => {
+                panic!("{source}: {:?} vs {:?}", expected.err(), actual.err())
+            }
+        }
+    }
+
+    // optimized programs execute fewer cycles, and thus, clk pushes smaller values onto the stack
+    let source = "begin push.2 push.3 add clk end";
+    let unoptimized = Assembler::default().compile(source).unwrap();
+    let optimized = Assembler::default().with_optimizations(true).compile(source).unwrap();
+    let expected =
+        miden::execute(&unoptimized, StackInputs::default(), MemAdviceProvider::default()).unwrap();
+    let actual =
+        miden::execute(&optimized, StackInputs::default(), MemAdviceProvider::default()).unwrap();
+    assert_eq!([4, 5], expected.stack_outputs().stack()[..2]);
+    assert_eq!([2, 5], actual.stack_outputs().stack()[..2]);
+
+    // constant folding shortens the execution trace, and optimized programs can be proven
+    let source = "begin repeat.10 push.2 push.3 add push.4 mul drop end end";
+    let unoptimized = Assembler::default().compile(source).unwrap();
+    let optimized = Assembler::default().with_optimizations(true).compile(source).unwrap();
+    let expected_cycles =
+        miden::execute(&unoptimized, StackInputs::default(), MemAdviceProvider::default())
+            .unwrap()
+            .cycle_count();
+    let (stack_outputs, proof) = prove(
+        &optimized,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+    let trace =
+        miden::execute(&optimized, StackInputs::default(), MemAdviceProvider::default()).unwrap();
+    assert!(trace.cycle_count() < expected_cycles);
+    let program_info = ProgramInfo::from(optimized);
+    miden::verify(program_info, StackInputs::default(), stack_outputs, proof).unwrap();
+}
+
+#[test]
+fn execute_with_chained_provider() {
+    use miden::crypto::{MerkleStore, MerkleTree};
+    use miden::ChainedProvider;
+
+    let leaves: Vec<[Felt; 4]> = (0..4).map(|i| [Felt::new(i + 10), ZERO, ZERO, ZERO]).collect();
+    let tree = MerkleTree::new(leaves.clone()).unwrap();
+    let root = tree.root();
+
+    // the advice stack is served by the primary provider and the Merkle tree by the fallback
+    let primary = MemAdviceProvider::from(AdviceInputs::default().with_stack([Felt::new(42)]));
+    let fallback = MemAdviceProvider::from(
+        AdviceInputs::default().with_merkle_store(MerkleStore::from(&tree)),
+    );
+    let program = build_test!("begin mtree_get adv_push.1 end").compile();
+    let stack_inputs =
+        StackInputs::new(vec![root[0], root[1], root[2], root[3], Felt::new(2), Felt::new(2)]);
+
+    let trace =
+        miden::execute(&program, stack_inputs.clone(), ChainedProvider::new(primary, fallback))
+            .unwrap();
+    let stack = trace.stack_outputs().stack_truncated(5);
+    assert_eq!(vec![42, 0, 0, 0, 12], stack.to_vec());
+
+    // without the fallback provider, the tree cannot be found
+    let primary = MemAdviceProvider::from(AdviceInputs::default().with_stack([Felt::new(42)]));
+    let result = miden::execute(&program, stack_inputs, primary);
+    assert!(matches!(
+        result,
+        Err(ExecutionError::MerklePathNotFound { root, index })
+            if root == tree.root() && index.depth() == 2 && index.value() == 2
+    ));
+}
+
+#[test]
+fn advice_inputs_json_roundtrip() {
+    use miden::crypto::{MerkleStore, MerkleTree, RpoDigest};
+    use miden::utils::{digest_to_hex, Serializable};
+
+    let leaves = [[ONE, ZERO, ZERO, ZERO], [ZERO, ONE, ZERO, ZERO]];
+    let tree = MerkleTree::new(leaves.to_vec()).unwrap();
+    let key = RpoDigest::from([Felt::new(7), ZERO, ZERO, ZERO]);
+    let mut store = MerkleStore::default();
+    store.extend(tree.inner_nodes());
+    let advice_inputs = AdviceInputs::default()
+        .with_stack_values([1, 2, 3])
+        .unwrap()
+        .with_map([(key.as_bytes(), vec![Felt::new(4), Felt::new(5)])])
+        .with_merkle_store(store);
+
+    let json = serde_json::to_string(&advice_inputs).unwrap();
+    let decoded: AdviceInputs = serde_json::from_str(&json).unwrap();
+    assert_eq!(advice_inputs.to_bytes(), decoded.to_bytes());
+
+    // Merkle trees can be described by their depth and leaves
+    let json = format!(
+        r#"{{
+            "stack": ["1", "2", "3"],
+            "map": {{ "{}": ["4", "5"] }},
+            "merkle_trees": [{{ "depth": 1, "leaves": ["{}", "{}"] }}]
+        }}"#,
+        digest_to_hex(&key),
+        digest_to_hex(&leaves[0].into()),
+        digest_to_hex(&leaves[1].into()),
+    );
+    let decoded: AdviceInputs = serde_json::from_str(&json).unwrap();
+    assert_eq!(advice_inputs.to_bytes(), decoded.to_bytes());
+
+    // the number of leaves must match the depth of the tree, and values must be field elements
+    let json = json.replace(r#""depth": 1"#, r#""depth": 2"#);
+    assert!(serde_json::from_str::<AdviceInputs>(&json).is_err());
+    let json = format!(r#"{{ "stack": ["{}"] }}"#, Felt::MODULUS);
+    assert!(serde_json::from_str::<AdviceInputs>(&json).is_err());
+
+    // stack inputs are encoded as decimal strings with the top of the stack last
+    let stack_inputs = StackInputs::try_from_values([1, 2, 3]).unwrap();
+    let json = serde_json::to_string(&stack_inputs).unwrap();
+    assert_eq!(r#"["1","2","3"]"#, json);
+    let decoded: StackInputs = serde_json::from_str(&json).unwrap();
+    assert_eq!(stack_inputs.values(), decoded.values());
+}
+
+#[test]
+fn prove_empty_program() {
+    let program = miden::Program::new_empty();
+    assert_eq!(
+        "f0db3924f3e2d677a51924b09ecef8a12416a6ceb09fadd39785bb4f685cab66",
+        program.hash_hex()
+    );
+
+    let trace =
+        miden::execute(&program, StackInputs::empty(), MemAdviceProvider::default()).unwrap();
+    assert_eq!(1024, trace.get_trace_len());
+
+    let (stack_outputs, proof) = prove(
+        &program,
+        StackInputs::empty(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(&[0; STACK_TOP_SIZE], stack_outputs.stack());
+    miden::verify(program.into(), StackInputs::empty(), stack_outputs, proof).unwrap();
+}
@@ -1,8 +1,9 @@
+use miden::InputError;
 use test_utils::{
-    build_expected_hash, build_expected_perm, build_op_test,
-    crypto::{init_merkle_leaf, init_merkle_store, MerkleTree, NodeIndex},
+    build_expected_hash, build_expected_perm, build_op_test, build_test,
+    crypto::{init_merkle_leaf, init_merkle_leaves, init_merkle_store, MerkleTree, NodeIndex},
     rand::rand_vector,
-    Felt, StarkField,
+    AdviceInputs, Felt, StarkField, Word,
 };
 
 // TESTS
@@ -133,6 +134,114 @@ fn mtree_get() {
     test.expect_stack(&final_stack);
 }
 
+#[test]
+fn mtree_get_from_path_set() {
+    let asm_op = "mtree_get";
+
+    let index = 3usize;
+    let leaves = init_merkle_leaves(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let tree = MerkleTree::new(leaves.clone()).unwrap();
+    let path_for = |i: usize| {
+        let path = tree.get_path(NodeIndex::new(tree.depth(), i as u64).unwrap()).unwrap();
+        (i as u64, leaves[i], path.iter().map(|&node| node.into()).collect::<Vec<Word>>())
+    };
+
+    let stack_inputs = [
+        tree.root()[0].as_int(),
+        tree.root()[1].as_int(),
+        tree.root()[2].as_int(),
+        tree.root()[3].as_int(),
+        index as u64,
+        tree.depth() as u64,
+    ];
+
+    let final_stack = [
+        leaves[index][3].as_int(),
+        leaves[index][2].as_int(),
+        leaves[index][1].as_int(),
+        leaves[index][0].as_int(),
+        tree.root()[3].as_int(),
+        tree.root()[2].as_int(),
+        tree.root()[1].as_int(),
+        tree.root()[0].as_int(),
+    ];
+
+    let mut test = build_op_test!(asm_op, &stack_inputs);
+    test.advice_inputs = AdviceInputs::default()
+        .with_merkle_path_set(tree.depth(), vec![path_for(index), path_for(6)])
+        .unwrap();
+    assert!(test.advice_inputs.contains_merkle_root(tree.root().into()));
+    assert!(!test.advice_inputs.contains_merkle_root(leaves[0]));
+
+    // only the nodes on the provided paths (and their siblings) can be resolved
+    let depth = tree.depth();
+    let node = test.advice_inputs.get_merkle_node(tree.root().into(), depth, 3).unwrap();
+    assert_eq!(leaves[index], node);
+    assert!(test.advice_inputs.contains_merkle_node(tree.root().into(), depth, 2));
+    assert!(test.advice_inputs.contains_merkle_node(tree.root().into(), depth, 7));
+    assert!(!test.advice_inputs.contains_merkle_node(tree.root().into(), depth, 0));
+    assert!(!test.advice_inputs.contains_merkle_node(tree.root().into(), depth, 8));
+    assert!(!test.advice_inputs.contains_merkle_node(leaves[0], depth, 3));
+    let node = test.advice_inputs.get_merkle_node(tree.root().into(), 1, 1).unwrap();
+    assert_eq!(Word::from(tree.get_node(NodeIndex::new(1, 1).unwrap()).unwrap()), node);
+    test.expect_stack(&final_stack);
+
+    // paths which resolve to different roots should be rejected
+    let (i, _, path) = path_for(6);
+    let result = AdviceInputs::default()
+        .with_merkle_path_set(tree.depth(), vec![path_for(index), (i, init_merkle_leaf(9), path)]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn mtree_get_after_leaf_update() {
+    let asm_op = "mtree_get";
+
+    let index = 5usize;
+    let (mut leaves, store) = init_merkle_store(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let tree = MerkleTree::new(leaves.clone()).unwrap();
+
+    let mut advice_inputs = AdviceInputs::default().with_merkle_store(store);
+    leaves[index] = init_merkle_leaf(9);
+    let new_root = advice_inputs
+        .update_merkle_leaf(tree.root().into(), tree.depth(), index as u64, leaves[index])
+        .unwrap();
+    let expected_root: Word = MerkleTree::new(leaves.clone()).unwrap().root().into();
+    assert_eq!(expected_root, new_root);
+
+    // out of range indexes and unknown roots should be rejected
+    assert!(advice_inputs
+        .update_merkle_leaf(new_root, tree.depth(), 8, leaves[index])
+        .is_err());
+    assert!(advice_inputs
+        .update_merkle_leaf(leaves[0], tree.depth(), 1, leaves[index])
+        .is_err());
+
+    let stack_inputs = [
+        new_root[0].as_int(),
+        new_root[1].as_int(),
+        new_root[2].as_int(),
+        new_root[3].as_int(),
+        index as u64,
+        tree.depth() as u64,
+    ];
+
+    let final_stack = [
+        leaves[index][3].as_int(),
+        leaves[index][2].as_int(),
+        leaves[index][1].as_int(),
+        leaves[index][0].as_int(),
+        new_root[3].as_int(),
+        new_root[2].as_int(),
+        new_root[1].as_int(),
+        new_root[0].as_int(),
+    ];
+
+    let mut test = build_op_test!(asm_op, &stack_inputs);
+    test.advice_inputs = advice_inputs;
+    test.expect_stack(&final_stack);
+}
+
 #[test]
 fn mtree_verify() {
     let asm_op = "mtree_verify";
@@ -258,3 +367,42 @@ fn mtree_update() {
     let test = build_op_test!(asm_op, &stack_inputs, &[], store.clone());
     test.expect_stack(&final_stack);
 }
+
+#[test]
+fn validate_advice_inputs_for_program() {
+    let (leaves, store) = init_merkle_store(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let tree = MerkleTree::new(leaves).unwrap();
+    let root = tree.root();
+    let source = format!(
+        "begin push.{}.{}.{}.{} push.3.{} mtree_get adv_push.2 end",
+        root[0],
+        root[1],
+        root[2],
+        root[3],
+        tree.depth()
+    );
+    let program = build_test!(&source).compile();
+
+    let advice_inputs = AdviceInputs::default().with_merkle_store(store).with_stack_values([1, 2]);
+    assert!(advice_inputs.unwrap().validate_for(&program).is_ok());
+
+    // the Merkle tree referenced by the program is missing
+    let advice_inputs = AdviceInputs::default().with_stack_values([1, 2]).unwrap();
+    let root_bytes: [u8; 32] = root.into();
+    assert!(matches!(
+        advice_inputs.validate_for(&program),
+        Err(InputError::MerkleRootNotFound(r)) if r == root_bytes
+    ));
+
+    // the advice stack is too short
+    let program = build_test!("begin adv_push.2 adv_loadw push.1 end").compile();
+    let advice_inputs = AdviceInputs::default().with_stack_values([1, 2, 3]).unwrap();
+    assert!(matches!(
+        advice_inputs.validate_for(&program),
+        Err(InputError::NotEnoughAdviceStackValues(6, 3))
+    ));
+
+    // requirements of conditionally executed code are not checked
+    let program = build_test!("begin push.1 if.true adv_push.2 end end").compile();
+    assert!(AdviceInputs::default().validate_for(&program).is_ok());
+}
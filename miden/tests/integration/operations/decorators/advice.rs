@@ -1,4 +1,4 @@
-use test_utils::{build_test, crypto::MerkleStore, rand::rand_value, Felt};
+use test_utils::{build_test, crypto::MerkleStore, rand::rand_value, Felt, TestError};
 
 // ADVICE INJECTION
 // ================================================================================================
@@ -338,3 +338,37 @@ fn key_to_bytes(key: [u64; 4]) -> [u8; 32] {
 
     result
 }
+
+#[test]
+fn advice_insert_mem_address_out_of_bounds() {
+    // the start address of the memory range exceeds 2^32
+    let source = "begin adv.insert_mem end";
+    let stack_inputs = [5, 1 << 32, 0, 0, 0, 0];
+    let expected_err =
+        TestError::ExecutionError("MemoryAddressOutOfBounds { clk: 1, addr: 4294967296 }");
+    build_test!(source, &stack_inputs).expect_error(expected_err);
+}
+
+#[test]
+fn advice_push_mapval_word_map() {
+    use test_utils::{AdviceInputs, ExecutionError, MemAdviceProvider, StackInputs};
+
+    // values are looked up by the word at the top of the stack
+    let source = "begin push.1.2.3.4 adv.push_mapval dropw adv_push.3 end";
+    let key = [1, 2, 3, 4].map(Felt::new);
+    let values = vec![Felt::new(7), Felt::new(8), Felt::new(9)];
+    let program = build_test!(source).compile();
+
+    let advice_inputs = AdviceInputs::default()
+        .with_word_map([(key, values.clone()), ([Felt::new(5); 4], vec![Felt::new(6)])]);
+    let trace =
+        miden::execute(&program, StackInputs::default(), MemAdviceProvider::from(advice_inputs))
+            .unwrap();
+    assert_eq!(&[9, 8, 7], &trace.stack_outputs().stack()[..3]);
+
+    // missing keys are reported with the key read from the stack
+    let advice_inputs = AdviceInputs::default().with_word_map([([Felt::new(5); 4], values)]);
+    let result =
+        miden::execute(&program, StackInputs::default(), MemAdviceProvider::from(advice_inputs));
+    assert!(matches!(result, Err(ExecutionError::AdviceKeyNotFound(k)) if k == key));
+}
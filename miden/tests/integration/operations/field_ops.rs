@@ -22,6 +22,23 @@ fn assert_fail() {
     test.expect_error(TestError::ExecutionError("FailedAssertion"));
 }
 
+#[test]
+fn assert_with_code() {
+    let asm_op = "assert.123";
+
+    let test = build_op_test!(asm_op, &[1]);
+    test.expect_stack(&[]);
+
+    let test = build_op_test!(asm_op, &[2]);
+    let err = test.execute().err().unwrap();
+    assert_eq!("Assertion failed at clock cycle 1 with error code 123", err.to_string());
+
+    // an error code of zero is the same as no error code
+    let test = build_op_test!("assert.0", &[2]);
+    let err = test.execute().err().unwrap();
+    assert_eq!("Assertion failed at clock cycle 1", err.to_string());
+}
+
 #[test]
 fn assert_eq() {
     let asm_op = "assert_eq";
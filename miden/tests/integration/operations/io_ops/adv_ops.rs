@@ -1,4 +1,5 @@
 use super::{build_op_test, build_test, TestError};
+use test_utils::{AdviceInputs, ExecutionError};
 use vm_core::{chiplets::hasher::apply_permutation, utils::ToElements, Felt, StarkField};
 
 // PUSHING VALUES ONTO THE STACK (PUSH)
@@ -32,6 +33,31 @@ fn adv_push_invalid() {
     test.expect_error(TestError::ExecutionError("AdviceStackReadFailed"));
 }
 
+#[test]
+fn adv_push_named_tape() {
+    // values are read from each tape in order, independently of other tapes and the advice stack
+    let source = "begin adv_push.a adv_push.b adv_push.1 adv_push.a adv_push.b end";
+    let mut test = build_test!(source);
+    test.advice_inputs = AdviceInputs::default()
+        .with_stack_values([7])
+        .unwrap()
+        .with_named_tape("a", vec![Felt::new(1), Felt::new(2)])
+        .with_named_tape("b", vec![Felt::new(3), Felt::new(4)]);
+    test.expect_stack(&[4, 2, 7, 3, 1]);
+}
+
+#[test]
+fn adv_push_named_tape_invalid() {
+    // attempting to read from a tape which was not provided should throw an error
+    let test = build_test!("begin adv_push.a end");
+    test.expect_error(TestError::ExecutionError("AdviceKeyNotFound"));
+
+    // attempting to read past the end of a tape should throw an error
+    let mut test = build_test!("begin adv_push.a adv_push.a end");
+    test.advice_inputs = AdviceInputs::default().with_named_tape("a", vec![Felt::new(1)]);
+    test.expect_error(TestError::ExecutionError("AdviceTapeExhausted"));
+}
+
 // OVERWRITING VALUES ON THE STACK (LOAD)
 // ================================================================================================
 
@@ -53,6 +79,21 @@ fn adv_loadw_invalid() {
     test.expect_error(TestError::ExecutionError("AdviceStackReadFailed"));
 }
 
+#[test]
+fn adv_loadw_insufficient_values() {
+    // the error should report how many values were requested and how many were available
+    let test = build_op_test!("adv_loadw", &[0, 0, 0, 0], &[1, 2]);
+    let result = test.execute();
+    assert!(matches!(
+        result,
+        Err(ExecutionError::AdviceStackReadFailed {
+            requested: 4,
+            available: 2,
+            ..
+        })
+    ));
+}
+
 // MOVING ELEMENTS TO MEMORY VIA THE STACK (PIPE)
 // ================================================================================================
 
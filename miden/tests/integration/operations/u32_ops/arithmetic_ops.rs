@@ -1,4 +1,4 @@
-use super::{test_param_out_of_bounds, test_unchecked_execution};
+use super::{test_inputs_out_of_bounds, test_param_out_of_bounds, test_unchecked_execution};
 use test_utils::{build_op_test, proptest::prelude::*, rand::rand_value, TestError, U32_BOUND};
 
 // U32 OPERATIONS TESTS - MANUAL - ARITHMETIC OPERATIONS
@@ -201,6 +201,42 @@ fn u32overflowing_add() {
     test_unchecked_execution(asm_op, 2);
 }
 
+#[test]
+fn u32checked_overflowing_add() {
+    let asm_op = "u32checked_overflowing_add";
+
+    // --- no overflow ----------------------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[1, 2]);
+    test.expect_stack(&[0, 3]);
+
+    // --- wrap-around at the boundary ------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[u32::MAX as u64, 1]);
+    test.expect_stack(&[1, 0]);
+
+    let test = build_op_test!(asm_op, &[u32::MAX as u64, u32::MAX as u64]);
+    test.expect_stack(&[1, u32::MAX as u64 - 1]);
+
+    // --- immediate value ------------------------------------------------------------------------
+    let test = build_op_test!("u32checked_overflowing_add.1", &[u32::MAX as u64]);
+    test.expect_stack(&[1, 0]);
+
+    let test = build_op_test!("u32checked_overflowing_add.0", &[u32::MAX as u64]);
+    test.expect_stack(&[0, u32::MAX as u64]);
+
+    // --- random u32 values ----------------------------------------------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let (c, overflow) = a.overflowing_add(b);
+    let test = build_op_test!(asm_op, &[a as u64, b as u64]);
+    test.expect_stack(&[overflow as u64, c as u64]);
+
+    // should fail when inputs are out of bounds.
+    test_inputs_out_of_bounds(asm_op, 2);
+    let test = build_op_test!("u32checked_overflowing_add.1", &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+    test_param_out_of_bounds(asm_op, U32_BOUND);
+}
+
 #[test]
 fn u32overflowing_add3() {
     let asm_op = "u32overflowing_add3";
@@ -465,6 +501,40 @@ fn u32overflowing_sub() {
     test_unchecked_execution(asm_op, 2);
 }
 
+#[test]
+fn u32checked_overflowing_sub() {
+    let asm_op = "u32checked_overflowing_sub";
+
+    // --- no underflow ---------------------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[3, 2]);
+    test.expect_stack(&[0, 1]);
+
+    let test = build_op_test!(asm_op, &[u32::MAX as u64, u32::MAX as u64]);
+    test.expect_stack(&[0, 0]);
+
+    // --- wrap-around at the boundary ------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[0, 1]);
+    test.expect_stack(&[1, u32::MAX as u64]);
+
+    let test = build_op_test!(asm_op, &[0, u32::MAX as u64]);
+    test.expect_stack(&[1, 1]);
+
+    // --- immediate value ------------------------------------------------------------------------
+    let test = build_op_test!("u32checked_overflowing_sub.1", &[0]);
+    test.expect_stack(&[1, u32::MAX as u64]);
+
+    // --- random u32 values ----------------------------------------------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let (c, underflow) = a.overflowing_sub(b);
+    let test = build_op_test!(asm_op, &[a as u64, b as u64]);
+    test.expect_stack(&[underflow as u64, c as u64]);
+
+    // should fail when inputs are out of bounds.
+    test_inputs_out_of_bounds(asm_op, 2);
+    test_param_out_of_bounds(asm_op, U32_BOUND);
+}
+
 #[test]
 fn u32checked_mul() {
     let asm_op = "u32checked_mul";
@@ -661,6 +731,38 @@ fn u32overflowing_mul() {
     test_unchecked_execution(asm_op, 2);
 }
 
+#[test]
+fn u32checked_overflowing_mul() {
+    let asm_op = "u32checked_overflowing_mul";
+
+    // --- no overflow ----------------------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[3, 2]);
+    test.expect_stack(&[0, 6]);
+
+    // --- wrap-around at the boundary ------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[1 << 16, 1 << 16]);
+    test.expect_stack(&[1, 0]);
+
+    // (2^32 - 1)^2 = (2^32 - 2) * 2^32 + 1
+    let test = build_op_test!(asm_op, &[u32::MAX as u64, u32::MAX as u64]);
+    test.expect_stack(&[u32::MAX as u64 - 1, 1]);
+
+    // --- immediate value ------------------------------------------------------------------------
+    let test = build_op_test!("u32checked_overflowing_mul.2", &[u32::MAX as u64]);
+    test.expect_stack(&[1, u32::MAX as u64 - 1]);
+
+    // --- random u32 values ----------------------------------------------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let product = a as u64 * b as u64;
+    let test = build_op_test!(asm_op, &[a as u64, b as u64]);
+    test.expect_stack(&[product >> 32, product & u32::MAX as u64]);
+
+    // should fail when inputs are out of bounds.
+    test_inputs_out_of_bounds(asm_op, 2);
+    test_param_out_of_bounds(asm_op, U32_BOUND);
+}
+
 #[test]
 fn u32overflowing_madd() {
     let asm_op = "u32overflowing_madd";
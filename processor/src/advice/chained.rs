@@ -0,0 +1,313 @@
+use super::{
+    AdviceProvider, AdviceSource, ExecutionError, Felt, MerklePath, StarkField, Vec, Word,
+};
+use vm_core::crypto::merkle::MerkleError;
+
+// CHAINED ADVICE PROVIDER
+// ================================================================================================
+
+/// An [AdviceProvider] which serves advice from a primary provider, and falls back onto a
+/// secondary provider for data which the primary provider does not contain.
+///
+/// This can be used, for example, to combine a small in-memory cache of frequently accessed data
+/// (the primary provider) with a larger store which is slower to access (the fallback provider).
+///
+/// A request is first sent to the primary provider, and is forwarded to the fallback provider
+/// only if the primary provider fails with a miss, i.e.:
+/// - [ExecutionError::AdviceKeyNotFound] for advice map lookups.
+/// - [ExecutionError::MerklePathNotFound] for Merkle store lookups.
+/// - [ExecutionError::MerkleStoreLookupFailed], [ExecutionError::MerkleStoreUpdateFailed], or
+///   [ExecutionError::MerkleStoreMergeFailed] caused by a Merkle root or node which is not
+///   present in the Merkle store.
+///
+/// All other errors of the primary provider (e.g., an invalid node index) are returned as is,
+/// without querying the fallback provider. If the fallback provider fails as well, the error of
+/// the fallback provider is returned.
+///
+/// The semantics of individual operations are as follows:
+/// - The advice stack of the primary provider is the only advice stack; elements are popped from
+///   and pushed onto the primary provider, and the advice stack of the fallback provider is never
+///   read. Similarly, new advice map entries are inserted into the primary provider only.
+/// - When the values for an advice map key are not found in the primary provider, they are read
+///   from the fallback provider and copied into the advice map of the primary provider before
+///   the request is retried. Thus, subsequent reads of the same key (including reads of the
+///   values as an advice tape) are served by the primary provider.
+/// - Merkle store reads which miss the primary provider are served by the fallback provider.
+///   Updates and merges of trees which are not present in the primary provider are applied to the
+///   fallback provider, and thus, the resulting trees are stored in the fallback provider. A merge
+///   of two roots which are located in different providers fails.
+#[derive(Debug, Clone, Default)]
+pub struct ChainedProvider<P, F>
+where
+    P: AdviceProvider,
+    F: AdviceProvider,
+{
+    primary: P,
+    fallback: F,
+}
+
+impl<P, F> ChainedProvider<P, F>
+where
+    P: AdviceProvider,
+    F: AdviceProvider,
+{
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [ChainedProvider] which queries the `primary` provider first, and the
+    /// `fallback` provider on a miss.
+    pub fn new(primary: P, fallback: F) -> Self {
+        Self { primary, fallback }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a reference to the primary provider.
+    pub fn primary(&self) -> &P {
+        &self.primary
+    }
+
+    /// Returns a reference to the fallback provider.
+    pub fn fallback(&self) -> &F {
+        &self.fallback
+    }
+
+    /// Decomposes this provider into the primary and the fallback providers.
+    pub fn into_parts(self) -> (P, F) {
+        (self.primary, self.fallback)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Copies the values stored under the specified key in the advice map of the fallback
+    /// provider into the advice map of the primary provider.
+    ///
+    /// The values are read by pushing them onto the advice stack of the fallback provider and
+    /// popping them right away; thus, the advice stack of the fallback provider is not changed.
+    fn copy_map_entry(&mut self, key: Word) -> Result<(), ExecutionError> {
+        self.fallback.push_stack(AdviceSource::Map {
+            key,
+            include_len: true,
+        })?;
+        let num_values = self.fallback.pop_stack()?.as_int() as usize;
+        let values = (0..num_values)
+            .map(|_| self.fallback.pop_stack())
+            .collect::<Result<Vec<_>, _>>()?;
+        self.primary.insert_into_map(key, values)
+    }
+}
+
+impl<P, F> AdviceProvider for ChainedProvider<P, F>
+where
+    P: AdviceProvider,
+    F: AdviceProvider,
+{
+    // ADVICE STACK
+    // --------------------------------------------------------------------------------------------
+
+    fn pop_stack(&mut self) -> Result<Felt, ExecutionError> {
+        self.primary.pop_stack()
+    }
+
+    fn pop_stack_word(&mut self) -> Result<Word, ExecutionError> {
+        self.primary.pop_stack_word()
+    }
+
+    fn pop_stack_dword(&mut self) -> Result<[Word; 2], ExecutionError> {
+        self.primary.pop_stack_dword()
+    }
+
+    fn push_stack(&mut self, source: AdviceSource) -> Result<(), ExecutionError> {
+        match self.primary.push_stack(source) {
+            Err(ExecutionError::AdviceKeyNotFound(key)) => {
+                self.copy_map_entry(key)?;
+                self.primary.push_stack(source)
+            }
+            result => result,
+        }
+    }
+
+    fn insert_into_map(&mut self, key: Word, values: Vec<Felt>) -> Result<(), ExecutionError> {
+        self.primary.insert_into_map(key, values)
+    }
+
+    // ADVISE SETS
+    // --------------------------------------------------------------------------------------------
+
+    fn get_tree_node(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<Word, ExecutionError> {
+        match self.primary.get_tree_node(root, depth, index) {
+            Err(err) if is_merkle_miss(&err) => self.fallback.get_tree_node(root, depth, index),
+            result => result,
+        }
+    }
+
+    fn get_merkle_path(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<MerklePath, ExecutionError> {
+        match self.primary.get_merkle_path(root, depth, index) {
+            Err(err) if is_merkle_miss(&err) => self.fallback.get_merkle_path(root, depth, index),
+            result => result,
+        }
+    }
+
+    fn get_leaf_depth(
+        &self,
+        root: Word,
+        tree_depth: &Felt,
+        index: &Felt,
+    ) -> Result<u8, ExecutionError> {
+        match self.primary.get_leaf_depth(root, tree_depth, index) {
+            Err(err) if is_merkle_miss(&err) => {
+                self.fallback.get_leaf_depth(root, tree_depth, index)
+            }
+            result => result,
+        }
+    }
+
+    fn update_merkle_node(
+        &mut self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+        value: Word,
+    ) -> Result<MerklePath, ExecutionError> {
+        match self.primary.update_merkle_node(root, depth, index, value) {
+            Err(err) if is_merkle_miss(&err) => {
+                self.fallback.update_merkle_node(root, depth, index, value)
+            }
+            result => result,
+        }
+    }
+
+    fn merge_roots(&mut self, lhs: Word, rhs: Word) -> Result<Word, ExecutionError> {
+        match self.primary.merge_roots(lhs, rhs) {
+            Err(err) if is_merkle_miss(&err) => self.fallback.merge_roots(lhs, rhs),
+            result => result,
+        }
+    }
+
+    // CONTEXT MANAGEMENT
+    // --------------------------------------------------------------------------------------------
+
+    fn advance_clock(&mut self) {
+        self.primary.advance_clock();
+        self.fallback.advance_clock();
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns true if the provided error was caused by a Merkle root or node which is not present in
+/// the Merkle store of an advice provider.
+fn is_merkle_miss(err: &ExecutionError) -> bool {
+    match err {
+        ExecutionError::MerklePathNotFound { .. } => true,
+        ExecutionError::MerkleStoreLookupFailed(err)
+        | ExecutionError::MerkleStoreUpdateFailed(err)
+        | ExecutionError::MerkleStoreMergeFailed(err) => {
+            matches!(err, MerkleError::RootNotInStore(_) | MerkleError::NodeNotInStore(..))
+        }
+        _ => false,
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{AdviceInputs, MemAdviceProvider},
+        AdviceProvider, AdviceSource, ChainedProvider, ExecutionError, Felt, Word,
+    };
+    use vm_core::{
+        crypto::merkle::{MerkleStore, MerkleTree},
+        utils::IntoBytes,
+        ONE, ZERO,
+    };
+
+    #[test]
+    fn chained_provider_map_fallback() {
+        let key: Word = [ONE, ZERO, ZERO, ZERO];
+        let values = vec![Felt::new(3), Felt::new(4)];
+        let primary = MemAdviceProvider::from(AdviceInputs::default().with_stack([Felt::new(9)]));
+        let fallback = MemAdviceProvider::from(
+            AdviceInputs::default()
+                .with_stack([Felt::new(8)])
+                .with_map([(key.into_bytes(), values.clone())]),
+        );
+        let mut provider = ChainedProvider::new(primary, fallback);
+
+        // values missing from the primary provider are read from the fallback provider and
+        // cached in the primary provider
+        provider
+            .push_stack(AdviceSource::Map {
+                key,
+                include_len: true,
+            })
+            .unwrap();
+        assert_eq!(
+            &[Felt::new(9), Felt::new(4), Felt::new(3), Felt::new(2)],
+            provider.primary().stack()
+        );
+        assert_eq!(Some(&values), provider.primary().map().get(&key.into_bytes()));
+        assert_eq!(&[Felt::new(8)], provider.fallback().stack());
+
+        provider.push_stack(AdviceSource::Tape { key }).unwrap();
+        provider.push_stack(AdviceSource::Tape { key }).unwrap();
+        assert_eq!(Felt::new(4), provider.pop_stack().unwrap());
+        assert_eq!(Felt::new(3), provider.pop_stack().unwrap());
+
+        // keys missing from both providers produce the error of the fallback provider
+        let missing_key: Word = [ZERO, ONE, ZERO, ZERO];
+        let result = provider.push_stack(AdviceSource::Map {
+            key: missing_key,
+            include_len: false,
+        });
+        assert!(matches!(result, Err(ExecutionError::AdviceKeyNotFound(k)) if k == missing_key));
+    }
+
+    #[test]
+    fn chained_provider_merkle_fallback() {
+        let leaves: Vec<Word> = (0..4).map(|i| [Felt::new(i); 4]).collect();
+        let tree = MerkleTree::new(leaves.clone()).unwrap();
+        let root: Word = tree.root().into();
+        let fallback = MemAdviceProvider::from(
+            AdviceInputs::default().with_merkle_store(MerkleStore::from(&tree)),
+        );
+        let mut provider = ChainedProvider::new(MemAdviceProvider::default(), fallback);
+
+        // nodes missing from the primary provider are read from the fallback provider
+        let depth = Felt::new(2);
+        assert_eq!(leaves[1], provider.get_tree_node(root, &depth, &ONE).unwrap());
+        assert_eq!(
+            tree.get_path(vm_core::crypto::merkle::NodeIndex::new(2, 1).unwrap()).unwrap(),
+            provider.get_merkle_path(root, &depth, &ONE).unwrap()
+        );
+
+        // updated trees are stored in the fallback provider
+        let new_value = [Felt::new(7); 4];
+        provider.update_merkle_node(root, &depth, &ONE, new_value).unwrap();
+        let mut new_tree = tree.clone();
+        new_tree.update_leaf(1, new_value).unwrap();
+        let new_root = new_tree.root();
+        assert!(!provider.primary().has_merkle_root(new_root));
+        assert!(provider.fallback().has_merkle_root(new_root));
+        assert_eq!(new_value, provider.get_tree_node(new_root.into(), &depth, &ONE).unwrap());
+
+        // errors which are not misses are not forwarded to the fallback provider
+        let result = provider.get_tree_node(root, &ZERO, &Felt::new(5));
+        assert!(matches!(result, Err(ExecutionError::InvalidTreeNodeIndex { .. })));
+    }
+}
@@ -1,4 +1,21 @@
-use super::{BTreeMap, Felt, InnerNodeInfo, InputError, MerkleStore, Vec};
+use super::{
+    advice_tape_key, AdviceInjector, BTreeMap, ByteReader, ByteWriter, CodeBlock, CodeBlockTable,
+    Decorator, Deserializable, DeserializationError, EmptySubtreeRoots, Felt, InnerNodeInfo,
+    InputError, IntoBytes, MerklePath, MerklePathSet, MerkleStore, NodeIndex, Operation, Program,
+    Rpo256, RpoDigest, Serializable, Span, StarkField, Vec, Word, ZERO,
+};
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "serde")]
+use vm_core::{
+    crypto::merkle::MerkleTree,
+    utils::{
+        digest_from_hex, digest_to_hex,
+        string::{String, ToString},
+        to_hex,
+    },
+};
 
 // ADVICE INPUTS
 // ================================================================================================
@@ -6,7 +23,8 @@ use super::{BTreeMap, Felt, InnerNodeInfo, InputError, MerkleStore, Vec};
 /// Inputs container to initialize advice provider for the execution of Miden VM programs.
 ///
 /// The program may request nondeterministic advice inputs from the prover. These inputs are secret
-/// inputs. This means that the prover does not need to share them with the verifier.
+/// inputs. This means that the prover does not need to share them with the verifier. Public inputs
+/// should be provided via [StackInputs](crate::StackInputs) instead.
 ///
 /// There are three types of advice inputs:
 ///
@@ -25,6 +43,14 @@ impl AdviceInputs {
     // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns advice inputs with an empty advice stack, an empty advice map, and a Merkle store
+    /// which contains only the roots of empty subtrees.
+    ///
+    /// This is the same as [AdviceInputs::default()].
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
     /// Attempts to extend the stack values with the given sequence of integers, returning an error
     /// if any of the numbers fails while converting to an element `[Felt]`.
     pub fn with_stack_values<I>(mut self, iter: I) -> Result<Self, InputError>
@@ -34,9 +60,14 @@ impl AdviceInputs {
         let stack = iter
             .into_iter()
             .map(|v| {
-                Felt::try_from(v).map_err(|_| {
-                    InputError::NotFieldElement(v, "the provided value isn't a valid field element")
-                })
+                if v < Felt::MODULUS {
+                    Ok(Felt::new(v))
+                } else {
+                    Err(InputError::NotFieldElement(
+                        v,
+                        "the provided value isn't a valid field element",
+                    ))
+                }
             })
             .collect::<Result<Vec<_>, _>>()?;
         self.stack.extend(stack);
@@ -61,12 +92,116 @@ impl AdviceInputs {
         self
     }
 
+    /// Extends the map of values with the given argument keyed by words, replacing previously
+    /// inserted items.
+    ///
+    /// This is equivalent to [AdviceInputs::with_map()] with each key converted into bytes. The
+    /// values under key `K` are pushed onto the advice stack by `adv.push_mapval` instruction
+    /// executed with `K` at the top of the operand stack (e.g., key `[a, b, c, d]` after
+    /// `push.a.b.c.d`); if no values are mapped to `K`, execution fails with
+    /// [ExecutionError::AdviceKeyNotFound](crate::ExecutionError::AdviceKeyNotFound).
+    pub fn with_word_map<I>(self, iter: I) -> Self
+    where
+        I: IntoIterator<Item = (Word, Vec<Felt>)>,
+    {
+        self.with_map(iter.into_iter().map(|(key, values)| (key.into_bytes(), values)))
+    }
+
+    /// Adds a named advice tape with the specified name and values, replacing a previously added
+    /// tape with the same name.
+    ///
+    /// Values of the tape can be read by the VM one at a time via `adv_push.<name>` instruction;
+    /// each tape has its own read cursor which is independent of the advice stack and of other
+    /// tapes. The tape is stored in the advice map under the key computed via
+    /// [advice_tape_key()](vm_core::advice_tape_key).
+    pub fn with_named_tape(mut self, name: &str, values: Vec<Felt>) -> Self {
+        self.map.insert(advice_tape_key(name).into_bytes(), values);
+        self
+    }
+
+    /// Adds a segment of words (e.g., static data of a program) to the advice map under the key
+    /// computed via [AdviceInputs::memory_segment_commitment()].
+    ///
+    /// The segment can be copied to memory via `std::mem::load_memory_segment` procedure, which
+    /// checks the words against the commitment; providing the commitment as a stack input binds
+    /// the proof of execution to the contents of the segment.
+    pub fn with_memory_segment(mut self, words: &[Word]) -> Self {
+        let key = Self::memory_segment_commitment(words);
+        self.map.insert(key.into_bytes(), words.iter().flatten().copied().collect());
+        self
+    }
+
     /// Replaces the [MerkleStore] with the provided argument.
     pub fn with_merkle_store(mut self, store: MerkleStore) -> Self {
         self.store = store;
         self
     }
 
+    /// Extends the [MerkleStore] with the nodes of a sparse Merkle tree of the specified `depth`
+    /// described by the provided `(index, leaf value, authentication path)` triples.
+    ///
+    /// Only the nodes on the provided paths are added to the store; the VM can query and update
+    /// the specified leaves via `mtree_get` and `mtree_set` instructions. Leaves which belong to
+    /// empty subtrees resolve to the empty subtree roots already present in the store.
+    ///
+    /// # Errors
+    /// Returns an error if any of the paths is not a valid path for a tree of the specified depth,
+    /// or if the paths disagree on a shared ancestor node (i.e., resolve to different roots).
+    pub fn with_merkle_path_set(
+        mut self,
+        depth: u8,
+        paths: Vec<(u64, Word, Vec<Word>)>,
+    ) -> Result<Self, InputError> {
+        let paths = paths.into_iter().map(|(index, value, path)| {
+            let path = path.into_iter().map(RpoDigest::from).collect::<Vec<_>>();
+            (index, RpoDigest::from(value), MerklePath::new(path))
+        });
+        let path_set = MerklePathSet::new(depth)
+            .with_paths(paths)
+            .map_err(InputError::InvalidMerklePathSet)?;
+        self.store
+            .add_merkle_path_set(&path_set)
+            .map_err(InputError::InvalidMerklePathSet)?;
+        Ok(self)
+    }
+
+    /// Combines these advice inputs with the `other` advice inputs.
+    ///
+    /// This allows independent components to provide fragments of advice inputs for the same
+    /// program. The fragments are combined as follows:
+    /// - Advice stack: the values of `other` are placed after the values of `self`; thus, the
+    ///   program reads all values provided by `self` before reading the values provided by `other`.
+    /// - Advice map: the result contains the entries of both maps. An entry present in both maps
+    ///   is kept only if the values are the same in both maps.
+    /// - Merkle store: the result contains the nodes of both stores. Since nodes are keyed by
+    ///   their hashes, the stores cannot conflict, and trees which share nodes (e.g., the same
+    ///   tree provided via both inputs) are stored only once.
+    ///
+    /// Named advice tapes and memory segments are stored in the advice map, and thus follow the
+    /// rules for the advice map.
+    ///
+    /// # Errors
+    /// Returns an error if the advice maps contain different values for the same key.
+    pub fn merge(mut self, other: Self) -> Result<Self, InputError> {
+        let Self { stack, map, store } = other;
+
+        for (key, values) in map {
+            match self.map.get(&key) {
+                Some(existing) if existing != &values => {
+                    return Err(InputError::ConflictingAdviceMapValues(key));
+                }
+                Some(_) => (),
+                None => {
+                    self.map.insert(key, values);
+                }
+            }
+        }
+        self.stack.extend(stack);
+        self.store.extend(store.inner_nodes());
+
+        Ok(self)
+    }
+
     // PUBLIC MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -94,6 +229,41 @@ impl AdviceInputs {
         self.store.extend(iter);
     }
 
+    /// Replaces the leaf at the specified `index` of the Merkle tree with the specified `root` and
+    /// `depth` with the provided `value`, and returns the root of the updated tree.
+    ///
+    /// Only the nodes on the path from the leaf to the root are recomputed; the nodes of the
+    /// original tree remain in the store.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The `index` is out of range for a tree of the specified `depth`.
+    /// - The tree with the specified `root` is not in the store, or the path to the specified leaf
+    ///   cannot be resolved from the store (e.g., if the tree has a different depth).
+    pub fn update_merkle_leaf(
+        &mut self,
+        root: Word,
+        depth: u8,
+        index: u64,
+        value: Word,
+    ) -> Result<Word, InputError> {
+        let index = NodeIndex::new(depth, index).map_err(InputError::MerkleStoreUpdateFailed)?;
+        self.store
+            .set_node(root.into(), index, value.into())
+            .map(|root_path| root_path.root.into())
+            .map_err(InputError::MerkleStoreUpdateFailed)
+    }
+
+    /// Returns the commitment to the specified segment of words, computed as the sequential RPO
+    /// hash of all elements of the segment.
+    ///
+    /// This is the commitment checked by `std::mem::load_memory_segment` procedure when the
+    /// segment is copied to memory.
+    pub fn memory_segment_commitment(words: &[Word]) -> Word {
+        let elements: Vec<Felt> = words.iter().flatten().copied().collect();
+        Rpo256::hash_elements(&elements).into()
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -112,6 +282,87 @@ impl AdviceInputs {
         &self.store
     }
 
+    /// Returns true if the [MerkleStore] contains a Merkle tree with the specified root.
+    ///
+    /// This can be used to check that a root passed to Merkle tree instructions (e.g.,
+    /// `mtree_get`) can be resolved by the advice provider before executing a program. Note that
+    /// the store does not track the depth of the trees it contains, and that the roots of empty
+    /// subtrees are always present in the store.
+    pub fn contains_merkle_root(&self, root: Word) -> bool {
+        self.store.get_node(root.into(), NodeIndex::root()).is_ok()
+    }
+
+    /// Returns true if the node at the specified `depth` and `index` of the Merkle tree with the
+    /// specified `root` can be resolved from the [MerkleStore].
+    ///
+    /// For trees added via [AdviceInputs::with_merkle_path_set()], this is the case only for the
+    /// nodes on the provided authentication paths (including their siblings); thus, a leaf which
+    /// is contained in the store can be read by the VM via `mtree_get` instruction.
+    pub fn contains_merkle_node(&self, root: Word, depth: u8, index: u64) -> bool {
+        self.get_merkle_node(root, depth, index).is_ok()
+    }
+
+    /// Returns the node at the specified `depth` and `index` of the Merkle tree with the specified
+    /// `root`.
+    ///
+    /// This returns the same value as would be returned by the VM for `mtree_get` instruction
+    /// executed with the same parameters.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The `index` is out of range for the specified `depth`.
+    /// - The node cannot be resolved from the store (e.g., if the tree with the specified `root`
+    ///   is not in the store, or if the node is not on any of the authentication paths provided
+    ///   for the tree).
+    pub fn get_merkle_node(&self, root: Word, depth: u8, index: u64) -> Result<Word, InputError> {
+        let index = NodeIndex::new(depth, index).map_err(InputError::MerkleStoreLookupFailed)?;
+        self.store
+            .get_node(root.into(), index)
+            .map(Word::from)
+            .map_err(InputError::MerkleStoreLookupFailed)
+    }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that these advice inputs satisfy the requirements of the specified program which can
+    /// be determined statically, before the program is executed.
+    ///
+    /// The check considers only the code which is guaranteed to be executed at the start of the
+    /// program (i.e., until the first conditional branch, loop, or syscall), and:
+    /// - Makes sure that the advice stack contains enough values for all advice stack reads
+    ///   performed before any values are pushed onto the advice stack by the program.
+    /// - Makes sure that the Merkle trees with roots pushed onto the stack as constants, and
+    ///   passed to Merkle tree instructions (e.g., `mtree_get`), are present in the Merkle store.
+    ///
+    /// Requirements which cannot be determined statically (e.g., Merkle roots computed at
+    /// runtime) are not checked. Stack inputs are not checked either, since programs do not
+    /// declare the number of inputs they expect. Thus, passing this check does not guarantee
+    /// that the program will execute successfully.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The advice stack contains fewer values than the program is guaranteed to read from it.
+    /// - A Merkle tree referenced by the program is not present in the Merkle store.
+    pub fn validate_for(&self, program: &Program) -> Result<(), InputError> {
+        let requirements = InputRequirements::new(program);
+
+        if requirements.num_advice_stack_reads > self.stack.len() {
+            return Err(InputError::NotEnoughAdviceStackValues(
+                requirements.num_advice_stack_reads,
+                self.stack.len(),
+            ));
+        }
+
+        for root in requirements.merkle_roots {
+            if !self.contains_merkle_root(root) {
+                return Err(InputError::MerkleRootNotFound(RpoDigest::from(root).into()));
+            }
+        }
+
+        Ok(())
+    }
+
     // DESTRUCTORS
     // --------------------------------------------------------------------------------------------
 
@@ -122,3 +373,458 @@ impl AdviceInputs {
         (stack, map, store)
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+/// Advice inputs are serialized canonically: the entries of the advice map are ordered by their
+/// keys, and the nodes of the Merkle store are ordered by their hashes. Thus, advice inputs with
+/// the same contents are serialized into identical bytes regardless of how they were built.
+impl Serializable for AdviceInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        // advice stack
+        debug_assert!(self.stack.len() <= u32::MAX as usize);
+        target.write_u32(self.stack.len() as u32);
+        Felt::write_batch_into(&self.stack, target);
+
+        // advice map
+        debug_assert!(self.map.len() <= u32::MAX as usize);
+        target.write_u32(self.map.len() as u32);
+        for (key, values) in self.map.iter() {
+            target.write_bytes(key);
+            debug_assert!(values.len() <= u32::MAX as usize);
+            target.write_u32(values.len() as u32);
+            Felt::write_batch_into(values, target);
+        }
+
+        // Merkle store
+        let nodes = serializable_store_nodes(&self.store);
+        target.write_u64(nodes.len() as u64);
+        for node in nodes {
+            node.left.write_into(target);
+            node.right.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for AdviceInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let stack = read_elements(source)?;
+
+        let map_len = source.read_u32()?;
+        let mut map = BTreeMap::new();
+        for _ in 0..map_len {
+            let key = source.read_array::<32>()?;
+            let values = read_elements(source)?;
+            map.insert(key, values);
+        }
+
+        let num_nodes = source.read_u64()?;
+        let mut store = MerkleStore::default();
+        for _ in 0..num_nodes {
+            let left = RpoDigest::read_from(source)?;
+            let right = RpoDigest::read_from(source)?;
+            let value = Rpo256::merge(&[left, right]);
+            store.extend(core::iter::once(InnerNodeInfo { value, left, right }));
+        }
+
+        Ok(Self { stack, map, store })
+    }
+}
+
+/// Serialized representation of [AdviceInputs].
+///
+/// Field elements are encoded as decimal strings, and keys of the advice map as well as words of
+/// the Merkle store are encoded as hex strings in the format of
+/// [digest_to_hex()](vm_core::utils::digest_to_hex). For example, in JSON:
+///
+/// ```json
+/// {
+///   "stack": ["1", "2", "3"],
+///   "map": { "<key hex>": ["4", "5"] },
+///   "merkle_trees": [{ "depth": 1, "leaves": ["<word hex>", "<word hex>"] }],
+///   "merkle_nodes": [["<left child hex>", "<right child hex>"]]
+/// }
+/// ```
+///
+/// Values of the advice stack are listed in the order in which they are removed from the stack
+/// by the VM. The Merkle store is described by full Merkle trees (`merkle_trees`) and by
+/// individual inner nodes given as pairs of their children (`merkle_nodes`). When advice inputs
+/// are serialized, the store is always written as a list of inner nodes in the canonical order
+/// defined by the [Serializable] implementation; `merkle_trees` is accepted only on input. All
+/// fields are optional on input.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "AdviceInputs")]
+struct AdviceInputsRepr {
+    #[serde(default)]
+    stack: Vec<String>,
+    #[serde(default)]
+    map: BTreeMap<String, Vec<String>>,
+    #[serde(default, skip_serializing)]
+    merkle_trees: Vec<MerkleTreeRepr>,
+    #[serde(default)]
+    merkle_nodes: Vec<[String; 2]>,
+}
+
+/// Serialized representation of a full Merkle tree with `2^depth` leaves.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct MerkleTreeRepr {
+    depth: u8,
+    leaves: Vec<String>,
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for AdviceInputs {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let felts_to_strings =
+            |values: &[Felt]| values.iter().map(|value| value.as_int().to_string()).collect();
+
+        let mut map = BTreeMap::new();
+        for (key, values) in self.map.iter() {
+            let key = to_hex(key).map_err(serde::ser::Error::custom)?;
+            map.insert(key, felts_to_strings(values));
+        }
+
+        let merkle_nodes = serializable_store_nodes(&self.store)
+            .into_iter()
+            .map(|node| [digest_to_hex(&node.left), digest_to_hex(&node.right)])
+            .collect();
+
+        AdviceInputsRepr {
+            stack: felts_to_strings(&self.stack),
+            map,
+            merkle_trees: Vec::new(),
+            merkle_nodes,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for AdviceInputs {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = AdviceInputsRepr::deserialize(deserializer)?;
+
+        let stack = parse_felts(&repr.stack)?;
+
+        let mut map = BTreeMap::new();
+        for (key, values) in repr.map.iter() {
+            map.insert(parse_digest(key)?.into(), parse_felts(values)?);
+        }
+
+        let mut store = MerkleStore::default();
+        for tree in repr.merkle_trees.iter() {
+            let expected_num_leaves = 1_usize.checked_shl(tree.depth.into()).unwrap_or(0);
+            if tree.leaves.len() != expected_num_leaves {
+                return Err(de::Error::custom(format_args!(
+                    "Merkle tree of depth {} must have {expected_num_leaves} leaves, but {} were \
+                    provided",
+                    tree.depth,
+                    tree.leaves.len()
+                )));
+            }
+            let leaves = tree
+                .leaves
+                .iter()
+                .map(|leaf| parse_digest(leaf).map(Word::from))
+                .collect::<Result<Vec<_>, _>>()?;
+            let tree = MerkleTree::new(leaves).map_err(de::Error::custom)?;
+            store.extend(tree.inner_nodes());
+        }
+        for [left, right] in repr.merkle_nodes.iter() {
+            let left = parse_digest(left)?;
+            let right = parse_digest(right)?;
+            let value = Rpo256::merge(&[left, right]);
+            store.extend(core::iter::once(InnerNodeInfo { value, left, right }));
+        }
+
+        Ok(Self { stack, map, store })
+    }
+}
+
+/// Reads a length-prefixed vector of field elements from the source.
+///
+/// The length is read from untrusted input, and thus only a bounded number of elements is
+/// preallocated; the vector grows as the elements are actually read.
+fn read_elements<R: ByteReader>(source: &mut R) -> Result<Vec<Felt>, DeserializationError> {
+    const MAX_PREALLOCATED_ELEMENTS: usize = 1024;
+
+    let num_elements = source.read_u32()? as usize;
+    let mut elements = Vec::with_capacity(num_elements.min(MAX_PREALLOCATED_ELEMENTS));
+    for _ in 0..num_elements {
+        elements.push(Felt::read_from(source)?);
+    }
+    Ok(elements)
+}
+
+/// Returns the inner nodes of the specified Merkle store which need to be serialized.
+///
+/// The roots of empty subtrees are present in every store, and thus only the remaining nodes are
+/// returned. The nodes are sorted by their hashes so that the encoding does not depend on the
+/// order in which the nodes were added to the store.
+fn serializable_store_nodes(store: &MerkleStore) -> Vec<InnerNodeInfo> {
+    let empty_roots = EmptySubtreeRoots::empty_hashes(u8::MAX);
+    let mut nodes = store
+        .inner_nodes()
+        .filter(|node| node.left != node.right || !empty_roots.contains(&node.value))
+        .collect::<Vec<_>>();
+    nodes.sort_unstable_by_key(|node| node.value);
+    nodes
+}
+
+/// Parses field elements from their decimal string representations.
+#[cfg(feature = "serde")]
+fn parse_felts<E: de::Error>(values: &[String]) -> Result<Vec<Felt>, E> {
+    values
+        .iter()
+        .map(|value| {
+            let int = value
+                .parse::<u64>()
+                .map_err(|err| E::custom(format_args!("invalid field element '{value}': {err}")))?;
+            if int >= Felt::MODULUS {
+                return Err(E::custom(InputError::NotFieldElement(
+                    int,
+                    "the provided value isn't a valid field element",
+                )));
+            }
+            Ok(Felt::new(int))
+        })
+        .collect()
+}
+
+/// Parses a digest from its hex string representation.
+#[cfg(feature = "serde")]
+fn parse_digest<E: de::Error>(hex: &str) -> Result<RpoDigest, E> {
+    digest_from_hex(hex).map_err(|err| E::custom(format_args!("invalid digest '{hex}': {err}")))
+}
+
+// INPUT REQUIREMENTS
+// ================================================================================================
+
+/// Advice inputs required by a program, determined via a conservative static analysis of the code
+/// which is guaranteed to be executed at the start of the program.
+///
+/// The analysis tracks the values of the top stack items which are known statically (i.e., the
+/// values pushed onto the stack by `PUSH` and `PAD` operations), and stops at the first code block
+/// for which it cannot be determined whether (or which of) its operations would be executed.
+struct InputRequirements<'a> {
+    cb_table: &'a CodeBlockTable,
+    /// Known values of the top stack items; the last item is at the top of the stack.
+    stack: Vec<Option<Felt>>,
+    /// Number of values guaranteed to be read from the initial advice stack.
+    num_advice_stack_reads: usize,
+    /// Roots of Merkle trees which are expected to be in the initial Merkle store.
+    merkle_roots: Vec<Word>,
+    /// Set to false once the advice stack may contain values pushed by the program.
+    count_advice_reads: bool,
+    /// Set to false once the Merkle store may contain trees added by the program.
+    record_merkle_roots: bool,
+}
+
+impl<'a> InputRequirements<'a> {
+    /// Returns advice input requirements of the specified program.
+    pub fn new(program: &'a Program) -> Self {
+        let mut requirements = Self {
+            cb_table: program.cb_table(),
+            stack: Vec::new(),
+            num_advice_stack_reads: 0,
+            merkle_roots: Vec::new(),
+            count_advice_reads: true,
+            record_merkle_roots: true,
+        };
+        requirements.visit_block(program.root());
+        requirements
+    }
+
+    /// Analyzes the specified code block, and returns false if the analysis cannot proceed past
+    /// this block.
+    fn visit_block(&mut self, block: &CodeBlock) -> bool {
+        match block {
+            CodeBlock::Span(span) => {
+                self.visit_span(span);
+                true
+            }
+            CodeBlock::Join(join) => {
+                self.visit_block(join.first()) && self.visit_block(join.second())
+            }
+            CodeBlock::Call(call) if !call.is_syscall() => {
+                // the callee starts in a new context; we don't track values moved between contexts
+                match self.cb_table.get(call.fn_hash()) {
+                    Some(body) => {
+                        self.stack.clear();
+                        let result = self.visit_block(body);
+                        self.stack.clear();
+                        result
+                    }
+                    None => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// Analyzes operations and decorators of the specified span block.
+    fn visit_span(&mut self, span: &Span) {
+        let mut decorators = span.decorators().iter().peekable();
+        for (op_idx, op) in span.op_batches().iter().flat_map(|batch| batch.ops()).enumerate() {
+            while let Some((_, decorator)) = decorators.next_if(|(idx, _)| *idx == op_idx) {
+                self.visit_decorator(decorator);
+            }
+            self.visit_op(*op);
+        }
+        for (_, decorator) in decorators {
+            self.visit_decorator(decorator);
+        }
+    }
+
+    fn visit_decorator(&mut self, decorator: &Decorator) {
+        if let Decorator::Advice(injector) = decorator {
+            self.count_advice_reads = false;
+            // reading a Merkle node onto the advice stack does not modify the Merkle store
+            if !matches!(injector, AdviceInjector::MerkleNodeToStack) {
+                self.record_merkle_roots = false;
+            }
+        }
+    }
+
+    fn visit_op(&mut self, op: Operation) {
+        match op {
+            Operation::Noop => (),
+            Operation::Push(value) => self.stack.push(Some(value)),
+            Operation::Pad => self.stack.push(Some(ZERO)),
+            Operation::Drop => {
+                self.stack.pop();
+            }
+            Operation::AdvPop => {
+                self.record_advice_reads(1);
+                self.stack.push(None);
+            }
+            Operation::AdvPopW => {
+                self.record_advice_reads(4);
+                let len = self.stack.len();
+                self.stack[len.saturating_sub(4)..].iter_mut().for_each(|value| *value = None);
+            }
+            Operation::Pipe => {
+                self.record_advice_reads(8);
+                self.stack.clear();
+            }
+            Operation::MpVerify => self.record_merkle_root(),
+            Operation::MrUpdate => {
+                self.record_merkle_root();
+                self.record_merkle_roots = false;
+                self.stack.clear();
+            }
+            _ => self.stack.clear(),
+        }
+    }
+
+    fn record_advice_reads(&mut self, num_values: usize) {
+        if self.count_advice_reads {
+            self.num_advice_stack_reads += num_values;
+        }
+    }
+
+    /// Records the root of the Merkle tree located at stack positions 6 - 9 if it is known.
+    fn record_merkle_root(&mut self) {
+        if !self.record_merkle_roots {
+            return;
+        }
+        let root = [self.get(9), self.get(8), self.get(7), self.get(6)];
+        if let [Some(a), Some(b), Some(c), Some(d)] = root {
+            self.merkle_roots.push([a, b, c, d]);
+        }
+    }
+
+    /// Returns the value at the specified stack position if it is known.
+    fn get(&self, pos: usize) -> Option<Felt> {
+        self.stack.len().checked_sub(pos + 1).and_then(|idx| self.stack[idx])
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AdviceInputs, Deserializable, Felt, InputError, MerkleStore, NodeIndex, Serializable, Word,
+    };
+    use vm_core::crypto::merkle::MerkleTree;
+
+    #[test]
+    fn advice_inputs_canonical_serialization() {
+        let leaves: Vec<Word> = (0..8).map(|i| [Felt::new(i); 4]).collect();
+        let tree = MerkleTree::new(leaves.clone()).unwrap();
+        let map = [([1; 32], vec![Felt::new(1)]), ([2; 32], vec![Felt::new(2), Felt::new(3)])];
+
+        // build the tree from all of its nodes, and the map in one order
+        let inputs1 = AdviceInputs::default()
+            .with_stack_values([1, 2, 3])
+            .unwrap()
+            .with_map(map.clone())
+            .with_merkle_store(MerkleStore::from(&tree));
+
+        // build the tree from the paths to its leaves in reverse order, and the map in the other
+        let paths = leaves
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(index, &leaf)| {
+                let path = tree.get_path(NodeIndex::new(3, index as u64).unwrap()).unwrap();
+                (index as u64, leaf, path.iter().map(|&node| node.into()).collect())
+            })
+            .collect();
+        let inputs2 = AdviceInputs::default()
+            .with_stack_values([1, 2, 3])
+            .unwrap()
+            .with_map(map.into_iter().rev())
+            .with_merkle_path_set(3, paths)
+            .unwrap();
+
+        let bytes = inputs1.to_bytes();
+        assert_eq!(bytes, inputs2.to_bytes());
+
+        // deserialized inputs are serialized into the same bytes
+        let deserialized = AdviceInputs::read_from_bytes(&bytes).unwrap();
+        assert_eq!(bytes, deserialized.to_bytes());
+
+        // a length which is not backed by elements is rejected without preallocating them
+        assert!(AdviceInputs::read_from_bytes(&[0xff; 4]).is_err());
+        assert!(AdviceInputs::read_from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn advice_inputs_merge() {
+        let tree1 = MerkleTree::new((0..4).map(|i| [Felt::new(i); 4]).collect()).unwrap();
+        let tree2 = MerkleTree::new((4..12).map(|i| [Felt::new(i); 4]).collect()).unwrap();
+
+        let inputs1 = AdviceInputs::default()
+            .with_stack_values([1, 2])
+            .unwrap()
+            .with_map([([1; 32], vec![Felt::new(1)]), ([2; 32], vec![Felt::new(2)])])
+            .with_merkle_store(MerkleStore::from(&tree1));
+        let inputs2 = AdviceInputs::default()
+            .with_stack_values([3])
+            .unwrap()
+            .with_map([([2; 32], vec![Felt::new(2)]), ([3; 32], vec![Felt::new(3)])])
+            .with_merkle_store(MerkleStore::from(&tree2));
+
+        let merged = inputs1.clone().merge(inputs2).unwrap();
+        assert_eq!(&[Felt::new(1), Felt::new(2), Felt::new(3)], merged.stack());
+        for key in 1..=3 {
+            assert_eq!(Some([Felt::new(key as u64)].as_slice()), merged.mapped_values(&[key; 32]));
+        }
+        assert!(merged.contains_merkle_root(tree1.root().into()));
+        assert!(merged.contains_merkle_root(tree2.root().into()));
+        assert_eq!([Felt::new(9); 4], merged.get_merkle_node(tree2.root().into(), 3, 5).unwrap());
+
+        // conflicting values in the advice map
+        let inputs2 = AdviceInputs::default().with_map([([2; 32], vec![Felt::new(5)])]);
+        let result = inputs1.merge(inputs2);
+        assert!(
+            matches!(result, Err(InputError::ConflictingAdviceMapValues(key)) if key == [2; 32])
+        );
+    }
+}
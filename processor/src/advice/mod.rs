@@ -1,15 +1,25 @@
-use super::{ExecutionError, Felt, InputError, StarkField, Word};
+use super::{
+    AdviceInjector, CodeBlock, CodeBlockTable, Decorator, ExecutionError, Felt, InputError,
+    Operation, Program, Span, StarkField, Word, ZERO,
+};
 use vm_core::{
+    advice_tape_key,
     crypto::{
-        hash::RpoDigest,
-        merkle::{InnerNodeInfo, MerklePath, MerkleStore, NodeIndex, StoreNode},
+        hash::{Rpo256, RpoDigest},
+        merkle::{
+            EmptySubtreeRoots, InnerNodeInfo, MerklePath, MerklePathSet, MerkleStore, NodeIndex,
+            StoreNode,
+        },
     },
     utils::{
         collections::{BTreeMap, KvMap, RecordingMap, Vec},
-        IntoBytes,
+        ByteReader, ByteWriter, Deserializable, DeserializationError, IntoBytes, Serializable,
     },
 };
 
+mod chained;
+pub use chained::ChainedProvider;
+
 mod inputs;
 pub use inputs::AdviceInputs;
 
@@ -38,6 +48,16 @@ pub use source::AdviceSource;
 /// 3. Merkle store, which contains structured data reducible to Merkle paths. The VM can request
 ///    Merkle paths from the store, as well as mutate it by updating or merging nodes contained in
 ///    the store.
+///
+/// The VM queries an advice provider only for the data it needs during execution (e.g., a single
+/// Merkle path per `mtree_get` instruction). Thus, an implementation does not need to hold all of
+/// its data in memory, and can instead fetch it lazily (e.g., from a database) or compute it on
+/// demand. [MemAdviceProvider] is an implementation which keeps all data in memory, and can be
+/// instantiated from [AdviceInputs].
+///
+/// The trait is object-safe; functions which accept an advice provider by value also accept
+/// `&mut dyn AdviceProvider` (or `&mut (dyn AdviceProvider + Send)` where the provider must be
+/// [Send]).
 pub trait AdviceProvider {
     // ACCESSORS
     // --------------------------------------------------------------------------------------------
@@ -46,7 +66,10 @@ pub trait AdviceProvider {
     ///
     /// The returned adapter also implements [AdviceProvider] and will simply mutably borrow this
     /// instance.
-    fn by_ref(&mut self) -> &mut Self {
+    fn by_ref(&mut self) -> &mut Self
+    where
+        Self: Sized,
+    {
         // this trait follows the same model as
         // [io::Read](https://doc.rust-lang.org/std/io/trait.Read.html#method.by_ref).
         //
@@ -192,7 +215,7 @@ pub trait AdviceProvider {
 
 impl<'a, T> AdviceProvider for &'a mut T
 where
-    T: AdviceProvider,
+    T: AdviceProvider + ?Sized,
 {
     fn pop_stack(&mut self) -> Result<Felt, ExecutionError> {
         T::pop_stack(self)
@@ -2,6 +2,7 @@ use super::{
     AdviceInputs, AdviceProvider, AdviceSource, BTreeMap, ExecutionError, Felt, IntoBytes, KvMap,
     MerklePath, MerkleStore, NodeIndex, RecordingMap, RpoDigest, StarkField, StoreNode, Vec, Word,
 };
+use vm_core::crypto::merkle::MerkleError;
 
 // TYPE ALIASES
 // ================================================================================================
@@ -27,6 +28,7 @@ where
     stack: Vec<Felt>,
     map: M,
     store: MerkleStore<S>,
+    tape_cursors: BTreeMap<[u8; 32], usize>,
 }
 
 impl<M, S> From<AdviceInputs> for BaseAdviceProvider<M, S>
@@ -42,10 +44,29 @@ where
             stack,
             map: map.into_iter().collect(),
             store: store.inner_nodes().collect(),
+            tape_cursors: BTreeMap::new(),
         }
     }
 }
 
+impl<M, S> BaseAdviceProvider<M, S>
+where
+    M: KvMap<[u8; 32], Vec<Felt>>,
+    S: KvMap<RpoDigest, StoreNode>,
+{
+    /// Returns an error if the advice stack contains fewer than `num_values` elements.
+    fn check_stack_len(&self, num_values: usize) -> Result<(), ExecutionError> {
+        if self.stack.len() < num_values {
+            return Err(ExecutionError::AdviceStackReadFailed {
+                step: self.step,
+                requested: num_values,
+                available: self.stack.len(),
+            });
+        }
+        Ok(())
+    }
+}
+
 impl<M, S> AdviceProvider for BaseAdviceProvider<M, S>
 where
     M: KvMap<[u8; 32], Vec<Felt>>,
@@ -55,13 +76,12 @@ where
     // --------------------------------------------------------------------------------------------
 
     fn pop_stack(&mut self) -> Result<Felt, ExecutionError> {
-        self.stack.pop().ok_or(ExecutionError::AdviceStackReadFailed(self.step))
+        self.check_stack_len(1)?;
+        Ok(self.stack.pop().expect("advice stack is empty"))
     }
 
     fn pop_stack_word(&mut self) -> Result<Word, ExecutionError> {
-        if self.stack.len() < 4 {
-            return Err(ExecutionError::AdviceStackReadFailed(self.step));
-        }
+        self.check_stack_len(4)?;
 
         let idx = self.stack.len() - 4;
         let result =
@@ -73,6 +93,8 @@ where
     }
 
     fn pop_stack_dword(&mut self) -> Result<[Word; 2], ExecutionError> {
+        // check the length upfront so that the stack is not modified if the read fails
+        self.check_stack_len(8)?;
         let word0 = self.pop_stack_word()?;
         let word1 = self.pop_stack_word()?;
 
@@ -98,6 +120,23 @@ where
                 }
                 Ok(())
             }
+
+            AdviceSource::Tape { key } => {
+                let key_bytes = key.into_bytes();
+                let values =
+                    self.map.get(&key_bytes).ok_or(ExecutionError::AdviceKeyNotFound(key))?;
+
+                let cursor = self.tape_cursors.entry(key_bytes).or_insert(0);
+                let value = values.get(*cursor).ok_or(ExecutionError::AdviceTapeExhausted {
+                    step: self.step,
+                    key,
+                    len: values.len(),
+                })?;
+                *cursor += 1;
+
+                self.stack.push(*value);
+                Ok(())
+            }
         }
     }
 
@@ -124,7 +163,7 @@ where
         self.store
             .get_node(root.into(), index)
             .map(|v| v.into())
-            .map_err(ExecutionError::MerkleStoreLookupFailed)
+            .map_err(|err| lookup_error(err, root, index))
     }
 
     fn get_merkle_path(
@@ -142,7 +181,7 @@ where
         self.store
             .get_path(root.into(), index)
             .map(|value| value.path)
-            .map_err(ExecutionError::MerkleStoreLookupFailed)
+            .map_err(|err| lookup_error(err, root, index))
     }
 
     fn get_leaf_depth(
@@ -311,6 +350,7 @@ impl RecAdviceProvider {
             stack: _,
             map,
             store,
+            tape_cursors: _,
         } = provider;
 
         let map = map.into_proof();
@@ -407,3 +447,23 @@ impl AdviceProvider for RecAdviceProvider {
         self.provider.advance_clock()
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Converts an error of a Merkle store lookup of the node at the specified index of the tree with
+/// the specified root into an [ExecutionError].
+///
+/// Lookups which fail because the tree or one of the nodes on the path to the requested node is
+/// not in the store are reported as [ExecutionError::MerklePathNotFound].
+fn lookup_error(err: MerkleError, root: Word, index: NodeIndex) -> ExecutionError {
+    match err {
+        MerkleError::RootNotInStore(_) | MerkleError::NodeNotInStore(..) => {
+            ExecutionError::MerklePathNotFound {
+                root: root.into(),
+                index,
+            }
+        }
+        err => ExecutionError::MerkleStoreLookupFailed(err),
+    }
+}
@@ -30,4 +30,16 @@ pub enum AdviceSource {
     /// # Errors
     /// Returns an error if the key was not found in the key-value map.
     Map { key: Word, include_len: bool },
+
+    /// Fetches the next unread element of the named advice tape stored under the specified key
+    /// in the advice map, pushes it onto the advice stack, and advances the read cursor of the
+    /// tape.
+    ///
+    /// Unlike [AdviceSource::Map], this does not modify the tape stored in the advice map; the
+    /// read cursors of the tapes are maintained by the advice provider.
+    ///
+    /// # Errors
+    /// Returns an error if the key was not found in the key-value map, or if all elements of the
+    /// tape have already been read.
+    Tape { key: Word },
 }
@@ -0,0 +1,152 @@
+use super::{
+    execute, AdviceInputs, ExecutionError, ExecutionTrace, MemAdviceProvider, Program, StackInputs,
+    StackOutputs, Word, FMP_MIN,
+};
+use vm_core::{utils::collections::Vec, ZERO};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The length (in words) up to which a memory segment built by [VmInitialState::from_trace()] is
+/// accepted regardless of how many of its words are zeros.
+const MIN_SPARSE_SEGMENT_LEN: u64 = 1024;
+
+// VM INITIAL STATE
+// ================================================================================================
+
+/// Initial state of the VM from which a program can continue the computation of a previously
+/// executed program.
+///
+/// The state consists of the full state of the stack (including the values stored in the stack
+/// overflow table) and, optionally, of a segment of words to be loaded into the root context
+/// memory starting at address 0. Thus, the outputs of one program can be passed to another
+/// program without being limited to the top 16 elements of the stack.
+///
+/// Since the memory of the VM is initialized to zeros, the memory segment cannot be placed into
+/// memory directly. Instead, [execute_continued()] adds the segment to the advice map under the
+/// key returned by [VmInitialState::memory_commitment()], and the program is expected to copy it
+/// to memory via `std::mem::load_memory_segment` procedure.
+#[derive(Clone, Debug, Default)]
+pub struct VmInitialState {
+    stack: StackInputs,
+    memory: Vec<Word>,
+}
+
+impl VmInitialState {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [VmInitialState] with the specified stack and empty memory.
+    pub fn new(stack: StackInputs) -> Self {
+        Self {
+            stack,
+            memory: Vec::new(),
+        }
+    }
+
+    /// Returns a new [VmInitialState] with the stack set to the full state of the stack (including
+    /// the overflow table) described by the provided outputs of a prior execution.
+    pub fn from_stack_outputs(outputs: &StackOutputs) -> Self {
+        // stack outputs are ordered from the top of the stack, while stack inputs are expected to
+        // be ordered from the bottom
+        let mut values = outputs.full_stack();
+        values.reverse();
+        Self::new(StackInputs::new(values))
+    }
+
+    /// Returns a new [VmInitialState] with the stack and the memory set to their state at the end
+    /// of the provided execution.
+    ///
+    /// The memory segment covers all addresses of the root context memory from 0 up to the
+    /// highest address below [FMP_MIN] holding a non-zero word. Addresses starting at [FMP_MIN]
+    /// hold the local variables of procedures, and are not carried over into the memory segment.
+    ///
+    /// # Errors
+    /// Returns an error if the memory segment is longer than 1024 words and more than half of its
+    /// words are zeros, since the segment would then be much larger than the memory it describes.
+    pub fn from_trace(trace: &ExecutionTrace) -> Result<Self, ExecutionError> {
+        let final_memory = trace
+            .final_memory()
+            .into_iter()
+            .take_while(|&(addr, _)| addr < FMP_MIN)
+            .collect::<Vec<_>>();
+
+        let mut memory = match final_memory.last() {
+            Some(&(addr, _)) => {
+                let len = addr + 1;
+                let num_words = final_memory.len();
+                if len > MIN_SPARSE_SEGMENT_LEN && len > 2 * num_words as u64 {
+                    return Err(ExecutionError::SparseMemorySegment { len, num_words });
+                }
+                vec![[ZERO; 4]; len as usize]
+            }
+            None => Vec::new(),
+        };
+        for (addr, value) in final_memory {
+            memory[addr as usize] = value;
+        }
+
+        Ok(Self::from_stack_outputs(trace.stack_outputs()).with_memory(memory))
+    }
+
+    /// Sets the memory segment of this state to the provided words; the word at index `i` is
+    /// expected to be loaded at address `i` of the root context memory.
+    pub fn with_memory(mut self, memory: Vec<Word>) -> Self {
+        self.memory = memory;
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the initial state of the stack.
+    pub fn stack(&self) -> &StackInputs {
+        &self.stack
+    }
+
+    /// Returns the words to be loaded into memory starting at address 0.
+    pub fn memory(&self) -> &[Word] {
+        &self.memory
+    }
+
+    /// Returns the commitment to the memory segment of this state, which is the advice map key
+    /// expected by `std::mem::load_memory_segment` procedure.
+    pub fn memory_commitment(&self) -> Word {
+        AdviceInputs::memory_segment_commitment(&self.memory)
+    }
+
+    // DESTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Decomposes this state into the initial state of the stack and the memory segment.
+    pub fn into_parts(self) -> (StackInputs, Vec<Word>) {
+        (self.stack, self.memory)
+    }
+}
+
+// EXECUTOR
+// ================================================================================================
+
+/// Returns an execution trace resulting from executing the provided program starting from the
+/// provided initial state of the VM.
+///
+/// The stack of the VM is initialized with the full stack of `initial_state`. If the state
+/// contains a non-empty memory segment, the segment is added to the advice map of `advice_inputs`
+/// (see [VmInitialState] for how the program loads it into memory).
+///
+/// # Errors
+/// Returns an error if the program fails to execute, or if the hash of the executed program is
+/// inconsistent with the hash of the provided `program`.
+pub fn execute_continued(
+    program: &Program,
+    initial_state: VmInitialState,
+    advice_inputs: AdviceInputs,
+) -> Result<ExecutionTrace, ExecutionError> {
+    let (stack_inputs, memory) = initial_state.into_parts();
+    let advice_inputs = if memory.is_empty() {
+        advice_inputs
+    } else {
+        advice_inputs.with_memory_segment(&memory)
+    };
+    execute(program, stack_inputs, MemAdviceProvider::from(advice_inputs))
+}
@@ -1,13 +1,26 @@
 use crate::{
-    advice::AdviceProvider, Chiplets, Decoder, ExecutionError, Felt, Process, Stack, StarkField,
-    System, Vec,
+    advice::AdviceProvider, BTreeMap, Chiplets, Decoder, ExecutionError, Felt, Process, Stack,
+    StarkField, System, Vec,
 };
-use core::fmt;
+use core::{fmt, ops::ControlFlow};
 use vm_core::{
     utils::string::{String, ToString},
-    AssemblyOp, Operation, StackOutputs, Word,
+    AssemblyOp, DebugOptions, Operation, SourceLocation, StackOutputs, Word,
 };
 
+/// DebugEvent holds the state of the VM captured by a `debug` decorator at a specific clock cycle.
+///
+/// Depending on the debug options, either the `stack` or the `memory` field is populated; the
+/// other field is left empty.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DebugEvent {
+    pub clk: u32,
+    pub ctx: u32,
+    pub options: DebugOptions,
+    pub stack: Vec<Felt>,
+    pub memory: Vec<(u64, Word)>,
+}
+
 /// VmState holds a current process state information at a specific clock cycle.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct VmState {
@@ -20,6 +33,67 @@ pub struct VmState {
     pub memory: Vec<(u64, Word)>,
 }
 
+impl VmState {
+    /// Returns the differences between this state and the `other` state.
+    ///
+    /// The states are compared field by field; stack items are compared position by position,
+    /// and memory words are compared address by address. The differences are listed in the order
+    /// of the fields of [VmState]. An empty vector is returned if the states are the same
+    /// (the assembly instructions associated with the states are not compared).
+    pub fn diff(&self, other: &VmState) -> Vec<StateDelta> {
+        let mut result = Vec::new();
+
+        if self.clk != other.clk {
+            result.push(StateDelta::Clk {
+                left: self.clk,
+                right: other.clk,
+            });
+        }
+        if self.ctx != other.ctx {
+            result.push(StateDelta::Ctx {
+                left: self.ctx,
+                right: other.ctx,
+            });
+        }
+        if self.op != other.op {
+            result.push(StateDelta::Op {
+                left: self.op,
+                right: other.op,
+            });
+        }
+        if self.fmp != other.fmp {
+            result.push(StateDelta::Fmp {
+                left: self.fmp,
+                right: other.fmp,
+            });
+        }
+
+        let stack_depth = self.stack.len().max(other.stack.len());
+        for index in 0..stack_depth {
+            let left = self.stack.get(index).copied();
+            let right = other.stack.get(index).copied();
+            if left != right {
+                result.push(StateDelta::Stack { index, left, right });
+            }
+        }
+
+        let mut memory: BTreeMap<u64, (Option<Word>, Option<Word>)> = BTreeMap::new();
+        for &(addr, word) in self.memory.iter() {
+            memory.entry(addr).or_default().0 = Some(word);
+        }
+        for &(addr, word) in other.memory.iter() {
+            memory.entry(addr).or_default().1 = Some(word);
+        }
+        for (addr, (left, right)) in memory {
+            if left != right {
+                result.push(StateDelta::Memory { addr, left, right });
+            }
+        }
+
+        result
+    }
+}
+
 impl fmt::Display for VmState {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let stack: Vec<u64> = self.stack.iter().map(|x| x.as_int()).collect();
@@ -42,6 +116,39 @@ impl fmt::Display for VmState {
     }
 }
 
+/// A difference between two [VmState]s, as reported by [VmState::diff()].
+///
+/// In each variant, `left` is the value in the state on which `diff()` was called, and `right` is
+/// the value in the other state.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StateDelta {
+    /// The clock cycles of the states differ.
+    Clk { left: u32, right: u32 },
+    /// The execution contexts of the states differ.
+    Ctx { left: u32, right: u32 },
+    /// The operations executed at the states differ.
+    Op {
+        left: Option<Operation>,
+        right: Option<Operation>,
+    },
+    /// The free memory pointers of the states differ.
+    Fmp { left: Felt, right: Felt },
+    /// The stack items at the specified position differ; an item is None if the stack of the
+    /// respective state is not deep enough.
+    Stack {
+        index: usize,
+        left: Option<Felt>,
+        right: Option<Felt>,
+    },
+    /// The words stored at the specified memory address differ; a word is None if the address has
+    /// not been accessed in the respective state.
+    Memory {
+        addr: u64,
+        left: Option<Word>,
+        right: Option<Word>,
+    },
+}
+
 /// Iterator that iterates through vm state at each step of the execution.
 /// This allows debugging or replaying ability to view various process state
 /// at each clock cycle.
@@ -223,12 +330,68 @@ impl Iterator for VmStateIterator {
     }
 }
 
+// EXECUTION HOOK
+// ================================================================================================
+
+impl<A> Process<'_, A>
+where
+    A: AdviceProvider,
+{
+    /// Invokes the hook of this process (if any) with the state of the VM at the current clock
+    /// cycle.
+    ///
+    /// # Errors
+    /// Returns an error if the hook requested the execution to be aborted.
+    pub(super) fn invoke_hook(&mut self) -> Result<(), ExecutionError> {
+        // the state of the VM is built only when a hook is registered
+        let hook = match self.hook.as_mut() {
+            Some(hook) => hook,
+            None => return Ok(()),
+        };
+
+        let clk = self.system.clk();
+        let ctx = self.system.get_ctx_at(clk);
+        let state = VmState {
+            clk,
+            ctx,
+            op: clk
+                .checked_sub(1)
+                .and_then(|i| self.decoder.debug_info().operations().get(i as usize))
+                .copied(),
+            asmop: get_asmop_at(self.decoder.debug_info().assembly_ops(), clk),
+            fmp: self.system.get_fmp_at(clk),
+            stack: self.stack.get_state_at(clk),
+            memory: self.chiplets.get_mem_state_at(ctx, clk),
+        };
+
+        match hook(&state) {
+            ControlFlow::Break(()) => Err(ExecutionError::ExecutionAborted(clk)),
+            ControlFlow::Continue(()) => Ok(()),
+        }
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 fn word_to_ints(word: &Word) -> [u64; 4] {
     [word[0].as_int(), word[1].as_int(), word[2].as_int(), word[3].as_int()]
 }
 
+/// Returns the asm op info for the operation executed at the specified clock cycle, given the
+/// list of assembly operations recorded by the decoder up to (and including) that cycle.
+fn get_asmop_at(assembly_ops: &[(usize, AssemblyOp)], clk: u32) -> Option<AsmOpInfo> {
+    // the operation which led to the state at clock cycle clk was executed at cycle clk - 1; the
+    // relevant assembly op is the last one which started at or before that cycle
+    let op_clk = clk.checked_sub(1)? as usize;
+    let (start, asmop) = assembly_ops.iter().rev().find(|(start, _)| *start <= op_clk)?;
+    let cycle_idx = op_clk - start + 1;
+    if cycle_idx <= asmop.num_cycles() as usize {
+        Some(AsmOpInfo::new(asmop.clone(), cycle_idx as u8))
+    } else {
+        None
+    }
+}
+
 /// Contains assembly instruction and operation index in the sequence corresponding to the specified
 /// AsmOp decorator. This index starts from 1 instead of 0.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -280,6 +443,11 @@ impl AsmOpInfo {
         self.cycle_idx
     }
 
+    /// Returns the location of the assembly instruction in the source code, if known.
+    pub fn location(&self) -> Option<SourceLocation> {
+        self.asmop.location()
+    }
+
     /// Returns `true` if the debug should break for this line.
     pub const fn should_break(&self) -> bool {
         self.asmop.should_break()
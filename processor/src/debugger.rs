@@ -0,0 +1,181 @@
+use crate::{execute_with_hook, AdviceProvider, ExecutionError, Program, StackInputs, VmState};
+use core::ops::ControlFlow;
+use std::{
+    collections::BTreeSet,
+    panic,
+    sync::mpsc::{self, Receiver, Sender},
+    thread::{self, JoinHandle},
+};
+
+// DEBUGGER
+// ================================================================================================
+
+/// A step debugger which allows callers to pause execution of a program at specific operations,
+/// inspect the state of the VM, and then resume execution.
+///
+/// Miden programs are trees of code blocks rather than sequences of instructions, and thus, the VM
+/// does not have a program counter; moreover, the same operation of a program may be executed many
+/// times (e.g., in a loop or in a procedure invoked from several places). Thus, operations are
+/// identified by their index in the sequence of executed operations. Since every operation is
+/// executed in exactly one cycle, this index is the clock cycle at which the operation is executed:
+/// a breakpoint at `clk` pauses execution at the state of the VM after `clk` operations have been
+/// executed (i.e., at the [VmState] with the same `clk`), before the next operation is executed.
+///
+/// The program is executed by the same interpreter as [execute()](crate::execute()) (in debug
+/// mode). Since the interpreter executes code blocks recursively, execution cannot be suspended
+/// in the middle of a program; instead, the program is executed in a separate thread which is
+/// paused after each cycle until the next state is requested. Thus, the program is executed only
+/// as far as the debugger is advanced, and execution is aborted when the debugger is dropped.
+pub struct Debugger {
+    states: Receiver<Result<VmState, ExecutionError>>,
+    resume: Option<Sender<()>>,
+    execution: Option<JoinHandle<()>>,
+    breakpoints: BTreeSet<u32>,
+    state: Option<VmState>,
+}
+
+impl Debugger {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new debugger for the execution of the specified `program` against the specified
+    /// inputs.
+    ///
+    /// The debugger starts before the initial state of the VM (clock cycle 0); the initial state
+    /// is returned by the first call to [Debugger::step()].
+    pub fn new<A>(program: &Program, stack_inputs: StackInputs, advice_provider: A) -> Self
+    where
+        A: AdviceProvider + Send + 'static,
+    {
+        let (states_tx, states) = mpsc::channel();
+        let (resume, resume_rx) = mpsc::channel();
+        let program = program.clone();
+        let execution = thread::spawn(move || {
+            // after sending a state, wait until the next state is requested; the execution is
+            // aborted if the debugger was dropped in the meantime
+            let mut hook = |state: &VmState| {
+                if states_tx.send(Ok(state.clone())).is_err() || resume_rx.recv().is_err() {
+                    return ControlFlow::Break(());
+                }
+                ControlFlow::Continue(())
+            };
+            let result = execute_with_hook(&program, stack_inputs, advice_provider, &mut hook);
+            if let Err(err) = result {
+                let _ = states_tx.send(Err(err));
+            }
+        });
+
+        Self {
+            states,
+            resume: Some(resume),
+            execution: Some(execution),
+            breakpoints: BTreeSet::new(),
+            state: None,
+        }
+    }
+
+    // BREAKPOINTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds a breakpoint at the operation executed at the specified clock cycle.
+    pub fn add_breakpoint(&mut self, clk: u32) {
+        self.breakpoints.insert(clk);
+    }
+
+    /// Removes the breakpoint at the specified clock cycle and returns true if the breakpoint was
+    /// present.
+    pub fn remove_breakpoint(&mut self, clk: u32) -> bool {
+        self.breakpoints.remove(&clk)
+    }
+
+    /// Returns the clock cycles of all breakpoints in ascending order.
+    pub fn breakpoints(&self) -> impl Iterator<Item = u32> + '_ {
+        self.breakpoints.iter().copied()
+    }
+
+    // EXECUTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the state of the VM at which the debugger is currently paused, or None if the
+    /// debugger has not been stepped yet.
+    pub fn state(&self) -> Option<&VmState> {
+        self.state.as_ref()
+    }
+
+    /// Advances execution by one clock cycle and returns the resulting state of the VM.
+    ///
+    /// Returns None if the execution has already completed.
+    ///
+    /// # Errors
+    /// Returns an error if the program failed to execute at the next clock cycle.
+    ///
+    /// # Panics
+    /// Panics if the execution of the program panicked.
+    pub fn step(&mut self) -> Result<Option<VmState>, ExecutionError> {
+        // the initial state is sent without waiting; every subsequent state needs to be requested
+        if self.state.is_some() {
+            if let Some(resume) = &self.resume {
+                // sending fails only if the execution has already completed
+                let _ = resume.send(());
+            }
+        }
+
+        match self.states.recv() {
+            Ok(Ok(state)) => {
+                self.state = Some(state.clone());
+                Ok(Some(state))
+            }
+            Ok(Err(err)) => Err(err),
+            Err(_) => {
+                // the execution thread has exited; propagate the panic if it did not exit cleanly
+                if let Some(Err(panic)) = self.execution.take().map(JoinHandle::join) {
+                    panic::resume_unwind(panic);
+                }
+                Ok(None)
+            }
+        }
+    }
+
+    /// Advances execution until a breakpoint is reached and returns the state of the VM at the
+    /// breakpoint.
+    ///
+    /// Execution is advanced by at least one clock cycle, and thus, calling this function while
+    /// paused at a breakpoint continues to the next breakpoint. Returns None if the execution
+    /// completed without reaching a breakpoint.
+    ///
+    /// # Errors
+    /// Returns an error if the program failed to execute before reaching a breakpoint.
+    pub fn run_to_breakpoint(&mut self) -> Result<Option<VmState>, ExecutionError> {
+        while let Some(state) = self.step()? {
+            if self.breakpoints.contains(&state.clk) {
+                return Ok(Some(state));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Advances execution to completion, ignoring all breakpoints, and returns the final state of
+    /// the VM.
+    ///
+    /// Returns None if the execution has already completed.
+    ///
+    /// # Errors
+    /// Returns an error if the program failed to execute.
+    pub fn resume(&mut self) -> Result<Option<VmState>, ExecutionError> {
+        let mut last_state = None;
+        while let Some(state) = self.step()? {
+            last_state = Some(state);
+        }
+        Ok(last_state)
+    }
+}
+
+impl Drop for Debugger {
+    fn drop(&mut self) {
+        // closing the channel aborts the execution at the next cycle
+        self.resume = None;
+        if let Some(execution) = self.execution.take() {
+            let _ = execution.join();
+        }
+    }
+}
@@ -10,7 +10,7 @@ use miden_air::trace::{
         OP_BATCH_1_GROUPS, OP_BATCH_2_GROUPS, OP_BATCH_4_GROUPS, OP_BATCH_8_GROUPS,
     },
 };
-use vm_core::{code_blocks::get_span_op_group_count, stack::STACK_TOP_SIZE, AssemblyOp};
+use vm_core::{code_blocks::get_span_op_group_count, stack::STACK_TOP_SIZE, AssemblyOp, OpClass};
 
 mod trace;
 use trace::DecoderTrace;
@@ -38,7 +38,7 @@ const HASH_CYCLE_LEN: Felt = Felt::new(miden_air::trace::chiplets::hasher::HASH_
 // DECODER PROCESS EXTENSION
 // ================================================================================================
 
-impl<A> Process<A>
+impl<A> Process<'_, A>
 where
     A: AdviceProvider,
 {
@@ -212,7 +212,10 @@ where
         // when a CALL block ends, stack depth must be exactly 16
         let stack_depth = self.stack.depth();
         if stack_depth > STACK_TOP_SIZE {
-            return Err(ExecutionError::InvalidStackDepthOnReturn(stack_depth));
+            return Err(ExecutionError::InvalidStackDepthOnReturn {
+                clk: self.system.clk(),
+                depth: stack_depth,
+            });
         }
 
         // this appends a row with END operation to the decoder trace; the returned value contains
@@ -369,6 +372,12 @@ impl Decoder {
         self.trace.program_hash()
     }
 
+    /// Returns the number of cycles executed so far by operations of each [OpClass], indexed by the
+    /// position of the class in [OpClass::ALL].
+    pub fn op_class_cycles(&self) -> &[u64; OpClass::ALL.len()] {
+        self.trace.op_class_cycles()
+    }
+
     pub fn debug_info(&self) -> &DebugInfo {
         debug_assert!(self.in_debug_mode());
         &self.debug_info
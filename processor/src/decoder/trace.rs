@@ -5,7 +5,7 @@ use super::{
     OP_BATCH_8_GROUPS, OP_BATCH_SIZE, ZERO,
 };
 use core::ops::Range;
-use vm_core::utils::new_array_vec;
+use vm_core::{utils::new_array_vec, OpClass};
 
 #[cfg(test)]
 use miden_air::trace::decoder::NUM_USER_OP_HELPERS;
@@ -46,6 +46,7 @@ pub struct DecoderTrace {
     op_idx_trace: Vec<Felt>,
     op_batch_flag_trace: [Vec<Felt>; NUM_OP_BATCH_FLAGS],
     op_bit_extra_trace: [Vec<Felt>; NUM_OP_BITS_EXTRA_COLS],
+    op_class_cycles: [u64; OpClass::ALL.len()],
 }
 
 impl DecoderTrace {
@@ -62,6 +63,7 @@ impl DecoderTrace {
             op_idx_trace: Vec::with_capacity(MIN_TRACE_LEN),
             op_batch_flag_trace: new_array_vec(MIN_TRACE_LEN),
             op_bit_extra_trace: new_array_vec(MIN_TRACE_LEN),
+            op_class_cycles: [0; OpClass::ALL.len()],
         }
     }
 
@@ -82,6 +84,12 @@ impl DecoderTrace {
         result
     }
 
+    /// Returns the number of trace rows appended so far for operations of each [OpClass], indexed
+    /// by the position of the class in [OpClass::ALL].
+    pub fn op_class_cycles(&self) -> &[u64; OpClass::ALL.len()] {
+        &self.op_class_cycles
+    }
+
     // TRACE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -489,6 +497,8 @@ impl DecoderTrace {
         let bit4 = self.op_bits_trace[NUM_OP_BITS - 3][clk];
         self.op_bit_extra_trace[0].push(bit6 * (ONE - bit5) * bit4);
         self.op_bit_extra_trace[1].push(bit6 * bit5);
+
+        self.op_class_cycles[op.op_class() as usize] += 1;
     }
 
     /// Add all provided values to the helper registers in the order provided, starting from the
@@ -4,7 +4,7 @@ use vm_core::{crypto::hash::Rpo256, utils::collections::Vec, Felt, StarkField, W
 // ADVICE INJECTORS
 // ================================================================================================
 
-impl<A> Process<A>
+impl<A> Process<'_, A>
 where
     A: AdviceProvider,
 {
@@ -86,10 +86,16 @@ where
         let end_addr = self.stack.get(end_idx).as_int();
 
         if start_addr > u32::MAX as u64 {
-            return Err(ExecutionError::MemoryAddressOutOfBounds(start_addr));
+            return Err(ExecutionError::MemoryAddressOutOfBounds {
+                clk: self.system.clk(),
+                addr: start_addr,
+            });
         }
         if end_addr > u32::MAX as u64 {
-            return Err(ExecutionError::MemoryAddressOutOfBounds(end_addr));
+            return Err(ExecutionError::MemoryAddressOutOfBounds {
+                clk: self.system.clk(),
+                addr: end_addr,
+            });
         }
 
         if start_addr > end_addr {
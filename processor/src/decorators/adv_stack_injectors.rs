@@ -25,7 +25,7 @@ const SMT_NORMALIZED_DEPTHS: [u8; 65] = [
 // ADVICE INJECTORS
 // ================================================================================================
 
-impl<A> Process<A>
+impl<A> Process<'_, A>
 where
     A: AdviceProvider,
 {
@@ -133,7 +133,10 @@ where
         let divisor = (divisor_hi << 32) + divisor_lo;
 
         if divisor == 0 {
-            return Err(ExecutionError::DivideByZero(self.system.clk()));
+            return Err(ExecutionError::DivideByZero {
+                clk: self.system.clk(),
+                source_location: self.source_location,
+            });
         }
 
         let dividend_hi = self.stack.get(2).as_int();
@@ -176,7 +179,10 @@ where
 
         let element = QuadFelt::new(coef0, coef1);
         if element == QuadFelt::ZERO {
-            return Err(ExecutionError::DivideByZero(self.system.clk()));
+            return Err(ExecutionError::DivideByZero {
+                clk: self.system.clk(),
+                source_location: self.source_location,
+            });
         }
         let result = element.inv().to_base_elements();
 
@@ -340,6 +346,26 @@ where
 
         Ok(())
     }
+
+    /// Pushes the next unread element of the named advice tape with the specified key onto the
+    /// advice stack.
+    ///
+    /// Inputs:
+    ///   Advice stack: [...]
+    ///   Advice map: {KEY: [v0, ..., vn]}
+    ///
+    /// Outputs:
+    ///   Advice stack: [vi, ...]
+    ///   Advice map: {KEY: [v0, ..., vn]}
+    ///
+    /// Where `vi` is the first element of the tape which has not been read yet.
+    ///
+    /// # Errors
+    /// Returns an error if the tape was not found in the advice map, or if all elements of the
+    /// tape have already been read.
+    pub(super) fn copy_tape_value_to_adv_stack(&mut self, key: Word) -> Result<(), ExecutionError> {
+        self.advice_provider.push_stack(AdviceSource::Tape { key })
+    }
 }
 
 // HELPER FUNCTIONS
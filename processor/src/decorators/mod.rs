@@ -1,4 +1,7 @@
-use super::{AdviceInjector, AdviceProvider, AdviceSource, Decorator, ExecutionError, Process};
+use super::{
+    AdviceInjector, AdviceProvider, AdviceSource, DebugEvent, DebugOptions, Decorator,
+    ExecutionError, Process, Vec,
+};
 
 mod adv_map_injectors;
 mod adv_stack_injectors;
@@ -9,7 +12,7 @@ mod tests;
 // DECORATORS
 // ================================================================================================
 
-impl<A> Process<A>
+impl<A> Process<'_, A>
 where
     A: AdviceProvider,
 {
@@ -21,14 +24,55 @@ where
         match decorator {
             Decorator::Advice(injector) => self.dec_advice(injector)?,
             Decorator::AsmOp(assembly_op) => {
+                // keep track of the location of the instruction being executed so that it can be
+                // included in runtime errors
+                self.source_location = assembly_op.location();
                 if self.decoder.in_debug_mode() {
                     self.decoder.append_asmop(self.system.clk(), assembly_op.clone());
                 }
             }
+            Decorator::Debug(options) => {
+                if self.decoder.in_debug_mode() {
+                    self.capture_debug_event(*options);
+                }
+            }
         }
         Ok(())
     }
 
+    // DEBUG EVENTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Records the state of the VM specified by the provided debug options as a [DebugEvent] at
+    /// the current clock cycle.
+    fn capture_debug_event(&mut self, options: DebugOptions) {
+        let clk = self.system.clk();
+        let ctx = self.system.ctx();
+
+        let mut stack = Vec::new();
+        let mut memory = Vec::new();
+        match options {
+            DebugOptions::StackAll => stack = self.stack.get_state_at(clk),
+            DebugOptions::StackTop(n) => {
+                stack = self.stack.get_state_at(clk);
+                stack.truncate(n as usize);
+            }
+            DebugOptions::MemAll => memory = self.chiplets.get_mem_state_at(ctx, clk),
+            DebugOptions::MemInterval(start, end) => {
+                memory = self.chiplets.get_mem_state_at(ctx, clk);
+                memory.retain(|&(addr, _)| addr >= start as u64 && addr <= end as u64);
+            }
+        }
+
+        self.debug_events.push(DebugEvent {
+            clk,
+            ctx,
+            options,
+            stack,
+            memory,
+        });
+    }
+
     // ADVICE INJECTION
     // --------------------------------------------------------------------------------------------
 
@@ -45,6 +89,7 @@ where
             AdviceInjector::Ext2Inv => self.push_ext2_inv_result(),
             AdviceInjector::Ext2Intt => self.push_ext2_intt_result(),
             AdviceInjector::SmtGet => self.push_smtget_inputs(),
+            AdviceInjector::TapeToStack { key } => self.copy_tape_value_to_adv_stack(*key),
             AdviceInjector::MemToMap => self.insert_mem_values_into_adv_map(),
             AdviceInjector::HdwordToMap { domain } => self.insert_hdword_into_adv_map(*domain),
         }
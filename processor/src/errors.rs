@@ -1,10 +1,13 @@
 use super::{
-    crypto::MerkleError,
+    crypto::{MerkleError, NodeIndex},
     system::{FMP_MAX, FMP_MIN},
-    CodeBlock, Digest, Felt, QuadFelt, Word,
+    CodeBlock, DeserializationError, Digest, Felt, Operation, QuadFelt, SourceLocation, Word,
 };
 use core::fmt::{Display, Formatter};
-use vm_core::{stack::STACK_TOP_SIZE, utils::to_hex};
+use vm_core::{
+    stack::STACK_TOP_SIZE,
+    utils::{string::String, to_hex},
+};
 use winter_prover::{math::FieldElement, ProverError};
 
 #[cfg(feature = "std")]
@@ -16,29 +19,95 @@ use std::error::Error;
 #[derive(Debug)]
 pub enum ExecutionError {
     AdviceKeyNotFound(Word),
-    AdviceStackReadFailed(u32),
+    AdviceStackReadFailed {
+        step: u32,
+        requested: usize,
+        available: usize,
+    },
+    AdviceTapeExhausted {
+        step: u32,
+        key: Word,
+        len: usize,
+    },
     CallerNotInSyscall,
     CodeBlockNotFound(Digest),
-    DivideByZero(u32),
+    CycleLimitExceeded(u32),
+    DivideByZero {
+        clk: u32,
+        source_location: Option<SourceLocation>,
+    },
+    ExecutionAborted(u32),
     Ext2InttError(Ext2InttError),
-    FailedAssertion(u32),
+    FailedAssertion {
+        clk: u32,
+        err_code: Felt,
+        source_location: Option<SourceLocation>,
+    },
+    InconsistentPublicInputs {
+        segment: PublicInputsSegment,
+        index: usize,
+        expected: Felt,
+        actual: Felt,
+    },
     InvalidFmpValue(Felt, Felt),
     InvalidFriDomainSegment(u64),
     InvalidFriLayerFolding(QuadFelt, QuadFelt),
-    InvalidMemoryRange { start_addr: u64, end_addr: u64 },
-    InvalidStackDepthOnReturn(usize),
+    InvalidMemoryRange {
+        start_addr: u64,
+        end_addr: u64,
+    },
+    InvalidStackDepthOnReturn {
+        clk: u32,
+        depth: usize,
+    },
     InvalidStackWordOffset(usize),
-    InvalidTreeDepth { depth: Felt },
-    InvalidTreeNodeIndex { depth: Felt, value: Felt },
-    MemoryAddressOutOfBounds(u64),
+    InvalidTreeDepth {
+        depth: Felt,
+    },
+    InvalidTreeNodeIndex {
+        depth: Felt,
+        value: Felt,
+    },
+    LoopBoundExceeded(u32),
+    MemoryAddressOutOfBounds {
+        clk: u32,
+        addr: u64,
+    },
+    MerklePathNotFound {
+        root: Digest,
+        index: NodeIndex,
+    },
     MerkleStoreMergeFailed(MerkleError),
     MerkleStoreLookupFailed(MerkleError),
     MerkleStoreUpdateFailed(MerkleError),
+    NonRecursiveProofOptions,
+    NonReproducibleProofOptions {
+        grinding_factor: u32,
+    },
     NotBinaryValue(Felt),
     NotU32Value(Felt),
+    ProgramDeserializationFailed(DeserializationError),
+    ProgramHashMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
     ProverError(ProverError),
+    StackOverflow {
+        depth: usize,
+        capacity: usize,
+    },
+    SparseMemorySegment {
+        len: u64,
+        num_words: usize,
+    },
     SyscallTargetNotInKernel(Digest),
+    ThreadPoolCreationFailed(String),
+    TraceTooLarge {
+        estimated_bytes: usize,
+        max_bytes: usize,
+    },
     UnexecutableCodeBlock(CodeBlock),
+    UnexecutableOperation(Operation),
 }
 
 impl Display for ExecutionError {
@@ -50,7 +119,25 @@ impl Display for ExecutionError {
                 let hex = to_hex(Felt::elements_as_bytes(key))?;
                 write!(f, "Can't push values onto the advice stack: value for key {hex} not present in the advice map.")
             }
-            AdviceStackReadFailed(step) => write!(f, "Advice stack read failed at step {step}"),
+            AdviceStackReadFailed {
+                step,
+                requested,
+                available,
+            } => {
+                write!(
+                    f,
+                    "Advice stack read failed at step {step}: requested {requested} values, but \
+                    only {available} were available"
+                )
+            }
+            AdviceTapeExhausted { step, key, len } => {
+                let hex = to_hex(Felt::elements_as_bytes(key))?;
+                write!(
+                    f,
+                    "Advice tape read failed at step {step}: all {len} values of the tape with \
+                    key {hex} have already been read"
+                )
+            }
             CallerNotInSyscall => {
                 write!(f, "Instruction `caller` used outside of kernel context")
             }
@@ -61,9 +148,37 @@ impl Display for ExecutionError {
                     "Failed to execute code block with root {hex}; the block could not be found"
                 )
             }
-            DivideByZero(clk) => write!(f, "Division by zero at clock cycle {clk}"),
+            CycleLimitExceeded(max_cycles) => {
+                write!(f, "Exceeded the allowed number of cycles: {max_cycles}")
+            }
+            DivideByZero {
+                clk,
+                source_location,
+            } => {
+                write!(f, "Division by zero at clock cycle {clk}")?;
+                write_source_location(f, source_location)
+            }
+            ExecutionAborted(clk) => write!(f, "Execution was aborted at clock cycle {clk}"),
             Ext2InttError(err) => write!(f, "Failed to execute Ext2Intt operation: {err}"),
-            FailedAssertion(clk) => write!(f, "Assertion failed at clock cycle {clk}"),
+            FailedAssertion {
+                clk,
+                err_code,
+                source_location,
+            } => {
+                write!(f, "Assertion failed at clock cycle {clk}")?;
+                if *err_code != Felt::ZERO {
+                    write!(f, " with error code {err_code}")?;
+                }
+                write_source_location(f, source_location)
+            }
+            InconsistentPublicInputs {
+                segment,
+                index,
+                expected,
+                actual,
+            } => {
+                write!(f, "{segment} are inconsistent with the execution trace: expected {expected} at position {index}, but trace contained {actual}")
+            }
             InvalidFmpValue(old, new) => {
                 write!(f, "Updating FMP register from {old} to {new} failed because {new} is outside of {FMP_MIN}..{FMP_MAX}")
             }
@@ -79,8 +194,8 @@ impl Display for ExecutionError {
             } => {
                 write!(f, "Memory range start address cannot exceed end address, but was ({start_addr}, {end_addr})")
             }
-            InvalidStackDepthOnReturn(depth) => {
-                write!(f, "When returning from a call, stack depth must be {STACK_TOP_SIZE}, but was {depth}")
+            InvalidStackDepthOnReturn { clk, depth } => {
+                write!(f, "When returning from a call at clock cycle {clk}, stack depth must be {STACK_TOP_SIZE}, but was {depth}")
             }
             InvalidStackWordOffset(offset) => {
                 write!(f, "Stack word offset cannot exceed 12, but was {offset}")
@@ -91,8 +206,21 @@ impl Display for ExecutionError {
             InvalidTreeNodeIndex { depth, value } => {
                 write!(f, "The provided index {value} is out of bounds for a node at depth {depth}")
             }
-            MemoryAddressOutOfBounds(addr) => {
-                write!(f, "Memory address cannot exceed 2^32 but was {addr}")
+            LoopBoundExceeded(max_iterations) => {
+                write!(f, "Loop body was executed more than the maximum of {max_iterations} times")
+            }
+            MemoryAddressOutOfBounds { clk, addr } => {
+                write!(f, "Memory address cannot exceed 2^32 but was {addr} at clock cycle {clk}")
+            }
+            MerklePathNotFound { root, index } => {
+                let hex = to_hex(&root.as_bytes())?;
+                write!(
+                    f,
+                    "Merkle path to the node at depth {} and index {} of the tree with root {hex} \
+                    is not present in the advice provider",
+                    index.depth(),
+                    index.value()
+                )
             }
             MerkleStoreLookupFailed(reason) => {
                 write!(f, "Advice provider Merkle store backend lookup failed: {reason}")
@@ -103,20 +231,64 @@ impl Display for ExecutionError {
             MerkleStoreUpdateFailed(reason) => {
                 write!(f, "Advice provider Merkle store backend update failed: {reason}")
             }
+            NonRecursiveProofOptions => {
+                write!(f, "Proofs can be encoded as field elements only if they are generated using RPO hash function over a quadratic extension field")
+            }
+            NonReproducibleProofOptions { grinding_factor } => {
+                write!(
+                    f,
+                    "Proofs generated with a grinding factor of {grinding_factor} depend on the \
+                    number of threads used for proving; a grinding factor of 0 is required"
+                )
+            }
             NotBinaryValue(v) => {
                 write!(f, "An operation expected a binary value, but received {v}")
             }
             NotU32Value(v) => {
                 write!(f, "An operation expected a u32 value, but received {v}")
             }
+            ProgramDeserializationFailed(error) => {
+                write!(f, "Failed to deserialize the program: {error}")
+            }
+            ProgramHashMismatch { expected, actual } => {
+                let expected = to_hex(&expected.as_bytes())?;
+                let actual = to_hex(&actual.as_bytes())?;
+                write!(f, "Program hash {expected} is inconsistent with the hash of the executed program {actual}")
+            }
             ProverError(error) => write!(f, "Proof generation failed: {error}"),
+            StackOverflow { depth, capacity } => {
+                write!(f, "Stack depth of {depth} exceeds the allowed maximum of {capacity} items")
+            }
+            SparseMemorySegment { len, num_words } => {
+                write!(
+                    f,
+                    "Memory segment of {len} words is too sparse: only {num_words} of its words \
+                    are non-zero"
+                )
+            }
             SyscallTargetNotInKernel(proc) => {
                 let hex = to_hex(&proc.as_bytes())?;
                 write!(f, "Syscall failed: procedure with root {hex} was not found in the kernel")
             }
+            ThreadPoolCreationFailed(reason) => {
+                write!(f, "Failed to create a thread pool for proving: {reason}")
+            }
+            TraceTooLarge {
+                estimated_bytes,
+                max_bytes,
+            } => {
+                write!(
+                    f,
+                    "Proving the execution trace requires an estimated {estimated_bytes} bytes of \
+                    memory, but at most {max_bytes} bytes are allowed"
+                )
+            }
             UnexecutableCodeBlock(block) => {
                 write!(f, "Execution reached unexecutable code block {block:?}")
             }
+            UnexecutableOperation(op) => {
+                write!(f, "Operation {op} cannot be executed as part of a span block")
+            }
         }
     }
 }
@@ -130,6 +302,26 @@ impl From<Ext2InttError> for ExecutionError {
     }
 }
 
+// PUBLIC INPUTS SEGMENT
+// ================================================================================================
+
+/// Identifies the part of the public inputs which caused an
+/// [ExecutionError::InconsistentPublicInputs] error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PublicInputsSegment {
+    StackInputs,
+    StackOutputs,
+}
+
+impl Display for PublicInputsSegment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            Self::StackInputs => write!(f, "Stack inputs"),
+            Self::StackOutputs => write!(f, "Stack outputs"),
+        }
+    }
+}
+
 // EXT2INTT ERROR
 // ================================================================================================
 
@@ -184,3 +376,19 @@ impl Display for Ext2InttError {
 
 #[cfg(feature = "std")]
 impl Error for Ext2InttError {}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes the source location of the instruction which caused an error, if it is known.
+fn write_source_location(
+    f: &mut Formatter<'_>,
+    source_location: &Option<SourceLocation>,
+) -> Result<(), core::fmt::Error> {
+    match source_location {
+        Some(location) => {
+            write!(f, " (line {}, column {})", location.line(), location.column())
+        }
+        None => Ok(()),
+    }
+}
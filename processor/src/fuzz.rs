@@ -0,0 +1,125 @@
+use super::{
+    execute_with_options, CodeBlock, ExecutionError, ExecutionOptions, MemAdviceProvider,
+    Operation, Program, StackInputs,
+};
+use vm_core::utils::{collections::Vec, ByteReader, Deserializable, SliceReader};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The maximum number of cycles a program executed via [execute_fuzz()] is allowed to run for.
+pub const FUZZ_MAX_CYCLES: u32 = 1 << 16;
+
+// FUZZING HARNESS
+// ================================================================================================
+
+/// Deserializes a sequence of operations from the provided bytes and executes it as a program
+/// consisting of a single span block.
+///
+/// This is intended as an entry point for fuzzers: the program is executed against empty inputs,
+/// limited to [FUZZ_MAX_CYCLES] cycles, and malformed inputs result in an error rather than a
+/// panic. Operations are read one at a time via the [Deserializable] implementation of
+/// [Operation] until the bytes are exhausted; an empty sequence of bytes is treated as an empty
+/// program and succeeds without executing anything.
+///
+/// # Errors
+/// Returns an error if the bytes cannot be deserialized into a sequence of operations, or if the
+/// program fails to execute.
+pub fn execute_fuzz(program_bytes: &[u8]) -> Result<(), ExecutionError> {
+    let mut source = SliceReader::new(program_bytes);
+    let mut operations = Vec::new();
+    while source.has_more_bytes() {
+        let op = Operation::read_from(&mut source)
+            .map_err(ExecutionError::ProgramDeserializationFailed)?;
+        operations.push(op);
+    }
+    if operations.is_empty() {
+        return Ok(());
+    }
+
+    let program = Program::new(CodeBlock::new_span(operations));
+    let options = ExecutionOptions::default().with_max_cycles(FUZZ_MAX_CYCLES);
+    execute_with_options(&program, StackInputs::default(), MemAdviceProvider::default(), options)?;
+    Ok(())
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{execute_fuzz, ExecutionError, Operation};
+    use rand_utils::{rand_value, rand_vector};
+    use vm_core::{
+        utils::{collections::Vec, Deserializable, Serializable, SliceReader},
+        Felt, StarkField,
+    };
+
+    #[test]
+    fn execute_fuzz_malformed_bytes() {
+        assert!(execute_fuzz(&[]).is_ok());
+
+        // 0b0111_1111 is not a valid opcode
+        let result = execute_fuzz(&[0b0111_1111]);
+        assert!(matches!(result, Err(ExecutionError::ProgramDeserializationFailed(_))));
+
+        // the immediate value of PUSH is truncated
+        let mut bytes = Operation::Push(Felt::new(7)).to_bytes();
+        bytes.pop();
+        let result = execute_fuzz(&bytes);
+        assert!(matches!(result, Err(ExecutionError::ProgramDeserializationFailed(_))));
+
+        // the immediate value of PUSH is not a valid field element
+        let mut bytes = vec![Operation::Push(Felt::new(7)).op_code()];
+        bytes.extend_from_slice(&Felt::MODULUS.to_le_bytes());
+        let result = execute_fuzz(&bytes);
+        assert!(matches!(result, Err(ExecutionError::ProgramDeserializationFailed(_))));
+    }
+
+    #[test]
+    fn execute_fuzz_control_flow_ops() {
+        for op in [Operation::Join, Operation::Span, Operation::End, Operation::Halt] {
+            let bytes = [Operation::Pad.to_bytes(), op.to_bytes()].concat();
+            let result = execute_fuzz(&bytes);
+            assert!(matches!(result, Err(ExecutionError::UnexecutableOperation(o)) if o == op));
+        }
+    }
+
+    #[test]
+    fn execute_fuzz_random_programs() {
+        // random bytes mostly fail to deserialize
+        for _ in 0..100 {
+            let _ = execute_fuzz(&rand_vector::<u8>(64));
+        }
+
+        // random sequences of valid operations
+        for _ in 0..100 {
+            let ops = rand_operations(64);
+            let bytes = ops.iter().flat_map(|op| op.to_bytes()).collect::<Vec<_>>();
+            let _ = execute_fuzz(&bytes);
+        }
+    }
+
+    #[test]
+    fn operation_serialization() {
+        for op in rand_operations(256) {
+            assert_eq!(op, Operation::read_from_bytes(&op.to_bytes()).unwrap());
+        }
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a vector of `n` operations with random opcodes and immediate values.
+    fn rand_operations(n: usize) -> Vec<Operation> {
+        let mut ops = Vec::with_capacity(n);
+        while ops.len() < n {
+            let mut bytes = vec![rand_value::<u8>()];
+            bytes.extend_from_slice(&rand_value::<Felt>().as_int().to_le_bytes());
+            if let Ok(op) = Operation::read_from(&mut SliceReader::new(&bytes)) {
+                ops.push(op);
+            }
+        }
+        ops
+    }
+}
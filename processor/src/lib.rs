@@ -9,8 +9,12 @@ use miden_air::trace::{
     SYS_TRACE_WIDTH,
 };
 pub use vm_core::{
-    chiplets::hasher::Digest, errors::InputError, utils::DeserializationError, AssemblyOp, Kernel,
-    Operation, Program, ProgramInfo, QuadExtension, StackInputs, StackOutputs, Word,
+    advice_tape_key,
+    chiplets::hasher::Digest,
+    errors::{DigestParseError, InputError, OutputMismatch},
+    utils::{DeserializationError, WordExt},
+    AssemblyOp, DebugOptions, Kernel, OpClass, Operation, ProcedureInfo, Program,
+    ProgramComplexity, ProgramInfo, QuadExtension, SourceLocation, StackInputs, StackOutputs, Word,
 };
 use vm_core::{
     code_blocks::{
@@ -21,6 +25,7 @@ use vm_core::{
     StackTopState, StarkField, ONE, ZERO,
 };
 
+use core::ops::ControlFlow;
 use winter_prover::ColMatrix;
 
 mod decorators;
@@ -41,23 +46,43 @@ use range::RangeChecker;
 
 mod advice;
 pub use advice::{
-    AdviceInputs, AdviceProvider, AdviceSource, MemAdviceProvider, RecAdviceProvider,
+    AdviceInputs, AdviceProvider, AdviceSource, ChainedProvider, MemAdviceProvider,
+    RecAdviceProvider,
 };
 
 mod chiplets;
 use chiplets::Chiplets;
 
 mod trace;
-pub use trace::ExecutionTrace;
+#[cfg(feature = "std")]
+pub use trace::ColumnStat;
 use trace::TraceFragment;
+pub use trace::{ExecutionTrace, TraceLenSummary};
 
 mod errors;
-pub use errors::{ExecutionError, Ext2InttError};
+pub use errors::{ExecutionError, Ext2InttError, PublicInputsSegment};
+
+mod options;
+pub use options::{ExecutionOptions, DEFAULT_MAX_STACK_DEPTH};
 
 pub mod utils;
 
 mod debug;
-pub use debug::{AsmOpInfo, VmState, VmStateIterator};
+pub use debug::{AsmOpInfo, DebugEvent, StateDelta, VmState, VmStateIterator};
+
+#[cfg(feature = "std")]
+mod debugger;
+#[cfg(feature = "std")]
+pub use debugger::Debugger;
+
+mod witness;
+pub use witness::{execute_to_witness, ExecutionWitness};
+
+mod fuzz;
+pub use fuzz::{execute_fuzz, FUZZ_MAX_CYCLES};
+
+mod continuation;
+pub use continuation::{execute_continued, VmInitialState};
 
 // RE-EXPORTS
 // ================================================================================================
@@ -70,7 +95,7 @@ pub mod math {
 pub mod crypto {
     pub use vm_core::crypto::{
         hash::{Blake3_192, Blake3_256, ElementHasher, Hasher, Rpo256, RpoDigest},
-        merkle::{MerkleError, MerklePath, MerkleStore, MerkleTree, SimpleSmt},
+        merkle::{MerkleError, MerklePath, MerkleStore, MerkleTree, NodeIndex, SimpleSmt},
         random::{RandomCoin, RpoRandomCoin, WinterRandomCoin},
     };
 }
@@ -107,6 +132,10 @@ pub struct ChipletsTrace {
 
 /// Returns an execution trace resulting from executing the provided program against the provided
 /// inputs.
+///
+/// # Errors
+/// Returns an error if the program fails to execute, or if the hash of the executed program is
+/// inconsistent with the hash of the provided `program`.
 pub fn execute<A>(
     program: &Program,
     stack_inputs: StackInputs,
@@ -115,15 +144,100 @@ pub fn execute<A>(
 where
     A: AdviceProvider,
 {
-    let mut process = Process::new(program.kernel().clone(), stack_inputs, advice_provider);
+    execute_bounded(program, stack_inputs, advice_provider, u32::MAX)
+}
+
+/// Returns an execution trace resulting from executing the provided program against the provided
+/// inputs, limiting the execution to at most `max_cycles` VM cycles.
+///
+/// The limit is enforced as the program is being executed; thus, programs which do not terminate
+/// within the specified number of cycles are aborted before the full execution trace is built.
+///
+/// # Errors
+/// Returns an error if the program fails to execute, if the execution requires more than
+/// `max_cycles` cycles, or if the hash of the executed program is inconsistent with the hash of
+/// the provided `program`.
+pub fn execute_bounded<A>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_provider: A,
+    max_cycles: u32,
+) -> Result<ExecutionTrace, ExecutionError>
+where
+    A: AdviceProvider,
+{
+    let options = ExecutionOptions::default().with_max_cycles(max_cycles);
+    execute_with_options(program, stack_inputs, advice_provider, options)
+}
+
+/// Returns an execution trace resulting from executing the provided program against the provided
+/// inputs, limiting the resources used by the program as specified by `options`.
+///
+/// The trace is padded to the minimum length specified by `options`.
+///
+/// # Errors
+/// Returns an error if the program fails to execute, if the execution exceeds any of the limits
+/// defined by `options`, or if the hash of the executed program is inconsistent with the hash of
+/// the provided `program`.
+pub fn execute_with_options<A>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_provider: A,
+    options: ExecutionOptions,
+) -> Result<ExecutionTrace, ExecutionError>
+where
+    A: AdviceProvider,
+{
+    let mut process = Process::new(program.kernel().clone(), stack_inputs, advice_provider)
+        .with_max_cycles(options.max_cycles())
+        .with_max_stack_depth(options.max_stack_depth());
     let stack_outputs = process.execute(program)?;
-    let trace = ExecutionTrace::new(process, stack_outputs);
-    assert_eq!(&program.hash(), trace.program_hash(), "inconsistent program hash");
+    let trace = ExecutionTrace::with_min_len(process, stack_outputs, options.min_trace_len());
+    if &program.hash() != trace.program_hash() {
+        return Err(ExecutionError::ProgramHashMismatch {
+            expected: program.hash(),
+            actual: *trace.program_hash(),
+        });
+    }
     Ok(trace)
 }
 
+/// Returns an execution trace resulting from executing the provided program against the provided
+/// inputs, together with the list of events captured by `debug` decorators during execution.
+///
+/// The program is executed in debug mode. Note that `debug` instructions are compiled into
+/// `debug` decorators only when the program is compiled in debug mode; otherwise, the returned
+/// list of events is empty.
+///
+/// # Errors
+/// Returns an error if the program fails to execute, or if the hash of the executed program is
+/// inconsistent with the hash of the provided `program`.
+pub fn execute_with_debug<A>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_provider: A,
+) -> Result<(ExecutionTrace, Vec<DebugEvent>), ExecutionError>
+where
+    A: AdviceProvider,
+{
+    let mut process = Process::new_debug(program.kernel().clone(), stack_inputs, advice_provider);
+    let stack_outputs = process.execute(program)?;
+    let debug_events = core::mem::take(&mut process.debug_events);
+    let trace = ExecutionTrace::new(process, stack_outputs);
+    if &program.hash() != trace.program_hash() {
+        return Err(ExecutionError::ProgramHashMismatch {
+            expected: program.hash(),
+            actual: *trace.program_hash(),
+        });
+    }
+    Ok((trace, debug_events))
+}
+
 /// Returns an iterator which allows callers to step through the execution and inspect VM state at
 /// each execution step.
+///
+/// If the program fails to execute, or if the hash of the executed program is inconsistent with
+/// the hash of the provided `program`, the iterator yields the error after the last state.
 pub fn execute_iter<A>(
     program: &Program,
     stack_inputs: StackInputs,
@@ -133,22 +247,61 @@ where
     A: AdviceProvider,
 {
     let mut process = Process::new_debug(program.kernel().clone(), stack_inputs, advice_provider);
-    let result = process.execute(program);
-    if result.is_ok() {
-        assert_eq!(
-            program.hash(),
-            process.decoder.program_hash().into(),
-            "inconsistent program hash"
-        );
-    }
+    let result = process.execute(program).and_then(|stack_outputs| {
+        let actual: Digest = process.decoder.program_hash().into();
+        if program.hash() != actual {
+            return Err(ExecutionError::ProgramHashMismatch {
+                expected: program.hash(),
+                actual,
+            });
+        }
+        Ok(stack_outputs)
+    });
     VmStateIterator::new(process, result)
 }
 
+/// Executes the provided program against the provided inputs and invokes the `hook` with the
+/// state of the VM at each clock cycle of the execution.
+///
+/// The hook is invoked by the VM after each cycle of the execution, starting with the initial
+/// state of the VM at clock cycle 0; thus, the states are observed while the program is being
+/// executed. If the hook returns [ControlFlow::Break], the execution is aborted. If execution
+/// fails, the hook is invoked for all cycles up to the cycle at which the execution stopped. The
+/// program is executed in debug mode; [execute()] should be used when VM states are not needed.
+///
+/// # Errors
+/// Returns an error if the program fails to execute, if the execution was aborted by the hook, or
+/// if the hash of the executed program is inconsistent with the hash of the provided `program`.
+pub fn execute_with_hook<A>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_provider: A,
+    hook: &mut dyn FnMut(&VmState) -> ControlFlow<()>,
+) -> Result<StackOutputs, ExecutionError>
+where
+    A: AdviceProvider,
+{
+    let mut process =
+        Process::new_debug(program.kernel().clone(), stack_inputs, advice_provider).with_hook(hook);
+    let stack_outputs = process.execute(program)?;
+    let program_hash: Digest = process.decoder.program_hash().into();
+    if program.hash() != program_hash {
+        return Err(ExecutionError::ProgramHashMismatch {
+            expected: program.hash(),
+            actual: program_hash,
+        });
+    }
+    Ok(stack_outputs)
+}
+
 // PROCESS
 // ================================================================================================
 
+/// A callback which a [Process] invokes with the state of the VM after each executed cycle.
+type ExecutionHook<'a> = dyn FnMut(&VmState) -> ControlFlow<()> + 'a;
+
 #[cfg(not(any(test, feature = "internals")))]
-struct Process<A>
+struct Process<'a, A>
 where
     A: AdviceProvider,
 {
@@ -158,9 +311,14 @@ where
     range: RangeChecker,
     chiplets: Chiplets,
     advice_provider: A,
+    max_cycles: u32,
+    max_stack_depth: usize,
+    debug_events: Vec<DebugEvent>,
+    source_location: Option<SourceLocation>,
+    hook: Option<&'a mut ExecutionHook<'a>>,
 }
 
-impl<A> Process<A>
+impl<'a, A> Process<'a, A>
 where
     A: AdviceProvider,
 {
@@ -189,15 +347,43 @@ where
             range: RangeChecker::new(),
             chiplets: Chiplets::new(kernel),
             advice_provider,
+            max_cycles: u32::MAX,
+            max_stack_depth: DEFAULT_MAX_STACK_DEPTH,
+            debug_events: Vec::new(),
+            source_location: None,
+            hook: None,
         }
     }
 
+    /// Sets the hook which this process invokes with the state of the VM after each executed
+    /// cycle; execution is aborted if the hook returns [ControlFlow::Break].
+    pub fn with_hook(mut self, hook: &'a mut ExecutionHook<'a>) -> Self {
+        self.hook = Some(hook);
+        self
+    }
+
+    // CONFIGURATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Limits the number of cycles this process is allowed to execute to `max_cycles`.
+    pub fn with_max_cycles(mut self, max_cycles: u32) -> Self {
+        self.max_cycles = max_cycles;
+        self
+    }
+
+    /// Limits the depth of the stack this process is allowed to reach to `max_stack_depth`.
+    pub fn with_max_stack_depth(mut self, max_stack_depth: usize) -> Self {
+        self.max_stack_depth = max_stack_depth;
+        self
+    }
+
     // PROGRAM EXECUTOR
     // --------------------------------------------------------------------------------------------
 
     /// Executes the provided [Program] in this process.
     pub fn execute(&mut self, program: &Program) -> Result<StackOutputs, ExecutionError> {
         assert_eq!(self.system.clk(), 0, "a program has already been executed in this process");
+        self.invoke_hook()?;
         self.execute_code_block(program.root(), program.cb_table())?;
 
         Ok(self.stack.build_stack_outputs())
@@ -281,7 +467,16 @@ where
             // keep executing the loop body until the condition on the top of the stack is no
             // longer ONE; each iteration of the loop is preceded by executing REPEAT operation
             // which drops the condition from the stack
+            let mut num_iterations = 1;
             while self.stack.peek() == ONE {
+                // make sure the loop body is not executed more times than allowed by the block
+                if let Some(max_iterations) = block.max_iterations() {
+                    if num_iterations >= max_iterations {
+                        return Err(ExecutionError::LoopBoundExceeded(max_iterations));
+                    }
+                }
+                num_iterations += 1;
+
                 self.decoder.repeat();
                 self.execute_op(Operation::Drop)?;
                 self.execute_code_block(block.body(), cb_table)?;
@@ -343,7 +538,17 @@ where
             op_offset += op_batch.ops().len();
         }
 
-        self.end_span_block(block)
+        self.end_span_block(block)?;
+
+        // execute any decorators which have not been executed during span ops execution; this
+        // can happen for decorators appearing after all operations in a block. these decorators
+        // are executed after the SPAN block is closed to make sure the VM clock cycle advances
+        // beyond the last clock cycle of the SPAN block ops.
+        while let Some(decorator) = decorators.next(op_offset) {
+            self.execute_decorator(decorator)?;
+        }
+
+        Ok(())
     }
 
     /// Executes all operations in an [OpBatch]. This also ensures that all alignment rules are
@@ -460,7 +665,7 @@ where
 // ================================================================================================
 
 #[cfg(any(test, feature = "internals"))]
-pub struct Process<A>
+pub struct Process<'a, A>
 where
     A: AdviceProvider,
 {
@@ -470,4 +675,9 @@ where
     pub range: RangeChecker,
     pub chiplets: Chiplets,
     pub advice_provider: A,
+    pub max_cycles: u32,
+    pub max_stack_depth: usize,
+    pub debug_events: Vec<DebugEvent>,
+    pub source_location: Option<SourceLocation>,
+    pub hook: Option<&'a mut ExecutionHook<'a>>,
 }
@@ -4,7 +4,7 @@ use vm_core::StarkField;
 // CRYPTOGRAPHIC OPERATIONS
 // ================================================================================================
 
-impl<A> Process<A>
+impl<A> Process<'_, A>
 where
     A: AdviceProvider,
 {
@@ -5,7 +5,7 @@ use super::{AdviceProvider, ExecutionError, Felt, Process};
 
 const TWO: Felt = Felt::new(2);
 
-impl<A> Process<A>
+impl<A> Process<'_, A>
 where
     A: AdviceProvider,
 {
@@ -1,10 +1,10 @@
 use super::{utils::assert_binary, AdviceProvider, ExecutionError, Felt, FieldElement, Process};
-use vm_core::{Operation, StarkField, ZERO};
+use vm_core::{Operation, StarkField, ONE, ZERO};
 
 // FIELD OPERATIONS
 // ================================================================================================
 
-impl<A> Process<A>
+impl<A> Process<'_, A>
 where
     A: AdviceProvider,
 {
@@ -47,7 +47,10 @@ where
     pub(super) fn op_inv(&mut self) -> Result<(), ExecutionError> {
         let a = self.stack.get(0);
         if a == Felt::ZERO {
-            return Err(ExecutionError::DivideByZero(self.system.clk()));
+            return Err(ExecutionError::DivideByZero {
+                clk: self.system.clk(),
+                source_location: self.source_location,
+            });
         }
 
         self.stack.set(0, a.inv());
@@ -190,7 +193,7 @@ where
         let bit = b.as_int() & 1;
 
         // value which would be incorporated in the accumulator.
-        let value = Felt::new((exp.as_int() - 1) * bit + 1);
+        let value = if bit == 1 { exp } else { ONE };
 
         // current value of acc after including the value based on whether the bit is
         // 1 or not.
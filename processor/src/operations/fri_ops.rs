@@ -19,7 +19,7 @@ const TAU3_INV: Felt = Felt::new(281474976710656); // tau^{-3}
 // FRI OPERATIONS
 // ================================================================================================
 
-impl<A> Process<A>
+impl<A> Process<'_, A>
 where
     A: AdviceProvider,
 {
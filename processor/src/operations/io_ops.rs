@@ -8,7 +8,7 @@ const TWO: Felt = Felt::new(2);
 // INPUT / OUTPUT OPERATIONS
 // ================================================================================================
 
-impl<A> Process<A>
+impl<A> Process<'_, A>
 where
     A: AdviceProvider,
 {
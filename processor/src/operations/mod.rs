@@ -17,12 +17,17 @@ use super::Kernel;
 // OPERATION DISPATCHER
 // ================================================================================================
 
-impl<A> Process<A>
+impl<A> Process<'_, A>
 where
     A: AdviceProvider,
 {
     /// Executes the specified operation.
     pub(super) fn execute_op(&mut self, op: Operation) -> Result<(), ExecutionError> {
+        // make sure the execution stays within the cycle budget of the process
+        if self.system.clk() >= self.max_cycles {
+            return Err(ExecutionError::CycleLimitExceeded(self.max_cycles));
+        }
+
         // make sure there is enough memory allocated to hold the execution trace
         self.ensure_trace_capacity();
 
@@ -30,7 +35,7 @@ where
         match op {
             // ----- system operations ------------------------------------------------------------
             Operation::Noop => self.stack.copy_state(0),
-            Operation::Assert => self.op_assert()?,
+            Operation::Assert(err_code) => self.op_assert(err_code)?,
 
             Operation::FmpAdd => self.op_fmpadd()?,
             Operation::FmpUpdate => self.op_fmpupdate()?,
@@ -41,17 +46,18 @@ where
             Operation::Clk => self.op_clk()?,
 
             // ----- flow control operations ------------------------------------------------------
-            // control flow operations are never executed directly
-            Operation::Join => unreachable!("control flow operation"),
-            Operation::Split => unreachable!("control flow operation"),
-            Operation::Loop => unreachable!("control flow operation"),
-            Operation::Call => unreachable!("control flow operation"),
-            Operation::SysCall => unreachable!("control flow operation"),
-            Operation::Span => unreachable!("control flow operation"),
-            Operation::Repeat => unreachable!("control flow operation"),
-            Operation::Respan => unreachable!("control flow operation"),
-            Operation::End => unreachable!("control flow operation"),
-            Operation::Halt => unreachable!("control flow operation"),
+            // control flow operations are never executed directly; they can reach this point only
+            // if they were placed into a span block
+            Operation::Join => return Err(ExecutionError::UnexecutableOperation(op)),
+            Operation::Split => return Err(ExecutionError::UnexecutableOperation(op)),
+            Operation::Loop => return Err(ExecutionError::UnexecutableOperation(op)),
+            Operation::Call => return Err(ExecutionError::UnexecutableOperation(op)),
+            Operation::SysCall => return Err(ExecutionError::UnexecutableOperation(op)),
+            Operation::Span => return Err(ExecutionError::UnexecutableOperation(op)),
+            Operation::Repeat => return Err(ExecutionError::UnexecutableOperation(op)),
+            Operation::Respan => return Err(ExecutionError::UnexecutableOperation(op)),
+            Operation::End => return Err(ExecutionError::UnexecutableOperation(op)),
+            Operation::Halt => return Err(ExecutionError::UnexecutableOperation(op)),
 
             // ----- field operations -------------------------------------------------------------
             Operation::Add => self.op_add()?,
@@ -149,7 +155,17 @@ where
             Operation::FriE2F4 => self.op_fri_ext2fold4()?,
         }
 
+        // make sure the stack stays within the depth allowed for the process
+        let depth = self.stack.full_depth();
+        if depth > self.max_stack_depth {
+            return Err(ExecutionError::StackOverflow {
+                depth,
+                capacity: self.max_stack_depth,
+            });
+        }
+
         self.advance_clock();
+        self.invoke_hook()?;
 
         Ok(())
     }
@@ -170,7 +186,7 @@ where
 }
 
 #[cfg(test)]
-impl Process<super::MemAdviceProvider> {
+impl Process<'_, super::MemAdviceProvider> {
     // TEST METHODS
     // --------------------------------------------------------------------------------------------
 
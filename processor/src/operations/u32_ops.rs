@@ -3,7 +3,7 @@ use super::{
     AdviceProvider, ExecutionError, Felt, FieldElement, Operation, Process, StarkField,
 };
 
-impl<A> Process<A>
+impl<A> Process<'_, A>
 where
     A: AdviceProvider,
 {
@@ -64,11 +64,19 @@ where
 
     /// Pops three elements off the stack, adds them, splits the result into low and high 32-bit
     /// values, and pushes these values back onto the stack.
+    ///
+    /// # Errors
+    /// Returns an error if the sum of the elements does not fit into 64 bits; this can happen only
+    /// if some of the elements are not u32 values.
     pub(super) fn op_u32add3(&mut self) -> Result<(), ExecutionError> {
         let c = self.stack.get(0).as_int();
         let b = self.stack.get(1).as_int();
         let a = self.stack.get(2).as_int();
-        let result = Felt::new(a + b + c);
+        let result = a
+            .checked_add(b)
+            .and_then(|sum| sum.checked_add(c))
+            .ok_or(ExecutionError::NotU32Value(Felt::new(a.max(b).max(c))))?;
+        let result = Felt::new(result);
         let (hi, lo) = split_element(result);
 
         self.add_range_checks(Operation::U32add3, lo, hi, false);
@@ -102,10 +110,15 @@ where
 
     /// Pops two elements off the stack, multiplies them, splits the result into low and high
     /// 32-bit values, and pushes these values back onto the stack.
+    ///
+    /// # Errors
+    /// Returns an error if the product of the elements does not fit into 64 bits; this can happen
+    /// only if some of the elements are not u32 values.
     pub(super) fn op_u32mul(&mut self) -> Result<(), ExecutionError> {
         let b = self.stack.get(0).as_int();
         let a = self.stack.get(1).as_int();
-        let result = Felt::new(a * b);
+        let result = a.checked_mul(b).ok_or(ExecutionError::NotU32Value(Felt::new(a.max(b))))?;
+        let result = Felt::new(result);
         let (hi, lo) = split_element(result);
 
         self.add_range_checks(Operation::U32mul, lo, hi, true);
@@ -119,11 +132,19 @@ where
     /// Pops three elements off the stack, multiplies the first two and adds the third element to
     /// the result, splits the result into low and high 32-bit values, and pushes these values
     /// back onto the stack.
+    ///
+    /// # Errors
+    /// Returns an error if the result does not fit into 64 bits; this can happen only if some of
+    /// the elements are not u32 values.
     pub(super) fn op_u32madd(&mut self) -> Result<(), ExecutionError> {
         let b = self.stack.get(0).as_int();
         let a = self.stack.get(1).as_int();
         let c = self.stack.get(2).as_int();
-        let result = Felt::new(a * b + c);
+        let result = a
+            .checked_mul(b)
+            .and_then(|product| product.checked_add(c))
+            .ok_or(ExecutionError::NotU32Value(Felt::new(a.max(b).max(c))))?;
+        let result = Felt::new(result);
         let (hi, lo) = split_element(result);
 
         self.add_range_checks(Operation::U32madd, lo, hi, true);
@@ -138,22 +159,35 @@ where
     /// the quotient and the remainder back onto the stack.
     ///
     /// # Errors
-    /// Returns an error if the divisor is ZERO.
+    /// Returns an error if:
+    /// - The divisor is ZERO.
+    /// - The values which need to be range checked do not fit into 32 bits; this can happen only
+    ///   if the dividend or the divisor is not a u32 value.
     pub(super) fn op_u32div(&mut self) -> Result<(), ExecutionError> {
         let b = self.stack.get(0).as_int();
         let a = self.stack.get(1).as_int();
 
         if b == 0 {
-            return Err(ExecutionError::DivideByZero(self.system.clk()));
+            return Err(ExecutionError::DivideByZero {
+                clk: self.system.clk(),
+                source_location: self.source_location,
+            });
         }
 
         let q = a / b;
         let r = a - q * b;
 
         // These range checks help enforce that q <= a.
-        let lo = Felt::new(a - q);
+        let lo = a - q;
+        if lo > u32::MAX as u64 {
+            return Err(ExecutionError::NotU32Value(Felt::new(a)));
+        }
         // These range checks help enforce that r < b.
-        let hi = Felt::new(b - r - 1);
+        let hi = b - r - 1;
+        if hi > u32::MAX as u64 {
+            return Err(ExecutionError::NotU32Value(Felt::new(b)));
+        }
+        let (lo, hi) = (Felt::new(lo), Felt::new(hi));
         self.add_range_checks(Operation::U32div, lo, hi, false);
 
         self.stack.set(0, Felt::new(r));
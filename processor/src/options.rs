@@ -0,0 +1,80 @@
+use miden_air::trace::MIN_TRACE_LEN;
+
+// EXECUTION OPTIONS
+// ================================================================================================
+
+/// The maximum depth of the stack (including the top 16 items) which programs are allowed to
+/// reach by default.
+pub const DEFAULT_MAX_STACK_DEPTH: usize = 1 << 16;
+
+/// A set of parameters which limit the resources a program is allowed to use during execution.
+///
+/// By default, the number of cycles is not limited, the depth of the stack is limited to
+/// [DEFAULT_MAX_STACK_DEPTH], and execution traces are padded to the smallest length able to hold
+/// the trace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutionOptions {
+    max_cycles: u32,
+    max_stack_depth: usize,
+    min_trace_len: usize,
+}
+
+impl ExecutionOptions {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Limits the number of cycles a program is allowed to execute to `max_cycles`.
+    pub fn with_max_cycles(mut self, max_cycles: u32) -> Self {
+        self.max_cycles = max_cycles;
+        self
+    }
+
+    /// Limits the depth of the stack a program is allowed to reach to `max_stack_depth` items.
+    ///
+    /// The depth of the stack includes the top 16 items as well as all items in the stack
+    /// overflow table, including the items hidden from the current execution context.
+    pub fn with_max_stack_depth(mut self, max_stack_depth: usize) -> Self {
+        self.max_stack_depth = max_stack_depth;
+        self
+    }
+
+    /// Pads execution traces to at least `min_trace_len` rows.
+    ///
+    /// Trace lengths are always powers of two; thus, `min_trace_len` is rounded up to the next
+    /// power of two. The trace is longer than that only if the execution requires more rows.
+    /// Together with the upper bound on the number of cycles of a program (see
+    /// [ProgramComplexity::max_cycles()](vm_core::ProgramComplexity::max_cycles)), this can be used
+    /// to generate traces of the same length for all executions of a program.
+    pub fn with_min_trace_len(mut self, min_trace_len: usize) -> Self {
+        self.min_trace_len = min_trace_len;
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the maximum number of cycles a program is allowed to execute.
+    pub fn max_cycles(&self) -> u32 {
+        self.max_cycles
+    }
+
+    /// Returns the maximum depth of the stack a program is allowed to reach.
+    pub fn max_stack_depth(&self) -> usize {
+        self.max_stack_depth
+    }
+
+    /// Returns the minimum number of rows of the execution traces generated with these options.
+    pub fn min_trace_len(&self) -> usize {
+        self.min_trace_len
+    }
+}
+
+impl Default for ExecutionOptions {
+    fn default() -> Self {
+        Self {
+            max_cycles: u32::MAX,
+            max_stack_depth: DEFAULT_MAX_STACK_DEPTH,
+            min_trace_len: MIN_TRACE_LEN,
+        }
+    }
+}
@@ -104,6 +104,14 @@ impl Stack {
         self.active_depth
     }
 
+    /// Returns depth of the stack across all execution contexts at the current clock cycle.
+    ///
+    /// Unlike [Stack::depth()], this includes the items of the overflow table which are hidden
+    /// from the current execution context.
+    pub fn full_depth(&self) -> usize {
+        self.full_depth
+    }
+
     /// Returns the current clock cycle of the execution trace.
     pub fn current_clk(&self) -> u32 {
         self.clk
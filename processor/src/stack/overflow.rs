@@ -176,7 +176,15 @@ impl OverflowTable {
     /// Panics when this overflow table was not initialized with `enable_trace` set to true.
     pub fn append_state_into(&self, target: &mut Vec<Felt>, clk: u64) {
         assert!(self.trace_enabled, "overflow trace not enabled");
-        if let Some(x) = self.trace.range(0..=clk).last() {
+        // the state at cycle clk reflects the updates made by operations executed before that
+        // cycle; rows added before the first cycle are recorded at "negative" (mod p) cycles
+        let init_clk = Felt::MODULUS - self.num_init_rows as u64;
+        let state = self
+            .trace
+            .range(..clk)
+            .next_back()
+            .or_else(|| self.trace.range(init_clk..).next_back());
+        if let Some(x) = state {
             for item in x.1.iter().rev() {
                 target.push(*item);
             }
@@ -6,14 +6,30 @@ use super::{
     FieldElement, Process, StackTopState, Vec,
 };
 use miden_air::trace::{
-    decoder::{NUM_USER_OP_HELPERS, USER_OP_HELPERS_OFFSET},
-    AUX_TRACE_RAND_ELEMENTS, AUX_TRACE_WIDTH, DECODER_TRACE_OFFSET, MIN_TRACE_LEN,
+    chiplets::{MEMORY_ADDR_COL_IDX, MEMORY_CLK_COL_IDX, MEMORY_CTX_COL_IDX, MEMORY_V_COL_RANGE},
+    decoder::{NUM_USER_OP_HELPERS, OP_BITS_RANGE, USER_OP_HELPERS_OFFSET},
+    AUX_TRACE_RAND_ELEMENTS, AUX_TRACE_WIDTH, CHIPLETS_OFFSET, DECODER_TRACE_OFFSET, MIN_TRACE_LEN,
     STACK_TRACE_OFFSET, TRACE_WIDTH,
 };
-use vm_core::{stack::STACK_TOP_SIZE, ProgramInfo, StackOutputs, ZERO};
-use winter_prover::{crypto::RandomCoin, EvaluationFrame, Trace, TraceLayout};
 
+use core::{fmt, ops::Range};
+#[cfg(feature = "std")]
+use miden_air::trace::{
+    decoder::{NUM_HASHER_COLUMNS, NUM_OP_BATCH_FLAGS, NUM_OP_BITS, NUM_OP_BITS_EXTRA_COLS},
+    CHIPLETS_WIDTH,
+};
+use miden_air::trace::{
+    stack::B0_COL_IDX, CHIPLETS_RANGE, CLK_COL_IDX, CTX_COL_IDX, DECODER_TRACE_RANGE, FMP_COL_IDX,
+    FN_HASH_RANGE, IN_SYSCALL_COL_IDX, RANGE_CHECK_TRACE_RANGE,
+};
 #[cfg(feature = "std")]
+use std::{collections::HashSet, io};
+use vm_core::{
+    stack::STACK_TOP_SIZE, utils::collections::BTreeMap, OpClass, ProgramInfo, StackOutputs, Word,
+    ONE, ZERO,
+};
+use winter_prover::{crypto::RandomCoin, EvaluationFrame, Trace, TraceLayout};
+
 use vm_core::StarkField;
 
 mod utils;
@@ -30,6 +46,95 @@ mod tests;
 /// Number of rows at the end of an execution trace which are injected with random values.
 pub const NUM_RAND_ROWS: usize = 1;
 
+// TRACE LENGTH SUMMARY
+// ================================================================================================
+
+/// Lengths of the components of an execution trace before and after padding.
+///
+/// The STARK protocol used to prove Miden VM executions requires the trace length to be a power
+/// of two which is at least [MIN_TRACE_LEN] (the trace is interpolated over a multiplicative
+/// subgroup of the field). Thus, the components of the trace are always padded to the smallest
+/// power of two which can fit the longest of the components together with [NUM_RAND_ROWS] rows
+/// of random values; no other padding strategy is supported.
+///
+/// Since the proving cost is determined by the padded length, a program whose unpadded trace
+/// length is just above a power of two takes nearly twice as long to prove as a program whose
+/// trace is just below it. Callers can use this summary to detect and restructure such programs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceLenSummary {
+    main_trace_len: usize,
+    range_trace_len: usize,
+    chiplets_trace_len: usize,
+    padded_trace_len: usize,
+}
+
+impl TraceLenSummary {
+    /// Returns the number of rows of the system, decoder, and stack components of the trace
+    /// (i.e., the number of executed VM cycles).
+    pub fn main_trace_len(&self) -> usize {
+        self.main_trace_len
+    }
+
+    /// Returns the number of rows of the range checker component of the trace.
+    pub fn range_trace_len(&self) -> usize {
+        self.range_trace_len
+    }
+
+    /// Returns the number of rows of the chiplets component of the trace.
+    pub fn chiplets_trace_len(&self) -> usize {
+        self.chiplets_trace_len
+    }
+
+    /// Returns the length of the longest component of the trace before padding.
+    pub fn trace_len(&self) -> usize {
+        self.main_trace_len.max(self.range_trace_len).max(self.chiplets_trace_len)
+    }
+
+    /// Returns the length of the trace after padding; this is always a power of two.
+    pub fn padded_trace_len(&self) -> usize {
+        self.padded_trace_len
+    }
+
+    /// Returns the percentage of the padded trace occupied by padding rows, rounded down.
+    pub fn padding_percentage(&self) -> usize {
+        (self.padded_trace_len - self.trace_len()) * 100 / self.padded_trace_len
+    }
+}
+
+// COLUMN STATISTICS
+// ================================================================================================
+
+/// Utilization statistics of a single column of the main segment of an execution trace.
+///
+/// The statistics are computed over all rows of the trace except for the rows injected with
+/// random values at the end of the trace (i.e., the padding rows are included, since they
+/// contribute to the proving cost in the same way as the rows of executed cycles).
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnStat {
+    label: String,
+    non_zero_fraction: f64,
+    num_distinct_values: usize,
+}
+
+#[cfg(feature = "std")]
+impl ColumnStat {
+    /// Returns the label of the column; see [ExecutionTrace::write_csv()] for the list of labels.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the fraction (between 0 and 1) of the rows in which the column is non-zero.
+    pub fn non_zero_fraction(&self) -> f64 {
+        self.non_zero_fraction
+    }
+
+    /// Returns the number of distinct values in the column.
+    pub fn num_distinct_values(&self) -> usize {
+        self.num_distinct_values
+    }
+}
+
 // VM EXECUTION TRACE
 // ================================================================================================
 
@@ -47,6 +152,13 @@ pub struct AuxTraceHints {
 ///   components.
 /// - Hints used during auxiliary trace segment construction.
 /// - Metadata needed by the STARK prover.
+///
+/// An execution trace always describes a complete execution of a program, and cannot be split
+/// into independently provable segments: AIR boundary constraints require the first row of the
+/// trace to be at clock cycle 0 with the decoder starting the execution of the program's root
+/// block, and require the auxiliary tables (e.g., the block stack table and the stack overflow
+/// table) to be initialized from the program hash and the stack inputs only. Thus, a sub-trace
+/// which starts in the middle of an execution would not satisfy the constraints.
 pub struct ExecutionTrace {
     meta: Vec<u8>,
     layout: TraceLayout,
@@ -54,6 +166,9 @@ pub struct ExecutionTrace {
     aux_trace_hints: AuxTraceHints,
     program_info: ProgramInfo,
     stack_outputs: StackOutputs,
+    cycle_count: u32,
+    trace_len_summary: TraceLenSummary,
+    op_class_cycles: [u64; OpClass::ALL.len()],
 }
 
 impl ExecutionTrace {
@@ -67,6 +182,19 @@ impl ExecutionTrace {
     // --------------------------------------------------------------------------------------------
     /// Builds an execution trace for the provided process.
     pub(super) fn new<A>(process: Process<A>, stack_outputs: StackOutputs) -> Self
+    where
+        A: AdviceProvider,
+    {
+        Self::with_min_len(process, stack_outputs, MIN_TRACE_LEN)
+    }
+
+    /// Builds an execution trace for the provided process padded to at least `min_trace_len` rows
+    /// (rounded up to the next power of two).
+    pub(super) fn with_min_len<A>(
+        process: Process<A>,
+        stack_outputs: StackOutputs,
+        min_trace_len: usize,
+    ) -> Self
     where
         A: AdviceProvider,
     {
@@ -80,7 +208,10 @@ impl ExecutionTrace {
         // create a new program info instance with the underlying kernel
         let kernel = process.kernel().clone();
         let program_info = ProgramInfo::new(program_hash, kernel);
-        let (main_trace, aux_trace_hints) = finalize_trace(process, rng);
+        let cycle_count = process.system.clk();
+        let op_class_cycles = *process.decoder.op_class_cycles();
+        let (main_trace, aux_trace_hints, trace_len_summary) =
+            finalize_trace(process, rng, min_trace_len);
 
         Self {
             meta: Vec::new(),
@@ -89,6 +220,9 @@ impl ExecutionTrace {
             aux_trace_hints,
             program_info,
             stack_outputs,
+            cycle_count,
+            trace_len_summary,
+            op_class_cycles,
         }
     }
 
@@ -112,20 +246,80 @@ impl ExecutionTrace {
 
     /// Returns the initial state of the top 16 stack registers.
     pub fn init_stack_state(&self) -> StackTopState {
-        let mut result = [ZERO; STACK_TOP_SIZE];
-        for (i, result) in result.iter_mut().enumerate() {
-            *result = self.main_trace.get_column(i + STACK_TRACE_OFFSET)[0];
-        }
-        result
+        self.read_stack_state(0)
     }
 
     /// Returns the final state of the top 16 stack registers.
     pub fn last_stack_state(&self) -> StackTopState {
-        let last_step = self.last_step();
-        let mut result = [ZERO; STACK_TOP_SIZE];
-        for (i, result) in result.iter_mut().enumerate() {
-            *result = self.main_trace.get_column(i + STACK_TRACE_OFFSET)[last_step];
+        self.read_stack_state(self.last_step())
+    }
+
+    /// Returns the state of the top 16 stack registers at the specified step, or None if the step
+    /// is out of range (i.e., not smaller than [ExecutionTrace::num_steps()]).
+    pub fn stack_state_at(&self, step: usize) -> Option<StackTopState> {
+        if step < self.num_steps() {
+            Some(self.read_stack_state(step))
+        } else {
+            None
         }
+    }
+
+    /// Returns the number of VM cycles executed by the program which resulted in this execution
+    /// trace.
+    ///
+    /// Unlike [ExecutionTrace::get_trace_len()], this does not include the rows used to pad the
+    /// trace to a power of two.
+    pub fn cycle_count(&self) -> u32 {
+        self.cycle_count
+    }
+
+    /// Returns the number of VM cycles executed by the program for each class of operations.
+    ///
+    /// The map contains an entry for every [OpClass] (including classes of which no operations
+    /// were executed), and the counts sum up to [ExecutionTrace::cycle_count()]; rows used to pad
+    /// the trace are not counted.
+    pub fn operation_histogram(&self) -> BTreeMap<OpClass, u64> {
+        OpClass::ALL.into_iter().zip(self.op_class_cycles).collect()
+    }
+
+    /// Returns the number of steps in this execution trace.
+    ///
+    /// This includes the steps used to pad the trace to a power of two after the program has
+    /// finished executing, but excludes the rows injected with random values at the end of the
+    /// trace.
+    pub fn num_steps(&self) -> usize {
+        self.last_step() + 1
+    }
+
+    /// Returns the word stored at the specified address of the root context memory at the
+    /// specified step, or None if the step is out of range or the address has not been accessed
+    /// before the specified step.
+    ///
+    /// Memory state at a given step reflects all memory accesses performed at the previous steps.
+    pub fn memory_at(&self, step: usize, addr: u64) -> Option<Word> {
+        if step >= self.num_steps() {
+            return None;
+        }
+
+        self.root_memory_accesses()
+            .filter(|&(access_addr, clk, _)| access_addr == addr && clk < step as u64)
+            .last()
+            .map(|(_, _, value)| value)
+    }
+
+    /// Returns all non-zero words stored in the root context memory at the end of execution,
+    /// ordered by address.
+    pub fn final_memory(&self) -> Vec<(u64, Word)> {
+        let mut result: Vec<(u64, Word)> = Vec::new();
+        for (addr, _, value) in self.root_memory_accesses() {
+            // accesses to the same address are sorted by clock cycle; thus, the last access
+            // determines the final value at this address
+            match result.last_mut() {
+                Some((last_addr, last_value)) if *last_addr == addr => *last_value = value,
+                _ => result.push((addr, value)),
+            }
+        }
+        result.retain(|(_, value)| value.iter().any(|&v| v != ZERO));
         result
     }
 
@@ -139,10 +333,51 @@ impl ExecutionTrace {
         result
     }
 
+    /// Returns the length of this execution trace.
+    ///
+    /// This is always a power of two, and includes the rows used to pad the trace after the
+    /// program has finished executing as well as the rows injected with random values.
     pub fn get_trace_len(&self) -> usize {
         self.main_trace.num_rows()
     }
 
+    /// Returns a summary of the lengths of the components of this execution trace before and
+    /// after padding.
+    pub fn trace_len_summary(&self) -> &TraceLenSummary {
+        &self.trace_len_summary
+    }
+
+    /// Returns the first step at which the state of the VM described by this execution trace
+    /// differs from the state of the VM described by the `other` execution trace, or None if both
+    /// traces describe the same execution.
+    ///
+    /// The state of the VM at a given step consists only of the user-visible values: the clock
+    /// cycle, the context ID, the free memory pointer, the operation being executed, the top 16
+    /// stack items, and the stack depth. Block hashes, hasher state, and other helper columns are
+    /// not compared, and thus two different programs which execute the same operations on the same
+    /// data up to a given step are considered to be in the same state up to that step. If the
+    /// states are the same at all steps of the shorter trace, the number of steps of the shorter
+    /// trace is returned.
+    pub fn first_divergence(&self, other: &ExecutionTrace) -> Option<usize> {
+        let op_bits_range =
+            DECODER_TRACE_OFFSET + OP_BITS_RANGE.start..DECODER_TRACE_OFFSET + OP_BITS_RANGE.end;
+        let stack_top_range = STACK_TRACE_OFFSET..STACK_TRACE_OFFSET + STACK_TOP_SIZE;
+        let state_cols = [CLK_COL_IDX, CTX_COL_IDX, FMP_COL_IDX, STACK_TRACE_OFFSET + B0_COL_IDX]
+            .into_iter()
+            .chain(op_bits_range)
+            .chain(stack_top_range)
+            .collect::<Vec<_>>();
+        let num_steps = self.num_steps().min(other.num_steps());
+
+        (0..num_steps)
+            .find(|&step| {
+                state_cols
+                    .iter()
+                    .any(|&col| self.main_trace.get(col, step) != other.main_trace.get(col, step))
+            })
+            .or_else(|| (self.num_steps() != other.num_steps()).then_some(num_steps))
+    }
+
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -151,6 +386,142 @@ impl ExecutionTrace {
         self.length() - NUM_RAND_ROWS - 1
     }
 
+    /// Returns an iterator over `(addr, clk, value)` tuples of all memory accesses in the root
+    /// context, as recorded in the memory section of the chiplets trace.
+    ///
+    /// The accesses are sorted by address, and accesses to the same address are sorted by clock
+    /// cycle.
+    fn root_memory_accesses(&self) -> impl Iterator<Item = (u64, u64, Word)> + '_ {
+        (0..self.num_steps())
+            .filter(|&row| {
+                // the memory segment of the chiplets trace is identified by selectors [1, 1, 0]
+                self.main_trace.get(CHIPLETS_OFFSET, row) == ONE
+                    && self.main_trace.get(CHIPLETS_OFFSET + 1, row) == ONE
+                    && self.main_trace.get(CHIPLETS_OFFSET + 2, row) == ZERO
+                    && self.main_trace.get(MEMORY_CTX_COL_IDX, row) == ZERO
+            })
+            .map(|row| {
+                let addr = self.main_trace.get(MEMORY_ADDR_COL_IDX, row).as_int();
+                let clk = self.main_trace.get(MEMORY_CLK_COL_IDX, row).as_int();
+                let mut value = [ZERO; 4];
+                for (i, col_idx) in MEMORY_V_COL_RANGE.enumerate() {
+                    value[i] = self.main_trace.get(col_idx, row);
+                }
+                (addr, clk, value)
+            })
+    }
+
+    /// Returns the state of the top 16 stack registers at the specified step.
+    fn read_stack_state(&self, step: usize) -> StackTopState {
+        let mut result = [ZERO; STACK_TOP_SIZE];
+        for (i, result) in result.iter_mut().enumerate() {
+            *result = self.main_trace.get_column(i + STACK_TRACE_OFFSET)[step];
+        }
+        result
+    }
+
+    // EXPORT
+    // --------------------------------------------------------------------------------------------
+
+    /// Writes the main segment of this execution trace into the specified writer in CSV format.
+    ///
+    /// The first line contains the labels of the columns, and each of the following lines
+    /// contains the values (as integers) of a single row of the trace, starting with the row at
+    /// clock cycle 0. All rows of the trace are written, including the padding rows and the
+    /// randomized rows at the end of the trace.
+    ///
+    /// The columns are written in the order in which they appear in the trace, and are labeled as
+    /// follows (indexes of multi-column values start at 0):
+    /// - system: `clk`, `fmp`, `ctx`, `in_syscall`, `fn_hash_0` to `fn_hash_3`.
+    /// - decoder: `decoder_addr`, `decoder_op_bits_0` to `decoder_op_bits_6`, `decoder_hasher_0`
+    ///   to `decoder_hasher_7`, `decoder_in_span`, `decoder_group_count`, `decoder_op_idx`,
+    ///   `decoder_op_batch_flags_0` to `decoder_op_batch_flags_2`, `decoder_op_bits_extra_0`,
+    ///   `decoder_op_bits_extra_1`.
+    /// - stack: `stack_0` to `stack_15`, `stack_b0`, `stack_b1`, `stack_h0`.
+    /// - range checker: `range_t`, `range_s0`, `range_s1`, `range_v`.
+    /// - chiplets: `chiplets_0` to `chiplets_16`.
+    ///
+    /// # Errors
+    /// Returns an error if writing into the writer fails.
+    #[cfg(feature = "std")]
+    pub fn write_csv<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        writeln!(writer, "{}", main_trace_column_labels().join(","))?;
+
+        let mut row = [ZERO; TRACE_WIDTH];
+        for i in 0..self.length() {
+            self.main_trace.read_row_into(i, &mut row);
+            let values = row.iter().map(|v| v.as_int().to_string()).collect::<Vec<_>>();
+            writeln!(writer, "{}", values.join(","))?;
+        }
+        Ok(())
+    }
+
+    /// Writes the main segment of this execution trace into the specified target with each row
+    /// split into labeled sections for the system, decoder, stack, range checker, and chiplets
+    /// columns.
+    ///
+    /// Only system columns and the stack are broken into individual values; the remaining
+    /// segments are written as lists of integers. Each row is written on a separate line.
+    ///
+    /// # Errors
+    /// Returns an error if writing into the target fails.
+    pub fn write_labeled<W: fmt::Write>(&self, target: &mut W) -> fmt::Result {
+        for i in 0..self.length() {
+            self.write_labeled_row(target, i)?;
+            writeln!(target)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the specified row of the main trace segment split into labeled sections into the
+    /// specified target.
+    fn write_labeled_row<W: fmt::Write>(&self, target: &mut W, row_idx: usize) -> fmt::Result {
+        let mut row = [ZERO; TRACE_WIDTH];
+        self.main_trace.read_row_into(row_idx, &mut row);
+        let ints = |range: Range<usize>| row[range].iter().map(|v| v.as_int()).collect::<Vec<_>>();
+
+        write!(
+            target,
+            "clk={}, ctx={}, fmp={}, in_syscall={}, fn_hash={:?}, decoder={:?}, \
+            stack_depth={}, stack={:?}, range={:?}, chiplets={:?}",
+            row[CLK_COL_IDX].as_int(),
+            row[CTX_COL_IDX].as_int(),
+            row[FMP_COL_IDX].as_int(),
+            row[IN_SYSCALL_COL_IDX].as_int(),
+            ints(FN_HASH_RANGE),
+            ints(DECODER_TRACE_RANGE),
+            row[STACK_TRACE_OFFSET + B0_COL_IDX].as_int(),
+            ints(STACK_TRACE_OFFSET..STACK_TRACE_OFFSET + STACK_TOP_SIZE),
+            ints(RANGE_CHECK_TRACE_RANGE),
+            ints(CHIPLETS_RANGE),
+        )
+    }
+
+    /// Returns utilization statistics for each column of the main segment of this execution
+    /// trace, in the order in which the columns appear in the trace.
+    ///
+    /// Columns which are zero (or hold a single distinct value) in most rows are candidates for
+    /// optimizing the AIR or for restructuring the program. Computing the statistics requires a
+    /// pass over the entire trace; see [ColumnStat] for the rows which are included.
+    #[cfg(feature = "std")]
+    pub fn column_stats(&self) -> Vec<ColumnStat> {
+        let num_rows = self.num_steps();
+        main_trace_column_labels()
+            .into_iter()
+            .enumerate()
+            .map(|(i, label)| {
+                let column = &self.main_trace.get_column(i)[..num_rows];
+                let num_non_zero = column.iter().filter(|&&v| v != ZERO).count();
+                let distinct = column.iter().map(|v| v.as_int()).collect::<HashSet<_>>();
+                ColumnStat {
+                    label,
+                    non_zero_fraction: num_non_zero as f64 / num_rows as f64,
+                    num_distinct_values: distinct.len(),
+                }
+            })
+            .collect()
+    }
+
     // TEST HELPERS
     // --------------------------------------------------------------------------------------------
     #[cfg(feature = "std")]
@@ -169,7 +540,8 @@ impl ExecutionTrace {
         A: AdviceProvider,
     {
         let rng = RpoRandomCoin::new(&[ZERO; 4]);
-        finalize_trace(process, rng)
+        let (main_trace, aux_trace_hints, _) = finalize_trace(process, rng, MIN_TRACE_LEN);
+        (main_trace, aux_trace_hints)
     }
 }
 
@@ -263,7 +635,11 @@ impl Trace for ExecutionTrace {
 /// - Inserting random values in the last row of all columns. This helps ensure that there
 ///   are no repeating patterns in each column and each column contains a least two distinct
 ///   values. This, in turn, ensures that polynomial degrees of all columns are stable.
-fn finalize_trace<A>(process: Process<A>, mut rng: RpoRandomCoin) -> (Vec<Vec<Felt>>, AuxTraceHints)
+fn finalize_trace<A>(
+    process: Process<A>,
+    mut rng: RpoRandomCoin,
+    min_trace_len: usize,
+) -> (Vec<Vec<Felt>>, AuxTraceHints, TraceLenSummary)
 where
     A: AdviceProvider,
 {
@@ -284,10 +660,14 @@ where
 
     // Get the trace length required to hold all execution trace steps.
     let max_len = range_table.len.max(clk as usize).max(chiplets.trace_len());
+    let range_trace_len = range_table.len;
+    let chiplets_trace_len = chiplets.trace_len();
 
     // pad the trace length to the next power of two and ensure that there is space for the
-    // rows to hold random values
-    let trace_len = (max_len + NUM_RAND_ROWS).next_power_of_two();
+    // rows to hold random values; the trace is also padded to the requested minimum length
+    let trace_len = (max_len + NUM_RAND_ROWS)
+        .next_power_of_two()
+        .max(min_trace_len.next_power_of_two());
     assert!(
         trace_len >= MIN_TRACE_LEN,
         "trace length must be at least {MIN_TRACE_LEN}, but was {trace_len}",
@@ -324,5 +704,39 @@ where
         chiplets: chiplets_trace.aux_builder,
     };
 
-    (trace, aux_trace_hints)
+    let trace_len_summary = TraceLenSummary {
+        main_trace_len: clk as usize,
+        range_trace_len,
+        chiplets_trace_len,
+        padded_trace_len: trace_len,
+    };
+
+    (trace, aux_trace_hints, trace_len_summary)
+}
+
+/// Returns the labels of the main trace columns in the order in which the columns appear in the
+/// trace; see [ExecutionTrace::write_csv()].
+#[cfg(feature = "std")]
+fn main_trace_column_labels() -> Vec<String> {
+    let indexed = |name: &'static str, count: usize| (0..count).map(move |i| format!("{name}_{i}"));
+
+    let mut labels = ["clk", "fmp", "ctx", "in_syscall"].map(String::from).to_vec();
+    labels.extend(indexed("fn_hash", FN_HASH_RANGE.len()));
+
+    labels.push("decoder_addr".into());
+    labels.extend(indexed("decoder_op_bits", NUM_OP_BITS));
+    labels.extend(indexed("decoder_hasher", NUM_HASHER_COLUMNS));
+    labels.extend(["decoder_in_span", "decoder_group_count", "decoder_op_idx"].map(String::from));
+    labels.extend(indexed("decoder_op_batch_flags", NUM_OP_BATCH_FLAGS));
+    labels.extend(indexed("decoder_op_bits_extra", NUM_OP_BITS_EXTRA_COLS));
+
+    labels.extend(indexed("stack", STACK_TOP_SIZE));
+    labels.extend(["stack_b0", "stack_b1", "stack_h0"].map(String::from));
+
+    labels.extend(["range_t", "range_s0", "range_s1", "range_v"].map(String::from));
+
+    labels.extend(indexed("chiplets", CHIPLETS_WIDTH));
+
+    debug_assert_eq!(TRACE_WIDTH, labels.len());
+    labels
 }
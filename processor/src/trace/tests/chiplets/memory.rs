@@ -140,6 +140,38 @@ fn b_chip_trace_mem() {
     }
 }
 
+#[test]
+fn memory_state_from_trace() {
+    let stack = [1, 2, 3, 4, 0];
+    let word = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+    let operations = vec![
+        Operation::MStoreW, // store [1, 2, 3, 4] at address 0
+        Operation::Drop,    // clear the stack
+        Operation::Drop,
+        Operation::Drop,
+        Operation::Drop,
+        Operation::Push(Felt::new(7)), // push a new value onto the stack
+        Operation::Push(Felt::new(2)), // push a new address onto the stack
+        Operation::MStore,             // store 7 at address 2
+        Operation::Push(Felt::new(3)), // push an unwritten address onto the stack
+        Operation::MLoad,              // read from address 3
+    ];
+    let trace = build_trace_from_ops(operations, &stack);
+
+    // the word stored at cycle 1 becomes visible at the next step
+    assert_eq!(None, trace.memory_at(1, 0));
+    assert_eq!(Some(word), trace.memory_at(2, 0));
+    assert_eq!(None, trace.memory_at(8, 2));
+    assert_eq!(Some([Felt::new(7), ZERO, ZERO, ZERO]), trace.memory_at(9, 2));
+    assert_eq!(Some([ZERO; 4]), trace.memory_at(trace.num_steps() - 1, 3));
+    assert_eq!(None, trace.memory_at(trace.num_steps() - 1, 4));
+    assert_eq!(None, trace.memory_at(trace.num_steps(), 0));
+
+    // addresses which were only read from are excluded from the final memory state
+    let expected = vec![(0, word), (2, [Felt::new(7), ZERO, ZERO, ZERO])];
+    assert_eq!(expected, trace.final_memory());
+}
+
 // TEST HELPERS
 // ================================================================================================
 
@@ -1,6 +1,6 @@
 use super::{
     super::chiplets::init_state_from_words, ExecutionTrace, Felt, FieldElement, LookupTableRow,
-    Process, Trace, Vec, NUM_RAND_ROWS,
+    Process, Trace, Vec, DECODER_TRACE_OFFSET, NUM_RAND_ROWS, STACK_TRACE_OFFSET, TRACE_WIDTH,
 };
 use crate::{AdviceInputs, MemAdviceProvider, StackInputs};
 use rand_utils::rand_array;
@@ -46,3 +46,53 @@ pub fn build_trace_from_ops_with_inputs(
     process.execute_code_block(&program, &CodeBlockTable::default()).unwrap();
     ExecutionTrace::new(process, StackOutputs::default())
 }
+
+#[test]
+fn write_csv() {
+    let ops = vec![Operation::Pad, Operation::Incr];
+    let trace = build_trace_from_ops(ops, &[]);
+
+    let mut csv = Vec::new();
+    trace.write_csv(&mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    let lines = csv.lines().collect::<Vec<_>>();
+    assert_eq!(trace.length() + 1, lines.len());
+
+    let labels = lines[0].split(',').collect::<Vec<_>>();
+    assert_eq!(TRACE_WIDTH, labels.len());
+    assert_eq!(["clk", "fmp", "ctx", "in_syscall", "fn_hash_0"], labels[..5]);
+    assert_eq!("decoder_addr", labels[DECODER_TRACE_OFFSET]);
+    assert_eq!("stack_0", labels[STACK_TRACE_OFFSET]);
+    assert_eq!("chiplets_16", labels[TRACE_WIDTH - 1]);
+
+    // SPAN, PAD, INCR: the pushed value is incremented to 1 at clk 3
+    let row = lines[4].split(',').collect::<Vec<_>>();
+    assert_eq!("3", row[0]);
+    assert_eq!("1", row[STACK_TRACE_OFFSET]);
+}
+
+#[test]
+fn column_stats() {
+    let ops = vec![Operation::Pad, Operation::Incr];
+    let trace = build_trace_from_ops(ops, &[]);
+
+    let stats = trace.column_stats();
+    assert_eq!(TRACE_WIDTH, stats.len());
+
+    // the clock column is zero only in the first row, and holds a distinct value in each row
+    let num_rows = trace.length() - NUM_RAND_ROWS;
+    assert_eq!("clk", stats[0].label());
+    assert_eq!(num_rows, stats[0].num_distinct_values());
+    assert_eq!((num_rows - 1) as f64 / num_rows as f64, stats[0].non_zero_fraction());
+
+    // the pushed value is 0 until it is incremented, and 1 for the rest of the trace
+    let stack_0 = &stats[STACK_TRACE_OFFSET];
+    assert_eq!("stack_0", stack_0.label());
+    assert_eq!(2, stack_0.num_distinct_values());
+    assert_eq!((num_rows - 3) as f64 / num_rows as f64, stack_0.non_zero_fraction());
+
+    // the bottom of the stack is never used
+    let stack_15 = &stats[STACK_TRACE_OFFSET + 15];
+    assert_eq!(1, stack_15.num_distinct_values());
+    assert_eq!(0.0, stack_15.non_zero_fraction());
+}
@@ -1,6 +1,6 @@
 use super::{
-    build_trace_from_ops, rand_array, Felt, FieldElement, LookupTableRow, Operation, Trace, Vec,
-    NUM_RAND_ROWS, ONE, ZERO,
+    build_trace_from_ops, build_trace_from_ops_with_inputs, rand_array, AdviceInputs, Felt,
+    FieldElement, LookupTableRow, Operation, StackInputs, Trace, Vec, NUM_RAND_ROWS, ONE, ZERO,
 };
 use crate::stack::OverflowTableRow;
 use miden_air::trace::{AUX_TRACE_RAND_ELEMENTS, STACK_AUX_TRACE_OFFSET};
@@ -11,6 +11,93 @@ use miden_air::trace::{AUX_TRACE_RAND_ELEMENTS, STACK_AUX_TRACE_OFFSET};
 const P1_COL_IDX: usize = STACK_AUX_TRACE_OFFSET;
 const TWO: Felt = Felt::new(2);
 
+// STACK STATE TESTS
+// ================================================================================================
+
+#[test]
+fn stack_state_at() {
+    let ops = vec![Operation::Add, Operation::Pad, Operation::Swap];
+    let init_stack = (1..17).collect::<Vec<_>>();
+    let trace = build_trace_from_ops(ops, &init_stack);
+
+    assert_eq!(trace.length() - NUM_RAND_ROWS, trace.num_steps());
+    assert_eq!(Some(trace.init_stack_state()), trace.stack_state_at(0));
+    assert_eq!(Some(trace.last_stack_state()), trace.stack_state_at(trace.num_steps() - 1));
+    assert_eq!(None, trace.stack_state_at(trace.num_steps()));
+
+    // the first operation of the span is executed at clk 1; its effect is visible at clk 2
+    let state = trace.stack_state_at(2).unwrap();
+    assert_eq!(Felt::new(31), state[0]);
+    assert_eq!(Felt::new(14), state[1]);
+
+    let state = trace.stack_state_at(4).unwrap();
+    assert_eq!(Felt::new(31), state[0]);
+    assert_eq!(ZERO, state[1]);
+    assert_eq!(Felt::new(14), state[2]);
+}
+
+#[test]
+fn first_divergence() {
+    let ops = vec![Operation::Pad, Operation::AdvPop, Operation::Add];
+    let build_trace = |advice_value: u64| {
+        let advice_inputs = AdviceInputs::default().with_stack_values([advice_value]).unwrap();
+        build_trace_from_ops_with_inputs(ops.clone(), StackInputs::default(), advice_inputs)
+    };
+
+    let trace = build_trace(1);
+    assert_eq!(None, trace.first_divergence(&build_trace(1)));
+
+    // the advice value is popped at clk 2; its effect is visible at clk 3
+    assert_eq!(Some(3), trace.first_divergence(&build_trace(2)));
+    assert_eq!(Some(3), build_trace(2).first_divergence(&trace));
+}
+
+#[test]
+fn first_divergence_of_different_programs() {
+    let init_stack = (1..17).collect::<Vec<_>>();
+    let ops = vec![Operation::Pad, Operation::Incr, Operation::Add, Operation::Pad];
+    let build_trace = |last_op: Operation| {
+        let mut ops = ops.clone();
+        ops.push(last_op);
+        build_trace_from_ops(ops, &init_stack)
+    };
+
+    // the programs have different hashes, but execute the same operations until the last one,
+    // which is executed at clk 5
+    let trace = build_trace(Operation::Pad);
+    assert_eq!(Some(5), trace.first_divergence(&build_trace(Operation::Incr)));
+}
+
+#[test]
+fn cycle_count() {
+    let ops = vec![Operation::Add, Operation::Pad, Operation::Swap];
+    let init_stack = (1..17).collect::<Vec<_>>();
+    let trace = build_trace_from_ops(ops, &init_stack);
+
+    // SPAN, 3 operations, and END
+    assert_eq!(5, trace.cycle_count());
+    assert!((trace.cycle_count() as usize) < trace.num_steps());
+}
+
+#[test]
+fn write_labeled() {
+    let ops = vec![Operation::Add, Operation::Pad];
+    let init_stack = (1..17).collect::<Vec<_>>();
+    let trace = build_trace_from_ops(ops, &init_stack);
+
+    let mut output = String::new();
+    trace.write_labeled(&mut output).unwrap();
+    let rows = output.lines().collect::<Vec<_>>();
+    assert_eq!(trace.length(), rows.len());
+
+    let row = rows[2];
+    assert!(row.starts_with("clk=2, ctx=0, fmp=1073741824, in_syscall=0, fn_hash=[0, 0, 0, 0]"));
+    assert!(row.contains("stack_depth=16, stack=[31, 14, 13,"));
+
+    let row = rows[3];
+    assert!(row.contains("stack_depth=17, stack=[0, 31, 14,"));
+}
+
 // OVERFLOW TABLE TESTS
 // ================================================================================================
 
@@ -0,0 +1,141 @@
+use super::{
+    AdviceInputs, Digest, ExecutionError, Process, Program, RecAdviceProvider, StackInputs,
+    StackOutputs,
+};
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+// EXECUTION WITNESS
+// ================================================================================================
+
+/// Inputs from which the execution of a program can be reproduced deterministically.
+///
+/// A witness consists of the initial state of the stack and of the advice inputs which were
+/// consumed during execution: the initial advice stack, and only the entries of the advice map and
+/// the nodes of the Merkle store which were read by the program. Thus, a witness is usually much
+/// smaller than the [ExecutionTrace](crate::ExecutionTrace) of the execution, and can be recorded
+/// by a lightweight client via [execute_to_witness()] and sent to a proving service.
+///
+/// Executing the program against the inputs of its witness produces the same execution trace as
+/// the original execution.
+#[derive(Clone, Debug)]
+pub struct ExecutionWitness {
+    stack_inputs: StackInputs,
+    advice_inputs: AdviceInputs,
+    stack_outputs: StackOutputs,
+}
+
+impl ExecutionWitness {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the initial state of the stack.
+    pub fn stack_inputs(&self) -> &StackInputs {
+        &self.stack_inputs
+    }
+
+    /// Returns the advice inputs consumed during execution.
+    pub fn advice_inputs(&self) -> &AdviceInputs {
+        &self.advice_inputs
+    }
+
+    /// Returns the outputs of the recorded execution.
+    pub fn stack_outputs(&self) -> &StackOutputs {
+        &self.stack_outputs
+    }
+
+    // DESTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Decomposes this witness into stack inputs, advice inputs, and stack outputs.
+    pub fn into_parts(self) -> (StackInputs, AdviceInputs, StackOutputs) {
+        (self.stack_inputs, self.advice_inputs, self.stack_outputs)
+    }
+}
+
+impl Serializable for ExecutionWitness {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.stack_inputs.write_into(target);
+        self.advice_inputs.write_into(target);
+        self.stack_outputs.write_into(target);
+    }
+}
+
+impl Deserializable for ExecutionWitness {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            stack_inputs: StackInputs::read_from(source)?,
+            advice_inputs: AdviceInputs::read_from(source)?,
+            stack_outputs: StackOutputs::read_from(source)?,
+        })
+    }
+}
+
+// EXECUTOR
+// ================================================================================================
+
+/// Executes the provided program against the provided inputs and returns a witness of the
+/// execution.
+///
+/// The program is executed without building an execution trace, while recording the advice
+/// inputs requested by the program. The execution can be reproduced (e.g., to generate
+/// a proof) from the returned [ExecutionWitness] and the program.
+///
+/// # Errors
+/// Returns an error if the program fails to execute, or if the hash of the executed program is
+/// inconsistent with the hash of the provided `program`.
+pub fn execute_to_witness(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_inputs: AdviceInputs,
+) -> Result<ExecutionWitness, ExecutionError> {
+    let mut advice_provider = RecAdviceProvider::from(advice_inputs);
+    let mut process =
+        Process::new(program.kernel().clone(), stack_inputs.clone(), &mut advice_provider);
+    let stack_outputs = process.execute(program)?;
+    let program_hash: Digest = process.decoder.program_hash().into();
+    if program.hash() != program_hash {
+        return Err(ExecutionError::ProgramHashMismatch {
+            expected: program.hash(),
+            actual: program_hash,
+        });
+    }
+
+    Ok(ExecutionWitness {
+        stack_inputs,
+        advice_inputs: advice_provider.into_proof(),
+        stack_outputs,
+    })
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{execute_to_witness, AdviceInputs, ExecutionWitness, Program, StackInputs};
+    use crate::{CodeBlock, Operation};
+    use vm_core::utils::{Deserializable, Serializable};
+
+    #[test]
+    fn execution_witness_malformed_bytes() {
+        let program = Program::new(CodeBlock::new_span(vec![Operation::AdvPop, Operation::Add]));
+        let stack_inputs = StackInputs::try_from_values([1, 2]).unwrap();
+        let advice_inputs = AdviceInputs::default().with_stack_values([3]).unwrap();
+        let witness = execute_to_witness(&program, stack_inputs, advice_inputs).unwrap();
+
+        let bytes = witness.to_bytes();
+        let deserialized = ExecutionWitness::read_from_bytes(&bytes).unwrap();
+        assert_eq!(witness.stack_outputs(), deserialized.stack_outputs());
+
+        // truncated witnesses are rejected
+        for len in 0..bytes.len() {
+            assert!(ExecutionWitness::read_from_bytes(&bytes[..len]).is_err());
+        }
+
+        // lengths which are not backed by elements are rejected without preallocating them
+        assert!(ExecutionWitness::read_from_bytes(&[0xff; 4]).is_err());
+        let mut oversized = StackInputs::default().to_bytes();
+        oversized.extend_from_slice(&u32::MAX.to_le_bytes());
+        assert!(ExecutionWitness::read_from_bytes(&oversized).is_err());
+    }
+}
@@ -1,13 +1,14 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use air::{ProcessorAir, PublicInputs};
-use core::marker::PhantomData;
+use core::{marker::PhantomData, time::Duration};
 use processor::{
     crypto::{
         Blake3_192, Blake3_256, ElementHasher, RandomCoin, Rpo256, RpoRandomCoin, WinterRandomCoin,
     },
-    math::Felt,
-    ExecutionTrace,
+    math::{Felt, FieldElement},
+    utils::collections::Vec,
+    ExecutionTrace, ProgramInfo,
 };
 use winter_prover::{ProofOptions as WinterProofOptions, Prover};
 
@@ -15,32 +16,50 @@ use winter_prover::{ProofOptions as WinterProofOptions, Prover};
 use log::debug;
 #[cfg(feature = "std")]
 use std::time::Instant;
-#[cfg(feature = "std")]
 use winter_prover::Trace;
 
 #[cfg(all(feature = "metal", target_arch = "aarch64", target_os = "macos"))]
 mod gpu;
 
+#[cfg(feature = "testing")]
+mod testing;
+
 // EXPORTS
 // ================================================================================================
 
-pub use air::{DeserializationError, ExecutionProof, FieldExtension, HashFunction, ProofOptions};
+pub use air::{
+    field_extension_name, parse_field_extension, DeserializationError, ExecutionProof,
+    FieldExtension, HashFunction, ParseOptionError, ProofOptions, ProofOptionsBuilder,
+    ProofOptionsError, PROOF_FORMAT_VERSION,
+};
 pub use processor::{
-    crypto, math, utils, AdviceInputs, AdviceProvider, Digest, ExecutionError, InputError,
-    MemAdviceProvider, Program, StackInputs, StackOutputs, Word,
+    crypto, execute_to_witness, math, utils, AdviceInputs, AdviceProvider, Digest,
+    DigestParseError, ExecutionError, ExecutionWitness, InputError, MemAdviceProvider,
+    OutputMismatch, Program, PublicInputsSegment, StackInputs, StackOutputs, TraceLenSummary, Word,
+    WordExt,
 };
 pub use winter_prover::StarkProof;
 
+#[cfg(feature = "testing")]
+pub use testing::{prove_and_verify, ProveAndVerifyError};
+
 // PROVER
 // ================================================================================================
 
 /// Executes and proves the specified `program` and returns the result together with a STARK-based proof of
 /// the program's execution.
 ///
-/// * `inputs` specifies the initial state of the stack as well as non-deterministic (secret)
-///   inputs for the VM.
+/// * `stack_inputs` specifies the initial state of the stack. These inputs are public; the same
+///   values must be provided to the verifier.
+/// * `advice_provider` supplies non-deterministic (secret) inputs for the VM. These inputs are not
+///   shared with the verifier. A [MemAdviceProvider] can be built from [AdviceInputs].
 /// * `options` defines parameters for STARK proof generation.
 ///
+/// The returned [StackOutputs] always contain the full state of the stack at the end of execution
+/// (at least 16 elements) because the AIR constrains every register at the top of the stack in the
+/// last step of the trace; thus, the same outputs must be provided to the verifier. Use
+/// [StackOutputs::stack_truncated()] to read only the top `n` elements of the result.
+///
 /// # Errors
 /// Returns an error if program execution or STARK proof generation fails for any reason.
 pub fn prove<A>(
@@ -49,6 +68,144 @@ pub fn prove<A>(
     advice_provider: A,
     options: ProofOptions,
 ) -> Result<(StackOutputs, ExecutionProof), ExecutionError>
+where
+    A: AdviceProvider,
+{
+    prove_with_stats(program, stack_inputs, advice_provider, options)
+        .map(|(stack_outputs, proof, _)| (stack_outputs, proof))
+}
+
+/// Executes and proves the specified `program` against the inputs recorded in the specified
+/// `witness`, and returns the result together with a STARK-based proof of the program's
+/// execution.
+///
+/// The witness is expected to have been produced by
+/// [execute_to_witness()](processor::execute_to_witness) for the same program; since execution is
+/// deterministic, the resulting proof attests to the execution recorded in the witness.
+///
+/// # Errors
+/// Returns an error if program execution or STARK proof generation fails for any reason (e.g., if
+/// the witness does not contain the advice inputs required by the program).
+pub fn prove_from_witness(
+    program: &Program,
+    witness: ExecutionWitness,
+    options: ProofOptions,
+) -> Result<(StackOutputs, ExecutionProof), ExecutionError> {
+    let (stack_inputs, advice_inputs, _) = witness.into_parts();
+    prove(program, stack_inputs, MemAdviceProvider::from(advice_inputs), options)
+}
+
+/// Executes and proves the specified `program` and returns the result together with the proof of
+/// the program's execution encoded as a sequence of field elements.
+///
+/// This is equivalent to [prove()], but the proof is returned in the layout expected by a
+/// recursive verifier, so that it can be supplied to a Miden program verifying other proofs
+/// without parsing proof bytes; see [verifier::proof_to_elements()] for the description of the
+/// layout. Only proofs generated using RPO hash function over a quadratic extension field can be
+/// encoded this way.
+///
+/// # Errors
+/// Returns an error if `options` do not specify RPO hash function over a quadratic extension
+/// field, or if program execution or STARK proof generation fails for any reason.
+pub fn prove_to_elements<A>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_provider: A,
+    options: ProofOptions,
+) -> Result<(StackOutputs, Vec<Felt>), ExecutionError>
+where
+    A: AdviceProvider,
+{
+    if options.hash_fn() != HashFunction::Rpo256
+        || options.field_extension() != FieldExtension::Quadratic
+    {
+        return Err(ExecutionError::NonRecursiveProofOptions);
+    }
+
+    let (stack_outputs, proof) = prove(program, stack_inputs.clone(), advice_provider, options)?;
+    let program_info = ProgramInfo::new(program.hash(), program.kernel().clone());
+    let elements =
+        verifier::proof_to_elements(program_info, stack_inputs, stack_outputs.clone(), &proof)
+            .expect("failed to encode a proof generated with supported options");
+
+    Ok((stack_outputs, elements))
+}
+
+/// Executes and proves the specified `program` using a thread pool of `num_threads` threads and
+/// returns the result together with a STARK-based proof of the program's execution.
+///
+/// This is equivalent to [prove()], but bounds the number of threads used for proof generation
+/// rather than using all available cores. If `num_threads` is 0, the default number of threads
+/// (see [num_threads()]) is used. When the `concurrent` feature is not enabled, proof generation
+/// is always single-threaded and `num_threads` is ignored.
+///
+/// The generated proof does not depend on the number of threads. To guarantee this, the grinding
+/// factor in the proof options must be 0: the proof-of-work nonce search may find different (but
+/// equally valid) nonces depending on how the work is split between threads.
+///
+/// # Errors
+/// Returns an error if:
+/// - The grinding factor in the proof options is not 0.
+/// - The thread pool cannot be created.
+/// - Program execution or STARK proof generation fails for any reason.
+pub fn prove_with_num_threads<A>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_provider: A,
+    options: ProofOptions,
+    num_threads: usize,
+) -> Result<(StackOutputs, ExecutionProof), ExecutionError>
+where
+    A: AdviceProvider + Send,
+{
+    let grinding_factor = options.grinding_factor();
+    if grinding_factor != 0 {
+        return Err(ExecutionError::NonReproducibleProofOptions { grinding_factor });
+    }
+
+    #[cfg(feature = "concurrent")]
+    {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .map_err(|err| ExecutionError::ThreadPoolCreationFailed(err.to_string()))?;
+        pool.install(|| prove(program, stack_inputs, advice_provider, options))
+    }
+
+    #[cfg(not(feature = "concurrent"))]
+    {
+        let _ = num_threads;
+        prove(program, stack_inputs, advice_provider, options)
+    }
+}
+
+/// Returns the number of threads used for proof generation by default.
+///
+/// When called from within [prove_with_num_threads()], returns the number of threads in the
+/// thread pool used for proving. When the `concurrent` feature is not enabled, always returns 1.
+pub fn num_threads() -> usize {
+    #[cfg(feature = "concurrent")]
+    return rayon::current_num_threads();
+
+    #[cfg(not(feature = "concurrent"))]
+    1
+}
+
+/// Executes and proves the specified `program` and returns the result together with a STARK-based
+/// proof of the program's execution and a set of statistics collected during proof generation.
+///
+/// This is equivalent to [prove()], but additionally returns [ProvingStats] describing the shape
+/// of the execution trace and the time spent on execution and proving. Time measurements are
+/// available only when the `std` feature is enabled; otherwise, they are set to zero.
+///
+/// # Errors
+/// Returns an error if program execution or STARK proof generation fails for any reason.
+pub fn prove_with_stats<A>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_provider: A,
+    options: ProofOptions,
+) -> Result<(StackOutputs, ExecutionProof, ProvingStats), ExecutionError>
 where
     A: AdviceProvider,
 {
@@ -56,15 +213,68 @@ where
     #[cfg(feature = "std")]
     let now = Instant::now();
     let trace = processor::execute(program, stack_inputs.clone(), advice_provider)?;
+    #[cfg(feature = "std")]
+    let execution_time = now.elapsed();
+    #[cfg(not(feature = "std"))]
+    let execution_time = Duration::ZERO;
+
     #[cfg(feature = "std")]
     debug!(
         "Generated execution trace of {} columns and {} steps in {} ms",
         trace.layout().main_trace_width(),
         trace.length(),
-        now.elapsed().as_millis()
+        execution_time.as_millis()
     );
 
+    let cycle_count = trace.cycle_count();
+    let trace_len = trace.length();
+    let trace_len_summary = *trace.trace_len_summary();
+    let main_trace_width = trace.layout().main_trace_width();
+    let aux_trace_width = trace.layout().aux_trace_width();
+
+    // generate STARK proof
+    #[cfg(feature = "std")]
+    let now = Instant::now();
+    let (stack_outputs, proof) = prove_trace(trace, stack_inputs, options)?;
+    #[cfg(feature = "std")]
+    let proving_time = now.elapsed();
+    #[cfg(not(feature = "std"))]
+    let proving_time = Duration::ZERO;
+
+    let stats = ProvingStats {
+        cycle_count,
+        trace_len,
+        trace_len_summary,
+        main_trace_width,
+        aux_trace_width,
+        execution_time,
+        proving_time,
+    };
+
+    Ok((stack_outputs, proof, stats))
+}
+
+/// Proves the execution of a program described by the specified execution `trace` and returns
+/// the outputs of the execution together with a STARK-based proof of the program's execution.
+///
+/// This can be used to avoid executing the program twice when the execution trace has already
+/// been generated via [processor::execute()].
+///
+/// * `stack_inputs` specifies the initial state of the stack used to generate the trace.
+/// * `options` defines parameters for STARK proof generation.
+///
+/// # Errors
+/// Returns an error if:
+/// - The provided `stack_inputs` do not match the initial state of the stack in the trace, or the
+///   stack outputs do not match the final state of the stack in the trace.
+/// - STARK proof generation fails for any reason.
+pub fn prove_trace(
+    trace: ExecutionTrace,
+    stack_inputs: StackInputs,
+    options: ProofOptions,
+) -> Result<(StackOutputs, ExecutionProof), ExecutionError> {
     let stack_outputs = trace.stack_outputs().clone();
+    validate_pub_inputs(&stack_inputs, &stack_outputs, &trace)?;
     let hash_fn = options.hash_fn();
 
     // generate STARK proof
@@ -98,6 +308,86 @@ where
     Ok((stack_outputs, proof))
 }
 
+/// Executes and proves the specified `program` in the same way as [prove()], but fails with
+/// [ExecutionError::TraceTooLarge] if proving the resulting execution trace is estimated to
+/// require more than `max_memory` bytes.
+///
+/// The check is performed after the program is executed but before the low-degree extension of
+/// the execution trace is computed. Thus, programs which would exhaust memory during proof
+/// generation fail gracefully instead of aborting the process (see [estimate_proving_memory()]
+/// for how the estimate is computed).
+///
+/// [prove()] and [prove_trace()] do not perform this check.
+///
+/// # Errors
+/// Returns an error if program execution or STARK proof generation fails for any reason.
+pub fn prove_with_memory_limit<A>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_provider: A,
+    options: ProofOptions,
+    max_memory: usize,
+) -> Result<(StackOutputs, ExecutionProof), ExecutionError>
+where
+    A: AdviceProvider,
+{
+    let trace = processor::execute(program, stack_inputs.clone(), advice_provider)?;
+    let estimated_bytes = estimate_proving_memory(&trace, &options);
+    if estimated_bytes > max_memory {
+        return Err(ExecutionError::TraceTooLarge {
+            estimated_bytes,
+            max_bytes: max_memory,
+        });
+    }
+    prove_trace(trace, stack_inputs, options)
+}
+
+/// Returns an estimate of the number of bytes needed to generate a proof for the specified
+/// execution `trace` using the specified proof `options`.
+///
+/// The estimate accounts for the dominant allocations made during proof generation: the
+/// low-degree extensions of the main and auxiliary trace segments, and the Merkle trees built
+/// over them. Auxiliary columns are assumed to be in the extension field defined by `options`.
+pub fn estimate_proving_memory(trace: &ExecutionTrace, options: &ProofOptions) -> usize {
+    const ELEMENT_SIZE: usize = core::mem::size_of::<Felt>();
+
+    let lde_len = trace.length().saturating_mul(options.blowup_factor());
+    let extension_degree = options.field_extension().degree() as usize;
+    let row_size = trace
+        .layout()
+        .main_trace_width()
+        .saturating_add(trace.layout().aux_trace_width().saturating_mul(extension_degree))
+        .saturating_mul(ELEMENT_SIZE);
+
+    // a Merkle tree over the LDE of each trace segment contains 2 * lde_len nodes
+    let num_segments = trace.layout().num_aux_segments() + 1;
+    let tree_size = options.hash_fn().digest_size().saturating_mul(2 * num_segments);
+
+    lde_len.saturating_mul(row_size.saturating_add(tree_size))
+}
+
+// PROVING STATS
+// ================================================================================================
+
+/// A set of statistics collected while generating a proof of program execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProvingStats {
+    /// Number of VM cycles executed by the program.
+    pub cycle_count: u32,
+    /// Number of rows in the execution trace (always a power of two).
+    pub trace_len: usize,
+    /// Lengths of the components of the execution trace before and after padding.
+    pub trace_len_summary: TraceLenSummary,
+    /// Number of columns in the main segment of the execution trace.
+    pub main_trace_width: usize,
+    /// Number of columns in the auxiliary segment of the execution trace.
+    pub aux_trace_width: usize,
+    /// Time spent executing the program; set to zero when `std` feature is disabled.
+    pub execution_time: Duration,
+    /// Time spent generating the STARK proof; set to zero when `std` feature is disabled.
+    pub proving_time: Duration,
+}
+
 // PROVER
 // ================================================================================================
 
@@ -133,22 +423,10 @@ where
     // HELPER FUNCTIONS
     // --------------------------------------------------------------------------------------------
 
-    /// Validates the stack inputs against the provided execution trace and returns true if valid.
-    fn are_inputs_valid(&self, trace: &ExecutionTrace) -> bool {
-        self.stack_inputs
-            .values()
-            .iter()
-            .zip(trace.init_stack_state().iter())
-            .all(|(l, r)| l == r)
-    }
-
-    /// Validates the stack outputs against the provided execution trace and returns true if valid.
-    fn are_outputs_valid(&self, trace: &ExecutionTrace) -> bool {
-        self.stack_outputs
-            .stack_top()
-            .iter()
-            .zip(trace.last_stack_state().iter())
-            .all(|(l, r)| l == r)
+    /// Returns an error if the stack inputs or outputs of this prover are inconsistent with the
+    /// provided execution trace.
+    fn validate_pub_inputs(&self, trace: &ExecutionTrace) -> Result<(), ExecutionError> {
+        validate_pub_inputs(&self.stack_inputs, &self.stack_outputs, trace)
     }
 }
 
@@ -168,17 +446,58 @@ where
     }
 
     fn get_pub_inputs(&self, trace: &ExecutionTrace) -> PublicInputs {
-        // ensure inputs and outputs are consistent with the execution trace.
-        debug_assert!(
-            self.are_inputs_valid(trace),
-            "provided inputs do not match the execution trace"
-        );
+        // inputs and outputs are validated against the execution trace before proving starts
+        // (see prove_trace()); the check here guards against any internal misuse of the prover.
         debug_assert!(
-            self.are_outputs_valid(trace),
-            "provided outputs do not match the execution trace"
+            self.validate_pub_inputs(trace).is_ok(),
+            "provided inputs or outputs do not match the execution trace"
         );
 
         let program_info = trace.program_info().clone();
         PublicInputs::new(program_info, self.stack_inputs.clone(), self.stack_outputs.clone())
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns an error if the provided stack inputs do not match the initial state of the stack in
+/// the provided execution trace, or if the provided stack outputs do not match the final state of
+/// the stack in the execution trace.
+///
+/// Only the top 16 stack positions are checked; stack inputs which are not provided are expected
+/// to be set to ZERO in the trace.
+fn validate_pub_inputs(
+    stack_inputs: &StackInputs,
+    stack_outputs: &StackOutputs,
+    trace: &ExecutionTrace,
+) -> Result<(), ExecutionError> {
+    let init_stack_state = trace.init_stack_state();
+    for (index, &actual) in init_stack_state.iter().enumerate() {
+        let expected = stack_inputs.values().get(index).copied().unwrap_or(Felt::ZERO);
+        if expected != actual {
+            return Err(ExecutionError::InconsistentPublicInputs {
+                segment: PublicInputsSegment::StackInputs,
+                index,
+                expected,
+                actual,
+            });
+        }
+    }
+
+    let last_stack_state = trace.last_stack_state();
+    for (index, (&expected, &actual)) in
+        stack_outputs.stack_top().iter().zip(last_stack_state.iter()).enumerate()
+    {
+        if expected != actual {
+            return Err(ExecutionError::InconsistentPublicInputs {
+                segment: PublicInputsSegment::StackOutputs,
+                index,
+                expected,
+                actual,
+            });
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use super::{
+    prove_trace, AdviceProvider, ExecutionError, Program, ProofOptions, StackInputs, StackOutputs,
+};
+use core::fmt;
+use verifier::VerificationError;
+
+// PROVE AND VERIFY
+// ================================================================================================
+
+/// Executes and proves the specified `program`, and then verifies the resulting proof against the
+/// public inputs derived from the execution.
+///
+/// This is intended to be used as a self-check in tests to catch inconsistencies between the
+/// prover and the AIR (i.e., cases where the prover generates proofs which do not verify).
+///
+/// # Errors
+/// Returns an error if program execution or STARK proof generation fails, or if the generated
+/// proof fails to verify.
+pub fn prove_and_verify<A>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_provider: A,
+    options: ProofOptions,
+) -> Result<StackOutputs, ProveAndVerifyError>
+where
+    A: AdviceProvider,
+{
+    let trace = processor::execute(program, stack_inputs.clone(), advice_provider)
+        .map_err(ProveAndVerifyError::ExecutionError)?;
+    let program_info = trace.program_info().clone();
+
+    let (stack_outputs, proof) = prove_trace(trace, stack_inputs.clone(), options)
+        .map_err(ProveAndVerifyError::ExecutionError)?;
+
+    verifier::verify(program_info, stack_inputs, stack_outputs.clone(), proof)
+        .map_err(ProveAndVerifyError::VerificationError)?;
+
+    Ok(stack_outputs)
+}
+
+// ERRORS
+// ================================================================================================
+
+/// An error which can be returned by [prove_and_verify()].
+#[derive(Debug)]
+pub enum ProveAndVerifyError {
+    ExecutionError(ExecutionError),
+    VerificationError(VerificationError),
+}
+
+impl fmt::Display for ProveAndVerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ProveAndVerifyError::*;
+        match self {
+            ExecutionError(e) => write!(f, "failed to generate proof: {e}"),
+            VerificationError(e) => write!(f, "failed to verify generated proof: {e}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ProveAndVerifyError {}
@@ -689,6 +689,26 @@ fn unchecked_divmod() {
     test.expect_stack(&[r1, r0, q1, q0]);
 }
 
+#[test]
+fn checked_divmod_prove_verify() {
+    let a: u64 = rand_value();
+    let b: u64 = rand_value();
+
+    // the quotient and the remainder are injected into the advice stack by the procedure itself,
+    // and thus, the proof must verify without any advice inputs provided by the caller
+    let source = "
+        use.std::math::u64
+        begin
+            exec.u64::checked_divmod
+        end";
+
+    let (a1, a0) = split_u64(a);
+    let (b1, b0) = split_u64(b);
+
+    let test = build_test!(source, &[a0, a1, b0, b1]);
+    test.prove_and_verify(vec![a0, a1, b0, b1], false);
+}
+
 #[test]
 fn checked_divmod_fail() {
     let source = "
@@ -1,6 +1,6 @@
 use test_utils::{
-    build_expected_hash, build_expected_perm, stack_to_ints, AdviceInputs, MemAdviceProvider,
-    Process, StackInputs, ONE, ZERO,
+    build_expected_hash, build_expected_perm, stack_to_ints, AdviceInputs, Felt, MemAdviceProvider,
+    Process, StackInputs, StarkField, Word, ONE, ZERO,
 };
 
 #[test]
@@ -174,3 +174,36 @@ fn test_pipe_preimage_to_memory_invalid_preimage() {
     let res = build_test!(three_words, operand_stack, &advice_stack).execute();
     assert!(res.is_err());
 }
+
+#[test]
+fn test_load_memory_segment() {
+    let source = "
+    use.std::mem
+
+    begin
+        exec.mem::load_memory_segment
+    end
+    ";
+
+    let mem_addr = 1000;
+    let data = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+    let words: Vec<Word> = data
+        .chunks(4)
+        .map(|chunk| [chunk[0], chunk[1], chunk[2], chunk[3]].map(Felt::new))
+        .collect();
+
+    // the commitment to the segment is provided as a public input
+    let commitment = AdviceInputs::memory_segment_commitment(&words);
+    let mut stack_inputs = vec![mem_addr as u64, 3];
+    stack_inputs.extend(commitment.iter().map(|value| value.as_int()));
+
+    let mut test = build_test!(source, &stack_inputs);
+    test.advice_inputs = AdviceInputs::default().with_memory_segment(&words);
+    test.expect_stack_and_memory(&[1003], mem_addr, &data);
+    test.prove_and_verify(stack_inputs.clone(), false);
+
+    // a segment which does not match the commitment cannot be loaded
+    let mut test = build_test!(source, &stack_inputs);
+    test.advice_inputs = AdviceInputs::default().with_memory_segment(&words[..2]);
+    assert!(test.execute().is_err());
+}
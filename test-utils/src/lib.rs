@@ -228,7 +228,7 @@ impl Test {
 
     /// Compiles the test's source to a Program and executes it with the tests inputs. Returns the
     /// process once execution is finished.
-    pub fn execute_process(&self) -> Result<Process<MemAdviceProvider>, ExecutionError> {
+    pub fn execute_process(&self) -> Result<Process<'static, MemAdviceProvider>, ExecutionError> {
         let program = self.compile();
         let advice_provider = MemAdviceProvider::from(self.advice_inputs.clone());
         let mut process =
@@ -241,7 +241,7 @@ impl Test {
     /// using the given public inputs and the specified number of stack outputs. When `test_fail`
     /// is true, this function will force a failure by modifying the first output.
     pub fn prove_and_verify(&self, pub_inputs: Vec<u64>, test_fail: bool) {
-        let stack_inputs = StackInputs::try_from_values(pub_inputs).unwrap();
+        let stack_inputs = stack_inputs_from_ints(&pub_inputs);
         let program = self.compile();
         let advice_provider = MemAdviceProvider::from(self.advice_inputs.clone());
         let (mut stack_outputs, proof) =
@@ -278,6 +278,12 @@ impl Test {
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Builds [StackInputs] from the provided integers, reducing values which are not valid field
+/// elements modulo the field modulus (this allows tests to check how the VM handles such values).
+pub fn stack_inputs_from_ints(values: &[u64]) -> StackInputs {
+    StackInputs::new(values.iter().map(|&v| Felt::new(v)).collect())
+}
+
 /// Converts an array of Felts into u64
 pub fn stack_to_ints(values: &[Felt]) -> Vec<u64> {
     values.iter().map(|e| (*e).as_int()).collect()
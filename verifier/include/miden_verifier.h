@@ -0,0 +1,73 @@
+#ifndef MIDEN_VERIFIER_H
+#define MIDEN_VERIFIER_H
+
+/* Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// The proof was verified successfully.
+#define MIDEN_VERIFY_OK 0
+
+// One of the provided pointers was null.
+#define MIDEN_VERIFY_ERR_NULL_POINTER -1
+
+// The program hash is not a valid digest (i.e., one of its elements is not a valid field
+// element).
+#define MIDEN_VERIFY_ERR_INVALID_PROGRAM_HASH -2
+
+// One of the stack inputs is not a valid field element.
+#define MIDEN_VERIFY_ERR_INVALID_INPUTS -3
+
+// One of the stack outputs is not a valid field element, or the number of outputs is not 16.
+#define MIDEN_VERIFY_ERR_INVALID_OUTPUTS -4
+
+// The proof could not be deserialized.
+#define MIDEN_VERIFY_ERR_INVALID_PROOF -5
+
+// The proof does not attest to a correct execution of the program against the specified inputs
+// and outputs.
+#define MIDEN_VERIFY_ERR_VERIFICATION_FAILED -6
+
+// An unexpected internal error (panic) occurred during verification.
+#define MIDEN_VERIFY_ERR_INTERNAL -7
+
+// The proof was generated with a hash function which is not supported by this build of the
+// verifier.
+#define MIDEN_VERIFY_ERR_UNSUPPORTED_HASH_FUNCTION -8
+
+// The proof was serialized in a format version which is not supported by this verifier.
+#define MIDEN_VERIFY_ERR_UNSUPPORTED_PROOF_VERSION -9
+
+// Verifies that a program with the specified hash was executed correctly against the specified
+// stack inputs, resulting in the specified stack outputs.
+//
+// * `program_hash` must point to 32 bytes containing a serialized program hash.
+// * `stack_inputs` must point to `num_inputs` integers ordered as if they would be pushed onto
+//   the stack one by one (i.e., the last value is at the top of the stack).
+// * `outputs` must point to `num_outputs` integers describing the top of the stack at the end of
+//   execution, starting with the top of the stack. Exactly 16 outputs are expected; proofs for
+//   executions which end with values in the stack overflow table are not supported.
+// * `proof` must point to `proof_len` bytes containing a serialized execution proof.
+//
+// All memory is owned by the caller and is not retained after this function returns. The program
+// is assumed to have been compiled with an empty kernel.
+//
+// Returns `MIDEN_VERIFY_OK` (0) if the proof is valid, or one of the negative `MIDEN_VERIFY_ERR_*`
+// error codes otherwise.
+//
+// # Safety
+// The caller must ensure that all pointers are valid for reads of the specified number of
+// elements.
+int32_t miden_verify(const uint8_t *program_hash,
+                     const uint64_t *stack_inputs,
+                     size_t num_inputs,
+                     const uint64_t *outputs,
+                     size_t num_outputs,
+                     const uint8_t *proof,
+                     size_t proof_len);
+
+#endif /* MIDEN_VERIFIER_H */
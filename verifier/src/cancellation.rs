@@ -0,0 +1,216 @@
+use super::{
+    check_public_inputs_and_proof, HashFunction, ProcessorAir, PublicInputs, VerificationError,
+};
+use air::ExecutionProof;
+use core::cell::Cell;
+use vm_core::{
+    utils::collections::Vec, Felt, FieldElement, ProgramInfo, StackInputs, StackOutputs,
+};
+use winter_verifier::{
+    crypto::{Hasher, RandomCoin, RandomCoinError},
+    verify as verify_proof,
+};
+
+#[cfg(feature = "rpo")]
+use vm_core::crypto::{hash::Rpo256, random::RpoRandomCoin};
+#[cfg(feature = "blake3")]
+use vm_core::crypto::{
+    hash::{Blake3_192, Blake3_256},
+    random::WinterRandomCoin,
+};
+
+// CANCELLABLE VERIFICATION
+// ================================================================================================
+
+/// Returns the security level of the proof if the specified program was executed correctly
+/// against the specified inputs and outputs, unless verification is cancelled via `should_cancel`.
+///
+/// This is equivalent to [verify()](super::verify), but `should_cancel` is polled before the
+/// public inputs and the proof context are checked, and then every time the verifier draws a value
+/// from the public coin while verifying the STARK proof. In particular, it is polled once per FRI
+/// layer when the random value used to fold the layer is drawn, and when the query positions are
+/// drawn. If it returns `true`, verification is aborted and [VerificationError::Cancelled] is
+/// returned. A cancellation flag shared with another thread can be passed as
+/// `|| flag.load(Ordering::Relaxed)`.
+///
+/// Once the query positions are drawn, the remaining checks (i.e., of the Merkle authentication
+/// paths of the queries and of the FRI layers) are not interrupted. Their cost is bounded by the
+/// number of queries of the proof, which can be checked against a
+/// [VerificationPolicy](super::VerificationPolicy) before verification.
+///
+/// # Errors
+/// Returns [VerificationError::Cancelled] if `should_cancel` returned `true` before verification
+/// completed, and any of the errors returned by [verify()](super::verify) otherwise.
+pub fn verify_with_cancellation(
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    proof: ExecutionProof,
+    should_cancel: impl Fn() -> bool,
+) -> Result<u32, VerificationError> {
+    if should_cancel() {
+        return Err(VerificationError::Cancelled);
+    }
+    check_public_inputs_and_proof(&stack_inputs, &stack_outputs, &proof)?;
+
+    let security_level = proof.security_level();
+    let pub_inputs = PublicInputs::new(program_info, stack_inputs, stack_outputs);
+    let (hash_fn, proof) = proof.into_parts();
+
+    let guard = CancellationGuard::new(&should_cancel);
+    let result = match hash_fn {
+        #[cfg(feature = "blake3")]
+        HashFunction::Blake3_192 => {
+            verify_proof::<ProcessorAir, Blake3_192, CancellableRandomCoin<WinterRandomCoin<_>>>(
+                proof, pub_inputs,
+            )
+        }
+        #[cfg(feature = "blake3")]
+        HashFunction::Blake3_256 => {
+            verify_proof::<ProcessorAir, Blake3_256, CancellableRandomCoin<WinterRandomCoin<_>>>(
+                proof, pub_inputs,
+            )
+        }
+        #[cfg(feature = "rpo")]
+        HashFunction::Rpo256 => {
+            verify_proof::<ProcessorAir, Rpo256, CancellableRandomCoin<RpoRandomCoin>>(
+                proof, pub_inputs,
+            )
+        }
+        // proofs with unsupported hash functions are rejected by check_public_inputs_and_proof()
+        #[allow(unreachable_patterns)]
+        _ => unreachable!("hash function support is checked before the STARK proof is verified"),
+    };
+
+    if guard.is_cancelled() {
+        return Err(VerificationError::Cancelled);
+    }
+    result.map_err(VerificationError::VerifierError)?;
+
+    Ok(security_level)
+}
+
+// CANCELLATION STATE
+// ================================================================================================
+
+/// The cancellation check of the verification running on the current thread.
+///
+/// Random coins are instantiated by the STARK verifier from a seed only, and thus, the check is
+/// made available to them via a thread-local variable for the duration of the verification.
+#[derive(Default)]
+struct CancellationState {
+    should_cancel: Cell<Option<*const (dyn Fn() -> bool + 'static)>>,
+    cancelled: Cell<bool>,
+}
+
+std::thread_local! {
+    static CANCELLATION: CancellationState = CancellationState::default();
+}
+
+/// Installs a cancellation check for the current thread, and removes it when dropped.
+struct CancellationGuard<'a> {
+    previous: Option<*const (dyn Fn() -> bool + 'static)>,
+    _should_cancel: core::marker::PhantomData<&'a dyn Fn() -> bool>,
+}
+
+impl<'a> CancellationGuard<'a> {
+    fn new(should_cancel: &'a dyn Fn() -> bool) -> Self {
+        let should_cancel: *const (dyn Fn() -> bool + 'a) = should_cancel;
+        // SAFETY: the pointer is dereferenced only by the current thread while the guard is
+        // alive, and the guard cannot outlive the check it borrows.
+        let should_cancel: *const (dyn Fn() -> bool + 'static) =
+            unsafe { core::mem::transmute(should_cancel) };
+        let previous = CANCELLATION.with(|state| {
+            state.cancelled.set(false);
+            state.should_cancel.replace(Some(should_cancel))
+        });
+        Self {
+            previous,
+            _should_cancel: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns true if the verification was cancelled while this guard was installed.
+    fn is_cancelled(&self) -> bool {
+        CANCELLATION.with(|state| state.cancelled.get())
+    }
+}
+
+impl Drop for CancellationGuard<'_> {
+    fn drop(&mut self) {
+        CANCELLATION.with(|state| {
+            state.should_cancel.set(self.previous);
+            state.cancelled.set(false);
+        });
+    }
+}
+
+/// Polls the cancellation check installed for the current thread, returning true if the
+/// verification should be aborted.
+fn poll_cancellation() -> bool {
+    CANCELLATION.with(|state| {
+        if state.cancelled.get() {
+            return true;
+        }
+        // SAFETY: the check is installed by a CancellationGuard which is alive for as long as the
+        // check is installed.
+        let cancelled = state.should_cancel.get().map_or(false, |check| unsafe { (*check)() });
+        state.cancelled.set(cancelled);
+        cancelled
+    })
+}
+
+// CANCELLABLE RANDOM COIN
+// ================================================================================================
+
+/// A random coin which fails to draw values once the verification is cancelled.
+///
+/// The STARK verifier draws from the public coin when it builds its transcript, and thus, a
+/// failed draw aborts the verification with
+/// [VerifierError::RandomCoinError](super::VerifierError::RandomCoinError).
+struct CancellableRandomCoin<R> {
+    coin: R,
+}
+
+impl<R: RandomCoin<BaseField = Felt>> RandomCoin for CancellableRandomCoin<R> {
+    type BaseField = Felt;
+    type Hasher = R::Hasher;
+
+    fn new(seed: &[Self::BaseField]) -> Self {
+        Self { coin: R::new(seed) }
+    }
+
+    fn reseed(&mut self, data: <Self::Hasher as Hasher>::Digest) {
+        self.coin.reseed(data)
+    }
+
+    fn reseed_with_int(&mut self, value: u64) {
+        self.coin.reseed_with_int(value)
+    }
+
+    fn leading_zeros(&self) -> u32 {
+        self.coin.leading_zeros()
+    }
+
+    fn check_leading_zeros(&self, value: u64) -> u32 {
+        self.coin.check_leading_zeros(value)
+    }
+
+    fn draw<E: FieldElement<BaseField = Self::BaseField>>(&mut self) -> Result<E, RandomCoinError> {
+        if poll_cancellation() {
+            return Err(RandomCoinError::FailedToDrawFieldElement(0));
+        }
+        self.coin.draw()
+    }
+
+    fn draw_integers(
+        &mut self,
+        num_values: usize,
+        domain_size: usize,
+    ) -> Result<Vec<usize>, RandomCoinError> {
+        if poll_cancellation() {
+            return Err(RandomCoinError::FailedToDrawIntegers(num_values, 0, 0));
+        }
+        self.coin.draw_integers(num_values, domain_size)
+    }
+}
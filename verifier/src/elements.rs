@@ -0,0 +1,230 @@
+use super::{
+    check_public_inputs_and_proof,
+    transcript::{build_transcript, QuadFelt},
+    VerificationError, VerifierError,
+};
+use air::{ExecutionProof, FieldExtension, HashFunction, ProcessorAir, PublicInputs};
+use vm_core::{
+    chiplets::hasher::Digest,
+    crypto::{hash::Rpo256, random::RpoRandomCoin},
+    utils::{collections::Vec, string::ToString},
+    Felt, FieldElement, ProgramInfo, StackInputs, StackOutputs, ToElements,
+};
+use winter_verifier::{crypto::BatchMerkleProof, Air, StarkProof};
+
+// PROOF ELEMENTS
+// ================================================================================================
+
+/// Encodes the provided execution proof as a flat list of field elements which can be parsed by
+/// a recursive verifier (i.e., a verifier circuit) without re-encoding the bytes of the proof.
+///
+/// Only proofs generated using the RPO hash function over a quadratic extension field are
+/// supported, as these are the only proofs which can be verified recursively. Elements of the
+/// extension field are encoded as two base field elements `[c0, c1]`, and digests as four base
+/// field elements. Merkle authentication paths are decompressed into one path per queried
+/// position, and are listed from the sibling of the leaf up to (but not including) the root; the
+/// leaves themselves are not included, as they are the hashes of the queried values.
+///
+/// The elements are laid out as follows:
+/// 1. The number of proof context elements `c`, followed by the `c` elements of the proof
+///    context (i.e., the elements with which the public coin is seeded before the public inputs).
+/// 2. Commitments to the main and auxiliary trace segments and to the constraint composition
+///    polynomial (3 digests).
+/// 3. The number of FRI commitments `f`, followed by the `f` commitments to the FRI layers (the
+///    commitment to the FRI remainder last).
+/// 4. Out-of-domain evaluations of the main and auxiliary trace columns at `z` and `z * g`
+///    (`2 * (TRACE_WIDTH + AUX_TRACE_WIDTH)` extension field elements), followed by out-of-domain
+///    evaluations of the constraint composition columns; both are listed in the order in which
+///    they are hashed into the public coin.
+/// 5. The proof-of-work nonce as two elements (the lower and the upper 32 bits).
+/// 6. The number of queries `q`, followed by `q` queries in the order in which their positions
+///    are drawn from the public coin. Each query consists of the queried position, the main
+///    trace row (`TRACE_WIDTH` base field elements) and its Merkle path, the auxiliary trace row
+///    (`AUX_TRACE_WIDTH` extension field elements) and its Merkle path, and the constraint
+///    composition row and its Merkle path.
+/// 7. For each FRI layer (except the remainder): the number of queried positions `m` in the
+///    layer, followed by `m` queries in the order in which they are derived from the query
+///    positions of the previous layer. Each query consists of `folding_factor` evaluations
+///    (extension field elements) and the Merkle path of the folded position.
+/// 8. The number of FRI remainder evaluations `r`, followed by `r` extension field elements.
+///
+/// # Errors
+/// Returns an error if the proof was not generated using RPO hash function over a quadratic
+/// extension field, if the proof or the stack outputs fail the checks performed by
+/// [verify()](crate::verify) before verifying the STARK proof, or if the proof cannot be parsed.
+pub fn proof_to_elements(
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    proof: &ExecutionProof,
+) -> Result<Vec<Felt>, VerificationError> {
+    let hash_fn = proof.hash_fn();
+    let field_extension = proof.stark_proof().options().field_extension();
+    if hash_fn != HashFunction::Rpo256 || field_extension != FieldExtension::Quadratic {
+        return Err(VerificationError::UnsupportedTranscriptOptions(hash_fn, field_extension));
+    }
+
+    // make sure the proof describes a Miden VM execution trace before parsing it
+    check_public_inputs_and_proof(&stack_inputs, &stack_outputs, proof)?;
+
+    // public inputs are consumed by both the AIR and the transcript
+    let air_inputs =
+        PublicInputs::new(program_info.clone(), stack_inputs.clone(), stack_outputs.clone());
+    let transcript_inputs = PublicInputs::new(program_info, stack_inputs, stack_outputs);
+    encode_proof(proof.stark_proof().clone(), air_inputs, transcript_inputs)
+        .map_err(VerificationError::VerifierError)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Encodes the provided proof using the layout described in [proof_to_elements()].
+fn encode_proof(
+    proof: StarkProof,
+    air_inputs: PublicInputs,
+    transcript_inputs: PublicInputs,
+) -> Result<Vec<Felt>, VerifierError> {
+    let air = ProcessorAir::new(proof.get_trace_info(), air_inputs, proof.options().clone());
+    let transcript =
+        build_transcript::<QuadFelt, Rpo256, RpoRandomCoin>(proof.clone(), transcript_inputs)?;
+    let StarkProof {
+        context,
+        ood_frame,
+        trace_queries,
+        constraint_queries,
+        fri_proof,
+        pow_nonce,
+        ..
+    } = proof;
+
+    let lde_domain_size = air.lde_domain_size();
+    let num_queries = transcript.query_positions.len();
+    let main_width = air.trace_layout().main_trace_width();
+    let aux_width = air.trace_layout().aux_trace_width();
+    let num_composition_columns = air.context().num_constraint_composition_columns();
+    let parse_err = |err: vm_core::utils::DeserializationError| {
+        VerifierError::ProofDeserializationError(err.to_string())
+    };
+    let missing_err = |msg: &str| VerifierError::ProofDeserializationError(msg.to_string());
+
+    let mut result = Vec::new();
+
+    // proof context and commitments
+    let context: Vec<Felt> = context.to_elements();
+    result.push(Felt::from(context.len() as u32));
+    result.extend(context);
+    for commitment in transcript.trace_commitments.iter() {
+        result.extend_from_slice(commitment.as_elements());
+    }
+    result.extend_from_slice(transcript.constraint_commitment.as_elements());
+    result.push(Felt::from(transcript.fri_commitments.len() as u32));
+    for commitment in transcript.fri_commitments.iter() {
+        result.extend_from_slice(commitment.as_elements());
+    }
+
+    // out-of-domain evaluations and proof-of-work nonce
+    let (ood_trace_evaluations, ood_constraint_evaluations) = ood_frame
+        .parse::<QuadFelt>(main_width, aux_width, num_composition_columns)
+        .map_err(parse_err)?;
+    result.extend_from_slice(QuadFelt::slice_as_base_elements(&ood_trace_evaluations));
+    result.extend_from_slice(QuadFelt::slice_as_base_elements(&ood_constraint_evaluations));
+    result.push(Felt::from(pow_nonce as u32));
+    result.push(Felt::from((pow_nonce >> 32) as u32));
+
+    // trace and constraint queries
+    let mut trace_queries = trace_queries.into_iter();
+    let (main_proof, main_rows) = trace_queries
+        .next()
+        .ok_or_else(|| missing_err("no main trace queries"))?
+        .parse::<Rpo256, Felt>(lde_domain_size, num_queries, main_width)
+        .map_err(parse_err)?;
+    let (aux_proof, aux_rows) = trace_queries
+        .next()
+        .ok_or_else(|| missing_err("no auxiliary trace queries"))?
+        .parse::<Rpo256, QuadFelt>(lde_domain_size, num_queries, aux_width)
+        .map_err(parse_err)?;
+    let (constraint_proof, constraint_rows) = constraint_queries
+        .parse::<Rpo256, QuadFelt>(lde_domain_size, num_queries, num_composition_columns)
+        .map_err(parse_err)?;
+
+    let positions = &transcript.query_positions;
+    let main_paths = decompress_paths(main_proof, positions)?;
+    let aux_paths = decompress_paths(aux_proof, positions)?;
+    let constraint_paths = decompress_paths(constraint_proof, positions)?;
+
+    result.push(Felt::from(num_queries as u32));
+    for (i, &position) in positions.iter().enumerate() {
+        result.push(Felt::from(position as u32));
+        result.extend_from_slice(main_rows.get_row(i));
+        append_path(&mut result, &main_paths[i]);
+        result.extend_from_slice(QuadFelt::slice_as_base_elements(aux_rows.get_row(i)));
+        append_path(&mut result, &aux_paths[i]);
+        result.extend_from_slice(QuadFelt::slice_as_base_elements(constraint_rows.get_row(i)));
+        append_path(&mut result, &constraint_paths[i]);
+    }
+
+    // FRI layers and remainder
+    let folding_factor = air.options().to_fri_options().folding_factor();
+    let remainder = fri_proof.parse_remainder::<QuadFelt>().map_err(parse_err)?;
+    let (layer_queries, layer_proofs) = fri_proof
+        .parse_layers::<Rpo256, QuadFelt>(lde_domain_size, folding_factor)
+        .map_err(parse_err)?;
+
+    let mut positions = positions.clone();
+    let mut domain_size = lde_domain_size;
+    for (evaluations, layer_proof) in layer_queries.into_iter().zip(layer_proofs) {
+        positions = fold_positions(&positions, domain_size, folding_factor);
+        domain_size /= folding_factor;
+        let paths = decompress_paths(layer_proof, &positions)?;
+        if evaluations.len() != paths.len() * folding_factor {
+            return Err(VerifierError::ProofDeserializationError(
+                "invalid number of FRI layer evaluations".to_string(),
+            ));
+        }
+
+        result.push(Felt::from(positions.len() as u32));
+        for (i, path) in paths.iter().enumerate() {
+            let evaluations = &evaluations[i * folding_factor..(i + 1) * folding_factor];
+            result.extend_from_slice(QuadFelt::slice_as_base_elements(evaluations));
+            append_path(&mut result, path);
+        }
+    }
+
+    result.push(Felt::from(remainder.len() as u32));
+    result.extend_from_slice(QuadFelt::slice_as_base_elements(&remainder));
+
+    Ok(result)
+}
+
+/// Decompresses the provided batch Merkle proof into one authentication path per position.
+fn decompress_paths(
+    proof: BatchMerkleProof<Rpo256>,
+    positions: &[usize],
+) -> Result<Vec<Vec<Digest>>, VerifierError> {
+    proof
+        .into_paths(positions)
+        .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))
+}
+
+/// Appends the nodes of the provided Merkle path to the result, skipping the leaf.
+fn append_path(result: &mut Vec<Felt>, path: &[Digest]) {
+    for node in path.iter().skip(1) {
+        result.extend_from_slice(node.as_elements());
+    }
+}
+
+/// Maps the positions in a domain of the specified size to the positions in the domain folded by
+/// the specified factor, removing duplicates while preserving the order of first occurrence.
+///
+/// This mirrors how FRI query positions are folded between layers in `winter-fri`.
+fn fold_positions(positions: &[usize], domain_size: usize, folding_factor: usize) -> Vec<usize> {
+    let target_domain_size = domain_size / folding_factor;
+    let mut result = Vec::new();
+    for position in positions {
+        let position = position % target_domain_size;
+        if !result.contains(&position) {
+            result.push(position);
+        }
+    }
+    result
+}
@@ -0,0 +1,140 @@
+//! C-compatible interface for verifying Miden VM execution proofs.
+//!
+//! The declarations for the exported functions are available in `include/miden_verifier.h`.
+
+use super::{verify_from_parts, VerificationError};
+use core::slice;
+use std::panic;
+use vm_core::stack::STACK_TOP_SIZE;
+
+// ERROR CODES
+// ================================================================================================
+
+/// The proof was verified successfully.
+pub const MIDEN_VERIFY_OK: i32 = 0;
+
+/// One of the provided pointers was null.
+pub const MIDEN_VERIFY_ERR_NULL_POINTER: i32 = -1;
+
+/// The program hash is not a valid digest (i.e., one of its elements is not a valid field
+/// element).
+pub const MIDEN_VERIFY_ERR_INVALID_PROGRAM_HASH: i32 = -2;
+
+/// One of the stack inputs is not a valid field element.
+pub const MIDEN_VERIFY_ERR_INVALID_INPUTS: i32 = -3;
+
+/// One of the stack outputs is not a valid field element, or the number of outputs is not 16.
+pub const MIDEN_VERIFY_ERR_INVALID_OUTPUTS: i32 = -4;
+
+/// The proof could not be deserialized.
+pub const MIDEN_VERIFY_ERR_INVALID_PROOF: i32 = -5;
+
+/// The proof does not attest to a correct execution of the program against the specified inputs
+/// and outputs.
+pub const MIDEN_VERIFY_ERR_VERIFICATION_FAILED: i32 = -6;
+
+/// An unexpected internal error (panic) occurred during verification.
+pub const MIDEN_VERIFY_ERR_INTERNAL: i32 = -7;
+
+/// The proof was generated with a hash function which is not supported by this build of the
+/// verifier.
+pub const MIDEN_VERIFY_ERR_UNSUPPORTED_HASH_FUNCTION: i32 = -8;
+
+/// The proof was serialized in a format version which is not supported by this verifier.
+pub const MIDEN_VERIFY_ERR_UNSUPPORTED_PROOF_VERSION: i32 = -9;
+
+// VERIFIER
+// ================================================================================================
+
+/// Verifies that a program with the specified hash was executed correctly against the specified
+/// stack inputs, resulting in the specified stack outputs.
+///
+/// * `program_hash` must point to 32 bytes containing a serialized program hash.
+/// * `stack_inputs` must point to `num_inputs` integers ordered as if they would be pushed onto
+///   the stack one by one (i.e., the last value is at the top of the stack).
+/// * `outputs` must point to `num_outputs` integers describing the top of the stack at the end of
+///   execution, starting with the top of the stack. Exactly 16 outputs are expected; proofs for
+///   executions which end with values in the stack overflow table are not supported.
+/// * `proof` must point to `proof_len` bytes containing a serialized execution proof.
+///
+/// All memory is owned by the caller and is not retained after this function returns. The program
+/// is assumed to have been compiled with an empty kernel.
+///
+/// Returns `MIDEN_VERIFY_OK` (0) if the proof is valid, or one of the negative `MIDEN_VERIFY_ERR_*`
+/// error codes otherwise.
+///
+/// # Safety
+/// The caller must ensure that all pointers are valid for reads of the specified number of
+/// elements.
+#[no_mangle]
+pub unsafe extern "C" fn miden_verify(
+    program_hash: *const u8,
+    stack_inputs: *const u64,
+    num_inputs: usize,
+    outputs: *const u64,
+    num_outputs: usize,
+    proof: *const u8,
+    proof_len: usize,
+) -> i32 {
+    if program_hash.is_null()
+        || (stack_inputs.is_null() && num_inputs > 0)
+        || outputs.is_null()
+        || proof.is_null()
+    {
+        return MIDEN_VERIFY_ERR_NULL_POINTER;
+    }
+
+    let program_hash = slice::from_raw_parts(program_hash, 32);
+    let stack_inputs = if num_inputs == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(stack_inputs, num_inputs)
+    };
+    let outputs = slice::from_raw_parts(outputs, num_outputs);
+    let proof = slice::from_raw_parts(proof, proof_len);
+
+    panic::catch_unwind(|| verify_slices(program_hash, stack_inputs, outputs, proof))
+        .unwrap_or(MIDEN_VERIFY_ERR_INTERNAL)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn verify_slices(program_hash: &[u8], stack_inputs: &[u64], outputs: &[u64], proof: &[u8]) -> i32 {
+    let program_hash = match <[u8; 32]>::try_from(program_hash) {
+        Ok(program_hash) => program_hash,
+        Err(_) => return MIDEN_VERIFY_ERR_INVALID_PROGRAM_HASH,
+    };
+    if outputs.len() != STACK_TOP_SIZE {
+        return MIDEN_VERIFY_ERR_INVALID_OUTPUTS;
+    }
+
+    match verify_from_parts(program_hash, stack_inputs, outputs, proof) {
+        Ok(_) => MIDEN_VERIFY_OK,
+        Err(VerificationError::InvalidProgramHash(_)) => MIDEN_VERIFY_ERR_INVALID_PROGRAM_HASH,
+        Err(VerificationError::InputNotFieldElement(_)) => MIDEN_VERIFY_ERR_INVALID_INPUTS,
+        Err(
+            VerificationError::OutputNotFieldElement(_)
+            | VerificationError::InsufficientStackOutputs(_)
+            | VerificationError::PublicInputsMismatch(_),
+        ) => MIDEN_VERIFY_ERR_INVALID_OUTPUTS,
+        Err(
+            VerificationError::ProofDeserializationFailed(_)
+            | VerificationError::UnexpectedTraceLayout { .. }
+            | VerificationError::TraceTooShort(_),
+        ) => MIDEN_VERIFY_ERR_INVALID_PROOF,
+        Err(
+            VerificationError::VerifierError(_)
+            | VerificationError::UnsupportedTranscriptOptions(..)
+            | VerificationError::PolicyViolation(_),
+        ) => MIDEN_VERIFY_ERR_VERIFICATION_FAILED,
+        Err(VerificationError::UnsupportedHashFunction { .. }) => {
+            MIDEN_VERIFY_ERR_UNSUPPORTED_HASH_FUNCTION
+        }
+        Err(VerificationError::UnsupportedProofVersion { .. }) => {
+            MIDEN_VERIFY_ERR_UNSUPPORTED_PROOF_VERSION
+        }
+        // verification via FFI cannot be cancelled
+        Err(VerificationError::Cancelled) => MIDEN_VERIFY_ERR_INTERNAL,
+    }
+}
@@ -1,22 +1,59 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use air::{HashFunction, ProcessorAir, PublicInputs};
-use core::fmt;
-use vm_core::crypto::{
-    hash::{Blake3_192, Blake3_256, Rpo256},
-    random::{RpoRandomCoin, WinterRandomCoin},
+#[cfg(not(any(feature = "blake3", feature = "rpo")))]
+compile_error!("at least one of the `blake3` and `rpo` features must be enabled");
+
+use air::{
+    trace::{AUX_TRACE_RAND_ELEMENTS, AUX_TRACE_WIDTH, MIN_TRACE_LEN, TRACE_WIDTH},
+    FieldExtension, HashFunction, ProcessorAir, PublicInputs, PROOF_FORMAT_VERSION,
+};
+use core::{fmt, ops::RangeInclusive};
+use vm_core::{
+    stack::STACK_TOP_SIZE,
+    utils::{collections::Vec, Deserializable},
+    Felt, StarkField,
 };
 use winter_verifier::verify as verify_proof;
 
+#[cfg(feature = "rpo")]
+use vm_core::crypto::{hash::Rpo256, random::RpoRandomCoin};
+#[cfg(feature = "blake3")]
+use vm_core::crypto::{
+    hash::{Blake3_192, Blake3_256},
+    random::WinterRandomCoin,
+};
+
 // EXPORTS
 // ================================================================================================
 
-pub use vm_core::{chiplets::hasher::Digest, Kernel, ProgramInfo, StackInputs, StackOutputs, Word};
+pub use vm_core::{
+    chiplets::hasher::Digest, Kernel, Program, ProgramInfo, StackInputs, StackOutputs, Word,
+};
 pub use winter_verifier::VerifierError;
 pub mod math {
     pub use vm_core::{Felt, FieldElement, StarkField};
 }
-pub use air::ExecutionProof;
+pub use air::{DeserializationError, ExecutionProof};
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+#[cfg(feature = "std")]
+mod cancellation;
+#[cfg(feature = "std")]
+pub use cancellation::verify_with_cancellation;
+
+mod elements;
+pub use elements::proof_to_elements;
+
+mod policy;
+pub use policy::{verify_with_policy, PolicyViolation, VerificationPolicy};
+
+mod report;
+pub use report::{verify_verbose, VerificationReport};
+
+mod transcript;
+pub use transcript::{verify_with_transcript, QuadFelt, VerificationTranscript};
 
 // VERIFIER
 // ================================================================================================
@@ -36,13 +73,198 @@ pub use air::ExecutionProof;
 /// `stack_outputs` slice, and the order of the rest of the output elements will also match the
 /// order on the stack. This is the reverse of the order of the `stack_inputs` slice.
 ///
+/// Before the STARK proof is checked, the context of the proof is checked against the public
+/// inputs: the proof must describe an execution trace with the layout of Miden VM traces and of
+/// at least the minimum length, and the stack overflow addresses must be consistent with the
+/// trace length. This allows rejecting malformed proofs cheaply. The proof does not contain
+/// the program hash; a proof for a different program is rejected only by the STARK verifier.
+///
 /// # Errors
-/// Returns an error if the provided proof does not prove a correct execution of the program.
+/// Returns an error if:
+/// - The proof was serialized in a format version which is not supported by this verifier (see
+///   [supported_proof_versions()]).
+/// - The proof was generated with a hash function which is not supported by this build of the
+///   verifier (see [supported_hash_functions()]).
+/// - The provided stack outputs contain fewer than 16 elements or contain values which are not
+///   valid field elements.
+/// - The number of stack overflow addresses is inconsistent with the number of stack outputs, or
+///   an overflow address is neither smaller than the length of the execution trace nor the
+///   address of an overflow table row initialized from the stack inputs.
+/// - The proof does not describe a Miden VM execution trace (i.e., the trace layout or length of
+///   the proof is invalid).
+/// - The provided proof does not prove a correct execution of the program.
 pub fn verify(
     program_info: ProgramInfo,
     stack_inputs: StackInputs,
     stack_outputs: StackOutputs,
     proof: ExecutionProof,
+) -> Result<u32, VerificationError> {
+    check_public_inputs_and_proof(&stack_inputs, &stack_outputs, &proof)?;
+    verify_stark_proof(program_info, stack_inputs, stack_outputs, proof)
+}
+
+/// Returns the security level of the proof if the specified `program` was executed correctly
+/// against the specified inputs and outputs.
+///
+/// This is a convenience wrapper around [verify()] which derives [ProgramInfo] (i.e., the program
+/// hash and the kernel) from the provided `program`. Stack inputs and outputs are expected to be
+/// ordered in the same way as for [verify()].
+///
+/// # Errors
+/// Returns an error if the provided proof does not prove a correct execution of the program.
+pub fn verify_program(
+    program: &Program,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    proof: ExecutionProof,
+) -> Result<u32, VerificationError> {
+    let program_info = ProgramInfo::new(program.hash(), program.kernel().clone());
+    verify(program_info, stack_inputs, stack_outputs, proof)
+}
+
+/// Returns the security level of the proof if a program with the specified `program_hash` was
+/// executed correctly against the specified inputs and outputs.
+///
+/// This is a convenience wrapper around [verify()] for callers which do not work with Miden VM
+/// types directly (e.g., CLI tools, RPC services, or foreign function interfaces):
+/// * `program_hash` is a serialized program hash; the program is assumed to have been compiled
+///   with an empty kernel.
+/// * `stack_inputs` and `stack_outputs` are expected to be ordered in the same way as for
+///   [verify()]. Stack outputs must contain the full state of the stack at the end of execution;
+///   executions which end with values in the stack overflow table are not supported.
+/// * `proof` is a serialized [ExecutionProof].
+///
+/// # Errors
+/// Returns an error if:
+/// - The program hash is not a valid digest.
+/// - Any of the stack inputs or outputs is not a valid field element.
+/// - The proof cannot be deserialized.
+/// - The proof does not prove a correct execution of the program.
+pub fn verify_from_parts(
+    program_hash: [u8; 32],
+    stack_inputs: &[u64],
+    stack_outputs: &[u64],
+    proof: &[u8],
+) -> Result<u32, VerificationError> {
+    let program_hash =
+        Digest::read_from_bytes(&program_hash).map_err(VerificationError::InvalidProgramHash)?;
+    let program_info = ProgramInfo::new(program_hash, Kernel::default());
+
+    if let Some(&value) = stack_inputs.iter().find(|&&v| v >= Felt::MODULUS) {
+        return Err(VerificationError::InputNotFieldElement(value));
+    }
+    let stack_inputs = StackInputs::new(stack_inputs.iter().map(|&v| Felt::new(v)).collect());
+
+    if let Some(&value) = stack_outputs.iter().find(|&&v| v >= Felt::MODULUS) {
+        return Err(VerificationError::OutputNotFieldElement(value));
+    }
+    let stack_outputs = StackOutputs::new(stack_outputs.to_vec(), Vec::new());
+
+    let proof =
+        ExecutionProof::from_bytes(proof).map_err(VerificationError::ProofDeserializationFailed)?;
+
+    verify(program_info, stack_inputs, stack_outputs, proof)
+}
+
+/// Verifies a batch of execution proofs, where each item consists of program info, stack inputs,
+/// stack outputs, and a proof in the same form as the parameters of [verify()].
+///
+/// Proofs are verified independently of each other; when the `concurrent` feature is enabled,
+/// they are verified in parallel using a thread pool.
+///
+/// # Errors
+/// Returns the index of the first item (in the order of the input vector) which failed to
+/// verify together with the corresponding error.
+pub fn verify_batch(
+    items: Vec<(ProgramInfo, StackInputs, StackOutputs, ExecutionProof)>,
+) -> Result<(), (usize, VerificationError)> {
+    #[cfg(not(feature = "concurrent"))]
+    for (i, (program_info, stack_inputs, stack_outputs, proof)) in items.into_iter().enumerate() {
+        verify(program_info, stack_inputs, stack_outputs, proof).map_err(|err| (i, err))?;
+    }
+
+    #[cfg(feature = "concurrent")]
+    {
+        use rayon::prelude::*;
+        let results = items
+            .into_par_iter()
+            .map(|(program_info, stack_inputs, stack_outputs, proof)| {
+                verify(program_info, stack_inputs, stack_outputs, proof)
+            })
+            .collect::<Vec<_>>();
+        for (i, result) in results.into_iter().enumerate() {
+            result.map_err(|err| (i, err))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the hash functions of the proofs which can be verified by this build of the verifier.
+///
+/// Support for each family of hash functions can be disabled (e.g., to reduce the size of an
+/// embedded verifier) by disabling the `blake3` or `rpo` features of this crate. Proofs generated
+/// with an unsupported hash function are rejected with
+/// [VerificationError::UnsupportedHashFunction] before any other check is performed; thus, the
+/// caller can fall back to a verifier built with the full set of hash functions.
+pub fn supported_hash_functions() -> Vec<HashFunction> {
+    let mut result = Vec::new();
+    #[cfg(feature = "blake3")]
+    result.extend([HashFunction::Blake3_192, HashFunction::Blake3_256]);
+    #[cfg(feature = "rpo")]
+    result.push(HashFunction::Rpo256);
+    result
+}
+
+/// Returns the range of proof format versions which can be verified by this verifier.
+///
+/// The range includes the current format version written by the prover, and version 0, i.e., the
+/// unversioned format of proofs generated by earlier releases. Proofs of other versions are
+/// rejected with [VerificationError::UnsupportedProofVersion].
+pub fn supported_proof_versions() -> RangeInclusive<u8> {
+    0..=PROOF_FORMAT_VERSION
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Checks that the provided proof can be verified by this verifier, and that the proof and the
+/// stack outputs are well-formed; see [verify()] for the list of checks.
+fn check_public_inputs_and_proof(
+    stack_inputs: &StackInputs,
+    stack_outputs: &StackOutputs,
+    proof: &ExecutionProof,
+) -> Result<(), VerificationError> {
+    // make sure this verifier can check proofs in the format and with the hash function of the
+    // proof
+    check_proof_version(proof.version())?;
+    check_hash_fn(proof.hash_fn())?;
+
+    // make sure the stack outputs are well-formed before building public inputs from them
+    let num_outputs = stack_outputs.stack().len();
+    if num_outputs < STACK_TOP_SIZE {
+        return Err(VerificationError::InsufficientStackOutputs(num_outputs));
+    }
+    if let Some(&value) = stack_outputs
+        .stack()
+        .iter()
+        .chain(stack_outputs.overflow_addrs())
+        .find(|&&v| v >= Felt::MODULUS)
+    {
+        return Err(VerificationError::OutputNotFieldElement(value));
+    }
+    check_proof_context(stack_inputs, stack_outputs, proof)
+}
+
+/// Verifies the STARK proof against the public inputs, returning the security level of the proof.
+///
+/// The proof and the public inputs are expected to have been checked via
+/// [check_public_inputs_and_proof()].
+fn verify_stark_proof(
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    proof: ExecutionProof,
 ) -> Result<u32, VerificationError> {
     // get security level of the proof
     let security_level = proof.security_level();
@@ -51,30 +273,161 @@ pub fn verify(
     let pub_inputs = PublicInputs::new(program_info, stack_inputs, stack_outputs);
     let (hash_fn, proof) = proof.into_parts();
     match hash_fn {
+        #[cfg(feature = "blake3")]
         HashFunction::Blake3_192 => {
             verify_proof::<ProcessorAir, Blake3_192, WinterRandomCoin<_>>(proof, pub_inputs)
         }
+        #[cfg(feature = "blake3")]
         HashFunction::Blake3_256 => {
             verify_proof::<ProcessorAir, Blake3_256, WinterRandomCoin<_>>(proof, pub_inputs)
         }
+        #[cfg(feature = "rpo")]
         HashFunction::Rpo256 => {
             verify_proof::<ProcessorAir, Rpo256, RpoRandomCoin>(proof, pub_inputs)
         }
+        // proofs with unsupported hash functions are rejected by check_public_inputs_and_proof()
+        #[allow(unreachable_patterns)]
+        _ => unreachable!("hash function support is checked before the STARK proof is verified"),
     }
     .map_err(VerificationError::VerifierError)?;
 
     Ok(security_level)
 }
 
+/// Checks that proofs of the specified format version can be verified by this verifier.
+fn check_proof_version(version: u8) -> Result<(), VerificationError> {
+    let supported = supported_proof_versions();
+    if supported.contains(&version) {
+        Ok(())
+    } else {
+        Err(VerificationError::UnsupportedProofVersion {
+            found: version,
+            supported,
+        })
+    }
+}
+
+/// Checks that proofs generated with the specified hash function can be verified by this build of
+/// the verifier.
+fn check_hash_fn(hash_fn: HashFunction) -> Result<(), VerificationError> {
+    let supported = supported_hash_functions();
+    if supported.contains(&hash_fn) {
+        Ok(())
+    } else {
+        Err(VerificationError::UnsupportedHashFunction {
+            requested: hash_fn,
+            supported,
+        })
+    }
+}
+
+/// Checks that the context of the proof describes a Miden VM execution trace, and that it is
+/// consistent with the provided stack outputs.
+fn check_proof_context(
+    stack_inputs: &StackInputs,
+    stack_outputs: &StackOutputs,
+    proof: &ExecutionProof,
+) -> Result<(), VerificationError> {
+    let proof = proof.stark_proof();
+    let layout = proof.trace_layout();
+    if layout.main_trace_width() != TRACE_WIDTH
+        || layout.num_aux_segments() != 1
+        || layout.get_aux_segment_width(0) != AUX_TRACE_WIDTH
+        || layout.get_aux_segment_rand_elements(0) != AUX_TRACE_RAND_ELEMENTS
+    {
+        return Err(VerificationError::UnexpectedTraceLayout {
+            main_width: layout.main_trace_width(),
+            aux_width: layout.aux_trace_width(),
+        });
+    }
+
+    let trace_len = proof.trace_length();
+    if trace_len < MIN_TRACE_LEN {
+        return Err(VerificationError::TraceTooShort(trace_len));
+    }
+
+    // when the overflow table is non-empty, the `prev` address of the first row is followed by
+    // the address of each row; overflow addresses are clock cycles, and thus, must be within the
+    // trace, except for the addresses of rows initialized from the stack inputs, which are
+    // "negative" (mod p) clock cycles
+    let num_overflow_values = stack_outputs.overflow_values().len();
+    let num_overflow_addrs = stack_outputs.overflow_addrs().len();
+    if (num_overflow_values == 0 && num_overflow_addrs != 0)
+        || (num_overflow_values != 0 && num_overflow_addrs != num_overflow_values + 1)
+    {
+        return Err(VerificationError::PublicInputsMismatch(
+            PublicInputsMismatch::InconsistentStackOverflow {
+                num_values: num_overflow_values,
+                num_addrs: num_overflow_addrs,
+            },
+        ));
+    }
+    let num_init_rows = stack_inputs.values().len().saturating_sub(STACK_TOP_SIZE) as u64;
+    let min_init_addr = Felt::MODULUS - num_init_rows;
+    if let Some(&addr) = stack_outputs
+        .overflow_addrs()
+        .iter()
+        .find(|&&a| a >= trace_len as u64 && a < min_init_addr)
+    {
+        return Err(VerificationError::PublicInputsMismatch(
+            PublicInputsMismatch::OverflowAddressOutOfBounds { addr, trace_len },
+        ));
+    }
+
+    Ok(())
+}
+
 // ERRORS
 // ================================================================================================
 
-/// TODO: add docs
+/// An error which can be generated while verifying an execution proof.
+///
+/// All variants except [VerificationError::VerifierError] describe malformed public inputs or
+/// proofs which are detected before the STARK proof is checked; public inputs which are
+/// inconsistent with the proof are reported as [VerificationError::PublicInputsMismatch]. Public
+/// inputs are bound into the proof transcript; thus, well-formed public inputs which differ from
+/// the ones used by the prover (e.g., a program hash with a wrong byte order) cannot be
+/// distinguished from an invalid proof, and are reported as [VerificationError::VerifierError].
 #[derive(Debug, PartialEq, Eq)]
 pub enum VerificationError {
+    /// The STARK proof is not valid for the provided public inputs.
     VerifierError(VerifierError),
+    /// The specified stack input is not a valid field element.
     InputNotFieldElement(u64),
+    /// The specified stack output is not a valid field element.
     OutputNotFieldElement(u64),
+    /// The stack outputs contain fewer than 16 elements.
+    InsufficientStackOutputs(usize),
+    /// The program hash could not be deserialized into a valid digest.
+    InvalidProgramHash(DeserializationError),
+    /// The proof could not be deserialized.
+    ProofDeserializationFailed(DeserializationError),
+    /// A verification transcript cannot be built for proofs generated with the specified hash
+    /// function and field extension.
+    UnsupportedTranscriptOptions(HashFunction, FieldExtension),
+    /// The parameters of the proof do not satisfy the verification policy.
+    PolicyViolation(PolicyViolation),
+    /// The proof describes an execution trace with widths of the main and auxiliary segments
+    /// which differ from the ones of Miden VM execution traces.
+    UnexpectedTraceLayout { main_width: usize, aux_width: usize },
+    /// The proof describes an execution trace shorter than the minimum trace length.
+    TraceTooShort(usize),
+    /// The public inputs are inconsistent with the proof.
+    PublicInputsMismatch(PublicInputsMismatch),
+    /// The proof was generated with a hash function which is not supported by this build of the
+    /// verifier; the error contains the list of supported hash functions.
+    UnsupportedHashFunction {
+        requested: HashFunction,
+        supported: Vec<HashFunction>,
+    },
+    /// The proof was serialized in a format version which is not supported by this verifier; the
+    /// error contains the range of supported versions.
+    UnsupportedProofVersion {
+        found: u8,
+        supported: RangeInclusive<u8>,
+    },
+    /// Verification was cancelled by the caller before it completed.
+    Cancelled,
 }
 
 impl fmt::Display for VerificationError {
@@ -84,9 +437,80 @@ impl fmt::Display for VerificationError {
             VerifierError(e) => write!(f, "{e}"),
             InputNotFieldElement(i) => write!(f, "the input {i} is not a valid field element!"),
             OutputNotFieldElement(o) => write!(f, "the output {o} is not a valid field element!"),
+            InsufficientStackOutputs(n) => {
+                write!(f, "expected at least {STACK_TOP_SIZE} stack outputs, but {n} were provided")
+            }
+            InvalidProgramHash(e) => write!(f, "the program hash is not a valid digest: {e}"),
+            ProofDeserializationFailed(e) => write!(f, "failed to deserialize the proof: {e}"),
+            UnsupportedTranscriptOptions(hash_fn, field_extension) => write!(
+                f,
+                "verification transcripts are not supported for {hash_fn:?} proofs over {field_extension:?} field"
+            ),
+            PolicyViolation(e) => write!(f, "the proof violates the verification policy: {e}"),
+            UnexpectedTraceLayout { main_width, aux_width } => write!(
+                f,
+                "the proof describes a trace with {main_width} main and {aux_width} auxiliary columns, \
+                but Miden VM traces have {TRACE_WIDTH} main and {AUX_TRACE_WIDTH} auxiliary columns"
+            ),
+            TraceTooShort(len) => write!(
+                f,
+                "the proof describes a trace of length {len}, but the minimum trace length is {MIN_TRACE_LEN}"
+            ),
+            PublicInputsMismatch(e) => {
+                write!(f, "the public inputs are inconsistent with the proof: {e}")
+            }
+            UnsupportedHashFunction { requested, supported } => {
+                write!(f, "proofs generated with {requested} hash function are not supported by this verifier")?;
+                write!(f, "; supported hash functions: ")?;
+                for (i, hash_fn) in supported.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{hash_fn}")?;
+                }
+                Ok(())
+            }
+            UnsupportedProofVersion { found, supported } => write!(
+                f,
+                "proof format version {found} is not supported by this verifier; supported versions: {}..={}",
+                supported.start(),
+                supported.end()
+            ),
+            Cancelled => write!(f, "verification was cancelled"),
         }
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for VerificationError {}
+
+/// An inconsistency between the public inputs and the proof which is detected before the STARK
+/// proof is checked; the variants name the public input which is inconsistent with the proof.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PublicInputsMismatch {
+    /// The number of stack overflow addresses in the stack outputs is inconsistent with the number
+    /// of stack outputs stored in the overflow table.
+    InconsistentStackOverflow { num_values: usize, num_addrs: usize },
+    /// A stack overflow address in the stack outputs is neither smaller than the length of the
+    /// execution trace nor the address of an overflow table row initialized from the stack inputs.
+    OverflowAddressOutOfBounds { addr: u64, trace_len: usize },
+}
+
+impl fmt::Display for PublicInputsMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use PublicInputsMismatch::*;
+        match self {
+            InconsistentStackOverflow { num_values, num_addrs } => write!(
+                f,
+                "{num_addrs} stack overflow addresses are inconsistent with {num_values} stack overflow values"
+            ),
+            OverflowAddressOutOfBounds { addr, trace_len } => write!(
+                f,
+                "stack overflow address {addr} is outside of the execution trace of length {trace_len}"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PublicInputsMismatch {}
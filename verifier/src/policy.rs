@@ -0,0 +1,212 @@
+use super::{verify, VerificationError};
+use air::{ExecutionProof, FieldExtension, HashFunction, ProofOptions};
+use core::fmt;
+use vm_core::{utils::collections::Vec, ProgramInfo, StackInputs, StackOutputs};
+
+#[cfg(feature = "serde")]
+use air::{field_extension_name, parse_field_extension};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "serde")]
+use vm_core::utils::string::{String, ToString};
+
+// VERIFICATION POLICY
+// ================================================================================================
+
+/// A set of requirements which the parameters of a proof must satisfy for the proof to be
+/// accepted by [verify_with_policy()].
+///
+/// Proof options are chosen by the prover and are embedded into the proof; [verify()] accepts
+/// proofs generated with any valid options. A policy allows the verifier to reject proofs which
+/// are valid but were generated with weaker parameters than the ones expected by the verifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerificationPolicy {
+    /// The minimum blowup factor of the low-degree extension domain.
+    pub min_blowup_factor: usize,
+    /// The minimum number of queries.
+    pub min_num_queries: usize,
+    /// The minimum number of bits of proof-of-work.
+    pub min_grinding_factor: u32,
+    /// Hash functions which the proof is allowed to be generated with.
+    pub allowed_hash_fns: Vec<HashFunction>,
+    /// Field extensions which the proof is allowed to be generated with.
+    pub allowed_field_extensions: Vec<FieldExtension>,
+}
+
+impl VerificationPolicy {
+    /// Returns a policy which accepts only proofs generated with parameters at least as strong as
+    /// the specified options.
+    ///
+    /// Blowup factor, number of queries, and grinding factor of the proof must not be smaller than
+    /// the ones of the specified options, and the proof must be generated using the same hash
+    /// function and field extension.
+    pub fn from_options(options: &ProofOptions) -> Self {
+        Self {
+            min_blowup_factor: options.blowup_factor(),
+            min_num_queries: options.num_queries(),
+            min_grinding_factor: options.grinding_factor(),
+            allowed_hash_fns: Vec::from([options.hash_fn()]),
+            allowed_field_extensions: Vec::from([options.field_extension()]),
+        }
+    }
+
+    /// Checks that the parameters of the specified proof satisfy this policy.
+    ///
+    /// # Errors
+    /// Returns an error describing the first requirement of this policy which the proof does not
+    /// satisfy.
+    pub fn check(&self, proof: &ExecutionProof) -> Result<(), PolicyViolation> {
+        let hash_fn = proof.hash_fn();
+        if !self.allowed_hash_fns.contains(&hash_fn) {
+            return Err(PolicyViolation::HashFunctionNotAllowed(hash_fn));
+        }
+
+        let options = proof.stark_proof().options();
+        let field_extension = options.field_extension();
+        if !self.allowed_field_extensions.contains(&field_extension) {
+            return Err(PolicyViolation::FieldExtensionNotAllowed(field_extension));
+        }
+        if options.blowup_factor() < self.min_blowup_factor {
+            return Err(PolicyViolation::BlowupFactorTooSmall {
+                actual: options.blowup_factor(),
+                min: self.min_blowup_factor,
+            });
+        }
+        if options.num_queries() < self.min_num_queries {
+            return Err(PolicyViolation::NumQueriesTooSmall {
+                actual: options.num_queries(),
+                min: self.min_num_queries,
+            });
+        }
+        if options.grinding_factor() < self.min_grinding_factor {
+            return Err(PolicyViolation::GrindingFactorTooSmall {
+                actual: options.grinding_factor(),
+                min: self.min_grinding_factor,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Serialized representation of [VerificationPolicy].
+///
+/// Field extensions are encoded by name (i.e., "none", "quadratic", or "cubic"), as accepted by
+/// [parse_field_extension()].
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "VerificationPolicy")]
+struct VerificationPolicyRepr {
+    min_blowup_factor: usize,
+    min_num_queries: usize,
+    min_grinding_factor: u32,
+    allowed_hash_fns: Vec<HashFunction>,
+    allowed_field_extensions: Vec<String>,
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for VerificationPolicy {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        VerificationPolicyRepr {
+            min_blowup_factor: self.min_blowup_factor,
+            min_num_queries: self.min_num_queries,
+            min_grinding_factor: self.min_grinding_factor,
+            allowed_hash_fns: self.allowed_hash_fns.clone(),
+            allowed_field_extensions: self
+                .allowed_field_extensions
+                .iter()
+                .map(|&field_extension| field_extension_name(field_extension).to_string())
+                .collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for VerificationPolicy {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = VerificationPolicyRepr::deserialize(deserializer)?;
+        let allowed_field_extensions = repr
+            .allowed_field_extensions
+            .into_iter()
+            .map(|name| parse_field_extension(&name).map_err(de::Error::custom))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            min_blowup_factor: repr.min_blowup_factor,
+            min_num_queries: repr.min_num_queries,
+            min_grinding_factor: repr.min_grinding_factor,
+            allowed_hash_fns: repr.allowed_hash_fns,
+            allowed_field_extensions,
+        })
+    }
+}
+
+// VERIFIER
+// ================================================================================================
+
+/// Returns the security level of the proof if the proof satisfies the specified `policy` and the
+/// specified program was executed correctly against the specified inputs and outputs.
+///
+/// The parameters of the proof are checked against the policy before the proof itself is
+/// verified; stack inputs and outputs are expected to be ordered in the same way as for
+/// [verify()].
+///
+/// # Errors
+/// Returns an error if:
+/// - The parameters of the proof do not satisfy the policy.
+/// - The proof fails verification (see [verify()]).
+pub fn verify_with_policy(
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    proof: ExecutionProof,
+    policy: &VerificationPolicy,
+) -> Result<u32, VerificationError> {
+    policy.check(&proof).map_err(VerificationError::PolicyViolation)?;
+    verify(program_info, stack_inputs, stack_outputs, proof)
+}
+
+// ERRORS
+// ================================================================================================
+
+/// A requirement of a [VerificationPolicy] which the parameters of a proof do not satisfy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolicyViolation {
+    /// The proof was generated using a hash function which is not allowed by the policy.
+    HashFunctionNotAllowed(HashFunction),
+    /// The proof was generated using a field extension which is not allowed by the policy.
+    FieldExtensionNotAllowed(FieldExtension),
+    /// The blowup factor of the proof is smaller than the minimum required by the policy.
+    BlowupFactorTooSmall { actual: usize, min: usize },
+    /// The number of queries of the proof is smaller than the minimum required by the policy.
+    NumQueriesTooSmall { actual: usize, min: usize },
+    /// The grinding factor of the proof is smaller than the minimum required by the policy.
+    GrindingFactorTooSmall { actual: u32, min: u32 },
+}
+
+impl fmt::Display for PolicyViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use PolicyViolation::*;
+        match self {
+            HashFunctionNotAllowed(hash_fn) => {
+                write!(f, "proofs generated with {hash_fn} hash function are not allowed")
+            }
+            FieldExtensionNotAllowed(field_extension) => {
+                write!(f, "proofs over {field_extension:?} field extension are not allowed")
+            }
+            BlowupFactorTooSmall { actual, min } => {
+                write!(f, "blowup factor {actual} is smaller than the required minimum of {min}")
+            }
+            NumQueriesTooSmall { actual, min } => {
+                write!(
+                    f,
+                    "number of queries {actual} is smaller than the required minimum of {min}"
+                )
+            }
+            GrindingFactorTooSmall { actual, min } => {
+                write!(f, "grinding factor {actual} is smaller than the required minimum of {min}")
+            }
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use super::{transcript::build_transcript, verify, VerificationError, VerifierError};
+use air::{ExecutionProof, FieldExtension, HashFunction, PublicInputs};
+use core::time::Duration;
+use vm_core::{
+    crypto::{hash::ElementHasher, random::RandomCoin},
+    utils::collections::Vec,
+    Felt, FieldElement, ProgramInfo, QuadExtension, StackInputs, StackOutputs,
+};
+
+#[cfg(feature = "rpo")]
+use vm_core::crypto::{hash::Rpo256, random::RpoRandomCoin};
+#[cfg(feature = "blake3")]
+use vm_core::crypto::{
+    hash::{Blake3_192, Blake3_256},
+    random::WinterRandomCoin,
+};
+use winter_verifier::{math::fields::CubeExtension, StarkProof};
+
+// VERIFICATION REPORT
+// ================================================================================================
+
+/// A summary of the work performed by the verifier while verifying an execution proof.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerificationReport {
+    /// Security level of the proof (in bits), as returned by [verify()].
+    pub security_level: u32,
+    /// Number of FRI layers committed to by the prover (not counting the FRI remainder).
+    pub num_fri_layers: usize,
+    /// Number of positions in the LDE domain at which the commitments were queried; query
+    /// positions drawn by the verifier are distinct.
+    pub num_queries: usize,
+    /// Out-of-domain evaluation point, given as its coordinates over the base field (i.e., one
+    /// element for proofs without field extension, and two or three elements for proofs over
+    /// quadratic or cubic extension fields respectively).
+    pub ood_point: Vec<Felt>,
+    /// Time spent verifying the proof; always `None` when the `std` feature is disabled.
+    pub verification_time: Option<Duration>,
+}
+
+// VERIFIER
+// ================================================================================================
+
+/// Verifies the proof in the same way as [verify()] and returns a report describing the work
+/// performed by the verifier.
+///
+/// Only the time spent in [verify()] is included in [VerificationReport::verification_time];
+/// deriving the rest of the report requires replaying the Fiat-Shamir interaction with the
+/// prover, which takes additional time.
+///
+/// # Errors
+/// Returns an error if the proof fails verification (see [verify()]).
+pub fn verify_verbose(
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    proof: ExecutionProof,
+) -> Result<VerificationReport, VerificationError> {
+    let pub_inputs =
+        PublicInputs::new(program_info.clone(), stack_inputs.clone(), stack_outputs.clone());
+    let (hash_fn, stark_proof) = proof.clone().into_parts();
+
+    #[cfg(feature = "std")]
+    let now = std::time::Instant::now();
+    let security_level = verify(program_info, stack_inputs, stack_outputs, proof)?;
+    #[cfg(feature = "std")]
+    let verification_time = Some(now.elapsed());
+    #[cfg(not(feature = "std"))]
+    let verification_time = None;
+
+    let (num_fri_layers, num_queries, ood_point) = match hash_fn {
+        #[cfg(feature = "blake3")]
+        HashFunction::Blake3_192 => {
+            replay_with_field::<Blake3_192, WinterRandomCoin<_>>(stark_proof, pub_inputs)
+        }
+        #[cfg(feature = "blake3")]
+        HashFunction::Blake3_256 => {
+            replay_with_field::<Blake3_256, WinterRandomCoin<_>>(stark_proof, pub_inputs)
+        }
+        #[cfg(feature = "rpo")]
+        HashFunction::Rpo256 => replay_with_field::<Rpo256, RpoRandomCoin>(stark_proof, pub_inputs),
+        // proofs with unsupported hash functions are rejected by verify() above
+        #[allow(unreachable_patterns)]
+        _ => unreachable!("hash function support is checked by verify()"),
+    }
+    .map_err(VerificationError::VerifierError)?;
+
+    Ok(VerificationReport {
+        security_level,
+        num_fri_layers,
+        num_queries,
+        ood_point,
+        verification_time,
+    })
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Replays the interaction between the prover and the verifier over the field extension
+/// specified by the proof options; see [replay()].
+fn replay_with_field<H, R>(
+    proof: StarkProof,
+    pub_inputs: PublicInputs,
+) -> Result<(usize, usize, Vec<Felt>), VerifierError>
+where
+    H: ElementHasher<BaseField = Felt>,
+    R: RandomCoin<BaseField = Felt, Hasher = H>,
+{
+    match proof.options().field_extension() {
+        FieldExtension::None => replay::<Felt, H, R>(proof, pub_inputs),
+        FieldExtension::Quadratic => replay::<QuadExtension<Felt>, H, R>(proof, pub_inputs),
+        FieldExtension::Cubic => replay::<CubeExtension<Felt>, H, R>(proof, pub_inputs),
+    }
+}
+
+/// Replays the interaction between the prover and the verifier for a proof which is known to be
+/// valid, and returns the number of FRI layers, the number of distinct query positions, and the
+/// out-of-domain point.
+///
+/// The interaction is replayed by [build_transcript()], and thus the report is derived from the
+/// same values as the transcript returned by
+/// [verify_with_transcript()](crate::verify_with_transcript).
+fn replay<E, H, R>(
+    proof: StarkProof,
+    pub_inputs: PublicInputs,
+) -> Result<(usize, usize, Vec<Felt>), VerifierError>
+where
+    E: FieldElement<BaseField = Felt>,
+    H: ElementHasher<BaseField = Felt>,
+    R: RandomCoin<BaseField = Felt, Hasher = H>,
+{
+    let transcript = build_transcript::<E, H, R>(proof, pub_inputs)?;
+
+    // the last FRI commitment is the commitment to the FRI remainder
+    let num_fri_layers = transcript.fri_commitments.len() - 1;
+
+    let ood_point = E::slice_as_base_elements(&[transcript.ood_point]).to_vec();
+    Ok((num_fri_layers, transcript.query_positions.len(), ood_point))
+}
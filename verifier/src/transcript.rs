@@ -0,0 +1,198 @@
+use super::{verify, VerificationError, VerifierError};
+use air::{ExecutionProof, FieldExtension, HashFunction, ProcessorAir, PublicInputs};
+use vm_core::{
+    chiplets::hasher::Digest,
+    crypto::{
+        hash::{ElementHasher, Rpo256},
+        random::{RandomCoin, RpoRandomCoin},
+    },
+    utils::{collections::Vec, string::ToString},
+    Felt, FieldElement, ProgramInfo, QuadExtension, StackInputs, StackOutputs, ToElements,
+};
+use winter_verifier::{Air, StarkProof};
+
+// VERIFICATION TRANSCRIPT
+// ================================================================================================
+
+/// Quadratic extension of the base field over which challenges of a recursive proof are drawn.
+pub type QuadFelt = QuadExtension<Felt>;
+
+/// Commitments sent by the prover and challenges drawn by the verifier while verifying an
+/// execution proof.
+///
+/// These are the values a recursive verifier (i.e., a verifier of a Miden VM proof executed
+/// inside another proof system) needs to re-derive from the public coin. Fields are listed in the
+/// order in which they are absorbed into or drawn from the public coin:
+/// 1. Commitments to the main and auxiliary execution trace segments, and the random elements
+///    drawn after the main trace commitment (used to build the auxiliary trace).
+/// 2. Coefficients for combining transition and boundary constraints into the constraint
+///    composition polynomial.
+/// 3. Commitment to the constraint composition polynomial, and the out-of-domain point `z`.
+/// 4. Coefficients for building the DEEP composition polynomial.
+/// 5. FRI layer commitments (the last one being the commitment to the FRI remainder), and the
+///    folding challenge drawn after each of them.
+/// 6. Proof-of-work nonce, and the query positions drawn from the public coin.
+///
+/// The set, meaning, and order of the fields will not change across patch releases; any changes
+/// to the layout of this struct will be marked as breaking in the changelog.
+///
+/// The type parameters specify the field from which challenges are drawn and the digest type of
+/// the hash function used for commitments; transcripts returned by [verify_with_transcript()]
+/// always use the defaults.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerificationTranscript<E = QuadFelt, D = Digest> {
+    /// Commitments to the execution trace segments (the main segment first).
+    pub trace_commitments: Vec<D>,
+    /// Random elements drawn for building the auxiliary trace segment.
+    pub aux_rand_elements: Vec<E>,
+    /// Composition coefficients for transition constraints.
+    pub transition_coefficients: Vec<E>,
+    /// Composition coefficients for boundary constraints.
+    pub boundary_coefficients: Vec<E>,
+    /// Commitment to the constraint composition polynomial.
+    pub constraint_commitment: D,
+    /// Out-of-domain evaluation point.
+    pub ood_point: E,
+    /// DEEP composition coefficients for the trace polynomials.
+    pub deep_trace_coefficients: Vec<E>,
+    /// DEEP composition coefficients for the constraint composition polynomial columns.
+    pub deep_constraint_coefficients: Vec<E>,
+    /// Commitments to the FRI layers (the remainder commitment last).
+    pub fri_commitments: Vec<D>,
+    /// FRI folding challenges; one per FRI layer commitment.
+    pub fri_alphas: Vec<E>,
+    /// Proof-of-work nonce which seeds the query positions.
+    pub pow_nonce: u64,
+    /// Positions in the LDE domain at which the trace and constraint commitments are queried.
+    pub query_positions: Vec<usize>,
+}
+
+// VERIFIER
+// ================================================================================================
+
+/// Verifies the proof in the same way as [verify()] and returns the commitments and the
+/// Fiat-Shamir challenges derived during verification.
+///
+/// Only proofs generated using the RPO hash function over a quadratic extension field (i.e., proofs
+/// generated with [ProofOptions::with_96_bit_security(true)](air::ProofOptions) or similar
+/// options) are supported, as these are the only proofs which can be verified recursively.
+///
+/// # Errors
+/// Returns an error if:
+/// - The proof was not generated using RPO hash function over a quadratic extension field.
+/// - The proof fails verification (see [verify()]).
+pub fn verify_with_transcript(
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    proof: ExecutionProof,
+) -> Result<VerificationTranscript, VerificationError> {
+    let hash_fn = proof.hash_fn();
+    let field_extension = proof.stark_proof().options().field_extension();
+    if hash_fn != HashFunction::Rpo256 || field_extension != FieldExtension::Quadratic {
+        return Err(VerificationError::UnsupportedTranscriptOptions(hash_fn, field_extension));
+    }
+
+    let pub_inputs =
+        PublicInputs::new(program_info.clone(), stack_inputs.clone(), stack_outputs.clone());
+    verify(program_info, stack_inputs, stack_outputs, proof.clone())?;
+
+    let (_, proof) = proof.into_parts();
+    build_transcript::<QuadFelt, Rpo256, RpoRandomCoin>(proof, pub_inputs)
+        .map_err(VerificationError::VerifierError)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Replays the interaction between the prover and the verifier for a proof which is known to be
+/// valid, and records all commitments and values drawn from the public coin.
+///
+/// This mirrors the order of operations of the STARK verifier in `winter-verifier`, and is used
+/// both for building transcripts and for deriving [VerificationReport](crate::VerificationReport)
+/// values.
+pub(crate) fn build_transcript<E, H, R>(
+    proof: StarkProof,
+    pub_inputs: PublicInputs,
+) -> Result<VerificationTranscript<E, H::Digest>, VerifierError>
+where
+    E: FieldElement<BaseField = Felt>,
+    H: ElementHasher<BaseField = Felt>,
+    R: RandomCoin<BaseField = Felt, Hasher = H>,
+{
+    let mut seed = proof.context.to_elements();
+    seed.append(&mut pub_inputs.to_elements());
+    let mut public_coin = R::new(&seed);
+
+    let air = ProcessorAir::new(proof.get_trace_info(), pub_inputs, proof.options().clone());
+    let StarkProof {
+        commitments,
+        ood_frame,
+        pow_nonce,
+        ..
+    } = proof;
+
+    // parse commitments and out-of-domain evaluations sent by the prover
+    let num_fri_layers = air.options().to_fri_options().num_fri_layers(air.lde_domain_size());
+    let (trace_commitments, constraint_commitment, fri_commitments) = commitments
+        .parse::<H>(air.trace_layout().num_segments(), num_fri_layers)
+        .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+    let (ood_trace_evaluations, ood_constraint_evaluations) = ood_frame
+        .parse::<E>(
+            air.trace_layout().main_trace_width(),
+            air.trace_layout().aux_trace_width(),
+            air.context().num_constraint_composition_columns(),
+        )
+        .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+
+    // trace commitments
+    public_coin.reseed(trace_commitments[0]);
+    let mut aux_rand_elements = Vec::new();
+    for (i, commitment) in trace_commitments.iter().skip(1).enumerate() {
+        let mut rand_elements = air
+            .get_aux_trace_segment_random_elements::<E, _>(i, &mut public_coin)
+            .map_err(|_| VerifierError::RandomCoinError)?;
+        aux_rand_elements.append(&mut rand_elements);
+        public_coin.reseed(*commitment);
+    }
+    let constraint_coeffs = air
+        .get_constraint_composition_coefficients::<E, _>(&mut public_coin)
+        .map_err(|_| VerifierError::RandomCoinError)?;
+
+    // constraint commitment and out-of-domain point
+    public_coin.reseed(constraint_commitment);
+    let ood_point = public_coin.draw::<E>().map_err(|_| VerifierError::RandomCoinError)?;
+    public_coin.reseed(H::hash_elements(&ood_trace_evaluations));
+    public_coin.reseed(H::hash_elements(&ood_constraint_evaluations));
+
+    // DEEP composition coefficients and FRI commitments
+    let deep_coeffs = air
+        .get_deep_composition_coefficients::<E, _>(&mut public_coin)
+        .map_err(|_| VerifierError::RandomCoinError)?;
+    let mut fri_alphas = Vec::with_capacity(fri_commitments.len());
+    for commitment in fri_commitments.iter() {
+        public_coin.reseed(*commitment);
+        fri_alphas.push(public_coin.draw().map_err(|_| VerifierError::RandomCoinError)?);
+    }
+
+    // query positions
+    public_coin.reseed_with_int(pow_nonce);
+    let query_positions = public_coin
+        .draw_integers(air.options().num_queries(), air.lde_domain_size())
+        .map_err(|_| VerifierError::RandomCoinError)?;
+
+    Ok(VerificationTranscript {
+        trace_commitments,
+        aux_rand_elements,
+        transition_coefficients: constraint_coeffs.transition,
+        boundary_coefficients: constraint_coeffs.boundary,
+        constraint_commitment,
+        ood_point,
+        deep_trace_coefficients: deep_coeffs.trace,
+        deep_constraint_coefficients: deep_coeffs.constraints,
+        fri_commitments,
+        fri_alphas,
+        pow_nonce,
+        query_positions,
+    })
+}